@@ -73,6 +73,10 @@ impl CatalogProviderList for DynamicFileCatalog {
             .catalog(name)
             .map(|catalog| Arc::new(DynamicFileCatalogProvider::new(catalog, state)) as _)
     }
+
+    fn deregister_catalog(&self, name: &str) -> Result<Option<Arc<dyn CatalogProvider>>> {
+        self.inner.deregister_catalog(name)
+    }
 }
 
 /// Wraps another catalog provider
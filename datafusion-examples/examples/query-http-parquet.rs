@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{ArrayRef, BooleanArray, Int32Array, RecordBatch, StringArray};
+use datafusion::common::runtime::SpawnedTask;
+use datafusion::error::Result;
+use datafusion::parquet::arrow::ArrowWriter;
+use datafusion::prelude::*;
+use object_store::http::HttpBuilder;
+use std::fs::File;
+use std::net::SocketAddr;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tempfile::tempdir;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use url::Url;
+
+/// This example demonstrates querying a Parquet file served by a plain HTTP
+/// server without downloading the whole file.
+///
+/// The `object_store` HTTP store learns the file size from a `HEAD` request
+/// and fetches the footer and the projected column chunks with `Range`
+/// requests, so only the bytes needed by the query are transferred. HTTP has
+/// no directory listing, so tables must point at individual files.
+///
+/// To run without network access, the example serves a Parquet file it
+/// writes to a temporary directory from a minimal local HTTP server, which
+/// prints the requests it receives.
+#[tokio::main]
+async fn main() -> Result<()> {
+    // write a parquet file and serve it over http
+    let dir = tempdir()?;
+    write_parquet_file(&dir.path().join("alltypes.parquet"))?;
+    let (addr, _server) = serve_files(dir.path().to_path_buf()).await?;
+
+    // create local execution context
+    let ctx = SessionContext::new();
+
+    // setup http object store
+    let base_url = Url::parse(&format!("http://{addr}")).unwrap();
+    let http_store = HttpBuilder::new()
+        .with_url(base_url.clone())
+        .with_client_options(object_store::ClientOptions::new().with_allow_http(true))
+        .build()?;
+    ctx.register_object_store(&base_url, Arc::new(http_store));
+
+    // register parquet file with the execution context
+    ctx.register_parquet(
+        "alltypes",
+        &format!("{base_url}alltypes.parquet"),
+        ParquetReadOptions::default(),
+    )
+    .await?;
+
+    // execute the query, only the `id` and `bool_col` column chunks are read,
+    // `string_col` is never fetched
+    let df = ctx
+        .sql("SELECT id, bool_col FROM alltypes WHERE id > 1 LIMIT 5")
+        .await?;
+
+    // print the results
+    df.show().await?;
+
+    Ok(())
+}
+
+/// Writes a Parquet file with a few columns to `path`
+fn write_parquet_file(path: &Path) -> Result<()> {
+    let num_rows = 1000;
+    let ids = Int32Array::from_iter_values(0..num_rows);
+    let bools = BooleanArray::from_iter((0..num_rows).map(|i| Some(i % 2 == 0)));
+    let strings =
+        StringArray::from_iter_values((0..num_rows).map(|i| format!("value {i}")));
+    let batch = RecordBatch::try_from_iter(vec![
+        ("id", Arc::new(ids) as ArrayRef),
+        ("bool_col", Arc::new(bools) as ArrayRef),
+        ("string_col", Arc::new(strings) as ArrayRef),
+    ])?;
+
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+/// Serves the files in `dir` over HTTP on a local port, returning its address
+/// and the task serving the requests, which stops when dropped.
+///
+/// Only `HEAD` requests and `GET` requests for the whole file or for a single
+/// byte range are supported, which is all the `object_store` HTTP store
+/// needs to read a file. Requests are answered one at a time.
+async fn serve_files(dir: PathBuf) -> Result<(SocketAddr, SpawnedTask<()>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let server = SpawnedTask::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            if let Err(e) = handle_request(stream, &dir).await {
+                eprintln!("Failed to handle request: {e}");
+            }
+        }
+    });
+    Ok((addr, server))
+}
+
+/// Answers a single request and closes the connection
+async fn handle_request(stream: TcpStream, dir: &Path) -> std::io::Result<()> {
+    let mut stream = BufReader::new(stream);
+    let mut request_line = String::new();
+    stream.read_line(&mut request_line).await?;
+    let mut range = None;
+    loop {
+        let mut line = String::new();
+        stream.read_line(&mut line).await?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+    let file = dir.join(path.trim_start_matches('/'));
+    let (status, headers, body) = match std::fs::read(file) {
+        Err(_) => ("404 Not Found", String::new(), vec![]),
+        Ok(data) if method == "HEAD" => {
+            println!("HEAD {path}");
+            let headers = format!("Content-Length: {}\r\n", data.len());
+            ("200 OK", headers, vec![])
+        }
+        Ok(data) => match range.and_then(|r| parse_range(&r, data.len())) {
+            Some(r) => {
+                let (start, end, len) = (r.start, r.end - 1, data.len());
+                println!("GET {path} bytes {start}-{end}");
+                let body = data[r].to_vec();
+                let headers = format!(
+                    "Content-Range: bytes {start}-{end}/{len}\r\nContent-Length: {}\r\n",
+                    body.len()
+                );
+                ("206 Partial Content", headers, body)
+            }
+            None => {
+                println!("GET {path}");
+                let headers = format!("Content-Length: {}\r\n", data.len());
+                ("200 OK", headers, data)
+            }
+        },
+    };
+
+    let mut stream = stream.into_inner();
+    let head = format!("HTTP/1.1 {status}\r\n{headers}Connection: close\r\n\r\n");
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&body).await?;
+    stream.shutdown().await
+}
+
+/// Parses a `Range` header for a single range of a file of `len` bytes
+fn parse_range(range: &str, len: usize) -> Option<Range<usize>> {
+    let (start, end) = range.strip_prefix("bytes=")?.split_once('-')?;
+    let range = match (start.parse::<usize>(), end.parse::<usize>()) {
+        (Ok(start), Ok(end)) => start..(end + 1).min(len),
+        (Ok(start), Err(_)) => start..len,
+        (Err(_), Ok(suffix)) => len.saturating_sub(suffix)..len,
+        (Err(_), Err(_)) => return None,
+    };
+    (range.start < range.end).then_some(range)
+}
@@ -170,4 +170,14 @@ pub trait CatalogProviderList: Sync + Send {
 
     /// Retrieves a specific catalog by name, provided it exists.
     fn catalog(&self, name: &str) -> Option<Arc<dyn CatalogProvider>>;
+
+    /// Removes a catalog from this catalog list, and returns it if it existed.
+    ///
+    /// By default returns a "Not Implemented" error
+    fn deregister_catalog(
+        &self,
+        _name: &str,
+    ) -> Result<Option<Arc<dyn CatalogProvider>>> {
+        not_impl_err!("Deregistering catalogs is not supported")
+    }
 }
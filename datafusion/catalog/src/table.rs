@@ -73,6 +73,21 @@ pub trait TableProvider: Sync + Send {
     /// The `ExecutionPlan` is responsible scanning the datasource's
     /// partitions in a streaming, parallelized fashion.
     ///
+    /// # Session
+    ///
+    /// `state` is the [`Session`] planning the query. Providers should read
+    /// their settings from it rather than from global state: the batch size
+    /// and target partitions from [`Session::config`], and the memory pool,
+    /// disk manager and object store registry from [`Session::runtime_env`].
+    ///
+    /// The returned `ExecutionPlan` is passed the [`TaskContext`] of the
+    /// query in [`ExecutionPlan::execute`], which carries the same session
+    /// id, configuration and runtime. Memory used while executing should be
+    /// accounted with a [`MemoryReservation`] from its memory pool.
+    ///
+    /// [`TaskContext`]: datafusion_execution::TaskContext
+    /// [`MemoryReservation`]: datafusion_execution::memory_pool::MemoryReservation
+    ///
     /// # Projection
     ///
     /// If specified, only a subset of columns should be returned, in the order
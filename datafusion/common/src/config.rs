@@ -287,6 +287,15 @@ config_namespace! {
         /// batches and merged.
         pub sort_in_place_threshold_bytes: usize, default = 1024 * 1024
 
+        /// Should hash joins spill to disk when their build side does not fit
+        /// in memory. If true, both inputs are hash partitioned on the join keys
+        /// into spill files, which are then joined one pair of partitions at a
+        /// time. Joins whose probe side is sorted never spill, as spilling does
+        /// not preserve its order.
+        ///
+        /// Note: This setting is irrelevant if there's no `DiskManager` configured.
+        pub enable_hash_join_spill: bool, default = false
+
         /// Number of files to read in parallel when inferring schema and statistics
         pub meta_fetch_concurrency: usize, default = 32
 
@@ -314,6 +323,19 @@ config_namespace! {
         /// tables (e.g. `/table/year=2021/month=01/data.parquet`).
         pub listing_table_ignore_subdirectory: bool, default = true
 
+        /// Should listing a table skip the files and directories that do not
+        /// exist or cannot be read for lack of permissions, instead of failing
+        /// the query. Other errors, such as network errors, still fail the
+        /// query. The skipped paths are logged as warnings.
+        pub listing_table_skip_unreadable_files: bool, default = false
+
+        /// Should listing a table return its files sorted by path, so that the
+        /// files, and the plans and inferred schemas depending on their order,
+        /// are the same across runs and platforms. Sorting requires the whole
+        /// listing to be collected in memory before the first file is returned,
+        /// instead of streaming it from the object store.
+        pub listing_table_sort_files: bool, default = false
+
         /// Should DataFusion support recursive CTEs
         pub enable_recursive_ctes: bool, default = true
 
@@ -341,6 +363,16 @@ config_namespace! {
         /// if the source of statistics is accurate.
         /// We plan to make this the default in the future.
         pub use_row_number_estimates_to_optimize_partitioning: bool, default = false
+
+        /// Scale of the result of dividing decimals. If not set, the result has the
+        /// scale of the dividend plus 4 and the remaining digits are truncated
+        pub decimal_division_scale: Option<usize>, default = None
+
+        /// Rounding mode of decimal division with `decimal_division_scale` set, of casts
+        /// of decimals to a smaller scale and of `round` on decimals. Valid values are
+        /// `half_up`, rounding ties away from zero, and `half_even`, rounding ties to
+        /// the even neighbor. Setting any other value is an error
+        pub decimal_rounding_mode: DecimalRoundingMode, default = DecimalRoundingMode::HalfUp
     }
 }
 
@@ -490,6 +522,15 @@ config_namespace! {
         /// (reading) If true, parquet reader will read columns of `Utf8/Utf8Large` with `Utf8View`,
         /// and `Binary/BinaryLarge` with `BinaryView`.
         pub schema_force_string_view: bool, default = false
+
+        /// (reading) If true, a row group that fails to decode is skipped,
+        /// after logging a warning, and the scan continues with the next row
+        /// group instead of failing the query. Batches decoded from the row
+        /// group before the error are kept. Errors fetching the data or
+        /// evaluating pushed down filters still fail the query. The number of
+        /// skipped row groups is reported by the `corrupt_row_groups_skipped`
+        /// metric.
+        pub skip_corrupt_row_groups: bool, default = false
     }
 }
 
@@ -615,6 +656,11 @@ config_namespace! {
         /// HashJoin can work more efficiently than SortMergeJoin but consumes more memory
         pub prefer_hash_join: bool, default = true
 
+        /// When set to true, the physical optimizer will use a SortMergeJoin instead of
+        /// a HashJoin when both inputs are already sorted on the join keys, avoiding
+        /// building a hash table and sorting the repartitioned inputs
+        pub prefer_sort_merge_join_for_sorted_inputs: bool, default = false
+
         /// The maximum estimated size in bytes for one input side of a HashJoin
         /// will be collected into a single partition
         pub hash_join_single_partition_threshold: usize, default = 1024 * 1024
@@ -1033,6 +1079,57 @@ impl ConfigField for CompressionTypeVariant {
     }
 }
 
+/// How decimal values are rounded when digits are dropped, see
+/// `datafusion.execution.decimal_rounding_mode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalRoundingMode {
+    /// Round ties away from zero, e.g. 2.5 to 3 and -2.5 to -3
+    HalfUp,
+    /// Round ties to the even neighbor, e.g. 2.5 to 2 and 3.5 to 4
+    HalfEven,
+}
+
+impl DecimalRoundingMode {
+    /// The name of this mode, as accepted by [`FromStr`]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HalfUp => "half_up",
+            Self::HalfEven => "half_even",
+        }
+    }
+}
+
+impl Display for DecimalRoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for DecimalRoundingMode {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "half_up" => Ok(Self::HalfUp),
+            "half_even" => Ok(Self::HalfEven),
+            _ => _config_err!(
+                "Unsupported decimal rounding mode {s}, valid values are half_up and half_even"
+            ),
+        }
+    }
+}
+
+impl ConfigField for DecimalRoundingMode {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = DecimalRoundingMode::from_str(value)?;
+        Ok(())
+    }
+}
+
 /// An implementation trait used to recursively walk configuration
 pub trait Visit {
     fn some<V: Display>(&mut self, key: &str, value: V, description: &'static str);
@@ -1789,8 +1886,8 @@ mod tests {
     use std::collections::HashMap;
 
     use crate::config::{
-        ConfigEntry, ConfigExtension, ConfigFileType, ExtensionOptions, Extensions,
-        TableOptions,
+        ConfigEntry, ConfigExtension, ConfigFileType, ConfigOptions, DecimalRoundingMode,
+        ExtensionOptions, Extensions, TableOptions,
     };
 
     #[derive(Default, Debug, Clone)]
@@ -1863,6 +1960,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decimal_rounding_mode_config() {
+        let mut config = ConfigOptions::new();
+        assert_eq!(
+            config.execution.decimal_rounding_mode,
+            DecimalRoundingMode::HalfUp
+        );
+        config
+            .set("datafusion.execution.decimal_rounding_mode", "HALF_EVEN")
+            .unwrap();
+        assert_eq!(
+            config.execution.decimal_rounding_mode,
+            DecimalRoundingMode::HalfEven
+        );
+
+        let err = config
+            .set("datafusion.execution.decimal_rounding_mode", "half_down")
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Invalid or Unsupported Configuration: Unsupported decimal rounding mode half_down, valid values are half_up and half_even"
+        );
+        assert_eq!(
+            config.execution.decimal_rounding_mode,
+            DecimalRoundingMode::HalfEven
+        );
+    }
+
     #[test]
     fn csv_u8_table_options() {
         let mut table_config = TableOptions::new();
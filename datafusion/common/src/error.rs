@@ -131,6 +131,16 @@ pub enum DataFusionError {
     /// Errors from either mapping LogicalPlans to/from Substrait plans
     /// or serializing/deserializing protobytes to Substrait plans
     Substrait(String),
+    /// An error whose [`ErrorOrigin`] is known from where it was raised,
+    /// rather than from its variant, see [`DataFusionError::with_origin`].
+    ///
+    /// It displays as the wrapped error.
+    WithOrigin(ErrorOrigin, Box<DataFusionError>),
+    /// An error reported by several consumers of the same input, for
+    /// example by every output partition of a join whose build side failed.
+    ///
+    /// It displays as the shared error.
+    Shared(Arc<DataFusionError>),
 }
 
 #[macro_export]
@@ -140,6 +150,70 @@ macro_rules! context {
     };
 }
 
+/// Where a [`DataFusionError`] originated, see [`DataFusionError::origin`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorOrigin {
+    /// The query or plan was rejected before execution started, for example
+    /// because of a syntax error, an unknown column or an unsupported
+    /// feature. Retrying the same query will fail again.
+    Planning,
+    /// The query failed while running, for example because of malformed
+    /// input data, exhausted resources or a violated internal invariant.
+    Execution,
+    /// The query needs a feature DataFusion does not implement or was built
+    /// without, such as a compression codec. Retrying the same query will
+    /// fail again.
+    Unsupported,
+    /// A system outside DataFusion, such as an object store or the local
+    /// file system, reported an error. These errors may be transient.
+    External,
+}
+
+/// Returns the origin of `error` itself, or `None` if it only wraps another
+/// error, see [`DataFusionError::origin`]
+fn error_origin(error: &(dyn Error + 'static)) -> Option<ErrorOrigin> {
+    let datafusion_error = error.downcast_ref::<DataFusionError>().or_else(|| {
+        error
+            .downcast_ref::<Arc<DataFusionError>>()
+            .map(|e| e.as_ref())
+    });
+    if let Some(e) = datafusion_error {
+        return match e {
+            DataFusionError::SQL(_, _)
+            | DataFusionError::Plan(_)
+            | DataFusionError::Configuration(_)
+            | DataFusionError::SchemaError(_, _)
+            | DataFusionError::Substrait(_) => Some(ErrorOrigin::Planning),
+            DataFusionError::NotImplemented(_) => Some(ErrorOrigin::Unsupported),
+            #[cfg(feature = "object_store")]
+            DataFusionError::ObjectStore(_) => Some(ErrorOrigin::External),
+            DataFusionError::IoError(_) | DataFusionError::External(_) => {
+                Some(ErrorOrigin::External)
+            }
+            DataFusionError::Execution(_)
+            | DataFusionError::ExecutionJoin(_)
+            | DataFusionError::ResourcesExhausted(_)
+            | DataFusionError::Internal(_) => Some(ErrorOrigin::Execution),
+            // the explicit origin is handled by `DataFusionError::origin`
+            DataFusionError::WithOrigin(_, _) => None,
+            DataFusionError::Shared(_) => None,
+            _ => None,
+        };
+    }
+    if let Some(e) = error.downcast_ref::<ArrowError>() {
+        return match e {
+            ArrowError::IoError(_, _) => Some(ErrorOrigin::External),
+            ArrowError::ExternalError(_) => None,
+            _ => Some(ErrorOrigin::Execution),
+        };
+    }
+    #[cfg(feature = "object_store")]
+    if error.is::<object_store::Error>() {
+        return Some(ErrorOrigin::External);
+    }
+    error.is::<io::Error>().then_some(ErrorOrigin::External)
+}
+
 /// Schema-related errors
 #[derive(Debug)]
 pub enum SchemaError {
@@ -316,6 +390,8 @@ impl Error for DataFusionError {
             DataFusionError::External(e) => Some(e.as_ref()),
             DataFusionError::Context(_, e) => Some(e.as_ref()),
             DataFusionError::Substrait(_) => None,
+            DataFusionError::WithOrigin(_, e) => Some(e.as_ref()),
+            DataFusionError::Shared(e) => Some(e.as_ref()),
         }
     }
 }
@@ -367,6 +443,52 @@ impl DataFusionError {
         last_datafusion_error
     }
 
+    /// Classify where this error originated, so callers can decide
+    /// programmatically whether retrying may help.
+    ///
+    /// The whole chain of [`Error::source`]s is walked and the deepest error
+    /// that can be classified decides, so an I/O or object store error
+    /// wrapped in [`Self::External`], [`Self::Context`] or an [`ArrowError`]
+    /// is still [`ErrorOrigin::External`].
+    ///
+    /// Otherwise, the origin of an error wrapped by [`Self::with_origin`]
+    /// takes precedence over the origin of its variant, so that, for example,
+    /// a [`Self::Plan`] error raised while executing a plan is
+    /// [`ErrorOrigin::Execution`].
+    pub fn origin(&self) -> ErrorOrigin {
+        // Note: This is a non-recursive algorithm so we do not run
+        // out of stack space, even for long error chains.
+        let mut origin = ErrorOrigin::Execution;
+        let mut explicit_origin = None;
+        let mut error: Option<&(dyn Error + 'static)> = Some(self);
+        while let Some(e) = error {
+            if let Some(DataFusionError::WithOrigin(e_origin, _)) =
+                e.downcast_ref::<DataFusionError>()
+            {
+                explicit_origin.get_or_insert(*e_origin);
+            } else if let Some(e_origin) = error_origin(e) {
+                origin = e_origin;
+            }
+            error = e.source();
+        }
+        match explicit_origin {
+            Some(explicit_origin) if origin != ErrorOrigin::External => explicit_origin,
+            _ => origin,
+        }
+    }
+
+    /// Wraps self in [`Self::WithOrigin`], recording that it was raised in
+    /// `origin`, see [`Self::origin`].
+    ///
+    /// An error that is already classified as `origin` is returned as is.
+    pub fn with_origin(self, origin: ErrorOrigin) -> Self {
+        if self.origin() == origin {
+            self
+        } else {
+            Self::WithOrigin(origin, Box::new(self))
+        }
+    }
+
     /// wraps self in Self::Context with a description
     pub fn context(self, description: impl Into<String>) -> Self {
         Self::Context(description.into(), Box::new(self))
@@ -429,6 +551,8 @@ impl DataFusionError {
             DataFusionError::External(_) => "External error: ",
             DataFusionError::Context(_, _) => "",
             DataFusionError::Substrait(_) => "Substrait error: ",
+            DataFusionError::WithOrigin(_, _) => "",
+            DataFusionError::Shared(_) => "",
         }
     }
 
@@ -469,6 +593,8 @@ impl DataFusionError {
                 Cow::Owned(format!("{desc}\ncaused by\n{}", *err))
             }
             DataFusionError::Substrait(ref desc) => Cow::Owned(desc.to_string()),
+            DataFusionError::WithOrigin(_, ref err) => Cow::Owned(err.to_string()),
+            DataFusionError::Shared(ref err) => Cow::Owned(err.to_string()),
         }
     }
 }
@@ -644,7 +770,7 @@ pub fn unqualified_field_not_found(name: &str, schema: &DFSchema) -> DataFusionE
 mod test {
     use std::sync::Arc;
 
-    use crate::error::DataFusionError;
+    use crate::error::{DataFusionError, ErrorOrigin};
     use arrow::error::ArrowError;
 
     #[test]
@@ -699,6 +825,125 @@ mod test {
         assert_eq!(res, "Error during planning: Err");
     }
 
+    #[test]
+    fn test_error_origin() {
+        assert_eq!(
+            DataFusionError::Plan("foo".to_string()).origin(),
+            ErrorOrigin::Planning
+        );
+        assert_eq!(
+            DataFusionError::NotImplemented("foo".to_string()).origin(),
+            ErrorOrigin::Unsupported
+        );
+        assert_eq!(
+            DataFusionError::ResourcesExhausted("foo".to_string()).origin(),
+            ErrorOrigin::Execution
+        );
+        assert_eq!(
+            DataFusionError::IoError(std::io::Error::other("foo")).origin(),
+            ErrorOrigin::External
+        );
+        // wrapped errors keep the origin of their root
+        assert_eq!(
+            DataFusionError::Context(
+                "it happened!".to_string(),
+                Box::new(DataFusionError::Plan("foo".to_string())),
+            )
+            .origin(),
+            ErrorOrigin::Planning
+        );
+        assert_eq!(
+            DataFusionError::ArrowError(
+                ArrowError::ExternalError(Box::new(DataFusionError::Execution(
+                    "foo".to_string()
+                ))),
+                None,
+            )
+            .origin(),
+            ErrorOrigin::Execution
+        );
+        assert_eq!(
+            DataFusionError::ArrowError(
+                ArrowError::ComputeError("foo".to_string()),
+                None
+            )
+            .origin(),
+            ErrorOrigin::Execution
+        );
+        // wrapped I/O errors are external
+        assert_eq!(
+            DataFusionError::Context(
+                "it happened!".to_string(),
+                Box::new(DataFusionError::External(Box::new(std::io::Error::other(
+                    "foo"
+                )))),
+            )
+            .origin(),
+            ErrorOrigin::External
+        );
+        assert_eq!(
+            DataFusionError::ArrowError(
+                ArrowError::IoError("foo".to_string(), std::io::Error::other("foo")),
+                None,
+            )
+            .origin(),
+            ErrorOrigin::External
+        );
+        assert_eq!(
+            DataFusionError::Execution("foo".to_string())
+                .context("it happened!")
+                .origin(),
+            ErrorOrigin::Execution
+        );
+        assert_eq!(
+            DataFusionError::ArrowError(
+                ArrowError::ExternalError(Box::new(Arc::new(
+                    DataFusionError::NotImplemented("foo".to_string())
+                ))),
+                None,
+            )
+            .origin(),
+            ErrorOrigin::Unsupported
+        );
+        // the origin of where the error was raised takes precedence
+        let raised_in_execution =
+            DataFusionError::Plan("foo".to_string()).with_origin(ErrorOrigin::Execution);
+        assert_eq!(raised_in_execution.origin(), ErrorOrigin::Execution);
+        assert_eq!(
+            raised_in_execution.to_string(),
+            "Error during planning: foo"
+        );
+        assert_eq!(
+            DataFusionError::ArrowError(
+                ArrowError::ExternalError(Box::new(raised_in_execution)),
+                None
+            )
+            .origin(),
+            ErrorOrigin::Execution
+        );
+        // unless another system reported the error
+        assert_eq!(
+            DataFusionError::IoError(std::io::Error::other("foo"))
+                .with_origin(ErrorOrigin::Execution)
+                .origin(),
+            ErrorOrigin::External
+        );
+    }
+
+    #[test]
+    fn test_shared_error() {
+        let shared = Arc::new(DataFusionError::Execution("foo".to_string()));
+        let err = DataFusionError::Shared(Arc::clone(&shared));
+        assert_eq!(err.to_string(), "Execution error: foo");
+        assert_eq!(err.origin(), ErrorOrigin::Execution);
+        assert!(matches!(err.find_root(), DataFusionError::Execution(_)));
+
+        let err = DataFusionError::Shared(Arc::new(DataFusionError::NotImplemented(
+            "foo".to_string(),
+        )));
+        assert_eq!(err.origin(), ErrorOrigin::Unsupported);
+    }
+
     #[test]
     fn test_find_root_error() {
         do_root_test(
@@ -176,6 +176,7 @@ impl ParquetOptions {
             maximum_buffered_record_batches_per_stream: _,
             bloom_filter_on_read: _, // reads not used for writer props
             schema_force_string_view: _,
+            skip_corrupt_row_groups: _,
         } = self;
 
         let mut builder = WriterProperties::builder()
@@ -442,6 +443,7 @@ mod tests {
                 .maximum_buffered_record_batches_per_stream,
             bloom_filter_on_read: defaults.bloom_filter_on_read,
             schema_force_string_view: defaults.schema_force_string_view,
+            skip_corrupt_row_groups: defaults.skip_corrupt_row_groups,
         }
     }
 
@@ -544,6 +546,7 @@ mod tests {
                 bloom_filter_on_read: global_options_defaults.bloom_filter_on_read,
                 schema_force_string_view: global_options_defaults
                     .schema_force_string_view,
+                skip_corrupt_row_groups: global_options_defaults.skip_corrupt_row_groups,
             },
             column_specific_options,
             key_value_metadata,
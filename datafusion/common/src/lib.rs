@@ -52,8 +52,8 @@ pub use dfschema::{
     qualified_name, DFSchema, DFSchemaRef, ExprSchema, SchemaExt, ToDFSchema,
 };
 pub use error::{
-    field_not_found, unqualified_field_not_found, DataFusionError, Result, SchemaError,
-    SharedResult,
+    field_not_found, unqualified_field_not_found, DataFusionError, ErrorOrigin, Result,
+    SchemaError, SharedResult,
 };
 pub use file_options::file_type::{
     GetExt, DEFAULT_ARROW_EXTENSION, DEFAULT_AVRO_EXTENSION, DEFAULT_CSV_EXTENSION,
@@ -21,8 +21,51 @@ use std::fmt::Display;
 use std::result;
 use std::str::FromStr;
 
+use arrow::compute::kernels::cast_utils::string_to_timestamp_nanos;
+use arrow::error::ArrowError;
+use chrono::format::{parse, Parsed, StrftimeItems};
 use sqlparser::parser::ParserError;
 
+/// Formats tried, in order, for strings that are not RFC3339 timestamps
+pub const TIMESTAMP_FALLBACK_FORMATS: &[&str] = &[
+    // 2021/09/07, 2021/09/07 16:00:00, 2021/09/07 16:00:00.123
+    "%Y/%m/%d",
+    "%Y/%m/%d %H:%M:%S%.f",
+    // 07-Sep-2021, 07-Sep-2021 16:00:00
+    "%d-%b-%Y",
+    "%d-%b-%Y %H:%M:%S%.f",
+    // 2021-09-07 16:00:00 +0200, 2021-09-07 16:00:00.123 +02:00
+    "%Y-%m-%d %H:%M:%S%.f %z",
+];
+
+/// Parses `s` as a nanosecond timestamp like [`string_to_timestamp_nanos`],
+/// then with the [`TIMESTAMP_FALLBACK_FORMATS`] in order.
+///
+/// Strings without an offset are taken to be UTC. If no format matches, the
+/// error of the RFC3339 parser is returned.
+pub fn string_to_timestamp_nanos_with_fallback(
+    s: &str,
+) -> result::Result<i64, ArrowError> {
+    string_to_timestamp_nanos(s).or_else(|e| {
+        TIMESTAMP_FALLBACK_FORMATS
+            .iter()
+            .find_map(|format| {
+                let mut parsed = Parsed::new();
+                parse(&mut parsed, s, StrftimeItems::new(format)).ok()?;
+                match parsed.to_datetime() {
+                    Ok(dt) => dt.timestamp_nanos_opt(),
+                    Err(_) => parsed
+                        .to_naive_datetime_with_offset(0)
+                        .or_else(|_| parsed.to_naive_date().map(|nd| nd.into()))
+                        .ok()?
+                        .and_utc()
+                        .timestamp_nanos_opt(),
+                }
+            })
+            .ok_or(e)
+    })
+}
+
 /// Readable file compression type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CompressionTypeVariant {
@@ -74,3 +117,35 @@ impl CompressionTypeVariant {
         !matches!(self, &Self::UNCOMPRESSED)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_fallback_formats() {
+        let cases = [
+            ("2021-09-07T16:00:00", 1631030400000000000),
+            ("2021/09/07", 1630972800000000000),
+            ("2021/09/07 16:00:00", 1631030400000000000),
+            ("2021/09/07 16:00:00.5", 1631030400500000000),
+            ("07-Sep-2021", 1630972800000000000),
+            ("07-Sep-2021 16:00:00", 1631030400000000000),
+            ("2021-09-07 16:00:00 +0200", 1631023200000000000),
+            ("2021-09-07 16:00:00.5 -01:00", 1631034000500000000),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(
+                string_to_timestamp_nanos_with_fallback(s).unwrap(),
+                expected,
+                "parsing {s}"
+            );
+        }
+
+        // the error of the RFC3339 parser is reported if no format matches
+        let err = string_to_timestamp_nanos_with_fallback("2021.09.07").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Error parsing timestamp from '2021.09.07'"));
+    }
+}
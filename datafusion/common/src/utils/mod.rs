@@ -238,6 +238,13 @@ where
 /// Given a list of 0 or more already sorted columns, finds the
 /// partition ranges that would partition equally across columns.
 ///
+/// Two rows are in the same range only if they are equal on every column,
+/// i.e. the ranges are the peer groups of a window function ordered by the
+/// full tuple of `partition_columns`. Rows that tie on the first column but
+/// differ on any later column are in different ranges. Nulls are equal to
+/// each other, and as the columns are already sorted the [`SortOptions`] of
+/// each column do not change the ranges.
+///
 /// See [`partition`] for more details.
 pub fn evaluate_partition_ranges(
     num_rows: usize,
@@ -126,6 +126,12 @@ fn criterion_benchmark(c: &mut Criterion) {
             b.iter(move || case.run())
         });
 
+        c.bench_function(&format!("resort sorted {name}"), |b| {
+            let data = f(true);
+            let case = BenchCase::sort(&data);
+            b.iter(move || case.run())
+        });
+
         c.bench_function(&format!("sort merge {name}"), |b| {
             let data = f(false);
             let case = BenchCase::sort_merge(&data);
@@ -197,8 +203,9 @@ impl BenchCase {
         }
     }
 
-    /// Test SortExec in "partitioned" mode which sorts the input streams
-    /// individually into some number of output streams
+    /// Test CoalescePartitionsExec followed by a SortExec, which sorts all
+    /// the input streams together into a single output stream, even if the
+    /// streams are already sorted
     fn sort(partitions: &[Vec<RecordBatch>]) -> Self {
         let runtime = tokio::runtime::Builder::new_multi_thread().build().unwrap();
         let session_ctx = SessionContext::new();
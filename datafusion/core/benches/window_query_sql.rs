@@ -25,6 +25,7 @@ use crate::criterion::Criterion;
 use data_utils::create_table_provider;
 use datafusion::error::Result;
 use datafusion::execution::context::SessionContext;
+use datafusion::prelude::SessionConfig;
 use parking_lot::Mutex;
 use std::sync::Arc;
 use tokio::runtime::Runtime;
@@ -39,8 +40,9 @@ fn create_context(
     partitions_len: usize,
     array_len: usize,
     batch_size: usize,
+    config: SessionConfig,
 ) -> Result<Arc<Mutex<SessionContext>>> {
-    let ctx = SessionContext::new();
+    let ctx = SessionContext::new_with_config(config);
     let provider = create_table_provider(partitions_len, array_len, batch_size)?;
     ctx.register_table("t", provider)?;
     Ok(Arc::new(Mutex::new(ctx)))
@@ -50,7 +52,8 @@ fn criterion_benchmark(c: &mut Criterion) {
     let partitions_len = 8;
     let array_len = 1024 * 1024;
     let batch_size = 8 * 1024;
-    let ctx = create_context(partitions_len, array_len, batch_size).unwrap();
+    let ctx = create_context(partitions_len, array_len, batch_size, SessionConfig::new())
+        .unwrap();
 
     c.bench_function("window empty over, aggregate functions", |b| {
         b.iter(|| {
@@ -222,6 +225,32 @@ fn criterion_benchmark(c: &mut Criterion) {
             })
         },
     );
+
+    // the same query without hash repartitioning on the PARTITION BY
+    // columns, evaluating all the window partitions on a single core
+    let serial_ctx = create_context(
+        partitions_len,
+        array_len,
+        batch_size,
+        SessionConfig::new().with_target_partitions(1),
+    )
+    .unwrap();
+
+    c.bench_function(
+        "window partition and order by, u64_wide, aggregate functions, single partition",
+        |b| {
+            b.iter(|| {
+                query(
+                    serial_ctx.clone(),
+                    "SELECT \
+                        MAX(f64) OVER (PARTITION BY u64_wide ORDER by f64), \
+                        MIN(f32) OVER (PARTITION BY u64_wide ORDER by f64), \
+                        SUM(u64_narrow) OVER (PARTITION BY u64_wide ORDER by f64) \
+                    FROM t",
+                )
+            })
+        },
+    );
 }
 
 criterion_group!(benches, criterion_benchmark);
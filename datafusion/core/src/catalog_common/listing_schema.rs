@@ -135,6 +135,7 @@ impl ListingSchemaProvider {
                             file_type: self.format.clone(),
                             table_partition_cols: vec![],
                             if_not_exists: false,
+                            or_replace: false,
                             definition: None,
                             order_exprs: vec![],
                             unbounded: false,
@@ -68,6 +68,13 @@ impl CatalogProviderList for MemoryCatalogProviderList {
     fn catalog(&self, name: &str) -> Option<Arc<dyn CatalogProvider>> {
         self.catalogs.get(name).map(|c| c.value().clone())
     }
+
+    fn deregister_catalog(
+        &self,
+        name: &str,
+    ) -> datafusion_common::Result<Option<Arc<dyn CatalogProvider>>> {
+        Ok(self.catalogs.remove(name).map(|(_, catalog)| catalog))
+    }
 }
 
 /// Simple in-memory implementation of a catalog.
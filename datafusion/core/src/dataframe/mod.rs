@@ -19,6 +19,9 @@
 
 #[cfg(feature = "parquet")]
 mod parquet;
+mod sample;
+
+pub use sample::SampleNode;
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -0,0 +1,223 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`SampleNode`] logical plan node created by [`DataFrame::sample`]
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use super::{DataFrame, LogicalPlan};
+use crate::error::Result;
+
+use datafusion_common::{internal_err, plan_err, DFSchemaRef};
+use datafusion_expr::{Expr, Extension, UserDefinedLogicalNodeCore};
+use datafusion_physical_plan::sample::SampleMethod;
+
+/// Logical plan node that returns a random subset of the rows of its input.
+///
+/// This node is planned into a
+/// [`SampleExec`](crate::physical_plan::sample::SampleExec) by the default
+/// physical planner. A [`SampleMethod::System`] sample of a Parquet scan is
+/// planned into the scan instead, which then skips the row groups that are
+/// not sampled.
+#[derive(Debug, Clone)]
+pub struct SampleNode {
+    /// The input plan
+    pub input: LogicalPlan,
+    /// How rows are selected
+    pub method: SampleMethod,
+    /// Expected fraction of the input rows to return
+    pub fraction: f64,
+    /// Whether a row may be returned more than once
+    pub with_replacement: bool,
+    /// Seed for the random number generator, `None` means a random seed
+    pub seed: Option<u64>,
+}
+
+impl PartialEq for SampleNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.input == other.input
+            && self.method == other.method
+            && self.fraction.to_bits() == other.fraction.to_bits()
+            && self.with_replacement == other.with_replacement
+            && self.seed == other.seed
+    }
+}
+
+impl Eq for SampleNode {}
+
+impl Hash for SampleNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.input.hash(state);
+        self.method.hash(state);
+        self.fraction.to_bits().hash(state);
+        self.with_replacement.hash(state);
+        self.seed.hash(state);
+    }
+}
+
+impl UserDefinedLogicalNodeCore for SampleNode {
+    fn name(&self) -> &str {
+        "Sample"
+    }
+
+    fn inputs(&self) -> Vec<&LogicalPlan> {
+        vec![&self.input]
+    }
+
+    fn schema(&self) -> &DFSchemaRef {
+        self.input.schema()
+    }
+
+    fn expressions(&self) -> Vec<Expr> {
+        vec![]
+    }
+
+    fn fmt_for_explain(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Sample: method={}, fraction={}, with_replacement={}, seed={}",
+            self.method,
+            self.fraction,
+            self.with_replacement,
+            self.seed.map_or("None".to_string(), |x| x.to_string())
+        )
+    }
+
+    fn with_exprs_and_inputs(
+        &self,
+        exprs: Vec<Expr>,
+        mut inputs: Vec<LogicalPlan>,
+    ) -> Result<Self> {
+        if !exprs.is_empty() || inputs.len() != 1 {
+            return internal_err!("Sample expects no expressions and a single input");
+        }
+        Ok(Self {
+            input: inputs.swap_remove(0),
+            method: self.method,
+            fraction: self.fraction,
+            with_replacement: self.with_replacement,
+            seed: self.seed,
+        })
+    }
+
+    fn necessary_children_exprs(
+        &self,
+        output_columns: &[usize],
+    ) -> Option<Vec<Vec<usize>>> {
+        // The output schema is the input schema, so only the requested
+        // columns are needed from the input.
+        Some(vec![output_columns.to_vec()])
+    }
+}
+
+impl DataFrame {
+    /// Return a new `DataFrame` containing a random sample of the rows of
+    /// the current `DataFrame`.
+    ///
+    /// Without replacement, each row is kept independently with probability
+    /// `fraction`, which must be between `0.0` and `1.0`. With replacement,
+    /// each row is returned a Poisson distributed number of times with mean
+    /// `fraction`, which may then be larger than `1.0`.
+    ///
+    /// When `seed` is set, executing the same plan over the same input
+    /// returns the same sample.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.sample(0.1, false, Some(42))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sample(
+        self,
+        fraction: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<DataFrame> {
+        self.sample_with_method(SampleMethod::Bernoulli, fraction, with_replacement, seed)
+    }
+
+    /// Return a new `DataFrame` containing a random sample of blocks of rows
+    /// of the current `DataFrame`, like `TABLESAMPLE SYSTEM` in SQL.
+    ///
+    /// Each block of rows is kept as a whole with probability `fraction`,
+    /// which must be between `0.0` and `1.0`. When the `DataFrame` scans
+    /// Parquet files, the blocks are the row groups of the files, and the
+    /// row groups that are not sampled are not read at all. Otherwise the
+    /// blocks are the record batches of the input. This is much cheaper than
+    /// [`Self::sample`], but the rows of a block are either all sampled or
+    /// not at all.
+    ///
+    /// When `seed` is set, executing the same plan over the same input
+    /// returns the same sample.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.sample_system(0.1, Some(42))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sample_system(self, fraction: f64, seed: Option<u64>) -> Result<DataFrame> {
+        self.sample_with_method(SampleMethod::System, fraction, false, seed)
+    }
+
+    fn sample_with_method(
+        self,
+        method: SampleMethod,
+        fraction: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<DataFrame> {
+        if !fraction.is_finite() || fraction < 0.0 {
+            return plan_err!(
+                "Sample fraction must be a non-negative number, got {fraction}"
+            );
+        }
+        if !with_replacement && fraction > 1.0 {
+            return plan_err!(
+                "Sample fraction must be between 0 and 1 when sampling without replacement, got {fraction}"
+            );
+        }
+        let plan = LogicalPlan::Extension(Extension {
+            node: Arc::new(SampleNode {
+                input: self.plan,
+                method,
+                fraction,
+                with_replacement,
+                seed,
+            }),
+        });
+        Ok(DataFrame {
+            session_state: self.session_state,
+            plan,
+        })
+    }
+}
@@ -476,6 +476,32 @@ pub trait ReadOptions<'a> {
         table_path: ListingTableUrl,
     ) -> Result<SchemaRef>;
 
+    /// Infer and resolve the schema from the files/sources provided, merged
+    /// across all the `table_paths`.
+    ///
+    /// The default implementation merges the schemas
+    /// [`Self::get_resolved_schema`] resolves for each of the `table_paths`.
+    async fn get_resolved_schema_for_paths(
+        &self,
+        config: &SessionConfig,
+        state: SessionState,
+        table_paths: &[ListingTableUrl],
+    ) -> Result<SchemaRef> {
+        if let [table_path] = table_paths {
+            return self
+                .get_resolved_schema(config, state, table_path.clone())
+                .await;
+        }
+        let mut schemas = Vec::with_capacity(table_paths.len());
+        for table_path in table_paths {
+            let schema = self
+                .get_resolved_schema(config, state.clone(), table_path.clone())
+                .await?;
+            schemas.push(Arc::unwrap_or_clone(schema));
+        }
+        Ok(Arc::new(Schema::try_merge(schemas)?))
+    }
+
     /// helper function to reduce repetitive code. Infers the schema from sources if not provided. Infinite data sources not supported through this function.
     async fn _get_resolved_schema(
         &'a self,
@@ -495,6 +521,27 @@ pub trait ReadOptions<'a> {
             .infer_schema(&state, &table_path)
             .await
     }
+
+    /// Like [`Self::_get_resolved_schema`] for several `table_paths`, listing
+    /// their files once, see [`ListingOptions::infer_schema_for_paths`].
+    async fn _get_resolved_schema_for_paths(
+        &'a self,
+        config: &SessionConfig,
+        state: SessionState,
+        table_paths: &[ListingTableUrl],
+        schema: Option<&'a Schema>,
+    ) -> Result<SchemaRef>
+    where
+        'a: 'async_trait,
+    {
+        if let Some(s) = schema {
+            return Ok(Arc::new(s.to_owned()));
+        }
+
+        self.to_listing_options(config, state.default_table_options())
+            .infer_schema_for_paths(&state, table_paths)
+            .await
+    }
 }
 
 #[async_trait]
@@ -531,6 +578,16 @@ impl ReadOptions<'_> for CsvReadOptions<'_> {
         self._get_resolved_schema(config, state, table_path, self.schema)
             .await
     }
+
+    async fn get_resolved_schema_for_paths(
+        &self,
+        config: &SessionConfig,
+        state: SessionState,
+        table_paths: &[ListingTableUrl],
+    ) -> Result<SchemaRef> {
+        self._get_resolved_schema_for_paths(config, state, table_paths, self.schema)
+            .await
+    }
 }
 
 #[cfg(feature = "parquet")]
@@ -566,6 +623,16 @@ impl ReadOptions<'_> for ParquetReadOptions<'_> {
         self._get_resolved_schema(config, state, table_path, self.schema)
             .await
     }
+
+    async fn get_resolved_schema_for_paths(
+        &self,
+        config: &SessionConfig,
+        state: SessionState,
+        table_paths: &[ListingTableUrl],
+    ) -> Result<SchemaRef> {
+        self._get_resolved_schema_for_paths(config, state, table_paths, self.schema)
+            .await
+    }
 }
 
 #[async_trait]
@@ -596,6 +663,16 @@ impl ReadOptions<'_> for NdJsonReadOptions<'_> {
         self._get_resolved_schema(config, state, table_path, self.schema)
             .await
     }
+
+    async fn get_resolved_schema_for_paths(
+        &self,
+        config: &SessionConfig,
+        state: SessionState,
+        table_paths: &[ListingTableUrl],
+    ) -> Result<SchemaRef> {
+        self._get_resolved_schema_for_paths(config, state, table_paths, self.schema)
+            .await
+    }
 }
 
 #[async_trait]
@@ -622,6 +699,16 @@ impl ReadOptions<'_> for AvroReadOptions<'_> {
         self._get_resolved_schema(config, state, table_path, self.schema)
             .await
     }
+
+    async fn get_resolved_schema_for_paths(
+        &self,
+        config: &SessionConfig,
+        state: SessionState,
+        table_paths: &[ListingTableUrl],
+    ) -> Result<SchemaRef> {
+        self._get_resolved_schema_for_paths(config, state, table_paths, self.schema)
+            .await
+    }
 }
 
 #[async_trait]
@@ -648,4 +735,14 @@ impl ReadOptions<'_> for ArrowReadOptions<'_> {
         self._get_resolved_schema(config, state, table_path, self.schema)
             .await
     }
+
+    async fn get_resolved_schema_for_paths(
+        &self,
+        config: &SessionConfig,
+        state: SessionState,
+        table_paths: &[ListingTableUrl],
+    ) -> Result<SchemaRef> {
+        self._get_resolved_schema_for_paths(config, state, table_paths, self.schema)
+            .await
+    }
 }
@@ -28,8 +28,11 @@ use super::{transform_schema_to_view, FileFormat, FileFormatFactory, FileScanCon
 use crate::arrow::array::RecordBatch;
 use crate::arrow::datatypes::{Fields, Schema, SchemaRef};
 use crate::datasource::file_format::file_compression_type::FileCompressionType;
+use crate::datasource::listing::PartitionedFile;
 use crate::datasource::physical_plan::{FileGroupDisplay, FileSinkConfig};
-use crate::datasource::statistics::{create_max_min_accs, get_col_stats};
+use crate::datasource::statistics::{
+    create_max_min_accs, get_col_stats, get_statistics_with_limit,
+};
 use crate::error::Result;
 use crate::execution::context::SessionState;
 use crate::physical_plan::insert::{DataSink, DataSinkExec};
@@ -487,6 +490,37 @@ async fn fetch_statistics(
     statistics_from_parquet_meta_calc(&metadata, table_schema)
 }
 
+/// Read the statistics of the given Parquet files and combine them into the
+/// [`Statistics`] of a table with schema `table_schema`.
+///
+/// Only the footer of each file is read, so this can be used to inspect the
+/// row count and the per-column min/max values and null counts of a dataset,
+/// for example from a catalog, without planning or executing a query. The
+/// statistics of each file are calculated as in
+/// [`statistics_from_parquet_meta_calc`] and combined like those of the files
+/// of a [`ListingTable`].
+///
+/// [`ListingTable`]: crate::datasource::listing::ListingTable
+pub async fn statistics_from_parquet_files(
+    store: &dyn ObjectStore,
+    table_schema: SchemaRef,
+    files: &[ObjectMeta],
+    metadata_size_hint: Option<usize>,
+) -> Result<Statistics> {
+    let files = futures::stream::iter(files).then(|file| {
+        let table_schema = Arc::clone(&table_schema);
+        async move {
+            let statistics =
+                fetch_statistics(store, table_schema, file, metadata_size_hint).await?;
+            let file = PartitionedFile::from(file.clone());
+            Ok::<_, DataFusionError>((file, Arc::new(statistics)))
+        }
+    });
+    let (_, statistics) =
+        get_statistics_with_limit(files, Arc::clone(&table_schema), None, true).await?;
+    Ok(statistics)
+}
+
 /// Convert statistics in  [`ParquetMetaData`] into [`Statistics`] using ['StatisticsConverter`]
 ///
 /// The statistics are calculated for each column in the table schema
@@ -1154,6 +1188,14 @@ pub(crate) mod test_util {
     use super::*;
     use crate::test::object_store::local_unpartitioned_file;
 
+    use std::fmt::{Display, Formatter};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::stream::BoxStream;
+    use object_store::{
+        GetOptions, GetResult, ListResult, MultipartUpload, PutMultipartOpts, PutOptions,
+        PutPayload, PutResult,
+    };
     use parquet::arrow::ArrowWriter;
     use tempfile::NamedTempFile;
 
@@ -1226,6 +1268,101 @@ pub(crate) mod test_util {
             i += num;
         }
     }
+
+    /// [`ObjectStore`] that counts the `get` requests made to an inner store
+    #[derive(Debug)]
+    pub struct RequestCountingObjectStore {
+        inner: Arc<dyn ObjectStore>,
+        request_count: AtomicUsize,
+    }
+
+    impl Display for RequestCountingObjectStore {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "RequestCounting({})", self.inner)
+        }
+    }
+
+    impl RequestCountingObjectStore {
+        pub fn new(inner: Arc<dyn ObjectStore>) -> Self {
+            Self {
+                inner,
+                request_count: Default::default(),
+            }
+        }
+
+        pub fn request_count(&self) -> usize {
+            self.request_count.load(Ordering::SeqCst)
+        }
+
+        pub fn upcast(self: &Arc<Self>) -> Arc<dyn ObjectStore> {
+            self.clone()
+        }
+    }
+
+    #[async_trait]
+    impl ObjectStore for RequestCountingObjectStore {
+        async fn put_opts(
+            &self,
+            _location: &Path,
+            _payload: PutPayload,
+            _opts: PutOptions,
+        ) -> object_store::Result<PutResult> {
+            Err(object_store::Error::NotImplemented)
+        }
+
+        async fn put_multipart_opts(
+            &self,
+            _location: &Path,
+            _opts: PutMultipartOpts,
+        ) -> object_store::Result<Box<dyn MultipartUpload>> {
+            Err(object_store::Error::NotImplemented)
+        }
+
+        async fn get_opts(
+            &self,
+            location: &Path,
+            options: GetOptions,
+        ) -> object_store::Result<GetResult> {
+            self.request_count.fetch_add(1, Ordering::SeqCst);
+            self.inner.get_opts(location, options).await
+        }
+
+        async fn head(&self, _location: &Path) -> object_store::Result<ObjectMeta> {
+            Err(object_store::Error::NotImplemented)
+        }
+
+        async fn delete(&self, _location: &Path) -> object_store::Result<()> {
+            Err(object_store::Error::NotImplemented)
+        }
+
+        fn list(
+            &self,
+            _prefix: Option<&Path>,
+        ) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+            Box::pin(futures::stream::once(async {
+                Err(object_store::Error::NotImplemented)
+            }))
+        }
+
+        async fn list_with_delimiter(
+            &self,
+            _prefix: Option<&Path>,
+        ) -> object_store::Result<ListResult> {
+            Err(object_store::Error::NotImplemented)
+        }
+
+        async fn copy(&self, _from: &Path, _to: &Path) -> object_store::Result<()> {
+            Err(object_store::Error::NotImplemented)
+        }
+
+        async fn copy_if_not_exists(
+            &self,
+            _from: &Path,
+            _to: &Path,
+        ) -> object_store::Result<()> {
+            Err(object_store::Error::NotImplemented)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1234,20 +1371,19 @@ mod tests {
     use crate::datasource::listing::{ListingTableUrl, PartitionedFile};
     use crate::physical_plan::collect;
     use crate::test_util::bounded_stream;
-    use std::fmt::{Display, Formatter};
-    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::time::Duration;
 
     use super::*;
 
-    use crate::datasource::file_format::parquet::test_util::store_parquet;
+    use crate::datasource::file_format::parquet::test_util::{
+        store_parquet, RequestCountingObjectStore,
+    };
     use crate::physical_plan::metrics::MetricValue;
     use crate::prelude::{SessionConfig, SessionContext};
     use arrow::array::{Array, ArrayRef, StringArray};
     use arrow_array::types::Int32Type;
     use arrow_array::{DictionaryArray, Int32Array, Int64Array};
     use arrow_schema::{DataType, Field};
-    use async_trait::async_trait;
     use datafusion_common::cast::{
         as_binary_array, as_boolean_array, as_float32_array, as_float64_array,
         as_int32_array, as_timestamp_nanosecond_array,
@@ -1258,13 +1394,8 @@ mod tests {
     use datafusion_execution::object_store::ObjectStoreUrl;
     use datafusion_execution::runtime_env::RuntimeEnv;
     use datafusion_physical_plan::stream::RecordBatchStreamAdapter;
-    use futures::stream::BoxStream;
     use log::error;
     use object_store::local::LocalFileSystem;
-    use object_store::{
-        GetOptions, GetResult, ListResult, MultipartUpload, PutMultipartOpts, PutOptions,
-        PutPayload, PutResult,
-    };
     use parquet::arrow::arrow_reader::ArrowReaderOptions;
     use parquet::arrow::ParquetRecordBatchStreamBuilder;
     use parquet::file::metadata::{KeyValue, ParquetColumnIndex, ParquetOffsetIndex};
@@ -1316,6 +1447,33 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn statistics_of_multiple_files() -> Result<()> {
+        // c1 is [5, null, 1] in the first file and [3, 9] in the second
+        let c1: ArrayRef = Arc::new(Int64Array::from(vec![Some(5), None, Some(1)]));
+        let batch1 = RecordBatch::try_from_iter(vec![("c1", c1)]).unwrap();
+        let c1: ArrayRef = Arc::new(Int64Array::from(vec![Some(3), Some(9)]));
+        let batch2 = RecordBatch::try_from_iter(vec![("c1", c1)]).unwrap();
+
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let (files, _file_names) = store_parquet(vec![batch1, batch2], false).await?;
+
+        let state = SessionContext::new().state();
+        let schema = ParquetFormat::default()
+            .infer_schema(&state, &store, &files)
+            .await?;
+
+        let stats =
+            statistics_from_parquet_files(store.as_ref(), schema, &files, None).await?;
+        assert_eq!(stats.num_rows, Precision::Exact(5));
+        let c1_stats = &stats.column_statistics[0];
+        assert_eq!(c1_stats.null_count, Precision::Exact(1));
+        assert_eq!(c1_stats.min_value, Precision::Exact(1i64.into()));
+        assert_eq!(c1_stats.max_value, Precision::Exact(9i64.into()));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn is_schema_stable() -> Result<()> {
         let c1: ArrayRef =
@@ -1352,100 +1510,6 @@ mod tests {
         Ok(())
     }
 
-    #[derive(Debug)]
-    struct RequestCountingObjectStore {
-        inner: Arc<dyn ObjectStore>,
-        request_count: AtomicUsize,
-    }
-
-    impl Display for RequestCountingObjectStore {
-        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-            write!(f, "RequestCounting({})", self.inner)
-        }
-    }
-
-    impl RequestCountingObjectStore {
-        pub fn new(inner: Arc<dyn ObjectStore>) -> Self {
-            Self {
-                inner,
-                request_count: Default::default(),
-            }
-        }
-
-        pub fn request_count(&self) -> usize {
-            self.request_count.load(Ordering::SeqCst)
-        }
-
-        pub fn upcast(self: &Arc<Self>) -> Arc<dyn ObjectStore> {
-            self.clone()
-        }
-    }
-
-    #[async_trait]
-    impl ObjectStore for RequestCountingObjectStore {
-        async fn put_opts(
-            &self,
-            _location: &Path,
-            _payload: PutPayload,
-            _opts: PutOptions,
-        ) -> object_store::Result<PutResult> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn put_multipart_opts(
-            &self,
-            _location: &Path,
-            _opts: PutMultipartOpts,
-        ) -> object_store::Result<Box<dyn MultipartUpload>> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn get_opts(
-            &self,
-            location: &Path,
-            options: GetOptions,
-        ) -> object_store::Result<GetResult> {
-            self.request_count.fetch_add(1, Ordering::SeqCst);
-            self.inner.get_opts(location, options).await
-        }
-
-        async fn head(&self, _location: &Path) -> object_store::Result<ObjectMeta> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn delete(&self, _location: &Path) -> object_store::Result<()> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        fn list(
-            &self,
-            _prefix: Option<&Path>,
-        ) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
-            Box::pin(futures::stream::once(async {
-                Err(object_store::Error::NotImplemented)
-            }))
-        }
-
-        async fn list_with_delimiter(
-            &self,
-            _prefix: Option<&Path>,
-        ) -> object_store::Result<ListResult> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn copy(&self, _from: &Path, _to: &Path) -> object_store::Result<()> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn copy_if_not_exists(
-            &self,
-            _from: &Path,
-            _to: &Path,
-        ) -> object_store::Result<()> {
-            Err(object_store::Error::NotImplemented)
-        }
-    }
-
     #[tokio::test]
     async fn fetch_metadata_with_size_hint() -> Result<()> {
         let c1: ArrayRef =
@@ -17,10 +17,12 @@
 
 //! Helper functions for the table implementation
 
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 use std::mem;
 use std::sync::Arc;
 
+use super::url::{collect_listing, is_unreadable_error};
 use super::ListingTableUrl;
 use super::PartitionedFile;
 use crate::execution::context::SessionState;
@@ -37,14 +39,14 @@ use arrow_schema::Fields;
 use datafusion_expr::execution_props::ExecutionProps;
 use futures::stream::FuturesUnordered;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
-use log::{debug, trace};
+use log::{debug, trace, warn};
 
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{Column, DFSchema, DataFusionError};
 use datafusion_expr::{Expr, Volatility};
 use datafusion_physical_expr::create_physical_expr;
 use object_store::path::Path;
-use object_store::{ObjectMeta, ObjectStore};
+use object_store::{ListResult, ObjectMeta, ObjectStore};
 
 /// Check whether the given expression can be resolved using only the columns `col_names`.
 /// This means that if this function returns true:
@@ -156,6 +158,43 @@ pub fn split_files(
     chunks
 }
 
+/// Divide the given files into at most `n` groups of roughly the same total
+/// size in bytes.
+///
+/// Unlike [`split_files`], which puts the same number of files in each group,
+/// this assigns the largest remaining file to the group with the fewest bytes
+/// so far, so that a few large files do not all end up in the same group.
+/// Files within each group are ordered by path.
+pub fn split_files_by_size(
+    mut partitioned_files: Vec<PartitionedFile>,
+    n: usize,
+) -> Vec<Vec<PartitionedFile>> {
+    if partitioned_files.is_empty() {
+        return vec![];
+    }
+
+    // Sort by path first so that files of equal size are always assigned in
+    // the same order, see the comment in `split_files`
+    partitioned_files.sort_by(|a, b| a.path().cmp(b.path()));
+    partitioned_files.sort_by_key(|f| std::cmp::Reverse(f.object_meta.size));
+
+    let n = n.clamp(1, partitioned_files.len());
+    let mut groups: Vec<Vec<PartitionedFile>> = vec![vec![]; n];
+    // groups ordered by (bytes, index), smallest first
+    let mut group_sizes: BinaryHeap<Reverse<(usize, usize)>> =
+        (0..n).map(|idx| Reverse((0, idx))).collect();
+    for file in partitioned_files {
+        let Reverse((size, idx)) = group_sizes.pop().unwrap();
+        group_sizes.push(Reverse((size + file.object_meta.size, idx)));
+        groups[idx].push(file);
+    }
+
+    for group in groups.iter_mut() {
+        group.sort_by(|a, b| a.path().cmp(b.path()));
+    }
+    groups
+}
+
 struct Partition {
     /// The path to the partition, including the table prefix
     path: Path,
@@ -169,10 +208,28 @@ struct Partition {
 impl Partition {
     /// List the direct children of this partition updating `self.files` with
     /// any child files, and returning a list of child "directories"
-    async fn list(mut self, store: &dyn ObjectStore) -> Result<(Self, Vec<Path>)> {
+    ///
+    /// If `skip_unreadable` is set, a partition that does not exist or cannot
+    /// be read for lack of permissions is logged and treated as empty instead
+    /// of failing.
+    async fn list(
+        mut self,
+        store: &dyn ObjectStore,
+        skip_unreadable: bool,
+    ) -> Result<(Self, Vec<Path>)> {
         trace!("Listing partition {}", self.path);
         let prefix = Some(&self.path).filter(|p| !p.as_ref().is_empty());
-        let result = store.list_with_delimiter(prefix).await?;
+        let result = match store.list_with_delimiter(prefix).await {
+            Ok(result) => result,
+            Err(e) if skip_unreadable && is_unreadable_error(&e) => {
+                warn!("Skipping unreadable partition {}: {e}", self.path);
+                ListResult {
+                    common_prefixes: vec![],
+                    objects: vec![],
+                }
+            }
+            Err(e) => return Err(e.into()),
+        };
         self.files = Some(result.objects);
         Ok((self, result.common_prefixes))
     }
@@ -184,6 +241,7 @@ async fn list_partitions(
     table_path: &ListingTableUrl,
     max_depth: usize,
     partition_prefix: Option<Path>,
+    skip_unreadable: bool,
 ) -> Result<Vec<Partition>> {
     let partition = Partition {
         path: match partition_prefix {
@@ -202,7 +260,7 @@ async fn list_partitions(
 
     let mut pending = vec![];
     let mut futures = FuturesUnordered::new();
-    futures.push(partition.list(store));
+    futures.push(partition.list(store, skip_unreadable));
 
     while let Some((partition, paths)) = futures.next().await.transpose()? {
         // If pending contains a future it implies prior to this iteration
@@ -222,8 +280,8 @@ async fn list_partitions(
             };
             match depth < max_depth {
                 true => match futures.len() < CONCURRENCY_LIMIT {
-                    true => futures.push(child.list(store)),
-                    false => pending.push(child.list(store)),
+                    true => futures.push(child.list(store, skip_unreadable)),
+                    false => pending.push(child.list(store, skip_unreadable)),
                 },
                 false => out.push(child),
             }
@@ -423,10 +481,19 @@ pub async fn pruned_partition_list<'a>(
         ));
     }
 
+    let skip_unreadable = ctx
+        .config_options()
+        .execution
+        .listing_table_skip_unreadable_files;
     let partition_prefix = evaluate_partition_prefix(partition_cols, filters);
-    let partitions =
-        list_partitions(store, table_path, partition_cols.len(), partition_prefix)
-            .await?;
+    let partitions = list_partitions(
+        store,
+        table_path,
+        partition_cols.len(),
+        partition_prefix,
+        skip_unreadable,
+    )
+    .await?;
     debug!("Listed {} partitions", partitions.len());
 
     let pruned =
@@ -452,7 +519,10 @@ pub async fn pruned_partition_list<'a>(
                 Some(files) => files,
                 None => {
                     trace!("Recursively listing partition {}", partition.path);
-                    store.list(Some(&partition.path)).try_collect().await?
+                    let list = store.list(Some(&partition.path));
+                    collect_listing(store, list, &partition.path, skip_unreadable)
+                        .await?
+                        .0
                 }
             };
             let files = files.into_iter().filter(move |o| {
@@ -517,8 +587,9 @@ mod tests {
 
     use futures::StreamExt;
 
-    use crate::test::object_store::make_test_store_and_state;
+    use crate::test::object_store::{make_test_store_and_state, UnreadableObjectStore};
     use datafusion_expr::{case, col, lit, Expr};
+    use itertools::Itertools;
 
     use super::*;
 
@@ -562,6 +633,56 @@ mod tests {
         assert_eq!(0, chunks.len());
     }
 
+    #[test]
+    fn test_split_files_by_size() {
+        let new_partitioned_file =
+            |path: &str, size: u64| PartitionedFile::new(path.to_owned(), size);
+        let files = vec![
+            new_partitioned_file("a", 5000),
+            new_partitioned_file("b", 10),
+            new_partitioned_file("c", 10),
+            new_partitioned_file("d", 10),
+            new_partitioned_file("e", 10),
+            new_partitioned_file("f", 10),
+        ];
+        let group_bytes = |groups: &[Vec<PartitionedFile>]| {
+            groups
+                .iter()
+                .map(|group| group.iter().map(|f| f.object_meta.size).sum::<usize>())
+                .collect::<Vec<_>>()
+        };
+        let group_paths = |groups: &[Vec<PartitionedFile>]| {
+            groups
+                .iter()
+                .map(|group| group.iter().map(|f| f.path().as_ref()).join(","))
+                .collect::<Vec<_>>()
+        };
+
+        // splitting by count puts the large file together with two others
+        let chunks = split_files(files.clone(), 2);
+        assert_eq!(vec![5020, 30], group_bytes(&chunks));
+
+        // splitting by size gives the large file a group of its own
+        let chunks = split_files_by_size(files.clone(), 2);
+        assert_eq!(vec![5000, 50], group_bytes(&chunks));
+        assert_eq!(vec!["a", "b,c,d,e,f"], group_paths(&chunks));
+
+        let chunks = split_files_by_size(files[1..].to_vec(), 2);
+        assert_eq!(vec![30, 20], group_bytes(&chunks));
+        assert_eq!(vec!["b,d,f", "c,e"], group_paths(&chunks));
+
+        let chunks = split_files_by_size(files.clone(), 123);
+        assert_eq!(6, chunks.len());
+        assert!(chunks.iter().all(|group| group.len() == 1));
+
+        let chunks = split_files_by_size(files, 0);
+        assert_eq!(1, chunks.len());
+        assert_eq!(6, chunks[0].len());
+
+        let chunks = split_files_by_size(vec![], 2);
+        assert_eq!(0, chunks.len());
+    }
+
     #[tokio::test]
     async fn test_pruned_partition_list_empty() {
         let (store, state) = make_test_store_and_state(&[
@@ -673,6 +794,73 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_pruned_partition_list_skip_unreadable() {
+        let (memory, mut state) = make_test_store_and_state(&[
+            ("tablepath/part1=p1v1/part2=p2v1/file.parquet", 100),
+            ("tablepath/part1=p1v2/part2=p2v1/file.parquet", 100),
+        ]);
+        let store =
+            UnreadableObjectStore::new(Arc::clone(&memory), "tablepath/part1=p1v2");
+        let table_path = ListingTableUrl::parse("file:///tablepath/").unwrap();
+        let part1 = (String::from("part1"), DataType::Utf8);
+        let part2 = (String::from("part2"), DataType::Utf8);
+
+        // the unreadable partition is either listed recursively, or listed
+        // level by level when it is above the deepest partition column
+        for partition_cols in [vec![part1.clone()], vec![part1, part2]] {
+            for skip_unreadable in [false, true] {
+                state
+                    .config_mut()
+                    .options_mut()
+                    .execution
+                    .listing_table_skip_unreadable_files = skip_unreadable;
+                let files = match pruned_partition_list(
+                    &state,
+                    &store,
+                    &table_path,
+                    &[],
+                    ".parquet",
+                    &partition_cols,
+                )
+                .await
+                {
+                    Ok(files) => files.try_collect::<Vec<_>>().await,
+                    Err(e) => Err(e),
+                };
+                if !skip_unreadable {
+                    assert!(files.is_err());
+                    continue;
+                }
+                let files = files.unwrap();
+                assert_eq!(files.len(), 1);
+                assert_eq!(
+                    files[0].object_meta.location.as_ref(),
+                    "tablepath/part1=p1v1/part2=p2v1/file.parquet"
+                );
+            }
+        }
+
+        // other errors are never skipped
+        let store = UnreadableObjectStore::new(memory, "tablepath/part1=p1v2")
+            .with_error_kind(std::io::ErrorKind::TimedOut);
+        let part1 = (String::from("part1"), DataType::Utf8);
+        let files = match pruned_partition_list(
+            &state,
+            &store,
+            &table_path,
+            &[],
+            ".parquet",
+            &[part1],
+        )
+        .await
+        {
+            Ok(files) => files.try_collect::<Vec<_>>().await,
+            Err(e) => Err(e),
+        };
+        assert!(files.is_err());
+    }
+
     #[test]
     fn test_parse_partitions_for_path() {
         assert_eq!(
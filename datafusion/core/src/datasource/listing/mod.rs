@@ -30,8 +30,8 @@ use object_store::{path::Path, ObjectMeta};
 use std::pin::Pin;
 use std::sync::Arc;
 
-pub use self::url::ListingTableUrl;
-pub use table::{ListingOptions, ListingTable, ListingTableConfig};
+pub use self::url::{ListingTableUrl, UnreadablePath};
+pub use table::{ListingOptions, ListingTable, ListingTableConfig, ListingToken};
 
 /// Stream of files get listed from object store
 pub type PartitionedFileStream =
@@ -17,11 +17,14 @@
 
 //! The table implementation.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::str::FromStr;
 use std::{any::Any, sync::Arc};
 
-use super::helpers::{expr_applicable_for_cols, pruned_partition_list, split_files};
+use super::helpers::{
+    expr_applicable_for_cols, pruned_partition_list, split_files, split_files_by_size,
+};
 use super::PartitionedFile;
 
 use super::ListingTableUrl;
@@ -40,11 +43,12 @@ use datafusion_physical_plan::{empty::EmptyExec, ExecutionPlan, Statistics};
 use arrow::datatypes::{DataType, Field, SchemaBuilder, SchemaRef};
 use arrow_schema::Schema;
 use datafusion_common::{
-    config_datafusion_err, internal_err, plan_err, project_schema, Constraints,
+    config_datafusion_err, exec_err, internal_err, plan_err, project_schema, Constraints,
     SchemaExt, ToDFSchema,
 };
 use datafusion_execution::cache::cache_manager::FileStatisticsCache;
 use datafusion_execution::cache::cache_unit::DefaultFileStatisticsCache;
+use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_physical_expr::{
     create_physical_expr, LexOrdering, PhysicalSortRequirement,
 };
@@ -53,7 +57,7 @@ use async_trait::async_trait;
 use datafusion_catalog::Session;
 use futures::{future, stream, StreamExt, TryStreamExt};
 use itertools::Itertools;
-use object_store::ObjectStore;
+use object_store::{ObjectMeta, ObjectStore};
 
 /// Configuration for creating a [`ListingTable`]
 #[derive(Debug, Clone)]
@@ -168,11 +172,9 @@ impl ListingTableConfig {
     pub async fn infer_schema(self, state: &SessionState) -> Result<Self> {
         match self.options {
             Some(options) => {
-                let schema = if let Some(url) = self.table_paths.first() {
-                    options.infer_schema(state, url).await?
-                } else {
-                    Arc::new(Schema::empty())
-                };
+                let schema = options
+                    .infer_schema_for_paths(state, &self.table_paths)
+                    .await?;
 
                 Ok(Self {
                     table_paths: self.table_paths,
@@ -208,6 +210,10 @@ pub struct ListingOptions {
     /// Group files to avoid that the number of partitions exceeds
     /// this limit
     pub target_partitions: usize,
+    /// Group files so that each group has about the same total size in
+    /// bytes, rather than the same number of files.
+    /// See [Self::with_split_files_by_size] for details
+    pub split_files_by_size: bool,
     /// Optional pre-known sort order(s). Must be `SortExpr`s.
     ///
     /// DataFusion may take advantage of this ordering to omit sorts
@@ -239,6 +245,7 @@ impl ListingOptions {
             table_partition_cols: vec![],
             collect_stat: true,
             target_partitions: 1,
+            split_files_by_size: false,
             file_sort_order: vec![],
         }
     }
@@ -366,6 +373,30 @@ impl ListingOptions {
         self
     }
 
+    /// Set whether the files are grouped into [`Self::target_partitions`]
+    /// groups by their size in bytes on [`ListingOptions`] and returns self.
+    ///
+    /// By default every group gets the same number of files, which leaves the
+    /// groups unbalanced when the file sizes are skewed. When enabled, each
+    /// file, largest first, is added to the group with the fewest bytes so far.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use datafusion::datasource::{listing::ListingOptions, file_format::parquet::ParquetFormat};
+    ///
+    /// let listing_options = ListingOptions::new(Arc::new(
+    ///     ParquetFormat::default()
+    ///   ))
+    ///   .with_target_partitions(8)
+    ///   .with_split_files_by_size(true);
+    ///
+    /// assert!(listing_options.split_files_by_size);
+    /// ```
+    pub fn with_split_files_by_size(mut self, split_files_by_size: bool) -> Self {
+        self.split_files_by_size = split_files_by_size;
+        self
+    }
+
     /// Set file sort order on [`ListingOptions`] and returns self.
     ///
     /// ```
@@ -414,6 +445,54 @@ impl ListingOptions {
         Ok(schema)
     }
 
+    /// Infer the schema of the files at all the given paths, as
+    /// [`Self::infer_schema`] does for a single path.
+    ///
+    /// The files are listed once even if they are matched by several paths,
+    /// and the schema is inferred once per object store.
+    pub async fn infer_schema_for_paths(
+        &self,
+        state: &SessionState,
+        table_paths: &[ListingTableUrl],
+    ) -> Result<SchemaRef> {
+        // the paths of each object store, in the order the stores appear
+        let mut stores: Vec<(ObjectStoreUrl, Vec<&ListingTableUrl>)> = vec![];
+        for table_path in table_paths {
+            let store_url = table_path.object_store();
+            match stores.iter_mut().find(|(url, _)| *url == store_url) {
+                Some((_, paths)) => paths.push(table_path),
+                None => stores.push((store_url, vec![table_path])),
+            }
+        }
+
+        let mut schemas = vec![];
+        for (store_url, table_paths) in stores {
+            let store = state.runtime_env().object_store(store_url)?;
+            let mut seen = HashSet::new();
+            let mut files = vec![];
+            for table_path in table_paths {
+                let listed: Vec<_> = table_path
+                    .list_all_files(state, store.as_ref(), &self.file_extension)
+                    .await?
+                    .try_collect()
+                    .await?;
+                files.extend(
+                    listed
+                        .into_iter()
+                        .filter(|file| seen.insert(file.location.clone())),
+                );
+            }
+            schemas.push(self.format.infer_schema(state, &store, &files).await?);
+        }
+        match schemas.len() {
+            0 => Ok(Arc::new(Schema::empty())),
+            1 => Ok(schemas.remove(0)),
+            _ => Ok(Arc::new(Schema::try_merge(
+                schemas.into_iter().map(Arc::unwrap_or_clone),
+            )?)),
+        }
+    }
+
     /// Infers the partition columns stored in `LOCATION` and compares
     /// them with the columns provided in `PARTITIONED BY` to help prevent
     /// accidental corrupts of partitioned tables.
@@ -521,6 +600,42 @@ impl ListingOptions {
     }
 }
 
+/// Summary of the files a [`ListingTable`] listed at some point in time.
+///
+/// Records the number of files and a digest of their locations, sizes and
+/// e-tags, so that [`ListingTable::verify_listing`] can detect a later
+/// listing that is truncated or otherwise differs from the recorded one.
+/// The digest is only meant to be compared within the same process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListingToken {
+    file_count: usize,
+    digest: u64,
+}
+
+impl ListingToken {
+    /// Create a [`ListingToken`] from the listed `files`, in any order
+    pub fn new(files: &[ObjectMeta]) -> Self {
+        let mut files = files.iter().collect::<Vec<_>>();
+        files.sort_by(|a, b| a.location.cmp(&b.location));
+
+        let mut hasher = DefaultHasher::new();
+        for file in &files {
+            file.location.as_ref().hash(&mut hasher);
+            file.size.hash(&mut hasher);
+            file.e_tag.hash(&mut hasher);
+        }
+        Self {
+            file_count: files.len(),
+            digest: hasher.finish(),
+        }
+    }
+
+    /// The number of files that were listed
+    pub fn file_count(&self) -> usize {
+        self.file_count
+    }
+}
+
 /// Reads data from one or more files as a single table.
 ///
 /// Implements [`TableProvider`], a DataFusion data source. The files are read
@@ -626,6 +741,7 @@ pub struct ListingTable {
     collected_statistics: FileStatisticsCache,
     constraints: Constraints,
     column_defaults: HashMap<String, Expr>,
+    listing_token: Option<ListingToken>,
 }
 
 impl ListingTable {
@@ -663,6 +779,7 @@ impl ListingTable {
             collected_statistics: Arc::new(DefaultFileStatisticsCache::default()),
             constraints: Constraints::empty(),
             column_defaults: HashMap::new(),
+            listing_token: None,
         };
 
         Ok(table)
@@ -701,6 +818,66 @@ impl ListingTable {
         self
     }
 
+    /// Assign the [`ListingToken`] checked by [`Self::verify_listing`]
+    pub fn with_listing_token(mut self, listing_token: Option<ListingToken>) -> Self {
+        self.listing_token = listing_token;
+        self
+    }
+
+    /// List the files of this table and record them as the [`ListingToken`]
+    /// checked by [`Self::verify_listing`].
+    ///
+    /// Fails if any of the listings fails, rather than recording the files
+    /// listed before the error.
+    pub async fn with_recorded_listing(self, state: &SessionState) -> Result<Self> {
+        let listing_token = self.list_token(state).await?;
+        Ok(self.with_listing_token(Some(listing_token)))
+    }
+
+    /// Get the recorded [`ListingToken`], if any
+    pub fn listing_token(&self) -> Option<&ListingToken> {
+        self.listing_token.as_ref()
+    }
+
+    /// List the files of this table again and check that they match the
+    /// recorded [`ListingToken`], returning an error if files are missing,
+    /// for example because the recorded listing was truncated, or were added
+    /// or changed since.
+    pub async fn verify_listing(&self, state: &SessionState) -> Result<()> {
+        let Some(expected) = self.listing_token else {
+            return plan_err!("No listing was recorded for this table");
+        };
+        let actual = self.list_token(state).await?;
+        if actual.file_count != expected.file_count {
+            return exec_err!(
+                "Expected {} files in the listing of the table but found {}",
+                expected.file_count,
+                actual.file_count
+            );
+        }
+        if actual.digest != expected.digest {
+            return exec_err!(
+                "The files of the table changed since its listing was recorded"
+            );
+        }
+        Ok(())
+    }
+
+    /// Create a [`ListingToken`] from the files in all table paths
+    async fn list_token(&self, state: &SessionState) -> Result<ListingToken> {
+        let mut files = vec![];
+        for table_path in &self.table_paths {
+            let store = state.runtime_env().object_store(table_path)?;
+            let listed: Vec<ObjectMeta> = table_path
+                .list_all_files(state, store.as_ref(), &self.options.file_extension)
+                .await?
+                .try_collect()
+                .await?;
+            files.extend(listed);
+        }
+        Ok(ListingToken::new(&files))
+    }
+
     /// Get paths ref
     pub fn table_paths(&self) -> &Vec<ListingTableUrl> {
         &self.table_paths
@@ -967,7 +1144,11 @@ impl ListingTable {
             )
         }))
         .await?;
-        let file_list = stream::iter(file_list).flatten();
+        // a file matched by several table paths is only scanned once
+        let mut seen = HashSet::new();
+        let file_list = stream::iter(file_list).flatten().try_filter(move |file| {
+            future::ready(seen.insert(file.object_meta.location.clone()))
+        });
         // collect the statistics if required by the config
         let files = file_list
             .map(|part_file| async {
@@ -994,10 +1175,12 @@ impl ListingTable {
         )
         .await?;
 
-        Ok((
-            split_files(files, self.options.target_partitions),
-            statistics,
-        ))
+        let file_groups = if self.options.split_files_by_size {
+            split_files_by_size(files, self.options.target_partitions)
+        } else {
+            split_files(files, self.options.target_partitions)
+        };
+        Ok((file_groups, statistics))
     }
 
     /// Collects statistics for a given partitioned file.
@@ -1050,21 +1233,23 @@ mod tests {
     use crate::datasource::{provider_as_source, MemTable};
     use crate::execution::options::ArrowReadOptions;
     use crate::prelude::*;
-    use crate::{
-        assert_batches_eq,
-        test::{columns, object_store::register_test_store},
+    use crate::test::object_store::{
+        make_test_store_and_state, register_test_store, UnreadableObjectStore,
     };
+    use crate::{assert_batches_eq, test::columns};
     use datafusion_physical_plan::collect;
 
     use arrow::record_batch::RecordBatch;
     use arrow_schema::SortOptions;
     use datafusion_common::stats::Precision;
-    use datafusion_common::{assert_contains, ScalarValue};
+    use datafusion_common::{assert_contains, ErrorOrigin, ScalarValue};
     use datafusion_expr::{BinaryExpr, LogicalPlanBuilder, Operator};
     use datafusion_physical_expr::PhysicalSortExpr;
     use datafusion_physical_plan::ExecutionPlanProperties;
 
+    use object_store::path::Path;
     use tempfile::TempDir;
+    use url::Url;
 
     #[tokio::test]
     async fn read_single_file() -> Result<()> {
@@ -1087,6 +1272,108 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn listing_error_fails_query() -> Result<()> {
+        let (memory, _) =
+            make_test_store_and_state(&[("table/a.csv", 10), ("table/sub/b.csv", 10)]);
+        // the listing fails once it reaches the files under table/sub
+        let store = UnreadableObjectStore::new(memory, "table/sub");
+        let ctx = SessionContext::new();
+        ctx.register_object_store(&Url::parse("test://").unwrap(), Arc::new(store));
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let config = ListingTableConfig::new(ListingTableUrl::parse("test:///table/")?)
+            .with_listing_options(ListingOptions::new(Arc::new(CsvFormat::default())))
+            .with_schema(schema);
+        ctx.register_table("t", Arc::new(ListingTable::try_new(config)?))?;
+
+        // the query fails instead of reading the files listed before the error
+        let err = ctx
+            .sql("SELECT * FROM t")
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "cannot read table/sub/b.csv");
+        assert_eq!(err.origin(), ErrorOrigin::External);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn listing_error_fails_recorded_listing() -> Result<()> {
+        let (memory, _) =
+            make_test_store_and_state(&[("table/a.csv", 10), ("table/sub/b.csv", 10)]);
+        let store = UnreadableObjectStore::new(memory, "table/sub");
+        let ctx = SessionContext::new();
+        ctx.register_object_store(&Url::parse("test://").unwrap(), Arc::new(store));
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let config = ListingTableConfig::new(ListingTableUrl::parse("test:///table/")?)
+            .with_listing_options(ListingOptions::new(Arc::new(CsvFormat::default())))
+            .with_schema(schema);
+
+        // no listing is recorded from the files listed before the error
+        let Err(err) = ListingTable::try_new(config)?
+            .with_recorded_listing(&ctx.state())
+            .await
+        else {
+            panic!("expected the recorded listing to fail");
+        };
+        assert_contains!(err.to_string(), "cannot read table/sub/b.csv");
+        assert_eq!(err.origin(), ErrorOrigin::External);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_recorded_listing() -> Result<()> {
+        let (memory, _) =
+            make_test_store_and_state(&[("table/a.csv", 10), ("table/b.csv", 10)]);
+        let ctx = SessionContext::new();
+        ctx.register_object_store(
+            &Url::parse("test://").unwrap(),
+            Arc::clone(&memory) as _,
+        );
+        let state = ctx.state();
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let config = ListingTableConfig::new(ListingTableUrl::parse("test:///table/")?)
+            .with_listing_options(ListingOptions::new(Arc::new(CsvFormat::default())))
+            .with_schema(schema);
+        let table = ListingTable::try_new(config)?;
+
+        let err = table.verify_listing(&state).await.unwrap_err();
+        assert_contains!(err.to_string(), "No listing was recorded for this table");
+
+        let table = table.with_recorded_listing(&state).await?;
+        assert_eq!(table.listing_token().unwrap().file_count(), 2);
+        table.verify_listing(&state).await?;
+
+        // a listing truncated before b.csv no longer matches
+        let truncated =
+            ListingToken::new(&[memory.head(&Path::from("table/a.csv")).await?]);
+        let truncated = table.with_listing_token(Some(truncated));
+        let err = truncated.verify_listing(&state).await.unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "Expected 1 files in the listing of the table but found 2"
+        );
+        let table = truncated.with_recorded_listing(&state).await?;
+
+        // so does a file that changed since the listing was recorded
+        memory
+            .put(&Path::from("table/b.csv"), vec![0; 20].into())
+            .await?;
+        let err = table.verify_listing(&state).await.unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "The files of the table changed since its listing was recorded"
+        );
+
+        Ok(())
+    }
+
     #[cfg(feature = "parquet")]
     #[tokio::test]
     async fn load_table_stats_by_default() -> Result<()> {
@@ -1424,6 +1711,45 @@ mod tests {
         Ok(Arc::new(table))
     }
 
+    #[tokio::test]
+    async fn test_list_files_for_scan_grouping_by_size() -> Result<()> {
+        let ctx = SessionContext::new();
+        register_test_store(
+            &ctx,
+            &[
+                ("bucket/key-prefix/file0", 5000),
+                ("bucket/key-prefix/file1", 10),
+                ("bucket/key-prefix/file2", 10),
+                ("bucket/key-prefix/file3", 10),
+            ],
+        );
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Boolean, false)]));
+        let table_path = ListingTableUrl::parse("test:///bucket/key-prefix/")?;
+
+        for (split_files_by_size, expected) in
+            [(false, vec![5010, 20]), (true, vec![5000, 30])]
+        {
+            let opt = ListingOptions::new(Arc::new(AvroFormat {}))
+                .with_file_extension("")
+                .with_target_partitions(2)
+                .with_split_files_by_size(split_files_by_size);
+            let config = ListingTableConfig::new(table_path.clone())
+                .with_listing_options(opt)
+                .with_schema(Arc::clone(&schema));
+            let table = ListingTable::try_new(config)?;
+
+            let (file_list, _) =
+                table.list_files_for_scan(&ctx.state(), &[], None).await?;
+            let group_bytes: Vec<usize> = file_list
+                .iter()
+                .map(|group| group.iter().map(|f| f.object_meta.size).sum())
+                .collect();
+            assert_eq!(group_bytes, expected);
+        }
+        Ok(())
+    }
+
     /// Check that the files listed by the table match the specified `output_partitioning`
     /// when the object store contains `files`.
     async fn assert_list_files_for_scan_grouping(
@@ -23,10 +23,12 @@ use futures::stream::BoxStream;
 use futures::{StreamExt, TryStreamExt};
 use glob::Pattern;
 use itertools::Itertools;
-use log::debug;
+use log::{debug, warn};
 use object_store::path::Path;
 use object_store::path::DELIMITER;
 use object_store::{ObjectMeta, ObjectStore};
+use std::error::Error;
+use std::io::ErrorKind;
 use std::sync::Arc;
 use url::Url;
 
@@ -115,6 +117,18 @@ impl ListingTableUrl {
         }
     }
 
+    /// Parse the provided string into the [`ListingTableUrl`]s it stands for
+    /// after expanding brace alternatives.
+    ///
+    /// Each `{a,b,...}` group produces one URL per alternative, e.g.
+    /// `data/{2021,2022}/*.parquet` is parsed as `data/2021/*.parquet` and
+    /// `data/2022/*.parquet`. Groups may be nested, and braces that do not
+    /// contain a `,` are kept as is. Each expanded string is parsed with
+    /// [`ListingTableUrl::parse`], which keeps all braces as is.
+    pub fn parse_expanded(s: impl AsRef<str>) -> Result<Vec<Self>> {
+        expand_braces(s.as_ref()).iter().map(Self::parse).collect()
+    }
+
     /// Creates a new [`ListingTableUrl`] interpreting `s` as a filesystem path
     #[cfg(not(target_arch = "wasm32"))]
     fn parse_path(s: &str) -> Result<Self> {
@@ -204,42 +218,128 @@ impl ListingTableUrl {
     }
 
     /// List all files identified by this [`ListingTableUrl`] for the provided `file_extension`
+    ///
+    /// If `listing_table_sort_files` is set, the files of a collection are
+    /// returned sorted by their path, so that files in nested directories
+    /// follow the files of their parent that sort before them, e.g. `a.csv`,
+    /// `a/b.csv`, `b.csv`. This collects the whole listing before returning
+    /// the first file.
     pub async fn list_all_files<'a>(
         &'a self,
         ctx: &'a SessionState,
         store: &'a dyn ObjectStore,
         file_extension: &'a str,
     ) -> Result<BoxStream<'a, Result<ObjectMeta>>> {
+        let skip_unreadable = ctx.options().execution.listing_table_skip_unreadable_files;
+        let (files, _) = self
+            .list_matching_files(ctx, store, skip_unreadable, move |path| {
+                path.ends_with(file_extension)
+            })
+            .await?;
+        Ok(files)
+    }
+
+    /// List all files identified by this [`ListingTableUrl`] for the provided
+    /// `file_extension`, like [`Self::list_all_files`], skipping the paths
+    /// that cannot be read.
+    ///
+    /// Returns the files that were listed and the paths that were skipped
+    /// because they do not exist or could not be read for lack of
+    /// permissions, whatever `listing_table_skip_unreadable_files` is set to.
+    /// Other errors, such as network errors, still fail the listing.
+    pub async fn list_all_files_lenient(
+        &self,
+        ctx: &SessionState,
+        store: &dyn ObjectStore,
+        file_extension: &str,
+    ) -> Result<(Vec<ObjectMeta>, Vec<UnreadablePath>)> {
+        let (files, skipped) = self
+            .list_matching_files(ctx, store, true, move |path| {
+                path.ends_with(file_extension)
+            })
+            .await?;
+        Ok((files.try_collect().await?, skipped))
+    }
+
+    /// List all files identified by this [`ListingTableUrl`] that end with any
+    /// of the provided `file_extensions`, e.g. both `.csv` and `.csv.gz`
+    pub async fn list_all_files_with_extensions<'a>(
+        &'a self,
+        ctx: &'a SessionState,
+        store: &'a dyn ObjectStore,
+        file_extensions: &'a [&'a str],
+    ) -> Result<BoxStream<'a, Result<ObjectMeta>>> {
+        let skip_unreadable = ctx.options().execution.listing_table_skip_unreadable_files;
+        let (files, _) = self
+            .list_matching_files(ctx, store, skip_unreadable, move |path| {
+                file_extensions.iter().any(|ext| path.ends_with(ext))
+            })
+            .await?;
+        Ok(files)
+    }
+
+    /// List all files identified by this [`ListingTableUrl`] whose path
+    /// matches `path_match`, and the paths skipped if `skip_unreadable` is
+    /// set, see [`collect_listing`]
+    async fn list_matching_files<'a>(
+        &'a self,
+        ctx: &'a SessionState,
+        store: &'a dyn ObjectStore,
+        skip_unreadable: bool,
+        path_match: impl Fn(&str) -> bool + Send + 'a,
+    ) -> Result<(BoxStream<'a, Result<ObjectMeta>>, Vec<UnreadablePath>)> {
         let exec_options = &ctx.options().execution;
         let ignore_subdirectory = exec_options.listing_table_ignore_subdirectory;
+        let sort_files = exec_options.listing_table_sort_files;
+        let cache = ctx.runtime_env().cache_manager.get_list_files_cache();
+        let mut skipped = vec![];
         // If the prefix is a file, use a head request, otherwise list
         let list = match self.is_collection() {
-            true => match ctx.runtime_env().cache_manager.get_list_files_cache() {
-                None => store.list(Some(&self.prefix)),
-                Some(cache) => {
-                    if let Some(res) = cache.get(&self.prefix) {
+            // nothing requires the whole listing, stream it
+            true if cache.is_none() && !sort_files && !skip_unreadable => {
+                store.list(Some(&self.prefix))
+            }
+            true => {
+                let mut files = match cache.as_ref().and_then(|c| c.get(&self.prefix)) {
+                    Some(res) => {
                         debug!("Hit list all files cache");
-                        futures::stream::iter(res.as_ref().clone().into_iter().map(Ok))
-                            .boxed()
-                    } else {
+                        res.as_ref().clone()
+                    }
+                    None => {
                         let list_res = store.list(Some(&self.prefix));
-                        let vec = list_res.try_collect::<Vec<ObjectMeta>>().await?;
-                        cache.put(&self.prefix, Arc::new(vec.clone()));
-                        futures::stream::iter(vec.into_iter().map(Ok)).boxed()
+                        let vec;
+                        (vec, skipped) = collect_listing(
+                            store,
+                            list_res,
+                            &self.prefix,
+                            skip_unreadable,
+                        )
+                        .await?;
+                        // a listing that skipped paths is incomplete, and
+                        // must not be served to later queries
+                        if let (Some(cache), true) = (cache, skipped.is_empty()) {
+                            cache.put(&self.prefix, Arc::new(vec.clone()));
+                        }
+                        vec
                     }
+                };
+                if sort_files {
+                    sort_by_location(&mut files);
                 }
-            },
+                futures::stream::iter(files.into_iter().map(Ok)).boxed()
+            }
             false => futures::stream::once(store.head(&self.prefix)).boxed(),
         };
-        Ok(list
+        let list = list
             .try_filter(move |meta| {
                 let path = &meta.location;
-                let extension_match = path.as_ref().ends_with(file_extension);
+                let extension_match = path_match(path.as_ref());
                 let glob_match = self.contains(path, ignore_subdirectory);
                 futures::future::ready(extension_match && glob_match)
             })
             .map_err(DataFusionError::ObjectStore)
-            .boxed())
+            .boxed();
+        Ok((list, skipped))
     }
 
     /// Returns this [`ListingTableUrl`] as a string
@@ -254,6 +354,140 @@ impl ListingTableUrl {
     }
 }
 
+/// Sorts `files` by their path, used if `listing_table_sort_files` is set.
+///
+/// [`ObjectStore::list`] does not guarantee any order, and for some
+/// implementations such as `LocalFileSystem` it follows the non-deterministic
+/// order of the file system. Sorting makes listing the same location return
+/// the files in the same order across runs and platforms, at the cost of
+/// collecting the whole listing first.
+fn sort_by_location(files: &mut [ObjectMeta]) {
+    files.sort_unstable_by(|a, b| a.location.cmp(&b.location));
+}
+
+/// A path skipped while listing files because it could not be read, see
+/// [`ListingTableUrl::list_all_files_lenient`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreadablePath {
+    /// The path that could not be read, or the listed prefix if no path
+    /// under it could be found to fail
+    pub path: Path,
+    /// The error reading the path
+    pub reason: String,
+}
+
+/// Returns true if `e` was raised because a path does not exist or cannot be
+/// read for lack of permissions, rather than, for example, because of a
+/// network error.
+///
+/// Stores report missing permissions as generic errors caused by an I/O
+/// error of kind [`ErrorKind::PermissionDenied`], e.g. when a directory of a
+/// `LocalFileSystem` cannot be read.
+pub(crate) fn is_unreadable_error(e: &object_store::Error) -> bool {
+    match e {
+        object_store::Error::NotFound { .. } => true,
+        object_store::Error::Generic { source, .. } => {
+            let mut source: Option<&(dyn Error + 'static)> = Some(source.as_ref());
+            while let Some(e) = source {
+                if let Some(io_error) = e.downcast_ref::<std::io::Error>() {
+                    if matches!(
+                        io_error.kind(),
+                        ErrorKind::PermissionDenied | ErrorKind::NotFound
+                    ) {
+                        return true;
+                    }
+                }
+                source = e.source();
+            }
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Collects the objects of the listing `list` of the path `listed` in
+/// `store`.
+///
+/// If `skip_unreadable` is set, the paths that do not exist or cannot be read
+/// for lack of permissions are logged as warnings and skipped instead of
+/// failing the listing. Other errors are always returned. Returns the listed
+/// objects and the skipped paths.
+pub(crate) async fn collect_listing(
+    store: &dyn ObjectStore,
+    mut list: BoxStream<'_, object_store::Result<ObjectMeta>>,
+    listed: &Path,
+    skip_unreadable: bool,
+) -> Result<(Vec<ObjectMeta>, Vec<UnreadablePath>)> {
+    let mut objects = vec![];
+    let mut skipped: Vec<UnreadablePath> = vec![];
+    while let Some(result) = list.next().await {
+        match result {
+            Ok(meta) => objects.push(meta),
+            Err(e) if skip_unreadable && is_unreadable_error(&e) => {
+                match unreadable_path(store, &e, listed, &skipped).await {
+                    Some(path) => {
+                        warn!("Skipping unreadable path {path} while listing files: {e}");
+                        skipped.push(UnreadablePath {
+                            path,
+                            reason: e.to_string(),
+                        });
+                    }
+                    None => debug!("Skipping unreadable path while listing files: {e}"),
+                }
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok((objects, skipped))
+}
+
+/// Returns the path that the [`is_unreadable_error`] `e` failed to read while
+/// listing `listed` in `store`, or `None` if it is one of the `skipped` paths.
+///
+/// Only [`object_store::Error::NotFound`] names the path that failed, the
+/// stores report the other errors of a listing without it, e.g. for a
+/// directory of a `LocalFileSystem` that cannot be read. The path is then
+/// located by listing `listed` one level at a time, looking for the first
+/// path that cannot be listed. This is only done once a listing failed.
+/// `listed` is returned if no path under it fails anymore.
+async fn unreadable_path(
+    store: &dyn ObjectStore,
+    e: &object_store::Error,
+    listed: &Path,
+    skipped: &[UnreadablePath],
+) -> Option<Path> {
+    let is_skipped = |path: &Path| skipped.iter().any(|s| path.prefix_matches(&s.path));
+    let path = match e {
+        object_store::Error::NotFound { path, .. } => Path::from(path.as_str()),
+        _ => {
+            let mut pending = vec![listed.clone()];
+            let mut found = None;
+            while let Some(path) = pending.pop() {
+                if is_skipped(&path) {
+                    continue;
+                }
+                let prefix = Some(&path).filter(|p| !p.as_ref().is_empty());
+                match store.list_with_delimiter(prefix).await {
+                    Ok(result) => {
+                        pending.extend(result.common_prefixes.into_iter().rev())
+                    }
+                    Err(e) if is_unreadable_error(&e) => {
+                        found = Some(path);
+                        break;
+                    }
+                    Err(_) => break,
+                }
+            }
+            match found {
+                Some(path) => path,
+                None if skipped.is_empty() => listed.clone(),
+                None => return None,
+            }
+        }
+    };
+    (!is_skipped(&path)).then_some(path)
+}
+
 /// Creates a file URL from a potentially relative filesystem path
 #[cfg(not(target_arch = "wasm32"))]
 fn url_from_filesystem_path(s: &str) -> Option<Url> {
@@ -303,6 +537,51 @@ impl std::fmt::Display for ListingTableUrl {
 
 const GLOB_START_CHARS: [char; 3] = ['?', '*', '['];
 
+/// Expands the first `{a,b,...}` group of `s` with at least two
+/// alternatives, recursively, returning `s` unchanged if there is none.
+fn expand_braces(s: &str) -> Vec<String> {
+    let mut search_from = 0;
+    while let Some(offset) = s[search_from..].find('{') {
+        let open = search_from + offset;
+        let mut depth = 0;
+        let mut start = open + 1;
+        let mut alternatives = vec![];
+        let mut close = None;
+        for (idx, c) in s[open..].char_indices() {
+            let idx = open + idx;
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        alternatives.push(&s[start..idx]);
+                        close = Some(idx);
+                        break;
+                    }
+                }
+                ',' if depth == 1 => {
+                    alternatives.push(&s[start..idx]);
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+        match close {
+            Some(close) if alternatives.len() > 1 => {
+                let (prefix, suffix) = (&s[..open], &s[close + 1..]);
+                return alternatives
+                    .into_iter()
+                    .flat_map(|alternative| {
+                        expand_braces(&format!("{prefix}{alternative}{suffix}"))
+                    })
+                    .collect();
+            }
+            _ => search_from = open + 1,
+        }
+    }
+    vec![s.to_string()]
+}
+
 /// Splits `path` at the first path segment containing a glob expression, returning
 /// `None` if no glob expression found.
 ///
@@ -464,6 +743,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_expand_braces() {
+        fn test(input: &str, expected: &[&str]) {
+            assert_eq!(expand_braces(input), expected, "expanding {input}");
+        }
+
+        test("data/*.parquet", &["data/*.parquet"]);
+        test(
+            "data/{2021,2022}/*.parquet",
+            &["data/2021/*.parquet", "data/2022/*.parquet"],
+        );
+        test(
+            "{a,b}/{c,d}.csv",
+            &["a/c.csv", "a/d.csv", "b/c.csv", "b/d.csv"],
+        );
+        test("data/{a,b{1,2}}", &["data/a", "data/b1", "data/b2"]);
+        test("data/{,x}y", &["data/y", "data/xy"]);
+        // braces without alternatives or without a closing brace are literal
+        test("data/{a}/{b,c}", &["data/{a}/b", "data/{a}/c"]);
+        test("data/{a,b", &["data/{a,b"]);
+    }
+
+    #[test]
+    fn test_parse_expanded() {
+        fn test(input: &str, expected: &[&str]) {
+            let urls = ListingTableUrl::parse_expanded(input).unwrap();
+            let urls: Vec<_> = urls.iter().map(|url| url.as_str()).collect();
+            assert_eq!(urls, expected, "parsing {input}");
+        }
+
+        test(
+            "s3://bucket/{a,b}/*.parquet",
+            &["s3://bucket/a/*.parquet", "s3://bucket/b/*.parquet"],
+        );
+        test("s3://bucket/{a,b}/", &["s3://bucket/a/", "s3://bucket/b/"]);
+
+        // braces are kept as is without expansion
+        let url = ListingTableUrl::parse("s3://bucket/{a,b}/").unwrap();
+        assert_eq!(url.as_str(), "s3://bucket/%7Ba,b%7D/");
+    }
+
     #[test]
     fn test_is_collection() {
         fn test(input: &str, expected: bool, message: &str) {
@@ -493,4 +813,188 @@ mod tests {
             "path not ends with / - fragment ends with / - not collection",
         );
     }
+
+    #[tokio::test]
+    async fn test_list_all_files_with_extensions() {
+        let dir = tempdir().unwrap();
+        for name in ["a.csv", "b.csv.gz", "c.parquet", "d.csv.bz2"] {
+            std::fs::File::create(dir.path().join(name)).unwrap();
+        }
+        let url = ListingTableUrl::parse(dir.path().to_str().unwrap()).unwrap();
+        let ctx = crate::prelude::SessionContext::new();
+        let state = ctx.state();
+        let store = object_store::local::LocalFileSystem::new();
+
+        async fn file_names(
+            files: Result<BoxStream<'_, Result<ObjectMeta>>>,
+        ) -> Vec<String> {
+            let mut names: Vec<_> = files
+                .unwrap()
+                .map_ok(|meta| meta.location.filename().unwrap().to_string())
+                .try_collect()
+                .await
+                .unwrap();
+            names.sort();
+            names
+        }
+
+        let files = url
+            .list_all_files_with_extensions(&state, &store, &[".csv", ".csv.gz"])
+            .await;
+        assert_eq!(file_names(files).await, vec!["a.csv", "b.csv.gz"]);
+
+        let files = url.list_all_files(&state, &store, ".csv").await;
+        assert_eq!(file_names(files).await, vec!["a.csv"]);
+
+        let files = url
+            .list_all_files_with_extensions(&state, &store, &[])
+            .await;
+        assert!(file_names(files).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_all_files_sorted() {
+        let dir = tempdir().unwrap();
+        for name in [
+            "c/a.csv",
+            "b.csv",
+            "a/z.csv",
+            "a.csv",
+            "a/b/c.csv",
+            "a-b.csv",
+        ] {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::File::create(path).unwrap();
+        }
+        let url = ListingTableUrl::parse(dir.path().to_str().unwrap()).unwrap();
+        let mut config = crate::prelude::SessionConfig::new();
+        let options = &mut config.options_mut().execution;
+        options.listing_table_ignore_subdirectory = false;
+        options.listing_table_sort_files = true;
+        let ctx = crate::prelude::SessionContext::new_with_config(config);
+        let state = ctx.state();
+        let store = object_store::local::LocalFileSystem::new();
+
+        let list = || async {
+            url.list_all_files(&state, &store, ".csv")
+                .await
+                .unwrap()
+                .map_ok(|meta| url.strip_prefix(&meta.location).unwrap().join("/"))
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap()
+        };
+
+        let files = list().await;
+        assert_eq!(
+            files,
+            vec![
+                "a-b.csv",
+                "a.csv",
+                "a/b/c.csv",
+                "a/z.csv",
+                "b.csv",
+                "c/a.csv"
+            ]
+        );
+        assert_eq!(list().await, files);
+    }
+
+    #[tokio::test]
+    async fn test_list_all_files_skip_unreadable() {
+        use crate::test::object_store::{
+            make_test_store_and_state, UnreadableObjectStore,
+        };
+        use datafusion_execution::cache::cache_manager::CacheManagerConfig;
+        use datafusion_execution::cache::cache_unit::DefaultListFilesCache;
+        use datafusion_execution::cache::CacheAccessor;
+        use datafusion_execution::runtime_env::RuntimeEnvBuilder;
+
+        let (memory, _) = make_test_store_and_state(&[
+            ("table/a.csv", 10),
+            ("table/sub/b.csv", 10),
+            ("table/sub/c.csv", 10),
+        ]);
+        let store = UnreadableObjectStore::new(memory, "table/sub");
+        let url = ListingTableUrl::parse("file:///table/").unwrap();
+
+        let list_files_cache = Arc::new(DefaultListFilesCache::default());
+        let runtime = RuntimeEnvBuilder::new()
+            .with_cache_manager(
+                CacheManagerConfig::default()
+                    .with_list_files_cache(Some(Arc::clone(&list_files_cache) as _)),
+            )
+            .build()
+            .unwrap();
+        let mut config = crate::prelude::SessionConfig::new();
+        config
+            .options_mut()
+            .execution
+            .listing_table_ignore_subdirectory = false;
+        let ctx =
+            crate::prelude::SessionContext::new_with_config_rt(config, Arc::new(runtime));
+
+        // by default the unreadable directory fails the listing
+        let mut state = ctx.state();
+        assert!(url.list_all_files(&state, &store, ".csv").await.is_err());
+
+        // the readable files are listed if configured to skip unreadable files
+        state
+            .config_mut()
+            .options_mut()
+            .execution
+            .listing_table_skip_unreadable_files = true;
+        let files = url
+            .list_all_files(&state, &store, ".csv")
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].location.filename(), Some("a.csv"));
+
+        // but the incomplete listing is not cached
+        assert_eq!(list_files_cache.len(), 0);
+
+        // the skipped paths are returned by the lenient listing
+        state
+            .config_mut()
+            .options_mut()
+            .execution
+            .listing_table_skip_unreadable_files = false;
+        let (files, skipped) = url
+            .list_all_files_lenient(&state, &store, ".csv")
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].path, Path::from("table/sub"));
+        assert!(skipped[0].reason.contains("cannot read table/sub/b.csv"));
+        assert_eq!(list_files_cache.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_all_files_skip_unreadable_other_errors() {
+        use crate::test::object_store::{
+            make_test_store_and_state, UnreadableObjectStore,
+        };
+
+        let (memory, state) =
+            make_test_store_and_state(&[("table/a.csv", 10), ("table/sub/b.csv", 10)]);
+        let store = UnreadableObjectStore::new(memory, "table/sub")
+            .with_error_kind(std::io::ErrorKind::ConnectionReset);
+        let url = ListingTableUrl::parse("file:///table/").unwrap();
+
+        // errors other than missing paths or permissions are never skipped
+        let err = url
+            .list_all_files_lenient(&state, &store, ".csv")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("cannot read table/sub/b.csv"),
+            "{err}"
+        );
+    }
 }
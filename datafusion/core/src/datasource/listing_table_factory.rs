@@ -176,6 +176,7 @@ mod tests {
             schema: Arc::new(DFSchema::empty()),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             definition: None,
             order_exprs: vec![],
             unbounded: false,
@@ -215,6 +216,7 @@ mod tests {
             schema: Arc::new(DFSchema::empty()),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             definition: None,
             order_exprs: vec![],
             unbounded: false,
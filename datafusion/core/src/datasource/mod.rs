@@ -30,6 +30,8 @@ pub mod listing_table_factory;
 pub mod memory;
 pub mod physical_plan;
 pub mod provider;
+pub mod retrying_object_store;
+pub mod row_filter;
 pub mod schema_adapter;
 mod statistics;
 pub mod stream;
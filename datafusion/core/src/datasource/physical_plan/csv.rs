@@ -1210,6 +1210,65 @@ mod tests {
         crate::assert_batches_eq!(expected, &result);
     }
 
+    #[tokio::test]
+    async fn test_multi_file_directory() -> Result<()> {
+        let config = SessionConfig::new()
+            .with_batch_size(2)
+            .with_target_partitions(2);
+        let session_ctx = SessionContext::new_with_config(config);
+        let store = object_store::memory::InMemory::new();
+        for (i, data) in ["1;a;x\n2;b;y\n3;c;z\n", "4;d;x\n5;e;y\n", "6;f;z\n"]
+            .into_iter()
+            .enumerate()
+        {
+            let path = object_store::path::Path::from(format!("dir/{i}.csv"));
+            store.put(&path, bytes::Bytes::from(data).into()).await?;
+        }
+        let url = Url::parse("memory://").unwrap();
+        session_ctx.register_object_store(&url, Arc::new(store));
+
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, false),
+            Field::new("tag", DataType::Utf8, false),
+        ]);
+        let options = CsvReadOptions::new()
+            .has_header(false)
+            .delimiter(b';')
+            .schema(&schema);
+        let df = session_ctx
+            .read_csv("memory:///dir/", options)
+            .await?
+            .select_columns(&["id", "tag"])?;
+
+        // the files are split over the target partitions, and only the
+        // projected columns are read
+        let plan = df.clone().create_physical_plan().await?;
+        let csv = plan
+            .as_any()
+            .downcast_ref::<CsvExec>()
+            .expect("projection pushed into the scan");
+        assert_eq!(csv.base_config().file_groups.len(), 2);
+        assert_eq!(csv.schema().fields().len(), 2);
+
+        let batches = df.collect().await?;
+        assert!(batches.iter().all(|batch| batch.num_rows() <= 2));
+        let expected = [
+            "+----+-----+",
+            "| id | tag |",
+            "+----+-----+",
+            "| 1  | x   |",
+            "| 2  | y   |",
+            "| 3  | z   |",
+            "| 4  | x   |",
+            "| 5  | y   |",
+            "| 6  | z   |",
+            "+----+-----+",
+        ];
+        crate::assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn write_csv_results_error_handling() -> Result<()> {
         let ctx = SessionContext::new();
@@ -30,7 +30,7 @@ use arrow::array::{ArrayData, BufferBuilder};
 use arrow::buffer::Buffer;
 use arrow::datatypes::{ArrowNativeType, UInt16Type};
 use arrow_array::{ArrayRef, DictionaryArray, RecordBatch, RecordBatchOptions};
-use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef};
 use datafusion_common::stats::Precision;
 use datafusion_common::{exec_err, ColumnStatistics, DataFusionError, Statistics};
 use datafusion_physical_expr::{LexOrdering, PhysicalSortExpr};
@@ -126,6 +126,9 @@ pub struct FileScanConfig {
     pub table_partition_cols: Vec<Field>,
     /// All equivalent lexicographical orderings that describe the schema.
     pub output_ordering: Vec<LexOrdering>,
+    /// Struct columns of which only some nested fields are projected, see
+    /// [`Self::with_nested_projection`]
+    nested_projection: HashMap<usize, FieldRef>,
 }
 
 impl FileScanConfig {
@@ -150,6 +153,7 @@ impl FileScanConfig {
             limit: None,
             table_partition_cols: vec![],
             output_ordering: vec![],
+            nested_projection: HashMap::new(),
         }
     }
 
@@ -165,6 +169,162 @@ impl FileScanConfig {
         self
     }
 
+    /// Set the projection of the files to the named columns, in that order
+    ///
+    /// The names are resolved to the indices expected by
+    /// [`Self::with_projection`] against the file schema followed by the
+    /// partition columns, so any partition columns must be set first.
+    ///
+    /// A dotted name such as `address.zip` that is not itself a column selects
+    /// a field nested in a struct column. The struct column is projected with
+    /// only the selected fields, keeping their nesting and file order, see
+    /// [`Self::with_nested_projection`]. Formats that decode struct columns by name,
+    /// such as Parquet, then only read the selected fields.
+    ///
+    /// Returns an error if there is no column or nested field with one of the
+    /// names.
+    pub fn with_projection_by_names(mut self, columns: &[&str]) -> Result<Self> {
+        let mut projection = Vec::with_capacity(columns.len());
+        // the nested fields selected in each column, empty if the whole
+        // column is selected
+        let mut selected_paths: HashMap<usize, Vec<Vec<&str>>> = HashMap::new();
+        for name in columns {
+            let (index, path) = self.resolve_column_path(name)?;
+            if !projection.contains(&index) {
+                projection.push(index);
+            }
+            selected_paths.entry(index).or_default().push(path);
+        }
+
+        self.nested_projection = HashMap::new();
+        Ok(self
+            .with_nested_paths(&selected_paths)
+            .with_projection(Some(projection)))
+    }
+
+    /// Set the struct columns of which only some nested fields are projected
+    ///
+    /// `nested_projection` maps the index of a column in [`Self::projection`]
+    /// numbering to the column with its struct type narrowed to the projected
+    /// fields. [`Self::file_schema`] keeps the full type of these columns.
+    pub fn with_nested_projection(
+        mut self,
+        nested_projection: HashMap<usize, FieldRef>,
+    ) -> Self {
+        self.nested_projection = nested_projection;
+        self
+    }
+
+    /// The struct columns set by [`Self::with_nested_projection`]
+    pub fn nested_projection(&self) -> &HashMap<usize, FieldRef> {
+        &self.nested_projection
+    }
+
+    /// Narrow struct columns to the fields nested in them at the given paths,
+    /// see [`Self::with_nested_projection`]
+    ///
+    /// `paths` maps the index of a column in [`Self::projection`] numbering
+    /// to the paths of the fields to keep, such as `["address", "zip"]` for
+    /// `address.zip` when the column is a struct with an `address` field of
+    /// struct type. An empty path keeps the whole column. Paths through
+    /// fields that are not structs keep the whole field, and columns already
+    /// narrowed are narrowed further.
+    pub fn with_nested_paths<S: AsRef<str>>(
+        mut self,
+        paths: &HashMap<usize, Vec<Vec<S>>>,
+    ) -> Self {
+        for (index, paths) in paths {
+            let field = Arc::new(self.projected_column(*index));
+            let paths: Vec<Vec<&str>> = paths
+                .iter()
+                .map(|path| path.iter().map(AsRef::as_ref).collect())
+                .collect();
+            let paths: Vec<&[&str]> = paths.iter().map(Vec::as_slice).collect();
+            let narrowed = narrow_struct_field(&field, &paths);
+            if narrowed != field {
+                self.nested_projection.insert(*index, narrowed);
+            }
+        }
+        self
+    }
+
+    /// The file columns followed by the partition columns, in the order
+    /// of the indices of [`Self::projection`]
+    fn columns(&self) -> impl Iterator<Item = &Field> {
+        self.file_schema
+            .fields()
+            .iter()
+            .map(|field| field.as_ref())
+            .chain(self.table_partition_cols.iter())
+    }
+
+    /// The column at `index` of [`Self::columns`], with its struct type
+    /// narrowed if only some of its nested fields are projected
+    fn projected_column(&self, index: usize) -> Field {
+        match self.nested_projection.get(&index) {
+            Some(field) => field.as_ref().clone(),
+            None => self.columns().nth(index).unwrap().clone(),
+        }
+    }
+
+    /// Resolve `name` to the index of a file or partition column and the path
+    /// of the field nested in it, see [`Self::with_projection_by_names`]
+    fn resolve_column_path<'a>(&self, name: &'a str) -> Result<(usize, Vec<&'a str>)> {
+        if let Some(index) = self.columns().position(|field| field.name() == name) {
+            return Ok((index, vec![]));
+        }
+
+        let mut parts = name.split('.');
+        let root = parts.next().unwrap_or_default();
+        let path: Vec<&str> = parts.collect();
+        let Some((index, field)) = self
+            .columns()
+            .enumerate()
+            .find(|(_, field)| field.name() == root)
+            .filter(|_| !path.is_empty())
+        else {
+            let valid_names = self
+                .columns()
+                .map(|field| field.name().as_str())
+                .collect::<Vec<_>>();
+            return Err(DataFusionError::Plan(format!(
+                "Column {name} not found in the files, valid columns are: {}",
+                valid_names.join(", ")
+            )));
+        };
+
+        let mut data_type = field.data_type();
+        for part in &path {
+            let child = match data_type {
+                DataType::Struct(fields) => fields.find(part).map(|(_, f)| f),
+                _ => None,
+            };
+            let Some(child) = child else {
+                return Err(DataFusionError::Plan(format!(
+                    "Column {name} not found in the files, {root} of type {} has no field {part}",
+                    field.data_type()
+                )));
+            };
+            data_type = child.data_type();
+        }
+        Ok((index, path))
+    }
+
+    /// The file schema with the struct columns narrowed to their projected
+    /// nested fields, see [`Self::with_nested_projection`]
+    pub fn file_schema_with_nested_projection(&self) -> SchemaRef {
+        if self.nested_projection.is_empty() {
+            return Arc::clone(&self.file_schema);
+        }
+        let fields = (0..self.file_schema.fields().len())
+            .map(|index| self.projected_column(index))
+            .collect::<Vec<_>>();
+        Arc::new(Schema::new_with_metadata(
+            fields,
+            self.file_schema.metadata().clone(),
+        ))
+    }
+
     /// Set the limit of the files
     pub fn with_limit(mut self, limit: Option<usize>) -> Self {
         self.limit = limit;
@@ -229,13 +389,10 @@ impl FileScanConfig {
         let mut table_fields = vec![];
         let mut table_cols_stats = vec![];
         for idx in proj_iter {
+            table_fields.push(self.projected_column(idx));
             if idx < self.file_schema.fields().len() {
-                let field = self.file_schema.field(idx);
-                table_fields.push(field.clone());
                 table_cols_stats.push(self.statistics.column_statistics[idx].clone())
             } else {
-                let partition_idx = idx - self.file_schema.fields().len();
-                table_fields.push(self.table_partition_cols[partition_idx].to_owned());
                 // TODO provide accurate stat for partition column (#1186)
                 table_cols_stats.push(ColumnStatistics::new_unknown())
             }
@@ -274,8 +431,7 @@ impl FileScanConfig {
         let fields = self.file_column_projection_indices().map(|indices| {
             indices
                 .iter()
-                .map(|col_idx| self.file_schema.field(*col_idx))
-                .cloned()
+                .map(|col_idx| self.projected_column(*col_idx))
                 .collect::<Vec<_>>()
         });
 
@@ -608,6 +764,35 @@ fn create_output_array(
     val.to_array_of_size(len)
 }
 
+/// Return `field` with its struct type narrowed to the nested fields at
+/// `paths`, keeping the order of the fields. An empty path selects the whole
+/// field.
+fn narrow_struct_field(field: &FieldRef, paths: &[&[&str]]) -> FieldRef {
+    let DataType::Struct(children) = field.data_type() else {
+        return Arc::clone(field);
+    };
+    if paths.iter().any(|path| path.is_empty()) {
+        return Arc::clone(field);
+    }
+    let children: Fields = children
+        .iter()
+        .filter_map(|child| {
+            let child_paths: Vec<&[&str]> = paths
+                .iter()
+                .filter(|path| path[0] == child.name().as_str())
+                .map(|path| &path[1..])
+                .collect();
+            (!child_paths.is_empty()).then(|| narrow_struct_field(child, &child_paths))
+        })
+        .collect();
+    Arc::new(
+        field
+            .as_ref()
+            .clone()
+            .with_data_type(DataType::Struct(children)),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use arrow_array::Int32Array;
@@ -615,6 +800,123 @@ mod tests {
     use super::*;
     use crate::{test::columns, test_util::aggr_test_schema};
 
+    #[test]
+    fn physical_plan_config_projection_by_names() -> Result<()> {
+        let file_schema = aggr_test_schema();
+        let conf = FileScanConfig::new(
+            ObjectStoreUrl::parse("test:///").unwrap(),
+            Arc::clone(&file_schema),
+        )
+        .with_table_partition_cols(to_partition_cols(vec![(
+            "date".to_owned(),
+            wrap_partition_type_in_dict(DataType::Utf8),
+        )]));
+
+        // partition columns follow the file columns
+        let conf = conf.with_projection_by_names(&["c3", "date", "c1"])?;
+        assert_eq!(conf.projection, Some(vec![2, 13, 0]));
+        let (proj_schema, _, _) = conf.project();
+        let names: Vec<_> = proj_schema.fields().iter().map(|f| f.name()).collect();
+        assert_eq!(names, vec!["c3", "date", "c1"]);
+
+        let err = conf.with_projection_by_names(&["c1", "c99"]).unwrap_err();
+        assert!(
+            err.strip_backtrace()
+                .starts_with("Error during planning: Column c99 not found in the files, valid columns are: c1, c2,"),
+            "{err}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn physical_plan_config_projection_by_nested_names() -> Result<()> {
+        let address = DataType::Struct(Fields::from(vec![
+            Field::new("street", DataType::Utf8, true),
+            Field::new(
+                "geo",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("lat", DataType::Float64, true),
+                    Field::new("lon", DataType::Float64, true),
+                ])),
+                true,
+            ),
+            Field::new("zip", DataType::Utf8, true),
+        ]));
+        let file_schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("address", address.clone(), true),
+        ]));
+        let conf = FileScanConfig::new(
+            ObjectStoreUrl::parse("test:///").unwrap(),
+            Arc::clone(&file_schema),
+        );
+
+        // the struct keeps the selected fields in file order
+        let projected = conf.clone().with_projection_by_names(&[
+            "address.zip",
+            "id",
+            "address.geo.lon",
+        ])?;
+        assert_eq!(projected.projection, Some(vec![1, 0]));
+        let (proj_schema, _, _) = projected.project();
+        let expected = Schema::new(vec![
+            Field::new(
+                "address",
+                DataType::Struct(Fields::from(vec![
+                    Field::new(
+                        "geo",
+                        DataType::Struct(Fields::from(vec![Field::new(
+                            "lon",
+                            DataType::Float64,
+                            true,
+                        )])),
+                        true,
+                    ),
+                    Field::new("zip", DataType::Utf8, true),
+                ])),
+                true,
+            ),
+            Field::new("id", DataType::Int32, false),
+        ]);
+        assert_eq!(proj_schema.as_ref(), &expected);
+        // the file schema keeps the full struct type
+        assert_eq!(projected.file_schema, file_schema);
+        assert_eq!(
+            projected.projected_file_schema().field(0),
+            expected.field(0)
+        );
+
+        // selecting the whole column as well keeps all its fields
+        let projected = conf
+            .clone()
+            .with_projection_by_names(&["address.zip", "address"])?;
+        assert_eq!(projected.projection, Some(vec![1]));
+        assert!(projected.nested_projection.is_empty());
+        let (proj_schema, _, _) = projected.project();
+        assert_eq!(proj_schema.field(0), file_schema.field(1));
+
+        let err = conf
+            .clone()
+            .with_projection_by_names(&["address.country"])
+            .unwrap_err();
+        assert!(
+            err.strip_backtrace().starts_with(
+                "Error during planning: Column address.country not found in the files"
+            ),
+            "{err}"
+        );
+        assert!(
+            err.strip_backtrace().ends_with("has no field country"),
+            "{err}"
+        );
+        let err = conf.with_projection_by_names(&["id.value"]).unwrap_err();
+        assert!(
+            err.strip_backtrace().contains("has no field value"),
+            "{err}"
+        );
+        Ok(())
+    }
+
     #[test]
     fn physical_plan_config_no_projection() {
         let file_schema = aggr_test_schema();
@@ -24,6 +24,8 @@
 use std::collections::VecDeque;
 use std::mem;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use crate::datasource::listing::PartitionedFile;
@@ -74,6 +76,10 @@ pub trait FileOpener: Unpin {
 }
 
 /// A stream that iterates record batch by record batch, file over file.
+///
+/// Files are only opened and read while the stream is polled, so dropping the
+/// stream, for example once a `LIMIT` upstream is satisfied, stops reading
+/// without any background task left to cancel.
 pub struct FileStream<F: FileOpener> {
     /// An iterator over input files.
     file_iter: VecDeque<PartitionedFile>,
@@ -82,6 +88,9 @@ pub struct FileStream<F: FileOpener> {
     projected_schema: SchemaRef,
     /// The remaining number of records to parse, None if no limit
     remain: Option<usize>,
+    /// The limit shared with the other partitions of the scan, if any, see
+    /// [`Self::with_shared_limit`]
+    shared_limit: Option<SharedLimit>,
     /// A generic [`FileOpener`]. Calling `open()` returns a [`FileOpenFuture`],
     /// which can be resolved to a stream of `RecordBatch`.
     file_opener: F,
@@ -97,6 +106,24 @@ pub struct FileStream<F: FileOpener> {
     on_error: OnError,
 }
 
+/// The number of rows produced by all the partitions of a scan, and the
+/// limit after which they stop reading
+struct SharedLimit {
+    limit: usize,
+    produced: Arc<AtomicUsize>,
+}
+
+impl SharedLimit {
+    fn is_reached(&self) -> bool {
+        self.produced.load(Ordering::Relaxed) >= self.limit
+    }
+
+    /// Record `rows` more produced rows, returning true if the limit is reached
+    fn add(&self, rows: usize) -> bool {
+        self.produced.fetch_add(rows, Ordering::Relaxed) + rows >= self.limit
+    }
+}
+
 /// Represents the state of the next `FileOpenFuture`. Since we need to poll
 /// this future while scanning the current file, we need to store the result if it
 /// is ready
@@ -266,6 +293,7 @@ impl<F: FileOpener> FileStream<F> {
             file_iter: files.into(),
             projected_schema,
             remain: config.limit,
+            shared_limit: None,
             file_opener,
             pc_projector,
             state: FileStreamState::Idle,
@@ -284,6 +312,19 @@ impl<F: FileOpener> FileStream<F> {
         self
     }
 
+    /// Share the limit of the scan with the other partitions reading it
+    ///
+    /// `produced` counts the rows produced by all the streams it is passed to.
+    /// Once it reaches the limit of the [`FileScanConfig`], every stream stops
+    /// without opening further files, rather than each partition reading up
+    /// to the limit on its own. This is only correct if the consumer does not
+    /// need `limit` rows from each partition, e.g. when the output is not
+    /// sorted. Has no effect if the scan has no limit.
+    pub fn with_shared_limit(mut self, produced: Arc<AtomicUsize>) -> Self {
+        self.shared_limit = self.remain.map(|limit| SharedLimit { limit, produced });
+        self
+    }
+
     /// Begin opening the next file in parallel while decoding the current file in FileStream.
     ///
     /// Since file opening is mostly IO (and may involve a
@@ -308,6 +349,10 @@ impl<F: FileOpener> FileStream<F> {
         loop {
             match &mut self.state {
                 FileStreamState::Idle => {
+                    if self.shared_limit.as_ref().is_some_and(|l| l.is_reached()) {
+                        self.state = FileStreamState::Limit;
+                        return Poll::Ready(None);
+                    }
                     self.file_stream_metrics.time_opening.start();
 
                     match self.start_next_file().transpose() {
@@ -411,6 +456,13 @@ impl<F: FileOpener> FileStream<F> {
                                     None => batch,
                                 });
 
+                            if let (Ok(batch), Some(shared_limit)) =
+                                (&result, &self.shared_limit)
+                            {
+                                if shared_limit.add(batch.num_rows()) {
+                                    self.state = FileStreamState::Limit;
+                                }
+                            }
                             if result.is_err() {
                                 // If the partition value projection fails, this is not governed by
                                 // the `OnError` behavior
@@ -515,9 +567,6 @@ impl<F: FileOpener> RecordBatchStream for FileStream<F> {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    use std::sync::Arc;
-
     use super::*;
     use crate::datasource::object_store::ObjectStoreUrl;
     use crate::prelude::SessionContext;
@@ -662,6 +711,61 @@ mod tests {
         }
     }
 
+    /// `FileOpener` counting the files opened and the batches read from them
+    struct CountingOpener {
+        opened: Arc<AtomicUsize>,
+        batches_read: Arc<AtomicUsize>,
+        records: Vec<RecordBatch>,
+    }
+
+    impl FileOpener for CountingOpener {
+        fn open(&self, _file_meta: FileMeta) -> Result<FileOpenFuture> {
+            self.opened.fetch_add(1, Ordering::SeqCst);
+            let batches_read = Arc::clone(&self.batches_read);
+            let stream = futures::stream::iter(self.records.clone())
+                .inspect(move |_| {
+                    batches_read.fetch_add(1, Ordering::SeqCst);
+                })
+                .map(Ok)
+                .boxed();
+            Ok(futures::future::ready(Ok(stream)).boxed())
+        }
+    }
+
+    #[tokio::test]
+    async fn dropping_stream_stops_reading() -> Result<()> {
+        let records = vec![make_partition(3), make_partition(2)];
+        let opened = Arc::new(AtomicUsize::new(0));
+        let batches_read = Arc::new(AtomicUsize::new(0));
+        let opener = CountingOpener {
+            opened: Arc::clone(&opened),
+            batches_read: Arc::clone(&batches_read),
+            records: records.clone(),
+        };
+
+        let file_group = (0..3)
+            .map(|idx| PartitionedFile::new(format!("mock_file{idx}"), 10))
+            .collect();
+        let config = FileScanConfig::new(
+            ObjectStoreUrl::parse("test:///").unwrap(),
+            records[0].schema(),
+        )
+        .with_file_group(file_group);
+        let metrics_set = ExecutionPlanMetricsSet::new();
+        let mut file_stream = FileStream::new(&config, 0, opener, &metrics_set)?;
+
+        let batch = file_stream.next().await.unwrap()?;
+        assert_eq!(batch.num_rows(), 3);
+        drop(file_stream);
+
+        // the files are only read while the stream is polled, so nothing
+        // besides the first batch and the opening of the next file happened
+        tokio::task::yield_now().await;
+        assert_eq!(opened.load(Ordering::SeqCst), 2);
+        assert_eq!(batches_read.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
     /// helper that creates a stream of 2 files with the same pair of batches in each ([0,1,2] and [0,1])
     async fn create_and_collect(limit: Option<usize>) -> Vec<RecordBatch> {
         FileStreamTest::new()
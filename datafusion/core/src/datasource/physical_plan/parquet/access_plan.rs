@@ -338,6 +338,25 @@ impl ParquetAccessPlan {
     }
 }
 
+/// Create a `ParquetAccessPlan` from a mask of the row groups to scan, for
+/// example computed by an external index: the i-th row group is scanned if
+/// the i-th element is `true` and skipped otherwise
+impl From<Vec<bool>> for ParquetAccessPlan {
+    fn from(row_group_mask: Vec<bool>) -> Self {
+        let row_groups = row_group_mask
+            .into_iter()
+            .map(|scan| {
+                if scan {
+                    RowGroupAccess::Scan
+                } else {
+                    RowGroupAccess::Skip
+                }
+            })
+            .collect();
+        Self::new(row_groups)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -366,6 +385,21 @@ mod test {
         assert_eq!(row_selection, None);
     }
 
+    #[test]
+    fn test_from_row_group_mask() {
+        let access_plan = ParquetAccessPlan::from(vec![true, false, false, true]);
+        assert_eq!(
+            access_plan.inner(),
+            &[
+                RowGroupAccess::Scan,
+                RowGroupAccess::Skip,
+                RowGroupAccess::Skip,
+                RowGroupAccess::Scan,
+            ]
+        );
+        assert_eq!(access_plan.row_group_indexes(), vec![0, 3]);
+    }
+
     #[test]
     fn test_only_skips() {
         let access_plan = ParquetAccessPlan::new(vec![
@@ -0,0 +1,203 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Prune whole files using their known [`Statistics`]
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray, UInt64Array};
+use arrow::datatypes::Schema;
+use datafusion_common::stats::Precision;
+use datafusion_common::{Column, ColumnStatistics, ScalarValue, Statistics};
+use datafusion_physical_plan::metrics::Count;
+use log::debug;
+
+use crate::datasource::listing::PartitionedFile;
+use crate::physical_optimizer::pruning::{PruningPredicate, PruningStatistics};
+
+/// Remove the files of `file_groups` whose [`PartitionedFile::statistics`]
+/// prove that they contain no row matching `pruning_predicate`.
+///
+/// This avoids opening the files, and reading their footers, at all. Files
+/// without statistics are kept. `files_pruned` is incremented for each
+/// removed file.
+pub(crate) fn prune_files_by_statistics(
+    file_groups: Vec<Vec<PartitionedFile>>,
+    pruning_predicate: &PruningPredicate,
+    file_schema: &Schema,
+    files_pruned: &Count,
+) -> Vec<Vec<PartitionedFile>> {
+    file_groups
+        .into_iter()
+        .map(|files| {
+            files
+                .into_iter()
+                .filter(|file| {
+                    let Some(statistics) = &file.statistics else {
+                        return true;
+                    };
+                    let pruning_stats = FilePruningStatistics {
+                        file_schema,
+                        statistics,
+                    };
+                    match pruning_predicate.prune(&pruning_stats) {
+                        Ok(values) if values == [false] => {
+                            files_pruned.add(1);
+                            false
+                        }
+                        Ok(_) => true,
+                        Err(e) => {
+                            debug!(
+                                "Error evaluating file predicate for {}: {e}",
+                                file.object_meta.location
+                            );
+                            true
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Wraps the [`Statistics`] of a single file as [`PruningStatistics`].
+///
+/// Only exact statistics are used, as estimates could prune files that
+/// contain matching rows.
+struct FilePruningStatistics<'a> {
+    file_schema: &'a Schema,
+    statistics: &'a Statistics,
+}
+
+impl FilePruningStatistics<'_> {
+    fn column(&self, column: &Column) -> Option<&ColumnStatistics> {
+        let idx = self.file_schema.index_of(column.name()).ok()?;
+        self.statistics.column_statistics.get(idx)
+    }
+}
+
+fn exact_value_array(value: &Precision<ScalarValue>) -> Option<ArrayRef> {
+    match value {
+        Precision::Exact(value) => value.to_array().ok(),
+        _ => None,
+    }
+}
+
+fn exact_count_array(count: &Precision<usize>) -> Option<ArrayRef> {
+    match count {
+        Precision::Exact(count) => Some(Arc::new(UInt64Array::from(vec![*count as u64]))),
+        _ => None,
+    }
+}
+
+impl PruningStatistics for FilePruningStatistics<'_> {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        exact_value_array(&self.column(column)?.min_value)
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        exact_value_array(&self.column(column)?.max_value)
+    }
+
+    fn num_containers(&self) -> usize {
+        1
+    }
+
+    fn null_counts(&self, column: &Column) -> Option<ArrayRef> {
+        exact_count_array(&self.column(column)?.null_count)
+    }
+
+    fn row_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        exact_count_array(&self.statistics.num_rows)
+    }
+
+    fn contained(
+        &self,
+        _column: &Column,
+        _values: &HashSet<ScalarValue>,
+    ) -> Option<BooleanArray> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physical_plan::metrics::ExecutionPlanMetricsSet;
+    use crate::physical_plan::metrics::MetricBuilder;
+
+    use arrow::datatypes::{DataType, Field};
+    use datafusion_expr::{col, lit};
+    use datafusion_physical_expr::planner::logical2physical;
+
+    fn file(name: &str, min: Option<i32>, max: Option<i32>) -> PartitionedFile {
+        let column_statistics = ColumnStatistics {
+            min_value: min.map_or(Precision::Absent, |min| {
+                Precision::Exact(ScalarValue::from(min))
+            }),
+            max_value: max.map_or(Precision::Absent, |max| {
+                Precision::Exact(ScalarValue::from(max))
+            }),
+            ..ColumnStatistics::new_unknown()
+        };
+        let mut file = PartitionedFile::new(name, 100);
+        file.statistics = Some(Statistics {
+            num_rows: Precision::Exact(10),
+            total_byte_size: Precision::Absent,
+            column_statistics: vec![column_statistics],
+        });
+        file
+    }
+
+    #[test]
+    fn prune_files() {
+        let schema = Schema::new(vec![Field::new("c1", DataType::Int32, true)]);
+        let expr = logical2physical(&col("c1").gt(lit(15)), &schema);
+        let pruning_predicate =
+            PruningPredicate::try_new(expr, Arc::new(schema.clone())).unwrap();
+        let metrics = ExecutionPlanMetricsSet::new();
+        let files_pruned = MetricBuilder::new(&metrics).global_counter("files_pruned");
+
+        let file_groups = vec![
+            vec![file("a", Some(1), Some(10)), file("b", Some(11), Some(20))],
+            vec![
+                file("c", None, None),
+                PartitionedFile::new("d", 100),
+                file("e", Some(0), Some(15)),
+            ],
+        ];
+        let file_groups = prune_files_by_statistics(
+            file_groups,
+            &pruning_predicate,
+            &schema,
+            &files_pruned,
+        );
+
+        let names: Vec<Vec<_>> = file_groups
+            .iter()
+            .map(|files| {
+                files
+                    .iter()
+                    .map(|f| f.object_meta.location.to_string())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(names, vec![vec!["b"], vec!["c", "d"]]);
+        assert_eq!(files_pruned.value(), 2);
+    }
+}
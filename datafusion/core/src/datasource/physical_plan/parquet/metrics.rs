@@ -37,6 +37,11 @@ pub struct ParquetFileMetrics {
     pub row_groups_matched_statistics: Count,
     /// Number of row groups pruned by statistics
     pub row_groups_pruned_statistics: Count,
+    /// Number of row groups skipped because they are not part of a sample,
+    /// see [`ParquetExec::with_row_group_sample`]
+    ///
+    /// [`ParquetExec::with_row_group_sample`]: super::ParquetExec::with_row_group_sample
+    pub row_groups_pruned_sample: Count,
     /// Total number of bytes scanned
     pub bytes_scanned: Count,
     /// Total rows filtered out by predicates pushed into parquet scan
@@ -47,6 +52,11 @@ pub struct ParquetFileMetrics {
     pub page_index_rows_filtered: Count,
     /// Total time spent evaluating parquet page index filters
     pub page_index_eval_time: Time,
+    /// Number of row groups skipped because they could not be decoded, see
+    /// `skip_corrupt_row_groups` in [`ParquetOptions`]
+    ///
+    /// [`ParquetOptions`]: datafusion_common::config::ParquetOptions
+    pub corrupt_row_groups_skipped: Count,
 }
 
 impl ParquetFileMetrics {
@@ -76,6 +86,10 @@ impl ParquetFileMetrics {
             .with_new_label("filename", filename.to_string())
             .counter("row_groups_pruned_statistics", partition);
 
+        let row_groups_pruned_sample = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("row_groups_pruned_sample", partition);
+
         let bytes_scanned = MetricBuilder::new(metrics)
             .with_new_label("filename", filename.to_string())
             .counter("bytes_scanned", partition);
@@ -95,17 +109,23 @@ impl ParquetFileMetrics {
             .with_new_label("filename", filename.to_string())
             .subset_time("page_index_eval_time", partition);
 
+        let corrupt_row_groups_skipped = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("corrupt_row_groups_skipped", partition);
+
         Self {
             predicate_evaluation_errors,
             row_groups_matched_bloom_filter,
             row_groups_pruned_bloom_filter,
             row_groups_matched_statistics,
             row_groups_pruned_statistics,
+            row_groups_pruned_sample,
             bytes_scanned,
             pushdown_rows_filtered,
             pushdown_eval_time,
             page_index_rows_filtered,
             page_index_eval_time,
+            corrupt_row_groups_skipped,
         }
     }
 }
@@ -18,7 +18,9 @@
 //! [`ParquetExec`] Execution plan for reading Parquet files
 
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
 use crate::datasource::listing::PartitionedFile;
@@ -40,12 +42,16 @@ use crate::{
 };
 
 use arrow::datatypes::SchemaRef;
+use datafusion_common::{not_impl_err, plan_err};
+use datafusion_physical_expr::utils::collect_columns;
 use datafusion_physical_expr::{EquivalenceProperties, LexOrdering, PhysicalExpr};
 
 use itertools::Itertools;
 use log::debug;
+use parking_lot::Mutex;
 
 mod access_plan;
+mod file_filter;
 mod metrics;
 mod opener;
 mod page_filter;
@@ -58,6 +64,7 @@ use crate::datasource::schema_adapter::{
     DefaultSchemaAdapterFactory, SchemaAdapterFactory,
 };
 pub use access_plan::{ParquetAccessPlan, RowGroupAccess};
+use file_filter::prune_files_by_statistics;
 pub use metrics::ParquetFileMetrics;
 use opener::ParquetOpener;
 pub use reader::{DefaultParquetFileReaderFactory, ParquetFileReaderFactory};
@@ -134,6 +141,12 @@ pub use writer::plan_to_parquet;
 /// * Schema evolution: read parquet files with different schemas into a unified
 ///   table schema. See [`SchemaAdapterFactory`] for more details.
 ///
+/// * Type coercion: columns whose type in the file differs from the table
+///   schema (the `file_schema` of the [`FileScanConfig`]) are cast to the table
+///   type, for example to read an `Int32` column as `Int64` or a `Utf8` column
+///   as `LargeUtf8`. The scan fails if the types cannot be cast. Columns are
+///   passed through unchanged when the types already match.
+///
 /// * metadata_size_hint: controls the number of bytes read from the end of the
 ///   file in the initial I/O when the default [`ParquetFileReaderFactory`]. If a
 ///   custom reader is used, it supplies the metadata directly and this parameter
@@ -142,6 +155,13 @@ pub use writer::plan_to_parquet;
 /// * User provided  [`ParquetAccessPlan`]s to skip row groups and/or pages
 ///   based on external information. See "Implementing External Indexes" below
 ///
+/// * Memory accounting: the memory to decode each batch is estimated from the
+///   Parquet metadata and reserved in the memory pool of the session before
+///   decoding. If the pool is exhausted, the scan waits briefly for other
+///   consumers to free memory, then reserves it regardless of the limit, so
+///   that consumers able to spill do so. The memory of the decoded batch
+///   stays reserved until the next batch is polled.
+///
 /// # Predicate Pushdown
 ///
 /// `ParquetExec` uses the provided [`PhysicalExpr`] predicate as a filter to
@@ -175,6 +195,10 @@ pub use writer::plan_to_parquet;
 ///
 /// The `ParquetExec` will try and reduce any provided `ParquetAccessPlan`
 /// further based on the contents of `ParquetMetadata` and other settings.
+/// If a predicate is also provided, only the row groups that are both
+/// selected by the access plan and not pruned by the predicate are read. A
+/// precomputed mask of the row groups to read can be converted into a
+/// `ParquetAccessPlan` with `ParquetAccessPlan::from(Vec<bool>)`.
 ///
 /// ## Example of providing a ParquetAccessPlan
 ///
@@ -254,6 +278,25 @@ pub struct ParquetExec {
     table_parquet_options: TableParquetOptions,
     /// Optional user defined schema adapter
     schema_adapter_factory: Option<Arc<dyn SchemaAdapterFactory>>,
+    /// Optional partitioning of the file groups declared by the user, see
+    /// [`ParquetExecBuilder::with_output_partitioning`]
+    declared_partitioning: Option<Partitioning>,
+    /// Rows produced by the partitions of the current execution, see
+    /// [`Self::shared_limit`]
+    shared_limit: Arc<Mutex<SharedLimitState>>,
+    /// Fraction and seed of the row groups to read, see
+    /// [`Self::with_row_group_sample`]
+    row_group_sample: Option<(f64, u64)>,
+}
+
+/// The counter of rows shared by the partitions of one execution of a
+/// [`ParquetExec`]
+#[derive(Debug, Default)]
+struct SharedLimitState {
+    /// The rows produced by the partitions of the execution
+    produced: Arc<AtomicUsize>,
+    /// The partitions executed so far
+    executed: HashSet<usize>,
 }
 
 /// [`ParquetExecBuilder`], builder for [`ParquetExec`].
@@ -266,6 +309,7 @@ pub struct ParquetExecBuilder {
     table_parquet_options: TableParquetOptions,
     parquet_file_reader_factory: Option<Arc<dyn ParquetFileReaderFactory>>,
     schema_adapter_factory: Option<Arc<dyn SchemaAdapterFactory>>,
+    declared_partitioning: Option<Partitioning>,
 }
 
 impl ParquetExecBuilder {
@@ -287,6 +331,7 @@ impl ParquetExecBuilder {
             table_parquet_options,
             parquet_file_reader_factory: None,
             schema_adapter_factory: None,
+            declared_partitioning: None,
         }
     }
 
@@ -356,6 +401,82 @@ impl ParquetExecBuilder {
         self
     }
 
+    /// Declare how the rows are partitioned across the file groups.
+    ///
+    /// By default [`ParquetExec`] reports
+    /// [`Partitioning::UnknownPartitioning`]. If the files were written such
+    /// that the rows are distributed across the file groups in a known way,
+    /// declaring it here lets the optimizer and downstream operators rely on
+    /// it:
+    ///
+    /// * [`Partitioning::Hash`]: each file group holds all rows whose hash of
+    ///   some columns maps to its index, with the hash function of
+    ///   [`RepartitionExec`]. This avoids repartitioning the scan output, for
+    ///   example below a partitioned join.
+    /// * [`Partitioning::Range`]: the file groups hold non overlapping,
+    ///   ascending ranges of a sort key, for example files written by ranges
+    ///   of a date.
+    ///
+    /// The declaration is trusted: the rows read are not checked against it,
+    /// so declaring a partitioning that the files do not follow produces
+    /// wrong results.
+    ///
+    /// The declared partitioning is discarded if the file groups are later
+    /// redistributed, such as by [`ExecutionPlan::repartitioned`].
+    ///
+    /// Returns a [`DataFusionError::NotImplemented`] error if the partitioning
+    /// is neither a hash nor a range partitioning, and an error if its
+    /// partition count does not match the number of file groups, or its
+    /// expressions do not refer to columns of the scan output.
+    ///
+    /// [`DataFusionError::NotImplemented`]: datafusion_common::DataFusionError::NotImplemented
+    /// [`RepartitionExec`]: crate::physical_plan::repartition::RepartitionExec
+    pub fn with_output_partitioning(
+        mut self,
+        partitioning: Partitioning,
+    ) -> Result<Self> {
+        let (exprs, partition_count) = match &partitioning {
+            Partitioning::Hash(exprs, partition_count) => {
+                (exprs.clone(), partition_count)
+            }
+            Partitioning::Range(sort_exprs, partition_count) => (
+                sort_exprs
+                    .iter()
+                    .map(|sort_expr| Arc::clone(&sort_expr.expr))
+                    .collect(),
+                partition_count,
+            ),
+            _ => {
+                return not_impl_err!(
+                    "Only hash or range partitioning can be declared for a parquet \
+                     scan, got {partitioning:?}"
+                )
+            }
+        };
+        let file_groups = self.file_scan_config.file_groups.len();
+        if *partition_count != file_groups {
+            return plan_err!(
+                "Declared output partitioning {partitioning:?} has {partition_count} partitions, \
+                 but the scan has {file_groups} file groups"
+            );
+        }
+        let (schema, _, _) = self.file_scan_config.project();
+        for column in exprs.iter().flat_map(collect_columns) {
+            let matches = schema
+                .fields()
+                .get(column.index())
+                .is_some_and(|field| field.name() == column.name());
+            if !matches {
+                return plan_err!(
+                    "Declared output partitioning refers to column {column} \
+                     that is not in the scan output"
+                );
+            }
+        }
+        self.declared_partitioning = Some(partitioning);
+        Ok(self)
+    }
+
     /// Convenience: build an `Arc`d `ParquetExec` from this builder
     pub fn build_arc(self) -> Arc<ParquetExec> {
         Arc::new(self.build())
@@ -371,9 +492,10 @@ impl ParquetExecBuilder {
             table_parquet_options,
             parquet_file_reader_factory,
             schema_adapter_factory,
+            declared_partitioning,
         } = self;
 
-        let base_config = file_scan_config;
+        let mut base_config = file_scan_config;
         debug!("Creating ParquetExec, files: {:?}, projection {:?}, predicate: {:?}, limit: {:?}",
         base_config.file_groups, base_config.projection, predicate, base_config.limit);
 
@@ -381,7 +503,7 @@ impl ParquetExecBuilder {
         let predicate_creation_errors =
             MetricBuilder::new(&metrics).global_counter("num_predicate_creation_errors");
 
-        let file_schema = &base_config.file_schema;
+        let file_schema = Arc::clone(&base_config.file_schema);
         let pruning_predicate = predicate
             .clone()
             .and_then(|predicate_expr| {
@@ -396,6 +518,18 @@ impl ParquetExecBuilder {
             })
             .filter(|p| !p.always_true());
 
+        // Skip the files whose statistics prove that no row matches
+        if let Some(pruning_predicate) = &pruning_predicate {
+            let files_pruned =
+                MetricBuilder::new(&metrics).global_counter("files_pruned");
+            base_config.file_groups = prune_files_by_statistics(
+                base_config.file_groups,
+                pruning_predicate,
+                &file_schema,
+                &files_pruned,
+            );
+        }
+
         let page_pruning_predicate = predicate
             .as_ref()
             .map(|predicate_expr| {
@@ -409,6 +543,7 @@ impl ParquetExecBuilder {
             projected_schema,
             &projected_output_ordering,
             &base_config,
+            declared_partitioning.clone(),
         );
         ParquetExec {
             base_config,
@@ -422,6 +557,9 @@ impl ParquetExecBuilder {
             cache,
             table_parquet_options,
             schema_adapter_factory,
+            declared_partitioning,
+            shared_limit: Default::default(),
+            row_group_sample: None,
         }
     }
 }
@@ -564,8 +702,73 @@ impl ParquetExec {
         self.table_parquet_options.global.bloom_filter_on_read
     }
 
-    fn output_partitioning_helper(file_config: &FileScanConfig) -> Partitioning {
-        Partitioning::UnknownPartitioning(file_config.file_groups.len())
+    /// If true, row groups that fail to decode are skipped instead of
+    /// failing the scan. Defaults to false
+    ///
+    /// See `skip_corrupt_row_groups` in [`ParquetOptions`] for details.
+    ///
+    /// [`ParquetOptions`]: datafusion_common::config::ParquetOptions
+    pub fn with_skip_corrupt_row_groups(mut self, skip_corrupt_row_groups: bool) -> Self {
+        self.table_parquet_options.global.skip_corrupt_row_groups =
+            skip_corrupt_row_groups;
+        self
+    }
+
+    /// Return the value described in [`Self::with_skip_corrupt_row_groups`]
+    fn skip_corrupt_row_groups(&self) -> bool {
+        self.table_parquet_options.global.skip_corrupt_row_groups
+    }
+
+    /// Only read a random `fraction` of the row groups of the files, for
+    /// `SYSTEM` sampling.
+    ///
+    /// Whether a row group is read only depends on `seed`, the path of its
+    /// file and its index in the file, so the same row groups are read
+    /// however the files are partitioned. The row groups skipped this way
+    /// are counted in the `row_groups_pruned_sample` metric.
+    pub fn with_row_group_sample(mut self, fraction: f64, seed: u64) -> Self {
+        self.row_group_sample = Some((fraction, seed));
+        self
+    }
+
+    /// Narrow the struct columns of this scan to the fields nested in them at
+    /// `paths`, so that only the leaf columns of those fields are read, see
+    /// [`FileScanConfig::with_nested_paths`]
+    pub fn with_nested_paths<S: AsRef<str>>(
+        mut self,
+        paths: &HashMap<usize, Vec<Vec<S>>>,
+    ) -> Self {
+        self.base_config = self.base_config.with_nested_paths(paths);
+        let (projected_schema, projected_statistics, projected_output_ordering) =
+            self.base_config.project();
+        self.projected_statistics = projected_statistics;
+        self.cache = Self::compute_properties(
+            projected_schema,
+            &projected_output_ordering,
+            &self.base_config,
+            self.declared_partitioning.clone(),
+        );
+        self
+    }
+
+    /// Fraction and seed set by [`Self::with_row_group_sample`]
+    pub fn row_group_sample(&self) -> Option<(f64, u64)> {
+        self.row_group_sample
+    }
+
+    /// Partitioning declared via [`ParquetExecBuilder::with_output_partitioning`],
+    /// if it still applies to the file groups of this scan
+    pub fn declared_partitioning(&self) -> Option<&Partitioning> {
+        self.declared_partitioning.as_ref()
+    }
+
+    fn output_partitioning_helper(
+        file_config: &FileScanConfig,
+        declared_partitioning: Option<Partitioning>,
+    ) -> Partitioning {
+        declared_partitioning.unwrap_or_else(|| {
+            Partitioning::UnknownPartitioning(file_config.file_groups.len())
+        })
     }
 
     /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
@@ -573,24 +776,61 @@ impl ParquetExec {
         schema: SchemaRef,
         orderings: &[LexOrdering],
         file_config: &FileScanConfig,
+        declared_partitioning: Option<Partitioning>,
     ) -> PlanProperties {
         // Equivalence Properties
         let eq_properties = EquivalenceProperties::new_with_orderings(schema, orderings);
 
         PlanProperties::new(
             eq_properties,
-            Self::output_partitioning_helper(file_config), // Output Partitioning
-            ExecutionMode::Bounded,                        // Execution Mode
+            // Output Partitioning
+            Self::output_partitioning_helper(file_config, declared_partitioning),
+            ExecutionMode::Bounded, // Execution Mode
         )
     }
 
     fn with_file_groups(mut self, file_groups: Vec<Vec<PartitionedFile>>) -> Self {
         self.base_config.file_groups = file_groups;
-        // Changing file groups may invalidate output partitioning. Update it also
-        let output_partitioning = Self::output_partitioning_helper(&self.base_config);
+        // Changing file groups may invalidate output partitioning, including
+        // the declared one. Update it also
+        self.declared_partitioning = None;
+        let output_partitioning =
+            Self::output_partitioning_helper(&self.base_config, None);
         self.cache = self.cache.with_partitioning(output_partitioning);
         self
     }
+
+    /// Returns the counter of rows shared by `partition` with the other
+    /// partitions of its execution, if this scan has a limit that they can
+    /// share.
+    ///
+    /// A sorted scan is usually merged, which needs the first `limit` rows of
+    /// every partition, so each partition keeps its own limit. Otherwise any
+    /// `limit` rows are enough, and the partitions of an execution share a
+    /// single counter so that they stop reading as soon as `limit` rows were
+    /// produced in total.
+    ///
+    /// The counter is kept in the plan and reset for every execution: a
+    /// partition joins the counter of the current execution, unless that
+    /// execution already executed this partition or all the partitions, in
+    /// which case a new execution starts. Executing a partition again thus
+    /// counts its rows again.
+    fn shared_limit(&self, partition: usize) -> Option<Arc<AtomicUsize>> {
+        if self.base_config.limit.is_none()
+            || !self.base_config.output_ordering.is_empty()
+        {
+            return None;
+        }
+        let partitions = self.base_config.file_groups.len();
+        let mut execution = self.shared_limit.lock();
+        if execution.executed.contains(&partition)
+            || execution.executed.len() >= partitions
+        {
+            *execution = SharedLimitState::default();
+        }
+        execution.executed.insert(partition);
+        Some(Arc::clone(&execution.produced))
+    }
 }
 
 impl DisplayAs for ParquetExec {
@@ -623,9 +863,20 @@ impl DisplayAs for ParquetExec {
                     })
                     .unwrap_or_default();
 
+                let sample_string = self
+                    .row_group_sample
+                    .map(|(fraction, seed)| {
+                        format!(", sample_fraction={fraction}, sample_seed={seed}")
+                    })
+                    .unwrap_or_default();
+
                 write!(f, "ParquetExec: ")?;
                 self.base_config.fmt_as(t, f)?;
-                write!(f, "{}{}", predicate_string, pruning_predicate_string,)
+                write!(
+                    f,
+                    "{}{}{}",
+                    predicate_string, pruning_predicate_string, sample_string
+                )
             }
         }
     }
@@ -716,7 +967,7 @@ impl ExecutionPlan for ParquetExec {
             predicate: self.predicate.clone(),
             pruning_predicate: self.pruning_predicate.clone(),
             page_pruning_predicate: self.page_pruning_predicate.clone(),
-            table_schema: self.base_config.file_schema.clone(),
+            table_schema: self.base_config.file_schema_with_nested_projection(),
             metadata_size_hint: self.metadata_size_hint,
             metrics: self.metrics.clone(),
             parquet_file_reader_factory,
@@ -729,10 +980,16 @@ impl ExecutionPlan for ParquetExec {
                 .table_parquet_options
                 .global
                 .schema_force_string_view,
+            skip_corrupt_row_groups: self.skip_corrupt_row_groups(),
+            memory_pool: Arc::clone(ctx.memory_pool()),
+            row_group_sample: self.row_group_sample,
         };
 
-        let stream =
+        let mut stream =
             FileStream::new(&self.base_config, partition_index, opener, &self.metrics)?;
+        if let Some(rows_produced) = self.shared_limit(partition_index) {
+            stream = stream.with_shared_limit(rows_produced);
+        }
 
         Ok(Box::pin(stream))
     }
@@ -742,7 +999,18 @@ impl ExecutionPlan for ParquetExec {
     }
 
     fn statistics(&self) -> Result<Statistics> {
-        Ok(self.projected_statistics.clone())
+        let Some((fraction, _)) = self.row_group_sample else {
+            return Ok(self.projected_statistics.clone());
+        };
+        // the number of rows read depends on the row groups that are sampled
+        let mut stats = self.projected_statistics.clone().to_inexact();
+        stats.num_rows = stats.num_rows.with_estimated_selectivity(fraction);
+        stats.total_byte_size =
+            stats.total_byte_size.with_estimated_selectivity(fraction);
+        for column in stats.column_statistics.iter_mut() {
+            column.null_count = column.null_count.with_estimated_selectivity(fraction);
+        }
+        Ok(stats)
     }
 
     fn fetch(&self) -> Option<usize> {
@@ -764,6 +1032,9 @@ impl ExecutionPlan for ParquetExec {
             cache: self.cache.clone(),
             table_parquet_options: self.table_parquet_options.clone(),
             schema_adapter_factory: self.schema_adapter_factory.clone(),
+            declared_partitioning: self.declared_partitioning.clone(),
+            shared_limit: Default::default(),
+            row_group_sample: self.row_group_sample,
         }))
     }
 }
@@ -794,6 +1065,8 @@ mod tests {
     use crate::datasource::listing::{FileRange, ListingOptions};
     use crate::datasource::object_store::ObjectStoreUrl;
     use crate::execution::context::SessionState;
+    use crate::physical_optimizer::enforce_distribution::EnforceDistribution;
+    use crate::physical_optimizer::PhysicalOptimizerRule;
     use crate::physical_plan::displayable;
     use crate::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
     use crate::test::object_store::local_unpartitioned_file;
@@ -804,15 +1077,23 @@ mod tests {
     };
 
     use arrow::array::{
-        ArrayRef, Date64Array, Int32Array, Int64Array, Int8Array, StringArray,
-        StructArray,
+        ArrayRef, Date64Array, Int32Array, Int64Array, Int8Array, LargeStringArray,
+        StringArray, StructArray,
     };
+    use arrow::compute::SortOptions;
     use arrow::datatypes::{Field, Schema, SchemaBuilder};
     use arrow::record_batch::RecordBatch;
     use arrow_schema::{DataType, Fields};
-    use datafusion_common::{assert_contains, ScalarValue};
+    use datafusion_common::{assert_contains, DataFusionError, JoinType, ScalarValue};
+    use datafusion_common_runtime::SpawnedTask;
     use datafusion_expr::{col, lit, when, Expr};
+    use datafusion_physical_expr::expressions::{Column, Literal};
     use datafusion_physical_expr::planner::logical2physical;
+    use datafusion_physical_expr::{Distribution, PhysicalSortExpr};
+    use datafusion_physical_plan::joins::{HashJoinExec, PartitionMode};
+    use datafusion_physical_plan::metrics::Time;
+    use datafusion_physical_plan::projection::ProjectionExec;
+    use datafusion_physical_plan::repartition::BatchPartitioner;
     use datafusion_physical_plan::ExecutionPlanProperties;
 
     use chrono::{TimeZone, Utc};
@@ -821,7 +1102,10 @@ mod tests {
     use object_store::path::Path;
     use object_store::ObjectMeta;
     use parquet::arrow::ArrowWriter;
+    use parquet::basic::Compression;
     use parquet::file::properties::WriterProperties;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::file::serialized_reader::ReadOptionsBuilder;
     use tempfile::TempDir;
     use url::Url;
 
@@ -842,6 +1126,7 @@ mod tests {
         predicate: Option<Expr>,
         pushdown_predicate: bool,
         page_index_predicate: bool,
+        skip_corrupt_row_groups: bool,
     }
 
     impl RoundTrip {
@@ -874,6 +1159,11 @@ mod tests {
             self
         }
 
+        fn with_skip_corrupt_row_groups(mut self) -> Self {
+            self.skip_corrupt_row_groups = true;
+            self
+        }
+
         /// run the test, returning only the resulting RecordBatches
         async fn round_trip_to_batches(
             self,
@@ -890,6 +1180,7 @@ mod tests {
                 predicate,
                 pushdown_predicate,
                 page_index_predicate,
+                skip_corrupt_row_groups,
             } = self;
 
             let file_schema = match schema {
@@ -932,6 +1223,10 @@ mod tests {
                 parquet_exec = parquet_exec.with_enable_page_index(true);
             }
 
+            if skip_corrupt_row_groups {
+                parquet_exec = parquet_exec.with_skip_corrupt_row_groups(true);
+            }
+
             let session_ctx = SessionContext::new();
             let task_ctx = session_ctx.task_ctx();
             let parquet_exec = Arc::new(parquet_exec);
@@ -1464,6 +1759,55 @@ mod tests {
         assert_batches_sorted_eq!(expected, &read);
     }
 
+    #[tokio::test]
+    async fn evolved_schema_cast_to_table_types() {
+        let c1: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(i32::MIN),
+            None,
+            Some(0),
+            Some(i32::MAX),
+        ]));
+        let c2: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some(""),
+            None,
+            Some("d"),
+        ]));
+        let batch = create_batch(vec![("c1", c1), ("c2", c2)]);
+
+        let table_schema = Arc::new(Schema::new(vec![
+            Field::new("c1", DataType::Int64, true),
+            Field::new("c2", DataType::LargeUtf8, true),
+        ]));
+
+        let read = RoundTrip::new()
+            .with_schema(Arc::clone(&table_schema))
+            .round_trip_to_batches(vec![batch])
+            .await
+            .unwrap();
+
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].schema(), table_schema);
+        let c1 = read[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(
+            c1.iter().collect::<Vec<_>>(),
+            vec![Some(i32::MIN as i64), None, Some(0), Some(i32::MAX as i64)]
+        );
+        let c2 = read[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<LargeStringArray>()
+            .unwrap();
+        assert_eq!(
+            c2.iter().collect::<Vec<_>>(),
+            vec![Some("a"), Some(""), None, Some("d")]
+        );
+    }
+
     #[tokio::test]
     async fn evolved_schema_incompatible_types() {
         let c1: ArrayRef =
@@ -1500,8 +1844,10 @@ mod tests {
             .with_schema(Arc::new(schema))
             .round_trip_to_batches(vec![batch1, batch2])
             .await;
-        assert_contains!(read.unwrap_err().to_string(),
-            "Cannot cast file schema field c3 of type Date64 to table schema field of type Int8");
+        let err = read.unwrap_err().to_string();
+        assert_contains!(&err, "Error during planning: Parquet file ");
+        assert_contains!(&err,
+            "does not match the table schema: Cannot cast file schema field c3 of type Date64 to table schema field of type Int8");
     }
 
     #[tokio::test]
@@ -1546,6 +1892,264 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn parquet_exec_with_projection_by_names() -> Result<()> {
+        let testdata = crate::test_util::parquet_test_data();
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let meta = local_unpartitioned_file(format!("{testdata}/alltypes_plain.parquet"));
+        let file_schema = ParquetFormat::default()
+            .infer_schema(&state, &store, &[meta.clone()])
+            .await?;
+
+        // the columns are output in the requested order rather than the
+        // order of the file
+        let config = FileScanConfig::new(ObjectStoreUrl::local_filesystem(), file_schema)
+            .with_file(meta.into())
+            .with_projection_by_names(&["bool_col", "id"])?;
+        let parquet_exec = ParquetExec::builder(config).build_arc();
+        let batches = collect(parquet_exec, state.task_ctx()).await?;
+        assert_eq!(batches.len(), 1);
+
+        let schema = batches[0].schema();
+        let field_names: Vec<&str> =
+            schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(vec!["bool_col", "id"], field_names);
+        assert_eq!(batches[0].column(1).data_type(), &DataType::Int32);
+        Ok(())
+    }
+
+    #[test]
+    fn parquet_exec_declared_output_partitioning() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let file_groups = vec![
+            vec![PartitionedFile::new("x.parquet", 100 * 1024 * 1024)],
+            vec![PartitionedFile::new("y.parquet", 100 * 1024 * 1024)],
+        ];
+        let config = FileScanConfig::new(ObjectStoreUrl::local_filesystem(), schema)
+            .with_file_groups(file_groups);
+        let hash_a = |n| {
+            Partitioning::Hash(
+                vec![Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>],
+                n,
+            )
+        };
+
+        // by default the partitioning of the files is unknown
+        let parquet_exec = ParquetExec::builder(config.clone()).build();
+        assert!(matches!(
+            parquet_exec.properties().output_partitioning(),
+            Partitioning::UnknownPartitioning(2)
+        ));
+
+        let err = ParquetExec::builder(config.clone())
+            .with_output_partitioning(hash_a(3))
+            .err()
+            .unwrap();
+        assert_contains!(
+            err.to_string(),
+            "has 3 partitions, but the scan has 2 file groups"
+        );
+
+        // only hash or range partitioning can be declared
+        for partitioning in [
+            Partitioning::RoundRobinBatch(2),
+            Partitioning::UnknownPartitioning(2),
+        ] {
+            let err = ParquetExec::builder(config.clone())
+                .with_output_partitioning(partitioning)
+                .err()
+                .unwrap();
+            assert!(matches!(err, DataFusionError::NotImplemented(_)), "{err}");
+            assert_contains!(
+                err.to_string(),
+                "Only hash or range partitioning can be declared for a parquet scan"
+            );
+        }
+
+        // the file groups hold ascending ranges of `a`
+        let range_a = Partitioning::Range(
+            vec![PhysicalSortExpr {
+                expr: Arc::new(Column::new("a", 0)),
+                options: SortOptions::default(),
+            }],
+            2,
+        );
+        let parquet_exec = ParquetExec::builder(config.clone())
+            .with_output_partitioning(range_a.clone())?
+            .build_arc();
+        assert_eq!(parquet_exec.properties().output_partitioning(), &range_a);
+        assert_eq!(
+            parquet_exec.properties().output_partitioning().to_string(),
+            "Range([a@0 ASC], 2)"
+        );
+        // operators above the scan report the range partitioning on their
+        // output columns
+        let projection = ProjectionExec::try_new(
+            vec![
+                (
+                    Arc::new(Literal::new(ScalarValue::Int32(Some(1)))) as _,
+                    "one".to_string(),
+                ),
+                (Arc::new(Column::new("a", 0)) as _, "a".to_string()),
+            ],
+            parquet_exec,
+        )?;
+        assert_eq!(
+            projection.properties().output_partitioning().to_string(),
+            "Range([a@1 ASC], 2)"
+        );
+        // but it does not satisfy a hash distribution
+        let eq_properties = projection.properties().equivalence_properties();
+        assert!(!projection.properties().output_partitioning().satisfy(
+            &Distribution::HashPartitioned(vec![Arc::new(Column::new("a", 1))]),
+            eq_properties
+        ));
+
+        let err = ParquetExec::builder(config.clone())
+            .with_output_partitioning(Partitioning::Hash(
+                vec![Arc::new(Column::new("b", 0)) as Arc<dyn PhysicalExpr>],
+                2,
+            ))
+            .err()
+            .unwrap();
+        assert_contains!(
+            err.to_string(),
+            "refers to column b@0 that is not in the scan output"
+        );
+
+        let parquet_exec = ParquetExec::builder(config)
+            .with_output_partitioning(hash_a(2))?
+            .build();
+        assert!(matches!(
+            parquet_exec.properties().output_partitioning(),
+            Partitioning::Hash(exprs, 2) if exprs.len() == 1
+        ));
+        assert!(parquet_exec.declared_partitioning().is_some());
+
+        // splitting the files into more groups no longer keeps the rows of a
+        // hash value together
+        let repartitioned = parquet_exec
+            .repartitioned(4, &ConfigOptions::default())?
+            .unwrap();
+        assert!(matches!(
+            repartitioned.properties().output_partitioning(),
+            Partitioning::UnknownPartitioning(4)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_declared_output_partitioning_join() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let hash_a = || {
+            Partitioning::Hash(
+                vec![Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>],
+                2,
+            )
+        };
+
+        // write the rows of a side to one file per hash partition of `a`
+        let write_side = |name: &str, a: Vec<i32>, b: Vec<i32>| -> Result<_> {
+            let batch = RecordBatch::try_new(
+                Arc::clone(&schema),
+                vec![Arc::new(Int32Array::from(a)), Arc::new(Int32Array::from(b))],
+            )?;
+            let mut buckets = vec![vec![]; 2];
+            BatchPartitioner::try_new(hash_a(), Time::new())?.partition(
+                batch,
+                |partition, batch| {
+                    buckets[partition].push(batch);
+                    Ok(())
+                },
+            )?;
+            let mut file_groups = vec![];
+            for (partition, batches) in buckets.into_iter().enumerate() {
+                let path = tmp_dir.path().join(format!("{name}_{partition}.parquet"));
+                let mut writer = ArrowWriter::try_new(
+                    File::create(&path)?,
+                    Arc::clone(&schema),
+                    None,
+                )?;
+                for batch in &batches {
+                    writer.write(batch)?;
+                }
+                writer.close()?;
+                file_groups.push(vec![local_unpartitioned_file(&path).into()]);
+            }
+            Ok(file_groups)
+        };
+        let left_files = write_side("left", (0..20).collect(), (0..20).collect())?;
+        let right_files = write_side(
+            "right",
+            (10..30).collect(),
+            (10..30).map(|v| v * 10).collect(),
+        )?;
+
+        let scan = |file_groups: Vec<Vec<PartitionedFile>>| -> Result<_> {
+            let config = FileScanConfig::new(
+                ObjectStoreUrl::local_filesystem(),
+                Arc::clone(&schema),
+            )
+            .with_file_groups(file_groups);
+            let exec = ParquetExec::builder(config)
+                .with_output_partitioning(hash_a())?
+                .build_arc();
+            Ok(exec as Arc<dyn ExecutionPlan>)
+        };
+        let join = |left, right| -> Result<_> {
+            let join = Arc::new(HashJoinExec::try_new(
+                left,
+                right,
+                vec![(
+                    Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>,
+                    Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>,
+                )],
+                None,
+                &JoinType::Inner,
+                None,
+                PartitionMode::Partitioned,
+                false,
+            )?);
+            let mut config = ConfigOptions::new();
+            config.execution.target_partitions = 2;
+            EnforceDistribution::new().optimize(join, &config)
+        };
+        let session_ctx = SessionContext::new();
+
+        // the declared partitioning satisfies the join, so no repartition is
+        // added, and the rows of equal keys meet in the same partition
+        let plan = join(scan(left_files)?, scan(right_files)?)?;
+        let displayed = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(!displayed.contains("RepartitionExec"), "{displayed}");
+        let batches = collect(plan, session_ctx.task_ctx()).await?;
+        let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(rows, 10);
+        let expected = [
+            "+----+----+----+-----+",
+            "| a  | b  | a  | b   |",
+            "+----+----+----+-----+",
+            "| 10 | 10 | 10 | 100 |",
+            "| 11 | 11 | 11 | 110 |",
+            "| 12 | 12 | 12 | 120 |",
+            "| 13 | 13 | 13 | 130 |",
+            "| 14 | 14 | 14 | 140 |",
+            "| 15 | 15 | 15 | 150 |",
+            "| 16 | 16 | 16 | 160 |",
+            "| 17 | 17 | 17 | 170 |",
+            "| 18 | 18 | 18 | 180 |",
+            "| 19 | 19 | 19 | 190 |",
+            "+----+----+----+-----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn parquet_exec_with_range() -> Result<()> {
         fn file_range(meta: &ObjectMeta, start: i64, end: i64) -> PartitionedFile {
@@ -1615,6 +2219,128 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn parquet_exec_with_shared_limit() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+
+        let testdata = crate::test_util::parquet_test_data();
+        let meta = local_unpartitioned_file(format!("{testdata}/alltypes_plain.parquet"));
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let file_schema = ParquetFormat::default()
+            .infer_schema(&state, &store, &[meta.clone()])
+            .await?;
+
+        // 3 partitions of 2 files with 8 rows each
+        let file_groups = vec![vec![PartitionedFile::from(meta.clone()); 2]; 3];
+        let config =
+            FileScanConfig::new(ObjectStoreUrl::local_filesystem(), file_schema.clone())
+                .with_file_groups(file_groups)
+                .with_limit(Some(10));
+
+        async fn count_rows(stream: &mut SendableRecordBatchStream) -> Result<usize> {
+            let mut rows = 0;
+            while let Some(batch) = stream.next().await {
+                rows += batch?.num_rows();
+            }
+            Ok(rows)
+        }
+
+        // the partitions of an execution stop once 10 rows were produced in
+        // total
+        let parquet_exec = ParquetExec::builder(config.clone()).build();
+        let mut rows = vec![];
+        for partition in 0..3 {
+            let mut stream = parquet_exec.execute(partition, Arc::clone(&task_ctx))?;
+            rows.push(count_rows(&mut stream).await?);
+        }
+        assert_eq!(rows, vec![10, 0, 0]);
+
+        // executing the plan again starts counting again
+        let mut stream0 = parquet_exec.execute(0, Arc::clone(&task_ctx))?;
+        assert_eq!(count_rows(&mut stream0).await?, 10);
+        drop(stream0);
+
+        // each partition of a sorted scan reads up to the limit
+        let sort_expr = PhysicalSortExpr {
+            expr: logical2physical(&col("id"), &file_schema),
+            options: Default::default(),
+        };
+        let parquet_exec =
+            ParquetExec::builder(config.with_output_ordering(vec![vec![sort_expr]]))
+                .build();
+        let mut stream0 = parquet_exec.execute(0, Arc::clone(&task_ctx))?;
+        assert_eq!(count_rows(&mut stream0).await?, 10);
+        let mut stream1 = parquet_exec.execute(1, task_ctx)?;
+        assert_eq!(count_rows(&mut stream1).await?, 10);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_with_shared_limit_per_execution() -> Result<()> {
+        // 3 partitions of 2 files with 8 rows each
+        let batches = (0..6)
+            .map(|i| {
+                let c1: ArrayRef =
+                    Arc::new(Int32Array::from_iter_values(i * 8..i * 8 + 8));
+                create_batch(vec![("c1", c1)])
+            })
+            .collect::<Vec<_>>();
+        let file_schema = batches[0].schema();
+        let (meta, _files) = store_parquet(batches, false).await?;
+        let file_groups = meta
+            .into_iter()
+            .map(PartitionedFile::from)
+            .chunks(2)
+            .into_iter()
+            .map(|files| files.collect())
+            .collect();
+        let config = FileScanConfig::new(ObjectStoreUrl::local_filesystem(), file_schema)
+            .with_file_groups(file_groups)
+            .with_limit(Some(10));
+        let parquet_exec = Arc::new(ParquetExec::builder(config.clone()).build());
+
+        async fn count_rows(
+            exec: Arc<ParquetExec>,
+            partition: usize,
+            task_ctx: Arc<TaskContext>,
+        ) -> Result<usize> {
+            let stream = exec.execute(partition, task_ctx)?;
+            let batches = crate::physical_plan::common::collect(stream).await?;
+            Ok(batches.iter().map(|batch| batch.num_rows()).sum())
+        }
+
+        // executing partition 0 again starts a new execution that counts its
+        // rows again, and the other partitions share its counter
+        let task_ctx = SessionContext::new().task_ctx();
+        let mut rows = vec![];
+        for partition in [0, 0, 1, 2] {
+            let exec = Arc::clone(&parquet_exec);
+            rows.push(count_rows(exec, partition, Arc::clone(&task_ctx)).await?);
+        }
+        assert_eq!(rows, vec![10, 10, 0, 0]);
+        // as does executing partition 1 again
+        let rows =
+            count_rows(Arc::clone(&parquet_exec), 1, Arc::clone(&task_ctx)).await?;
+        assert_eq!(rows, 10);
+
+        // concurrent executions of partition 0 count their rows on their own
+        let parquet_exec = Arc::new(ParquetExec::builder(config).build());
+        let tasks = (0..2)
+            .map(|_| {
+                let exec = Arc::clone(&parquet_exec);
+                SpawnedTask::spawn(count_rows(exec, 0, Arc::clone(&task_ctx)))
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            assert_eq!(task.join().await.unwrap()?, 10);
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn parquet_exec_with_partition() -> Result<()> {
         let session_ctx = SessionContext::new();
@@ -1841,6 +2567,284 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn parquet_exec_pushdown_rows_filtered() {
+        let num_rows = |batches: &[RecordBatch]| -> usize {
+            batches.iter().map(|b| b.num_rows()).sum()
+        };
+        let filter = col("c1").not_eq(lit("bar"));
+
+        // without pushdown the scan returns every row, leaving the filtering
+        // to the plan above it
+        let unfiltered = RoundTrip::new()
+            .with_predicate(filter.clone())
+            .round_trip(vec![string_batch()])
+            .await;
+        let unfiltered_rows = num_rows(&unfiltered.batches.unwrap());
+        let metrics = unfiltered.parquet_exec.metrics().unwrap();
+        assert_eq!(unfiltered_rows, 7);
+        assert_eq!(get_value(&metrics, "pushdown_rows_filtered"), 0);
+
+        // with pushdown the eliminated rows are counted instead of returned
+        let filtered = RoundTrip::new()
+            .with_predicate(filter)
+            .with_pushdown_predicate()
+            .round_trip(vec![string_batch()])
+            .await;
+        let filtered_rows = num_rows(&filtered.batches.unwrap());
+        let metrics = filtered.parquet_exec.metrics().unwrap();
+        assert_eq!(filtered_rows, 2);
+        assert_eq!(
+            get_value(&metrics, "pushdown_rows_filtered"),
+            unfiltered_rows - filtered_rows
+        );
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_unsupported_codec() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("lzo.parquet");
+        let c1: ArrayRef = Arc::new(Int64Array::from_iter_values(0..3));
+        let batch = create_batch(vec![("c1", c1)]);
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let file = File::create(&path)?;
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        // the parquet crate can neither write nor read LZO, so the codec of
+        // the column chunk, written with the thrift compact protocol after the
+        // path of the column, is patched from SNAPPY (1) to LZO (3), both in
+        // the footer and in the copy of the metadata after the column chunk
+        let mut bytes = fs::read(&path)?;
+        let snappy_codec = b"\x18\x02c1\x15\x02";
+        for pos in 0..bytes.len() - snappy_codec.len() {
+            if bytes[pos..].starts_with(snappy_codec) {
+                bytes[pos + snappy_codec.len() - 1] = 0x06;
+            }
+        }
+        fs::write(&path, bytes)?;
+        let reader = SerializedFileReader::new(File::open(&path)?)?;
+        let codec = reader.metadata().row_group(0).column(0).compression();
+        assert_eq!(codec, Compression::LZO);
+
+        let config =
+            FileScanConfig::new(ObjectStoreUrl::local_filesystem(), batch.schema())
+                .with_file(local_unpartitioned_file(&path).into());
+        let parquet_exec = Arc::new(ParquetExec::builder(config).build());
+        let session_ctx = SessionContext::new();
+        let err = collect(parquet_exec, session_ctx.task_ctx())
+            .await
+            .unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "This feature is not implemented: compression LZO not enabled"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_skip_corrupt_row_groups() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("corrupt.parquet");
+        let c1: ArrayRef = Arc::new(StringArray::from_iter_values(
+            (0..9).map(|i| format!("v{i}")),
+        ));
+        let batch = create_batch(vec![("c1", c1)]);
+
+        // write 3 row groups of 3 rows each
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(3)
+            .build();
+        let file = File::create(&path)?;
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        // corrupt the second row group, so that its pages can not be decoded
+        corrupt_value_length(&path, "v4")?;
+
+        let meta = local_unpartitioned_file(&path);
+        let parquet_exec = |skip_corrupt_row_groups| {
+            let config =
+                FileScanConfig::new(ObjectStoreUrl::local_filesystem(), batch.schema())
+                    .with_file(meta.clone().into());
+            ParquetExec::builder(config)
+                .build()
+                .with_skip_corrupt_row_groups(skip_corrupt_row_groups)
+        };
+        let session_ctx = SessionContext::new();
+
+        // by default the corrupt row group fails the scan
+        let strict = Arc::new(parquet_exec(false));
+        assert!(collect(strict, session_ctx.task_ctx()).await.is_err());
+
+        let tolerant = Arc::new(parquet_exec(true));
+        let batches = collect(tolerant.clone(), session_ctx.task_ctx()).await?;
+        let expected = [
+            "+----+", "| c1 |", "+----+", "| v0 |", "| v1 |", "| v2 |", "| v6 |",
+            "| v7 |", "| v8 |", "+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        let metrics = tolerant.metrics().unwrap();
+        assert_eq!(get_value(&metrics, "corrupt_row_groups_skipped"), 1);
+
+        // the limit applies to the rows of the whole file
+        let limited = parquet_exec(true).with_fetch(Some(4)).unwrap();
+        let batches = collect(limited, session_ctx.task_ctx()).await?;
+        let expected = [
+            "+----+", "| c1 |", "+----+", "| v0 |", "| v1 |", "| v2 |", "| v6 |",
+            "+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_skip_corrupt_row_groups_partial_output() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("corrupt.parquet");
+        let c1: ArrayRef = Arc::new(StringArray::from_iter_values(
+            (0..12).map(|i| format!("v{i:02}")),
+        ));
+        let batch = create_batch(vec![("c1", c1)]);
+
+        // write a row group of 3 pages of 3 rows, and one of 3 rows
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(9)
+            .set_data_page_row_count_limit(3)
+            .set_write_batch_size(3)
+            .set_dictionary_enabled(false)
+            .build();
+        let file = File::create(&path)?;
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        // corrupt the last page of the first row group
+        let options = ReadOptionsBuilder::new().with_page_index().build();
+        let reader = SerializedFileReader::new_with_options(File::open(&path)?, options)?;
+        let pages = &reader.metadata().offset_index().unwrap()[0][0];
+        assert_eq!(pages.len(), 3);
+        corrupt_value_length(&path, "v07")?;
+
+        let config =
+            FileScanConfig::new(ObjectStoreUrl::local_filesystem(), batch.schema())
+                .with_file(local_unpartitioned_file(&path).into());
+        let parquet_exec = Arc::new(
+            ParquetExec::builder(config)
+                .build()
+                .with_skip_corrupt_row_groups(true),
+        );
+        let session_ctx =
+            SessionContext::new_with_config(SessionConfig::new().with_batch_size(3));
+
+        // the batches decoded before the corrupt page are kept
+        let batches = collect(parquet_exec.clone(), session_ctx.task_ctx()).await?;
+        let expected = [
+            "+-----+", "| c1  |", "+-----+", "| v00 |", "| v01 |", "| v02 |", "| v03 |",
+            "| v04 |", "| v05 |", "| v09 |", "| v10 |", "| v11 |", "+-----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        let metrics = parquet_exec.metrics().unwrap();
+        assert_eq!(get_value(&metrics, "corrupt_row_groups_skipped"), 1);
+        Ok(())
+    }
+
+    /// Overwrite the length of the plain encoded string `value` in the
+    /// parquet file at `path` so that the page containing it fails to decode
+    /// with an EOF error
+    fn corrupt_value_length(path: &std::path::Path, value: &str) -> Result<()> {
+        let mut encoded = (value.len() as u32).to_le_bytes().to_vec();
+        encoded.extend_from_slice(value.as_bytes());
+        let mut bytes = fs::read(path)?;
+        let pos = bytes
+            .windows(encoded.len())
+            .position(|w| w == encoded)
+            .unwrap();
+        bytes[pos..pos + 4].fill(0xFF);
+        fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_skip_corrupt_row_groups_filter_error() -> Result<()> {
+        let c1: ArrayRef = Arc::new(Int64Array::from_iter_values(0..9));
+        let batch = create_batch(vec![("c1", c1)]);
+
+        // the row filter fails on every row group, which is not corrupt
+        let predicate = (col("c1") / lit(0_i64)).gt(lit(1_i64));
+        let err = RoundTrip::new()
+            .with_predicate(predicate)
+            .with_pushdown_predicate()
+            .with_skip_corrupt_row_groups()
+            .round_trip(vec![batch])
+            .await
+            .batches
+            .unwrap_err();
+        assert_contains!(err.to_string(), "Error evaluating filter predicate");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_struct_field_projection() {
+        let struct_fields = Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Int64, true),
+        ]);
+        let columns = vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])) as _,
+            Arc::new(StringArray::from(vec!["x".repeat(1000); 3])) as _,
+            Arc::new(Int64Array::from(vec![10, 20, 30])) as _,
+        ];
+        let s: ArrayRef = Arc::new(StructArray::new(struct_fields, columns, None));
+        let batch = create_batch(vec![("s", s)]);
+
+        let full = RoundTrip::new().round_trip(vec![batch.clone()]).await;
+        full.batches.unwrap();
+
+        // the table only declares fields `a` and `c` of the struct
+        let pruned_fields = Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("c", DataType::Int64, true),
+        ]);
+        let table_schema = Arc::new(Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(pruned_fields),
+            true,
+        )]));
+        let pruned = RoundTrip::new()
+            .with_schema(table_schema)
+            .round_trip(vec![batch])
+            .await;
+
+        let expected = [
+            "+---------------+",
+            "| s             |",
+            "+---------------+",
+            "| {a: 1, c: 10} |",
+            "| {a: 2, c: 20} |",
+            "| {a: 3, c: 30} |",
+            "+---------------+",
+        ];
+        assert_batches_sorted_eq!(expected, &pruned.batches.unwrap());
+
+        // the leaf column of `s.b` is not fetched
+        let full_bytes =
+            get_value(&full.parquet_exec.metrics().unwrap(), "bytes_scanned");
+        let pruned_bytes =
+            get_value(&pruned.parquet_exec.metrics().unwrap(), "bytes_scanned");
+        assert!(
+            pruned_bytes < full_bytes,
+            "expected fewer than {full_bytes} bytes scanned, got {pruned_bytes}"
+        );
+    }
+
     #[tokio::test]
     async fn parquet_exec_display() {
         // batch1: c1(string)
@@ -2070,6 +3074,35 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn parquet_exec_with_nested_projection_by_names() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().to_str().unwrap().to_string() + "/test.parquet";
+        write_file(&path);
+        let state = SessionContext::new().state();
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let meta = local_unpartitioned_file(&path);
+        let file_schema = ParquetFormat::default()
+            .infer_schema(&state, &store, &[meta.clone()])
+            .await?;
+
+        let config = FileScanConfig::new(ObjectStoreUrl::local_filesystem(), file_schema)
+            .with_file(meta.into())
+            .with_projection_by_names(&["struct.name", "id"])?;
+        let parquet_exec = ParquetExec::builder(config).build_arc();
+        let batches = collect(parquet_exec, state.task_ctx()).await?;
+        let expected = [
+            "+--------------+----+",
+            "| struct       | id |",
+            "+--------------+----+",
+            "| {name: aaa1} | 1  |",
+            "| {name: aaa2} | 2  |",
+            "+--------------+----+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_struct_filter_parquet() -> Result<()> {
         let tmp_dir = TempDir::new()?;
@@ -21,23 +21,39 @@ use crate::datasource::file_format::transform_schema_to_view;
 use crate::datasource::physical_plan::parquet::page_filter::PagePruningAccessPlanFilter;
 use crate::datasource::physical_plan::parquet::row_group_filter::RowGroupAccessPlanFilter;
 use crate::datasource::physical_plan::parquet::{
-    row_filter, should_enable_page_index, ParquetAccessPlan,
+    row_filter, should_enable_page_index, ParquetAccessPlan, RowGroupAccess,
 };
 use crate::datasource::physical_plan::{
     FileMeta, FileOpenFuture, FileOpener, ParquetFileMetrics, ParquetFileReaderFactory,
 };
 use crate::datasource::schema_adapter::SchemaAdapterFactory;
 use crate::physical_optimizer::pruning::PruningPredicate;
-use arrow_schema::{ArrowError, SchemaRef};
-use datafusion_common::{exec_err, Result};
+use arrow::record_batch::RecordBatch;
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields, Schema, SchemaRef};
+use bytes::Bytes;
+use datafusion_common::{exec_err, DataFusionError, Result};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use datafusion_physical_plan::metrics::Count;
 use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
-use futures::{StreamExt, TryStreamExt};
-use log::debug;
-use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
-use parquet::arrow::async_reader::AsyncFileReader;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use futures::{FutureExt, StreamExt, TryStreamExt};
+use log::{debug, warn};
+use parquet::arrow::arrow_reader::{
+    ArrowReaderMetadata, ArrowReaderOptions, RowSelection,
+};
+use parquet::arrow::async_reader::{AsyncFileReader, ParquetRecordBatchStream};
 use parquet::arrow::{ParquetRecordBatchStreamBuilder, ProjectionMask};
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::metadata::{ParquetMetaData, RowGroupMetaData};
+use parquet::schema::types::SchemaDescriptor;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Implements [`FileOpener`] for a parquet file
 pub(super) struct ParquetOpener {
@@ -58,6 +74,13 @@ pub(super) struct ParquetOpener {
     pub enable_bloom_filter: bool,
     pub schema_adapter_factory: Arc<dyn SchemaAdapterFactory>,
     pub schema_force_string_view: bool,
+    /// Skip the row groups that fail to decode instead of failing the scan
+    pub skip_corrupt_row_groups: bool,
+    /// Pool the decoded batches are accounted in
+    pub memory_pool: Arc<dyn MemoryPool>,
+    /// Fraction and seed of the row groups to read, see
+    /// [`ParquetExec::with_row_group_sample`](super::ParquetExec::with_row_group_sample)
+    pub row_group_sample: Option<(f64, u64)>,
 }
 
 impl FileOpener for ParquetOpener {
@@ -79,7 +102,9 @@ impl FileOpener for ParquetOpener {
         let batch_size = self.batch_size;
         let projection = self.projection.clone();
         let projected_schema = SchemaRef::from(self.table_schema.project(&projection)?);
-        let schema_adapter = self.schema_adapter_factory.create(projected_schema);
+        let schema_adapter = self
+            .schema_adapter_factory
+            .create(Arc::clone(&projected_schema));
         let predicate = self.predicate.clone();
         let pruning_predicate = self.pruning_predicate.clone();
         let page_pruning_predicate = self.page_pruning_predicate.clone();
@@ -93,6 +118,14 @@ impl FileOpener for ParquetOpener {
         let enable_bloom_filter = self.enable_bloom_filter;
         let limit = self.limit;
         let schema_force_string_view = self.schema_force_string_view;
+        let skip_corrupt_row_groups = self.skip_corrupt_row_groups;
+        let row_group_sample = self.row_group_sample;
+        let consumer_id = NEXT_CONSUMER_ID.fetch_add(1, Ordering::Relaxed);
+        let reservation = MemoryConsumer::new(format!(
+            "ParquetExec[{}]#{consumer_id}({file_name})",
+            self.partition_index
+        ))
+        .register(&self.memory_pool);
 
         Ok(Box::pin(async move {
             let options = ArrowReaderOptions::new().with_page_index(enable_page_index);
@@ -111,44 +144,84 @@ impl FileOpener for ParquetOpener {
             let metadata =
                 ArrowReaderMetadata::try_new(metadata.metadata().clone(), options)?;
 
-            let mut builder =
-                ParquetRecordBatchStreamBuilder::new_with_metadata(reader, metadata);
+            // in skip mode the row groups are read by streams of their own
+            // that share the reader
+            let (reader, shared_reader): (Box<dyn AsyncFileReader>, _) =
+                if skip_corrupt_row_groups {
+                    let shared_reader = SharedFileReader::new(reader);
+                    (Box::new(shared_reader.clone()), Some(shared_reader))
+                } else {
+                    (reader, None)
+                };
+            let mut builder = ParquetRecordBatchStreamBuilder::new_with_metadata(
+                reader,
+                metadata.clone(),
+            );
 
             let file_schema = builder.schema().clone();
 
-            let (schema_mapping, adapted_projections) =
-                schema_adapter.map_schema(&file_schema)?;
+            // Only decode the struct fields requested by the table schema
+            let pruned_schema = prune_struct_fields(&file_schema, &projected_schema);
 
-            let mask = ProjectionMask::roots(
-                builder.parquet_schema(),
-                adapted_projections.iter().cloned(),
-            );
+            // name the file whose schema can not be read as the table schema
+            let (schema_mapping, adapted_projections) = schema_adapter
+                .map_schema(&pruned_schema)
+                .map_err(|e| match e {
+                    DataFusionError::Plan(msg) => DataFusionError::Plan(format!(
+                        "Parquet file {file_name} does not match the table schema: {msg}"
+                    )),
+                    e => e,
+                })?;
+
+            let mask = if pruned_schema == file_schema {
+                ProjectionMask::roots(
+                    builder.parquet_schema(),
+                    adapted_projections.iter().cloned(),
+                )
+            } else {
+                ProjectionMask::leaves(
+                    builder.parquet_schema(),
+                    projected_leaves(
+                        builder.parquet_schema(),
+                        &pruned_schema,
+                        &adapted_projections,
+                    ),
+                )
+            };
 
             // Filter pushdown: evaluate predicates during scan
-            if let Some(predicate) = pushdown_filters.then_some(predicate).flatten() {
-                let row_filter = row_filter::build_row_filter(
-                    &predicate,
-                    &file_schema,
-                    &table_schema,
-                    builder.metadata(),
-                    reorder_predicates,
-                    &file_metrics,
-                    Arc::clone(&schema_mapping),
-                );
+            let pushdown_predicate = pushdown_filters.then_some(predicate).flatten();
+            let build_row_filter = {
+                let file_schema = Arc::clone(&file_schema);
+                let file_metrics = file_metrics.clone();
+                let schema_mapping = Arc::clone(&schema_mapping);
+                move |metadata: &ParquetMetaData| {
+                    let predicate = pushdown_predicate.as_ref()?;
+                    let row_filter = row_filter::build_row_filter(
+                        predicate,
+                        &file_schema,
+                        &table_schema,
+                        metadata,
+                        reorder_predicates,
+                        &file_metrics,
+                        Arc::clone(&schema_mapping),
+                    );
 
-                match row_filter {
-                    Ok(Some(filter)) => {
-                        builder = builder.with_row_filter(filter);
+                    match row_filter {
+                        Ok(filter) => filter,
+                        Err(e) => {
+                            debug!(
+                                "Ignoring error building row filter for '{:?}': {}",
+                                predicate, e
+                            );
+                            None
+                        }
                     }
-                    Ok(None) => {}
-                    Err(e) => {
-                        debug!(
-                            "Ignoring error building row filter for '{:?}': {}",
-                            predicate, e
-                        );
-                    }
-                };
+                }
             };
+            if let Some(filter) = build_row_filter(builder.metadata()) {
+                builder = builder.with_row_filter(filter);
+            }
 
             // Determine which row groups to actually read. The idea is to skip
             // as many row groups as possible based on the metadata and query
@@ -157,12 +230,15 @@ impl FileOpener for ParquetOpener {
             let rg_metadata = file_metadata.row_groups();
             // track which row groups to actually read
             let access_plan =
-                create_initial_plan(&file_name, extensions, rg_metadata.len())?;
+                create_initial_plan(&file_name, extensions.clone(), rg_metadata.len())?;
             let mut row_groups = RowGroupAccessPlanFilter::new(access_plan);
             // if there is a range restricting what parts of the file to read
             if let Some(range) = file_range.as_ref() {
                 row_groups.prune_by_range(rg_metadata, range);
             }
+            if let Some((fraction, seed)) = row_group_sample {
+                row_groups.prune_by_sample(&file_name, fraction, seed, &file_metrics);
+            }
             // If there is a predicate that can be evaluated against the metadata
             if let Some(predicate) = predicate.as_ref() {
                 row_groups.prune_by_statistics(
@@ -185,6 +261,15 @@ impl FileOpener for ParquetOpener {
                 }
             }
 
+            // remember the codecs used by the file to report decode errors
+            // caused by codecs that the parquet crate can not decompress
+            let mut codecs = vec![];
+            for column in rg_metadata.iter().flat_map(|rg| rg.columns()) {
+                if !codecs.contains(&column.compression()) {
+                    codecs.push(column.compression());
+                }
+            }
+
             let mut access_plan = row_groups.build();
 
             // page index pruning: if all data on individual pages can
@@ -202,35 +287,522 @@ impl FileOpener for ParquetOpener {
                 }
             }
 
-            let row_group_indexes = access_plan.row_group_indexes();
-            if let Some(row_selection) =
-                access_plan.into_overall_row_selection(rg_metadata)?
-            {
-                builder = builder.with_row_selection(row_selection);
-            }
+            let batch_memory = estimate_batch_memory(
+                rg_metadata,
+                &access_plan.row_group_indexes(),
+                &mask,
+                batch_size,
+            );
 
-            if let Some(limit) = limit {
-                builder = builder.with_limit(limit)
-            }
+            let batches = if let Some(shared_reader) = shared_reader {
+                // read every row group with a stream of its own, so that the
+                // scan can continue with the next one after a decode error.
+                // The streams are opened one after the other and share the
+                // reader of the file
+                let row_groups = access_plan
+                    .into_inner()
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(idx, access)| match access {
+                        RowGroupAccess::Skip => None,
+                        RowGroupAccess::Scan => Some((idx, None)),
+                        RowGroupAccess::Selection(selection) => {
+                            Some((idx, Some(selection)))
+                        }
+                    })
+                    .collect();
+                let open_row_group =
+                    move |idx: usize,
+                          selection: Option<RowSelection>,
+                          limit: Option<usize>| {
+                        let reader: Box<dyn AsyncFileReader> =
+                            Box::new(shared_reader.clone());
+                        let mut builder =
+                            ParquetRecordBatchStreamBuilder::new_with_metadata(
+                                reader,
+                                metadata.clone(),
+                            );
+                        if let Some(filter) = build_row_filter(builder.metadata()) {
+                            builder = builder.with_row_filter(filter);
+                        }
+                        if let Some(selection) = selection {
+                            builder = builder.with_row_selection(selection);
+                        }
+                        if let Some(limit) = limit {
+                            builder = builder.with_limit(limit)
+                        }
+                        builder
+                            .with_projection(mask.clone())
+                            .with_batch_size(batch_size)
+                            .with_row_groups(vec![idx])
+                            .build()
+                    };
 
-            let stream = builder
-                .with_projection(mask)
-                .with_batch_size(batch_size)
-                .with_row_groups(row_group_indexes)
-                .build()?;
+                read_skipping_corrupt_row_groups(SkippingReaderState {
+                    row_groups,
+                    stream: None,
+                    open_row_group: Box::new(open_row_group),
+                    remaining: limit,
+                    file_name,
+                    skipped: file_metrics.corrupt_row_groups_skipped.clone(),
+                    codecs,
+                })
+            } else {
+                let row_group_indexes = access_plan.row_group_indexes();
+                if let Some(row_selection) =
+                    access_plan.into_overall_row_selection(rg_metadata)?
+                {
+                    builder = builder.with_row_selection(row_selection);
+                }
+
+                if let Some(limit) = limit {
+                    builder = builder.with_limit(limit)
+                }
+
+                builder
+                    .with_projection(mask)
+                    .with_batch_size(batch_size)
+                    .with_row_groups(row_group_indexes)
+                    .build()?
+                    .map_err(move |e| map_decode_error(e, &codecs))
+                    .boxed()
+            };
 
-            let adapted = stream
-                .map_err(|e| ArrowError::ExternalError(Box::new(e)))
-                .map(move |maybe_batch| {
+            let adapted = reserve_decoding(batches, reservation, batch_memory).map(
+                move |maybe_batch| {
                     maybe_batch
                         .and_then(|b| schema_mapping.map_batch(b).map_err(Into::into))
-                });
+                },
+            );
 
             Ok(adapted.boxed())
         }))
     }
 }
 
+/// Distinguishes the memory consumers of the files being scanned
+static NEXT_CONSUMER_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Estimates the memory needed to decode a batch of `batch_size` rows of the
+/// columns in `mask`, from the uncompressed size of the largest of the
+/// `row_groups` to read
+fn estimate_batch_memory(
+    rg_metadata: &[RowGroupMetaData],
+    row_groups: &[usize],
+    mask: &ProjectionMask,
+    batch_size: usize,
+) -> usize {
+    row_groups
+        .iter()
+        .map(|&idx| {
+            let row_group = &rg_metadata[idx];
+            let rows = row_group.num_rows().max(1) as usize;
+            let bytes: i64 = row_group
+                .columns()
+                .iter()
+                .enumerate()
+                .filter(|(leaf, _)| mask.leaf_included(*leaf))
+                .map(|(_, column)| column.uncompressed_size())
+                .sum();
+            (bytes.max(0) as usize).saturating_mul(batch_size.min(rows)) / rows
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Reserves `batch_memory` bytes in `reservation` before decoding every batch
+/// of `batches`, see [`reserve_or_wait`], and keeps that memory, or the memory
+/// of the decoded batch if larger, reserved until the next batch is polled.
+///
+/// As the reservation is not released between batches, other consumers of
+/// the pool, such as a sort that spills, can not take the memory the scan
+/// needs to decode its next batch.
+///
+/// The stream fails with a [`DataFusionError::ResourcesExhausted`] error if
+/// the memory can not be reserved, so the scan never reserves more memory
+/// than the pool holds.
+fn reserve_decoding(
+    batches: BoxStream<'static, Result<RecordBatch, ArrowError>>,
+    reservation: MemoryReservation,
+    batch_memory: usize,
+) -> BoxStream<'static, Result<RecordBatch, ArrowError>> {
+    futures::stream::unfold(Some((batches, reservation)), move |state| async move {
+        let (mut batches, mut reservation) = state?;
+        // polling the next batch hands the previous one on
+        let reserved = match reserve_or_wait(&mut reservation, batch_memory).await {
+            Ok(()) => batches.next().await,
+            Err(e) => Some(Err(e.into())),
+        };
+        let batch = match reserved {
+            Some(Ok(batch)) => reservation
+                .try_resize(batch.get_array_memory_size().max(batch_memory))
+                .map(|_| batch)
+                .map_err(Into::into),
+            Some(Err(e)) => Err(e),
+            None => return None,
+        };
+        match batch {
+            Ok(batch) => Some((Ok(batch), Some((batches, reservation)))),
+            // the reservation is freed when dropped
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+    .boxed()
+}
+
+/// Delays between the attempts of [`reserve_or_wait`] to reserve memory
+const DECODE_MEMORY_RETRY_DELAYS: [Duration; 5] = [
+    Duration::from_millis(1),
+    Duration::from_millis(2),
+    Duration::from_millis(4),
+    Duration::from_millis(8),
+    Duration::from_millis(16),
+];
+
+/// Resizes `reservation` to `size` bytes, waiting for other consumers of the
+/// pool to free memory if it is exhausted.
+///
+/// The consumers holding the memory may themselves be waiting for the batch
+/// about to be decoded, so waiting gives up after the last of the
+/// [`DECODE_MEMORY_RETRY_DELAYS`], returning the
+/// [`DataFusionError::ResourcesExhausted`] error of the pool rather than
+/// reserving more memory than it holds.
+async fn reserve_or_wait(reservation: &mut MemoryReservation, size: usize) -> Result<()> {
+    for delay in DECODE_MEMORY_RETRY_DELAYS {
+        if reservation.try_resize(size).is_ok() {
+            return Ok(());
+        }
+        debug!(
+            "Memory pool exhausted, {} waits {delay:?} to reserve {size} bytes",
+            reservation.consumer().name()
+        );
+        tokio::time::sleep(delay).await;
+    }
+    reservation.try_resize(size)
+}
+
+/// Opens a stream reading the row group with the given index, row selection
+/// and limit
+type OpenRowGroup = Box<
+    dyn FnMut(
+            usize,
+            Option<RowSelection>,
+            Option<usize>,
+        ) -> parquet::errors::Result<
+            ParquetRecordBatchStream<Box<dyn AsyncFileReader>>,
+        > + Send,
+>;
+
+/// State of [`read_skipping_corrupt_row_groups`]
+struct SkippingReaderState {
+    /// Row groups left to read, with their row selection
+    row_groups: VecDeque<(usize, Option<RowSelection>)>,
+    /// Stream of the row group being read
+    stream: Option<ParquetRecordBatchStream<Box<dyn AsyncFileReader>>>,
+    open_row_group: OpenRowGroup,
+    /// Rows left to read from the file, if limited
+    remaining: Option<usize>,
+    file_name: String,
+    skipped: Count,
+    /// Codecs used by the file
+    codecs: Vec<Compression>,
+}
+
+/// Read the row groups of a file one after the other, skipping the rest of a
+/// row group as soon as its data fails to decode, see [`is_corrupt_data_error`].
+///
+/// The batches of a row group are returned as they are decoded, so the
+/// batches decoded before the error are kept when the rest of the row group
+/// is skipped, and the row group is not buffered in memory.
+///
+/// Other errors, such as failing to fetch the data of a row group or to
+/// evaluate a row filter, are still returned.
+fn read_skipping_corrupt_row_groups(
+    state: SkippingReaderState,
+) -> BoxStream<'static, Result<RecordBatch, ArrowError>> {
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.remaining == Some(0) {
+                return None;
+            }
+            let stream = match &mut state.stream {
+                Some(stream) => stream,
+                None => {
+                    let (idx, selection) = state.row_groups.pop_front()?;
+                    match (state.open_row_group)(idx, selection, state.remaining) {
+                        Ok(stream) => state.stream.insert(stream),
+                        Err(e) => {
+                            let e = map_decode_error(e, &state.codecs);
+                            state.row_groups.clear();
+                            return Some((Err(e), state));
+                        }
+                    }
+                }
+            };
+            match stream.next().await {
+                Some(Ok(batch)) => {
+                    if let Some(remaining) = &mut state.remaining {
+                        *remaining = remaining.saturating_sub(batch.num_rows());
+                    }
+                    return Some((Ok(batch), state));
+                }
+                Some(Err(e)) if is_corrupt_data_error(&e, &state.codecs) => {
+                    warn!(
+                        "Skipping corrupt row group of parquet file {}: {e}",
+                        state.file_name
+                    );
+                    state.skipped.add(1);
+                    state.stream = None;
+                }
+                Some(Err(e)) => {
+                    let e = map_decode_error(e, &state.codecs);
+                    state.stream = None;
+                    state.row_groups.clear();
+                    return Some((Err(e), state));
+                }
+                None => state.stream = None,
+            }
+        }
+    })
+    .boxed()
+}
+
+/// Return true if `e` was raised because the data of a row group could not
+/// be decoded, rather than because it could not be fetched, a row filter
+/// failed, or the file uses a codec that is not available.
+///
+/// The `parquet` crate reports data that can not be decoded as
+/// [`ParquetError::General`] and [`ParquetError::EOF`] errors. When raised
+/// while decoding the pages of a row group, they are converted to an
+/// [`ArrowError::ParquetError`] keeping only their message, which is then
+/// either returned as a [`ParquetError::ArrowError`] or, while evaluating a
+/// row filter, wrapped in a [`ParquetError::External`] error.
+fn is_corrupt_data_error(e: &ParquetError, codecs: &[Compression]) -> bool {
+    match e {
+        ParquetError::General(_) | ParquetError::EOF(_) => {
+            unavailable_codec(e, codecs).is_none()
+        }
+        ParquetError::ArrowError(message) => message
+            .strip_prefix("Parquet argument error: ")
+            .is_some_and(is_decode_error_message),
+        ParquetError::External(e) => matches!(
+            e.downcast_ref::<ArrowError>(),
+            Some(ArrowError::ParquetError(message)) if is_decode_error_message(message)
+        ),
+        _ => false,
+    }
+}
+
+/// Return true if `message` is the message of a [`ParquetError::General`] or
+/// [`ParquetError::EOF`] error
+fn is_decode_error_message(message: &str) -> bool {
+    message.starts_with("Parquet error: ") || message.starts_with("EOF: ")
+}
+
+/// An [`AsyncFileReader`] shared by the streams that read the row groups of a
+/// file one after the other, so that the file is only opened once
+#[derive(Clone)]
+struct SharedFileReader(Arc<futures::lock::Mutex<Box<dyn AsyncFileReader>>>);
+
+impl SharedFileReader {
+    fn new(reader: Box<dyn AsyncFileReader>) -> Self {
+        Self(Arc::new(futures::lock::Mutex::new(reader)))
+    }
+}
+
+impl AsyncFileReader for SharedFileReader {
+    fn get_bytes(
+        &mut self,
+        range: Range<usize>,
+    ) -> BoxFuture<'_, parquet::errors::Result<Bytes>> {
+        let inner = Arc::clone(&self.0);
+        async move { inner.lock().await.get_bytes(range).await }.boxed()
+    }
+
+    fn get_byte_ranges(
+        &mut self,
+        ranges: Vec<Range<usize>>,
+    ) -> BoxFuture<'_, parquet::errors::Result<Vec<Bytes>>> {
+        let inner = Arc::clone(&self.0);
+        async move { inner.lock().await.get_byte_ranges(ranges).await }.boxed()
+    }
+
+    fn get_metadata(
+        &mut self,
+    ) -> BoxFuture<'_, parquet::errors::Result<Arc<ParquetMetaData>>> {
+        let inner = Arc::clone(&self.0);
+        async move { inner.lock().await.get_metadata().await }.boxed()
+    }
+}
+
+/// Return `file_schema` with the struct columns narrowed to the fields
+/// declared for them in `table_schema`.
+///
+/// A table may declare a struct column with a subset of the fields stored in
+/// the file, in which case only the parquet leaf columns of those fields need
+/// to be read. Structs are only narrowed when the table fields appear in the
+/// same order in the file, as the decoded struct is later cast to the table
+/// type by position.
+fn prune_struct_fields(file_schema: &SchemaRef, table_schema: &Schema) -> SchemaRef {
+    let fields: Fields = file_schema
+        .fields()
+        .iter()
+        .map(
+            |file_field| match table_schema.field_with_name(file_field.name()) {
+                Ok(table_field) => prune_struct_field(file_field, table_field),
+                Err(_) => Arc::clone(file_field),
+            },
+        )
+        .collect();
+    if fields == *file_schema.fields() {
+        return Arc::clone(file_schema);
+    }
+    Arc::new(Schema::new_with_metadata(
+        fields,
+        file_schema.metadata().clone(),
+    ))
+}
+
+fn prune_struct_field(file_field: &FieldRef, table_field: &Field) -> FieldRef {
+    match (file_field.data_type(), table_field.data_type()) {
+        (DataType::Struct(file_fields), DataType::Struct(table_fields))
+            if !table_fields.is_empty()
+                && is_ordered_subset(file_fields, table_fields) =>
+        {
+            let fields: Fields = file_fields
+                .iter()
+                .filter_map(|file_field| {
+                    let (_, table_field) = table_fields.find(file_field.name())?;
+                    Some(prune_struct_field(file_field, table_field))
+                })
+                .collect();
+            Arc::new(
+                file_field
+                    .as_ref()
+                    .clone()
+                    .with_data_type(DataType::Struct(fields)),
+            )
+        }
+        _ => Arc::clone(file_field),
+    }
+}
+
+/// Return true if every field of `table` is found in `file`, in the same order
+fn is_ordered_subset(file: &Fields, table: &Fields) -> bool {
+    let mut file_names = file.iter().map(|f| f.name());
+    table
+        .iter()
+        .all(|table_field| file_names.any(|name| name == table_field.name()))
+}
+
+/// Return the indexes of the parquet leaf columns of the `projection` root
+/// columns that are part of `pruned_schema`
+fn projected_leaves<'a>(
+    parquet_schema: &'a SchemaDescriptor,
+    pruned_schema: &'a Schema,
+    projection: &'a [usize],
+) -> impl Iterator<Item = usize> + 'a {
+    parquet_schema
+        .columns()
+        .iter()
+        .enumerate()
+        .filter(move |(leaf_idx, column)| {
+            let root_idx = parquet_schema.get_column_root_idx(*leaf_idx);
+            projection.contains(&root_idx)
+                && is_leaf_selected(
+                    pruned_schema.field(root_idx).data_type(),
+                    &column.path().parts()[1..],
+                )
+        })
+        .map(|(leaf_idx, _)| leaf_idx)
+}
+
+/// Return true if the leaf column at `path` below a column of type
+/// `data_type` was not pruned from it
+fn is_leaf_selected(data_type: &DataType, path: &[String]) -> bool {
+    match (data_type, path.split_first()) {
+        (DataType::Struct(fields), Some((name, rest))) => fields
+            .find(name)
+            .is_some_and(|(_, field)| is_leaf_selected(field.data_type(), rest)),
+        _ => true,
+    }
+}
+
+/// Convert a [`ParquetError`] raised while decoding a file into an
+/// [`ArrowError`].
+///
+/// Errors raised because the `parquet` crate can not decompress one of the
+/// `codecs` used by the file, see [`unavailable_codec`], are reported as
+/// [`DataFusionError::NotImplemented`] naming the codec found in the column
+/// chunk metadata, so users know which feature to enable.
+fn map_decode_error(e: ParquetError, codecs: &[Compression]) -> ArrowError {
+    let Some(codec) = unavailable_codec(&e, codecs) else {
+        return ArrowError::ExternalError(Box::new(e));
+    };
+    let message = match codec_feature(&codec) {
+        Some(feature) => format!(
+            "compression {} not enabled, rebuild the parquet crate with the '{feature}' feature",
+            codec_name(&codec)
+        ),
+        None => format!(
+            "compression {} not enabled, it is not supported by the parquet crate",
+            codec_name(&codec)
+        ),
+    };
+    ArrowError::ExternalError(Box::new(DataFusionError::NotImplemented(message)))
+}
+
+/// Return the codec of `codecs`, the codecs used by a file, that `e` was
+/// raised for because the `parquet` crate can not decompress it.
+///
+/// The `parquet` crate reports a codec whose feature is disabled at compile
+/// time with a [`ParquetError::General`] error naming the feature, and a
+/// codec it does not support at all with a [`ParquetError::NYI`] error naming
+/// the codec. Other errors, including other errors with these variants, are
+/// not caused by a codec.
+fn unavailable_codec(e: &ParquetError, codecs: &[Compression]) -> Option<Compression> {
+    match e {
+        ParquetError::General(message) => {
+            let feature = message.strip_prefix("Disabled feature at compile time: ")?;
+            codecs
+                .iter()
+                .find(|codec| codec_feature(codec) == Some(feature))
+                .copied()
+        }
+        ParquetError::NYI(message) => {
+            let name = message
+                .strip_prefix("The codec type ")?
+                .strip_suffix(" is not supported yet")?;
+            codecs
+                .iter()
+                .find(|codec| codec.to_string() == name)
+                .copied()
+        }
+        _ => None,
+    }
+}
+
+/// Return the name of the `parquet` crate feature providing `codec`
+fn codec_feature(codec: &Compression) -> Option<&'static str> {
+    match codec {
+        Compression::SNAPPY => Some("snap"),
+        Compression::GZIP(_) => Some("flate2"),
+        Compression::BROTLI(_) => Some("brotli"),
+        Compression::LZ4 | Compression::LZ4_RAW => Some("lz4"),
+        Compression::ZSTD(_) => Some("zstd"),
+        Compression::UNCOMPRESSED | Compression::LZO => None,
+    }
+}
+
+/// Return the name of `codec` without its compression level
+fn codec_name(codec: &Compression) -> String {
+    let name = codec.to_string();
+    match name.split_once('(') {
+        Some((name, _level)) => name.to_string(),
+        None => name,
+    }
+}
+
 /// Return the initial [`ParquetAccessPlan`]
 ///
 /// If the user has supplied one as an extension, use that
@@ -263,3 +835,125 @@ fn create_initial_plan(
     // default to scanning all row groups
     Ok(ParquetAccessPlan::new_all(row_group_count))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion_common_runtime::SpawnedTask;
+    use datafusion_execution::memory_pool::GreedyMemoryPool;
+    use parquet::basic::ZstdLevel;
+
+    #[test]
+    fn disabled_codec_error() {
+        let codecs = [Compression::SNAPPY, Compression::ZSTD(ZstdLevel::default())];
+        let err = map_decode_error(
+            ParquetError::General("Disabled feature at compile time: zstd".into()),
+            &codecs,
+        );
+        let err = match err {
+            ArrowError::ExternalError(err) => err.downcast::<DataFusionError>().unwrap(),
+            other => panic!("unexpected error {other}"),
+        };
+        assert!(matches!(*err, DataFusionError::NotImplemented(_)));
+        assert_eq!(
+            err.to_string(),
+            "This feature is not implemented: compression ZSTD not enabled, rebuild the parquet crate with the 'zstd' feature"
+        );
+
+        let err = map_decode_error(
+            ParquetError::NYI("The codec type LZO is not supported yet".into()),
+            &[Compression::LZO],
+        );
+        assert_eq!(
+            err.to_string(),
+            "External error: This feature is not implemented: compression LZO not enabled, it is not supported by the parquet crate"
+        );
+    }
+
+    #[test]
+    fn other_decode_error() {
+        let err = map_decode_error(ParquetError::EOF("unexpected end".into()), &[]);
+        assert_eq!(err.to_string(), "External error: EOF: unexpected end");
+
+        // only the errors naming a codec of the file are rewritten
+        let general = || ParquetError::General("invalid page header".into());
+        let err = map_decode_error(general(), &[Compression::SNAPPY]);
+        assert_eq!(
+            err.to_string(),
+            "External error: Parquet error: invalid page header"
+        );
+        let disabled =
+            ParquetError::General("Disabled feature at compile time: zstd".into());
+        let err = map_decode_error(disabled, &[Compression::SNAPPY]);
+        assert_eq!(
+            err.to_string(),
+            "External error: Parquet error: Disabled feature at compile time: zstd"
+        );
+    }
+
+    #[tokio::test]
+    async fn reserve_or_wait_for_memory() {
+        let pool: Arc<dyn MemoryPool> = Arc::new(GreedyMemoryPool::new(100));
+        let mut other = MemoryConsumer::new("other").register(&pool);
+        let mut scan = MemoryConsumer::new("scan").register(&pool);
+        other.grow(100);
+
+        // the memory freed by the other consumer while waiting is reserved
+        let free = SpawnedTask::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(2)).await;
+            other.free();
+            other
+        });
+        reserve_or_wait(&mut scan, 50).await.unwrap();
+        let mut other = free.join().await.unwrap();
+        assert_eq!(scan.size(), 50);
+        assert_eq!(pool.reserved(), 50);
+
+        // the pool is exhausted if no memory is freed
+        other.grow(50);
+        let err = reserve_or_wait(&mut scan, 80).await.unwrap_err();
+        assert!(
+            matches!(err, DataFusionError::ResourcesExhausted(_)),
+            "{err}"
+        );
+        assert_eq!(scan.size(), 50);
+        assert_eq!(pool.reserved(), 100);
+    }
+
+    #[test]
+    fn corrupt_data_errors() {
+        let general = || ParquetError::General("invalid page".into());
+        assert!(is_corrupt_data_error(&general(), &[]));
+        assert!(is_corrupt_data_error(&ParquetError::EOF("".into()), &[]));
+        // the codecs the parquet crate can not decompress are not corrupt
+        let disabled =
+            ParquetError::General("Disabled feature at compile time: snap".into());
+        assert!(!is_corrupt_data_error(&disabled, &[Compression::SNAPPY]));
+        assert!(is_corrupt_data_error(&general(), &[Compression::SNAPPY]));
+
+        // decoding the pages of a row group
+        let decode =
+            ArrowError::from(ParquetError::EOF("eof decoding byte array".into()));
+        let decode = ParquetError::ArrowError(decode.to_string());
+        assert!(is_corrupt_data_error(&decode, &[]));
+        let convert = ArrowError::CastError("invalid cast".into());
+        let convert = ParquetError::ArrowError(convert.to_string());
+        assert!(!is_corrupt_data_error(&convert, &[]));
+
+        // decoding a page while evaluating a row filter
+        let decode = ArrowError::from(general());
+        assert!(is_corrupt_data_error(&decode.into(), &[]));
+        // evaluating a row filter
+        let filter = ArrowError::ComputeError("Divide by zero".into());
+        assert!(!is_corrupt_data_error(&filter.into(), &[]));
+        // fetching the data
+        let fetch = object_store::Error::NotFound {
+            path: "file.parquet".into(),
+            source: "missing".into(),
+        };
+        assert!(!is_corrupt_data_error(&fetch.into(), &[]));
+        let io = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        assert!(!is_corrupt_data_error(&io.into(), &[]));
+        assert!(!is_corrupt_data_error(&ParquetError::NYI("".into()), &[]));
+    }
+}
@@ -20,11 +20,17 @@
 
 use crate::datasource::physical_plan::{FileMeta, ParquetFileMetrics};
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
 use futures::future::BoxFuture;
-use object_store::ObjectStore;
+use futures::FutureExt;
+use hashbrown::HashMap;
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStore};
+use parking_lot::Mutex;
 use parquet::arrow::async_reader::{AsyncFileReader, ParquetObjectReader};
 use parquet::file::metadata::ParquetMetaData;
+use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::ops::Range;
 use std::sync::Arc;
@@ -65,16 +71,104 @@ pub trait ParquetFileReaderFactory: Debug + Send + Sync + 'static {
 /// This implementation:
 /// 1. Reads parquet directly from an underlying [`ObjectStore`] instance.
 /// 2. Reads the footer and page metadata on demand.
-/// 3. Does not cache metadata or coalesce I/O operations.
+/// 3. Does not coalesce I/O operations.
+/// 4. Does not cache metadata, unless enabled with
+///    [`Self::with_metadata_cache`].
 #[derive(Debug)]
 pub struct DefaultParquetFileReaderFactory {
     store: Arc<dyn ObjectStore>,
+    metadata_cache: Option<Arc<ParquetMetadataCache>>,
 }
 
 impl DefaultParquetFileReaderFactory {
     /// Create a new `DefaultParquetFileReaderFactory`.
     pub fn new(store: Arc<dyn ObjectStore>) -> Self {
-        Self { store }
+        Self {
+            store,
+            metadata_cache: None,
+        }
+    }
+
+    /// Cache the footer metadata of up to `capacity` files, so that repeated
+    /// scans of the same files through this factory do not re-read and
+    /// re-decode their footers.
+    ///
+    /// Entries are keyed by file location and are only reused while the size
+    /// and last modification time of the file are unchanged. When the cache
+    /// is full, the least recently used entry is evicted. A `capacity` of `0`
+    /// disables the cache.
+    ///
+    /// Note that the page index is not part of the cached metadata and is
+    /// still loaded on demand.
+    pub fn with_metadata_cache(mut self, capacity: usize) -> Self {
+        self.metadata_cache =
+            (capacity > 0).then(|| Arc::new(ParquetMetadataCache::new(capacity)));
+        self
+    }
+}
+
+/// Bounded, thread-safe cache of decoded [`ParquetMetaData`], evicting the
+/// least recently used entry once `capacity` files are cached.
+#[derive(Debug)]
+struct ParquetMetadataCache {
+    capacity: usize,
+    state: Mutex<MetadataCacheState>,
+}
+
+#[derive(Debug, Default)]
+struct MetadataCacheState {
+    /// Cached metadata with the `(size, last_modified)` of the file it was
+    /// read from
+    entries: HashMap<Path, (usize, DateTime<Utc>, Arc<ParquetMetaData>)>,
+    /// Cached locations, from least to most recently used
+    lru: VecDeque<Path>,
+}
+
+impl ParquetMetadataCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(MetadataCacheState::default()),
+        }
+    }
+
+    /// Return the cached metadata for `meta`, if the file has not changed
+    /// since it was cached
+    fn get(&self, meta: &ObjectMeta) -> Option<Arc<ParquetMetaData>> {
+        let mut state = self.state.lock();
+        let (size, last_modified, metadata) = state.entries.get(&meta.location)?;
+        if *size != meta.size || *last_modified != meta.last_modified {
+            return None;
+        }
+        let metadata = Arc::clone(metadata);
+        state.touch(&meta.location);
+        Some(metadata)
+    }
+
+    fn put(&self, meta: &ObjectMeta, metadata: Arc<ParquetMetaData>) {
+        let mut state = self.state.lock();
+        let entry = (meta.size, meta.last_modified, metadata);
+        if state.entries.insert(meta.location.clone(), entry).is_some() {
+            state.touch(&meta.location);
+            return;
+        }
+        state.lru.push_back(meta.location.clone());
+        while state.lru.len() > self.capacity {
+            if let Some(evicted) = state.lru.pop_front() {
+                state.entries.remove(&evicted);
+            }
+        }
+    }
+}
+
+impl MetadataCacheState {
+    /// Mark `location` as the most recently used entry
+    fn touch(&mut self, location: &Path) {
+        if let Some(idx) = self.lru.iter().position(|l| l == location) {
+            if let Some(location) = self.lru.remove(idx) {
+                self.lru.push_back(location);
+            }
+        }
     }
 }
 
@@ -119,6 +213,47 @@ impl AsyncFileReader for ParquetFileReader {
     }
 }
 
+/// [`AsyncFileReader`] that serves the metadata from a [`ParquetMetadataCache`]
+/// when possible, and populates it otherwise.
+struct CachedMetadataParquetFileReader {
+    inner: ParquetFileReader,
+    object_meta: ObjectMeta,
+    cache: Arc<ParquetMetadataCache>,
+}
+
+impl AsyncFileReader for CachedMetadataParquetFileReader {
+    fn get_bytes(
+        &mut self,
+        range: Range<usize>,
+    ) -> BoxFuture<'_, parquet::errors::Result<Bytes>> {
+        self.inner.get_bytes(range)
+    }
+
+    fn get_byte_ranges(
+        &mut self,
+        ranges: Vec<Range<usize>>,
+    ) -> BoxFuture<'_, parquet::errors::Result<Vec<Bytes>>>
+    where
+        Self: Send,
+    {
+        self.inner.get_byte_ranges(ranges)
+    }
+
+    fn get_metadata(
+        &mut self,
+    ) -> BoxFuture<'_, parquet::errors::Result<Arc<ParquetMetaData>>> {
+        if let Some(metadata) = self.cache.get(&self.object_meta) {
+            return futures::future::ready(Ok(metadata)).boxed();
+        }
+        async move {
+            let metadata = self.inner.get_metadata().await?;
+            self.cache.put(&self.object_meta, Arc::clone(&metadata));
+            Ok(metadata)
+        }
+        .boxed()
+    }
+}
+
 impl ParquetFileReaderFactory for DefaultParquetFileReaderFactory {
     fn create_reader(
         &self,
@@ -133,15 +268,123 @@ impl ParquetFileReaderFactory for DefaultParquetFileReaderFactory {
             metrics,
         );
         let store = Arc::clone(&self.store);
-        let mut inner = ParquetObjectReader::new(store, file_meta.object_meta);
+        let object_meta = file_meta.object_meta;
+        let mut inner = ParquetObjectReader::new(store, object_meta.clone());
 
         if let Some(hint) = metadata_size_hint {
             inner = inner.with_footer_size_hint(hint)
         };
 
-        Ok(Box::new(ParquetFileReader {
+        let reader = ParquetFileReader {
             inner,
             file_metrics,
-        }))
+        };
+        match &self.metadata_cache {
+            Some(cache) => Ok(Box::new(CachedMetadataParquetFileReader {
+                inner: reader,
+                object_meta,
+                cache: Arc::clone(cache),
+            })),
+            None => Ok(Box::new(reader)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::file_format::parquet::test_util::{
+        store_parquet, RequestCountingObjectStore,
+    };
+
+    use arrow::array::{ArrayRef, Int32Array, RecordBatch};
+    use object_store::local::LocalFileSystem;
+
+    async fn read_metadata(
+        factory: &DefaultParquetFileReaderFactory,
+        meta: &ObjectMeta,
+    ) -> Arc<ParquetMetaData> {
+        let metrics = ExecutionPlanMetricsSet::new();
+        let mut reader = factory
+            .create_reader(0, meta.clone().into(), None, &metrics)
+            .unwrap();
+        reader.get_metadata().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn metadata_cache() {
+        let c1: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_from_iter(vec![("c1", c1)]).unwrap();
+        let (meta, _files) = store_parquet(vec![batch.clone(), batch], false)
+            .await
+            .unwrap();
+
+        let store = Arc::new(RequestCountingObjectStore::new(Arc::new(
+            LocalFileSystem::new(),
+        )));
+
+        // without a cache, every scan reads the footer again
+        let factory = DefaultParquetFileReaderFactory::new(store.upcast());
+        read_metadata(&factory, &meta[0]).await;
+        let requests = store.request_count();
+        assert!(requests > 0);
+        read_metadata(&factory, &meta[0]).await;
+        assert_eq!(store.request_count(), 2 * requests);
+
+        // with a cache, only the first scan reads the footer
+        let factory =
+            DefaultParquetFileReaderFactory::new(store.upcast()).with_metadata_cache(1);
+        let before = store.request_count();
+        let first = read_metadata(&factory, &meta[0]).await;
+        assert_eq!(store.request_count(), before + requests);
+        let second = read_metadata(&factory, &meta[0]).await;
+        assert_eq!(store.request_count(), before + requests);
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // a modified file is not served from the cache
+        let mut modified = meta[0].clone();
+        modified.last_modified += chrono::Duration::seconds(1);
+        read_metadata(&factory, &modified).await;
+        assert_eq!(store.request_count(), before + 2 * requests);
+
+        // caching another file evicts the least recently used entry
+        read_metadata(&factory, &meta[1]).await;
+        assert_eq!(store.request_count(), before + 3 * requests);
+        read_metadata(&factory, &modified).await;
+        assert_eq!(store.request_count(), before + 4 * requests);
+    }
+
+    #[tokio::test]
+    async fn concurrent_byte_ranges() {
+        let c1: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_from_iter(vec![("c1", c1)]).unwrap();
+        let (meta, files) = store_parquet(vec![batch], false).await.unwrap();
+        let expected = std::fs::read(files[0].path()).unwrap();
+        let len = expected.len();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(LocalFileSystem::new());
+        let factory = DefaultParquetFileReaderFactory::new(store);
+        let metrics = ExecutionPlanMetricsSet::new();
+        let ranges = vec![0..4, 4..len - 8, len - 8..len];
+
+        // ranges are read on the async runtime, from several readers at once
+        let reads = ranges.iter().map(|range| {
+            let mut reader = factory
+                .create_reader(0, meta[0].clone().into(), None, &metrics)
+                .unwrap();
+            let range = range.clone();
+            async move { reader.get_bytes(range).await }
+        });
+        let bytes = futures::future::try_join_all(reads).await.unwrap();
+        for (range, bytes) in ranges.iter().zip(&bytes) {
+            assert_eq!(bytes.as_ref(), &expected[range.clone()]);
+        }
+
+        // a single reader can also fetch several ranges in one call
+        let mut reader = factory
+            .create_reader(0, meta[0].clone().into(), None, &metrics)
+            .unwrap();
+        let bytes = reader.get_byte_ranges(ranges.clone()).await.unwrap();
+        assert_eq!(bytes.concat(), expected);
     }
 }
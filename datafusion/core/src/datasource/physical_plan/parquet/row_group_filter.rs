@@ -20,6 +20,7 @@ use crate::physical_optimizer::pruning::{PruningPredicate, PruningStatistics};
 use arrow::{array::ArrayRef, datatypes::Schema};
 use arrow_array::BooleanArray;
 use datafusion_common::{Column, Result, ScalarValue};
+use datafusion_physical_plan::sample::sample_block;
 use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
 use parquet::arrow::parquet_column;
 use parquet::basic::Type;
@@ -91,6 +92,26 @@ impl RowGroupAccessPlanFilter {
             }
         }
     }
+
+    /// Prune the remaining row groups that are not part of a `SYSTEM` sample
+    /// of `fraction` of the row groups, see [`sample_block`]
+    pub fn prune_by_sample(
+        &mut self,
+        file_name: &str,
+        fraction: f64,
+        seed: u64,
+        metrics: &ParquetFileMetrics,
+    ) {
+        for idx in 0..self.access_plan.len() {
+            if self.access_plan.should_scan(idx)
+                && !sample_block(file_name, idx, fraction, seed)
+            {
+                self.access_plan.skip(idx);
+                metrics.row_groups_pruned_sample.add(1);
+            }
+        }
+    }
+
     /// Prune remaining row groups using min/max/null_count statistics and
     /// the [`PruningPredicate`] to determine if the predicate can not be true.
     ///
@@ -710,6 +731,39 @@ mod tests {
         assert_pruned(row_groups, ExpectedPruning::Some(vec![1]));
     }
 
+    #[test]
+    fn row_group_pruning_predicate_not_null_expr() {
+        use datafusion_expr::col;
+        // IsNotNull(int) => c1_null_count != row_count
+        let schema = Arc::new(Schema::new(vec![Field::new("c1", DataType::Int32, true)]));
+        let schema_descr = get_test_schema_descr(vec![PrimitiveTypeField::new(
+            "c1",
+            PhysicalType::INT32,
+        )]);
+        let rgm1 = get_row_group_meta_data(
+            &schema_descr,
+            vec![ParquetStatistics::int32(None, None, None, 1000, false)],
+        );
+        let rgm2 = get_row_group_meta_data(
+            &schema_descr,
+            vec![ParquetStatistics::int32(Some(1), Some(10), None, 10, false)],
+        );
+        let expr = logical2physical(&col("c1").is_not_null(), &schema);
+        let pruning_predicate = PruningPredicate::try_new(expr, schema.clone()).unwrap();
+
+        let metrics = parquet_file_metrics();
+        // First row group was filtered out because all its 1000 values are null
+        let mut row_groups = RowGroupAccessPlanFilter::new(ParquetAccessPlan::new_all(2));
+        row_groups.prune_by_statistics(
+            &schema,
+            &schema_descr,
+            &[rgm1, rgm2],
+            &pruning_predicate,
+            &metrics,
+        );
+        assert_pruned(row_groups, ExpectedPruning::Some(vec![1]));
+    }
+
     #[test]
     fn row_group_pruning_predicate_eq_null_expr() {
         use datafusion_expr::{col, lit};
@@ -0,0 +1,910 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`RetryingObjectStore`] retries reads that fail with transient errors
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::future::Future;
+use std::io::ErrorKind;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use log::debug;
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, GetResultPayload, ListResult, MultipartUpload, ObjectMeta,
+    ObjectStore, PutMultipartOpts, PutOptions, PutPayload, PutResult, Result,
+};
+
+/// Decides whether an [`object_store::Error`] is transient, see
+/// [`RetryingObjectStore::with_transient_error_classifier`]
+pub type TransientErrorClassifier =
+    Arc<dyn Fn(&object_store::Error) -> bool + Send + Sync>;
+
+/// An [`ObjectStore`] that retries the reads of another store when they fail
+/// with an error classified as transient, waiting with an exponential backoff
+/// between the attempts.
+///
+/// Register it in place of the wrapped store to make the scans of all tables
+/// in that store retry their reads, for example:
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion::datasource::retrying_object_store::RetryingObjectStore;
+/// # use datafusion::prelude::SessionContext;
+/// # use object_store::memory::InMemory;
+/// # use url::Url;
+/// let store = RetryingObjectStore::new(Arc::new(InMemory::new())).with_max_retries(5);
+/// let ctx = SessionContext::new();
+/// ctx.register_object_store(&Url::parse("memory://").unwrap(), Arc::new(store));
+/// ```
+///
+/// Only requests that do not modify the store are retried: `get`, `head`,
+/// the range reads and the listings. If the body of a `get` fails while it
+/// is streamed, the rest of the body is requested again, provided the object
+/// has an e-tag or a version to make sure the same object is read. If a
+/// `list` fails while it is streamed, the listing is requested again, after
+/// the last object listed if the objects are listed in order, and the
+/// objects already listed are skipped.
+pub struct RetryingObjectStore {
+    inner: Arc<dyn ObjectStore>,
+    policy: RetryPolicy,
+}
+
+impl RetryingObjectStore {
+    /// Wrap `inner`, retrying up to 3 times with a backoff starting at
+    /// 100ms and classifying errors with [`is_transient_error`]
+    pub fn new(inner: Arc<dyn ObjectStore>) -> Self {
+        Self {
+            inner,
+            policy: RetryPolicy {
+                max_retries: 3,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_secs(10),
+                is_transient: Arc::new(is_transient_error),
+            },
+        }
+    }
+
+    /// Set the maximum number of retries of a request, after the first attempt
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.policy.max_retries = max_retries;
+        self
+    }
+
+    /// Set the time to wait before the first retry, which doubles for every
+    /// following retry up to `max_backoff`
+    pub fn with_backoff(
+        mut self,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Self {
+        self.policy.initial_backoff = initial_backoff;
+        self.policy.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set the function deciding which errors are retried
+    pub fn with_transient_error_classifier(
+        mut self,
+        is_transient: TransientErrorClassifier,
+    ) -> Self {
+        self.policy.is_transient = is_transient;
+        self
+    }
+
+    /// Return the wrapped store
+    pub fn inner(&self) -> &Arc<dyn ObjectStore> {
+        &self.inner
+    }
+}
+
+/// How the requests of a [`RetryingObjectStore`] are retried
+#[derive(Clone)]
+struct RetryPolicy {
+    max_retries: usize,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    is_transient: TransientErrorClassifier,
+}
+
+impl RetryPolicy {
+    /// Return true if the request that failed with `error` after `retries`
+    /// retries should be attempted again
+    fn should_retry(&self, error: &object_store::Error, retries: usize) -> bool {
+        retries < self.max_retries && (self.is_transient)(error)
+    }
+
+    /// The time to wait before the retry number `retry`, starting at 1
+    fn backoff(&self, retry: usize) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1) as u32);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    async fn retry<T, F, Fut>(&self, operation: &str, location: &Path, f: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut retries = 0;
+        loop {
+            match f().await {
+                Err(e) if self.should_retry(&e, retries) => {
+                    retries += 1;
+                    let backoff = self.backoff(retries);
+                    debug!(
+                        "Retrying {operation} of {location} in {backoff:?} \
+                         ({retries}/{}) after error: {e}",
+                        self.max_retries
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// State of [`resume_body_on_error`]
+struct ResumableBody {
+    inner: Arc<dyn ObjectStore>,
+    policy: RetryPolicy,
+    location: Path,
+    /// The e-tag and version of the object, to only resume reading the same
+    /// object
+    e_tag: Option<String>,
+    version: Option<String>,
+    /// The rest of the body
+    body: BoxStream<'static, Result<Bytes>>,
+    /// Offset of the next byte of the body, and end of the requested range
+    offset: usize,
+    end: usize,
+    /// Retries since the last successfully read bytes
+    retries: usize,
+}
+
+/// Return the body of `result`, which requests the rest of the body again
+/// when reading it fails with a transient error
+fn resume_body_on_error(
+    inner: Arc<dyn ObjectStore>,
+    policy: RetryPolicy,
+    location: Path,
+    result: GetResult,
+) -> BoxStream<'static, Result<Bytes>> {
+    let state = ResumableBody {
+        inner,
+        policy,
+        location,
+        e_tag: result.meta.e_tag.clone(),
+        version: result.meta.version.clone(),
+        offset: result.range.start,
+        end: result.range.end,
+        body: result.into_stream(),
+        retries: 0,
+    };
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            match state.body.next().await? {
+                Ok(bytes) => {
+                    state.offset += bytes.len();
+                    state.retries = 0;
+                    return Some((Ok(bytes), state));
+                }
+                Err(e)
+                    if (state.e_tag.is_some() || state.version.is_some())
+                        && state.policy.should_retry(&e, state.retries) =>
+                {
+                    state.retries += 1;
+                    let backoff = state.policy.backoff(state.retries);
+                    debug!(
+                        "Resuming get of {} at byte {} in {backoff:?} ({}/{}) \
+                         after error: {e}",
+                        state.location,
+                        state.offset,
+                        state.retries,
+                        state.policy.max_retries
+                    );
+                    tokio::time::sleep(backoff).await;
+                    let options = GetOptions {
+                        if_match: state.e_tag.clone(),
+                        version: state.version.clone(),
+                        range: Some((state.offset..state.end).into()),
+                        ..Default::default()
+                    };
+                    // an error of the new request is classified by the next
+                    // iteration, like an error of the body
+                    state.body =
+                        match state.inner.get_opts(&state.location, options).await {
+                            Ok(result) => result.into_stream(),
+                            Err(e) => futures::stream::once(async { Err(e) }).boxed(),
+                        };
+                }
+                Err(e) => {
+                    state.body = futures::stream::empty().boxed();
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+/// State of [`resume_list_on_error`]
+struct ResumableList<'a> {
+    inner: &'a dyn ObjectStore,
+    policy: RetryPolicy,
+    prefix: Option<Path>,
+    /// The offset of the listing, if any
+    offset: Option<Path>,
+    /// The rest of the listing
+    list: BoxStream<'a, Result<ObjectMeta>>,
+    /// The objects listed so far, skipped if they are listed again
+    listed: HashSet<Path>,
+    /// The last object listed, and whether the objects listed so far are in
+    /// increasing order
+    last: Option<Path>,
+    sorted: bool,
+    /// Retries since the last object listed
+    retries: usize,
+}
+
+/// Request the objects under `prefix` after `offset`
+fn request_list<'a>(
+    inner: &'a dyn ObjectStore,
+    prefix: Option<&Path>,
+    offset: Option<&Path>,
+) -> BoxStream<'a, Result<ObjectMeta>> {
+    match offset {
+        Some(offset) => inner.list_with_offset(prefix, offset),
+        None => inner.list(prefix),
+    }
+}
+
+/// List the objects of `inner` under `prefix` after `offset`, requesting the
+/// rest of the listing again when it fails with a transient error
+fn resume_list_on_error(
+    inner: &dyn ObjectStore,
+    policy: RetryPolicy,
+    prefix: Option<Path>,
+    offset: Option<Path>,
+) -> BoxStream<'_, Result<ObjectMeta>> {
+    let state = ResumableList {
+        inner,
+        policy,
+        list: request_list(inner, prefix.as_ref(), offset.as_ref()),
+        prefix,
+        offset,
+        listed: HashSet::new(),
+        last: None,
+        sorted: true,
+        retries: 0,
+    };
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            match state.list.next().await? {
+                Ok(meta) => {
+                    if !state.listed.insert(meta.location.clone()) {
+                        continue;
+                    }
+                    state.sorted &= state
+                        .last
+                        .as_ref()
+                        .map_or(true, |last| *last < meta.location);
+                    state.last = Some(meta.location.clone());
+                    state.retries = 0;
+                    return Some((Ok(meta), state));
+                }
+                Err(e) if state.policy.should_retry(&e, state.retries) => {
+                    state.retries += 1;
+                    let backoff = state.policy.backoff(state.retries);
+                    debug!(
+                        "Resuming list of {} after {} objects in {backoff:?} ({}/{}) \
+                         after error: {e}",
+                        state.prefix.clone().unwrap_or_default(),
+                        state.listed.len(),
+                        state.retries,
+                        state.policy.max_retries
+                    );
+                    tokio::time::sleep(backoff).await;
+                    // the objects listed in order can be listed again from the
+                    // last one, the others are listed again from the start
+                    let offset = match &state.last {
+                        Some(last) if state.sorted => Some(last),
+                        _ => state.offset.as_ref(),
+                    };
+                    state.list = request_list(state.inner, state.prefix.as_ref(), offset);
+                }
+                Err(e) => {
+                    state.list = futures::stream::empty().boxed();
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+/// Copy `options` for another attempt of a request.
+///
+/// [`GetOptions`] does not implement `Clone` in object_store 0.10. The
+/// options are destructured without `..` so that a field added by a newer
+/// version fails to compile here instead of being silently dropped on retry.
+fn copy_get_options(options: &GetOptions) -> GetOptions {
+    let GetOptions {
+        if_match,
+        if_none_match,
+        if_modified_since,
+        if_unmodified_since,
+        range,
+        version,
+        head,
+    } = options;
+    GetOptions {
+        if_match: if_match.clone(),
+        if_none_match: if_none_match.clone(),
+        if_modified_since: *if_modified_since,
+        if_unmodified_since: *if_unmodified_since,
+        range: range.clone(),
+        version: version.clone(),
+        head: *head,
+    }
+}
+
+/// The default [`TransientErrorClassifier`] of [`RetryingObjectStore`].
+///
+/// An error is transient if it was caused by an I/O error that may not
+/// happen again, such as a connection reset or a timeout. The stores report
+/// these failures as [`object_store::Error::Generic`] errors, which they
+/// also use for failures that will not change when the request is repeated,
+/// such as invalid credentials or configuration, so the causes of the error
+/// are inspected rather than its variant.
+pub fn is_transient_error(error: &object_store::Error) -> bool {
+    let mut source: Option<&(dyn Error + 'static)> = match error {
+        object_store::Error::Generic { source, .. } => Some(source.as_ref()),
+        _ => return false,
+    };
+    while let Some(error) = source {
+        if let Some(io_error) = error.downcast_ref::<std::io::Error>() {
+            if matches!(
+                io_error.kind(),
+                ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::ConnectionRefused
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::TimedOut
+                    | ErrorKind::Interrupted
+                    | ErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+        source = error.source();
+    }
+    false
+}
+
+impl Debug for RetryingObjectStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryingObjectStore")
+            .field("inner", &self.inner)
+            .field("max_retries", &self.policy.max_retries)
+            .field("initial_backoff", &self.policy.initial_backoff)
+            .field("max_backoff", &self.policy.max_backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Display for RetryingObjectStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RetryingObjectStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for RetryingObjectStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> Result<PutResult> {
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOpts,
+    ) -> Result<Box<dyn MultipartUpload>> {
+        self.inner.put_multipart_opts(location, opts).await
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        let result = self
+            .policy
+            .retry("get", location, || {
+                self.inner.get_opts(location, copy_get_options(&options))
+            })
+            .await?;
+        match result.payload {
+            GetResultPayload::Stream(_) => Ok(GetResult {
+                meta: result.meta.clone(),
+                range: result.range.clone(),
+                attributes: result.attributes.clone(),
+                payload: GetResultPayload::Stream(resume_body_on_error(
+                    Arc::clone(&self.inner),
+                    self.policy.clone(),
+                    location.clone(),
+                    result,
+                )),
+            }),
+            GetResultPayload::File(_, _) => Ok(result),
+        }
+    }
+
+    async fn get_range(&self, location: &Path, range: Range<usize>) -> Result<Bytes> {
+        self.policy
+            .retry("get_range", location, || {
+                self.inner.get_range(location, range.clone())
+            })
+            .await
+    }
+
+    async fn get_ranges(
+        &self,
+        location: &Path,
+        ranges: &[Range<usize>],
+    ) -> Result<Vec<Bytes>> {
+        self.policy
+            .retry("get_ranges", location, || {
+                self.inner.get_ranges(location, ranges)
+            })
+            .await
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        self.policy
+            .retry("head", location, || self.inner.head(location))
+            .await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'_, Result<ObjectMeta>> {
+        resume_list_on_error(
+            self.inner.as_ref(),
+            self.policy.clone(),
+            prefix.cloned(),
+            None,
+        )
+    }
+
+    fn list_with_offset(
+        &self,
+        prefix: Option<&Path>,
+        offset: &Path,
+    ) -> BoxStream<'_, Result<ObjectMeta>> {
+        resume_list_on_error(
+            self.inner.as_ref(),
+            self.policy.clone(),
+            prefix.cloned(),
+            Some(offset.clone()),
+        )
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        let location = prefix.cloned().unwrap_or_default();
+        self.policy
+            .retry("list", &location, || self.inner.list_with_delimiter(prefix))
+            .await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner.copy_if_not_exists(from, to).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+
+    fn connection_reset() -> object_store::Error {
+        object_store::Error::Generic {
+            store: "flaky",
+            source: Box::new(std::io::Error::new(
+                ErrorKind::ConnectionReset,
+                "connection reset",
+            )),
+        }
+    }
+
+    /// Store whose reads fail with a transient error until `failures`
+    /// attempts have been made, whose `get` bodies fail halfway until
+    /// `body_failures` bodies have been read, and whose listings fail after
+    /// the first object until `list_failures` listings have been made
+    #[derive(Debug)]
+    struct FlakyObjectStore {
+        inner: InMemory,
+        failures: usize,
+        attempts: AtomicUsize,
+        body_failures: usize,
+        body_attempts: AtomicUsize,
+        list_failures: usize,
+        list_attempts: AtomicUsize,
+    }
+
+    impl FlakyObjectStore {
+        fn check(&self) -> Result<()> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(connection_reset());
+            }
+            Ok(())
+        }
+
+        fn fail_list<'a>(
+            &self,
+            list: BoxStream<'a, Result<ObjectMeta>>,
+        ) -> BoxStream<'a, Result<ObjectMeta>> {
+            if self.list_attempts.fetch_add(1, Ordering::SeqCst) >= self.list_failures {
+                return list;
+            }
+            list.take(1)
+                .chain(futures::stream::once(async { Err(connection_reset()) }))
+                .boxed()
+        }
+    }
+
+    impl Display for FlakyObjectStore {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "FlakyObjectStore")
+        }
+    }
+
+    #[async_trait]
+    impl ObjectStore for FlakyObjectStore {
+        async fn put_opts(
+            &self,
+            location: &Path,
+            payload: PutPayload,
+            opts: PutOptions,
+        ) -> Result<PutResult> {
+            self.inner.put_opts(location, payload, opts).await
+        }
+
+        async fn put_multipart_opts(
+            &self,
+            location: &Path,
+            opts: PutMultipartOpts,
+        ) -> Result<Box<dyn MultipartUpload>> {
+            self.inner.put_multipart_opts(location, opts).await
+        }
+
+        async fn get_opts(
+            &self,
+            location: &Path,
+            options: GetOptions,
+        ) -> Result<GetResult> {
+            self.check()?;
+            let result = self.inner.get_opts(location, options).await?;
+            if self.body_attempts.fetch_add(1, Ordering::SeqCst) >= self.body_failures {
+                return Ok(result);
+            }
+            let (meta, range, attributes) = (
+                result.meta.clone(),
+                result.range.clone(),
+                result.attributes.clone(),
+            );
+            let bytes = result.bytes().await?;
+            let half = bytes.slice(0..bytes.len() / 2);
+            let body = futures::stream::iter(vec![Ok(half), Err(connection_reset())]);
+            Ok(GetResult {
+                payload: GetResultPayload::Stream(body.boxed()),
+                meta,
+                range,
+                attributes,
+            })
+        }
+
+        async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+            self.check()?;
+            self.inner.head(location).await
+        }
+
+        async fn delete(&self, location: &Path) -> Result<()> {
+            self.inner.delete(location).await
+        }
+
+        fn list(&self, prefix: Option<&Path>) -> BoxStream<'_, Result<ObjectMeta>> {
+            self.fail_list(self.inner.list(prefix))
+        }
+
+        fn list_with_offset(
+            &self,
+            prefix: Option<&Path>,
+            offset: &Path,
+        ) -> BoxStream<'_, Result<ObjectMeta>> {
+            self.fail_list(self.inner.list_with_offset(prefix, offset))
+        }
+
+        async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+            self.inner.list_with_delimiter(prefix).await
+        }
+
+        async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+            self.inner.copy(from, to).await
+        }
+
+        async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+            self.inner.copy_if_not_exists(from, to).await
+        }
+    }
+
+    async fn flaky_store(failures: usize) -> Arc<FlakyObjectStore> {
+        flaky_body_store(failures, 0).await
+    }
+
+    async fn flaky_body_store(
+        failures: usize,
+        body_failures: usize,
+    ) -> Arc<FlakyObjectStore> {
+        let inner = InMemory::new();
+        inner
+            .put(&Path::from("data.csv"), "a,b\n1,2\n".into())
+            .await
+            .unwrap();
+        Arc::new(FlakyObjectStore {
+            inner,
+            failures,
+            attempts: AtomicUsize::new(0),
+            body_failures,
+            body_attempts: AtomicUsize::new(0),
+            list_failures: 0,
+            list_attempts: AtomicUsize::new(0),
+        })
+    }
+
+    async fn flaky_list_store(list_failures: usize) -> Arc<FlakyObjectStore> {
+        let inner = InMemory::new();
+        for location in ["a.csv", "b.csv", "c.csv"] {
+            inner
+                .put(&Path::from(location), "a,b\n1,2\n".into())
+                .await
+                .unwrap();
+        }
+        Arc::new(FlakyObjectStore {
+            inner,
+            failures: 0,
+            attempts: AtomicUsize::new(0),
+            body_failures: 0,
+            body_attempts: AtomicUsize::new(0),
+            list_failures,
+            list_attempts: AtomicUsize::new(0),
+        })
+    }
+
+    fn retrying(
+        store: &Arc<FlakyObjectStore>,
+        max_retries: usize,
+    ) -> RetryingObjectStore {
+        RetryingObjectStore::new(Arc::clone(store) as _)
+            .with_max_retries(max_retries)
+            .with_backoff(Duration::ZERO, Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors() {
+        let location = Path::from("data.csv");
+
+        let flaky = flaky_store(2).await;
+        let store = retrying(&flaky, 2);
+        let bytes = store.get_range(&location, 0..3).await.unwrap();
+        assert_eq!(bytes.as_ref(), b"a,b");
+        assert_eq!(flaky.attempts.load(Ordering::SeqCst), 3);
+
+        let flaky = flaky_store(2).await;
+        let store = retrying(&flaky, 2);
+        let meta = store.head(&location).await.unwrap();
+        assert_eq!(meta.size, 8);
+
+        let flaky = flaky_store(3).await;
+        let store = retrying(&flaky, 2);
+        let err = store.get(&location).await.unwrap_err();
+        assert!(err.to_string().contains("connection reset"), "{err}");
+        assert_eq!(flaky.attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn classify_transient_errors() {
+        assert!(is_transient_error(&connection_reset()));
+        let permission_denied = object_store::Error::Generic {
+            store: "flaky",
+            source: Box::new(std::io::Error::new(
+                ErrorKind::PermissionDenied,
+                "permission denied",
+            )),
+        };
+        assert!(!is_transient_error(&permission_denied));
+        let invalid_credentials = object_store::Error::Generic {
+            store: "flaky",
+            source: "invalid credentials".into(),
+        };
+        assert!(!is_transient_error(&invalid_credentials));
+        let not_found = object_store::Error::NotFound {
+            path: "data.csv".to_string(),
+            source: Box::new(std::io::Error::new(ErrorKind::TimedOut, "timed out")),
+        };
+        assert!(!is_transient_error(&not_found));
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_permanent_errors() {
+        let flaky = flaky_store(0).await;
+        let store = retrying(&flaky, 5);
+        let err = store.get(&Path::from("missing.csv")).await.unwrap_err();
+        assert!(matches!(err, object_store::Error::NotFound { .. }), "{err}");
+        assert_eq!(flaky.attempts.load(Ordering::SeqCst), 1);
+
+        // a custom classifier can decide otherwise
+        let flaky = flaky_store(2).await;
+        let store =
+            retrying(&flaky, 5).with_transient_error_classifier(Arc::new(|_| false));
+        store.head(&Path::from("data.csv")).await.unwrap_err();
+        assert_eq!(flaky.attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn resumes_body_after_transient_error() {
+        let location = Path::from("data.csv");
+
+        // every body fails halfway, the rest is requested again
+        let flaky = flaky_body_store(0, 2).await;
+        let store = retrying(&flaky, 2);
+        let bytes = store.get(&location).await.unwrap().bytes().await.unwrap();
+        assert_eq!(bytes.as_ref(), b"a,b\n1,2\n");
+        assert_eq!(flaky.body_attempts.load(Ordering::SeqCst), 3);
+
+        let flaky = flaky_body_store(0, 2).await;
+        let store = retrying(&flaky, 2);
+        let result = store.get_opts(
+            &location,
+            GetOptions {
+                range: Some((2..8).into()),
+                ..Default::default()
+            },
+        );
+        let bytes = result.await.unwrap().bytes().await.unwrap();
+        assert_eq!(bytes.as_ref(), b"b\n1,2\n");
+
+        // the failures of the requests resuming the body are retried too
+        let flaky = flaky_body_store(1, 1).await;
+        let store = retrying(&flaky, 2);
+        let result = store.get(&location).await.unwrap();
+        flaky.attempts.store(0, Ordering::SeqCst);
+        let bytes = result.bytes().await.unwrap();
+        assert_eq!(bytes.as_ref(), b"a,b\n1,2\n");
+
+        // the retries are counted since the last bytes read
+        let flaky = flaky_body_store(0, 3).await;
+        let store = retrying(&flaky, 1);
+        let bytes = store.get(&location).await.unwrap().bytes().await.unwrap();
+        assert_eq!(bytes.as_ref(), b"a,b\n1,2\n");
+
+        let flaky = flaky_body_store(0, 1).await;
+        let store = retrying(&flaky, 0);
+        let err = store
+            .get(&location)
+            .await
+            .unwrap()
+            .bytes()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("connection reset"), "{err}");
+        assert_eq!(flaky.body_attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn resumes_list_after_transient_error() {
+        async fn list(
+            store: &RetryingObjectStore,
+            offset: Option<&str>,
+        ) -> Result<Vec<String>> {
+            let list = match offset {
+                Some(offset) => store.list_with_offset(None, &Path::from(offset)),
+                None => store.list(None),
+            };
+            list.map_ok(|meta| meta.location.to_string())
+                .try_collect()
+                .await
+        }
+
+        // the listing fails after the first object, the rest is listed again
+        let flaky = flaky_list_store(1).await;
+        let store = retrying(&flaky, 1);
+        assert_eq!(
+            list(&store, None).await.unwrap(),
+            ["a.csv", "b.csv", "c.csv"]
+        );
+        assert_eq!(flaky.list_attempts.load(Ordering::SeqCst), 2);
+
+        let flaky = flaky_list_store(1).await;
+        let store = retrying(&flaky, 1);
+        assert_eq!(
+            list(&store, Some("a.csv")).await.unwrap(),
+            ["b.csv", "c.csv"]
+        );
+        assert_eq!(flaky.list_attempts.load(Ordering::SeqCst), 2);
+
+        // the retries are counted since the last object listed
+        let flaky = flaky_list_store(2).await;
+        let store = retrying(&flaky, 1);
+        assert_eq!(
+            list(&store, None).await.unwrap(),
+            ["a.csv", "b.csv", "c.csv"]
+        );
+        assert_eq!(flaky.list_attempts.load(Ordering::SeqCst), 3);
+
+        let flaky = flaky_list_store(1).await;
+        let store = retrying(&flaky, 0);
+        let err = list(&store, None).await.unwrap_err();
+        assert!(err.to_string().contains("connection reset"), "{err}");
+        assert_eq!(flaky.list_attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn scan_through_retrying_store() -> crate::error::Result<()> {
+        use crate::prelude::{CsvReadOptions, SessionContext};
+        use url::Url;
+
+        let flaky = flaky_store(2).await;
+        let ctx = SessionContext::new();
+        ctx.register_object_store(
+            &Url::parse("flaky://").unwrap(),
+            Arc::new(retrying(&flaky, 3)),
+        );
+        ctx.register_csv("t", "flaky:///data.csv", CsvReadOptions::new())
+            .await?;
+        let batches = ctx.sql("SELECT a, b FROM t").await?.collect().await?;
+        let expected = [
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | 2 |",
+            "+---+---+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+}
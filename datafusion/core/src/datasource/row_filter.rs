@@ -0,0 +1,758 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Table provider restricting the rows of another table to those matching a
+//! filter, for row level security.
+
+use std::{any::Any, sync::Arc};
+
+use arrow::compute::filter_record_batch;
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use async_trait::async_trait;
+use datafusion_catalog::Session;
+use datafusion_common::cast::as_boolean_array;
+use datafusion_common::{exec_err, Constraints, DFSchema, TableReference};
+use datafusion_execution::TaskContext;
+use datafusion_expr::expr_rewriter::unnormalize_col;
+use datafusion_expr::TableProviderFilterPushDown;
+use datafusion_physical_expr::expressions::Column;
+use datafusion_physical_expr::PhysicalExpr;
+use futures::{future, TryStreamExt};
+
+use crate::datasource::{TableProvider, TableType};
+use crate::error::Result;
+use crate::logical_expr::Expr;
+use crate::physical_plan::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet,
+};
+use crate::physical_plan::projection::ProjectionExec;
+use crate::physical_plan::stream::RecordBatchStreamAdapter;
+use crate::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties,
+    SendableRecordBatchStream,
+};
+
+/// Decides which rows of each table the queries of a session may see.
+///
+/// The policy is applied when a plan is optimized: every table the plan
+/// scans, whether it comes from SQL, a [`DataFrame`] or a view however old,
+/// is wrapped in a [`RowFilterTable`] if the policy restricts its rows. The
+/// rows inserted into a table must match its filter. Providers read directly
+/// with [`SessionContext::read_table`] and its variants are checked against
+/// the policy as [`UNNAMED_TABLE`].
+///
+/// [`DataFrame`]: crate::dataframe::DataFrame
+/// [`SessionContext::read_table`]: crate::execution::context::SessionContext::read_table
+/// [`UNNAMED_TABLE`]: crate::logical_expr::UNNAMED_TABLE
+pub trait RowFilterPolicy: Send + Sync {
+    /// Returns the predicate the rows of `table` must satisfy, or `None` if
+    /// they are not restricted. `table` is fully qualified, unless it is
+    /// [`UNNAMED_TABLE`](crate::logical_expr::UNNAMED_TABLE).
+    fn row_filter(&self, table: &TableReference) -> Option<Expr>;
+}
+
+impl<F> RowFilterPolicy for F
+where
+    F: Fn(&TableReference) -> Option<Expr> + Send + Sync,
+{
+    fn row_filter(&self, table: &TableReference) -> Option<Expr> {
+        self(table)
+    }
+}
+
+/// A [`TableProvider`] that only returns the rows of another table that
+/// match a filter.
+///
+/// Scans of the table are wrapped in a [`RowFilterExec`] dropping the rows
+/// not matching the filter. The filter is also pushed down to the inner
+/// table, so that it can be used for pruning, but it is applied whatever the
+/// inner table does with it. The filters of a query are never pushed down
+/// through the table: they are evaluated above the [`RowFilterExec`], so that
+/// they only ever see the rows matching the filter. Inserted rows must match
+/// the filter.
+pub struct RowFilterTable {
+    /// The reference the filter resolves its columns against
+    table_ref: TableReference,
+    /// The table whose rows are filtered
+    inner: Arc<dyn TableProvider>,
+    /// The predicate the rows of `inner` must satisfy
+    filter: Expr,
+}
+
+impl RowFilterTable {
+    /// Restrict the rows of `inner`, scanned as `table_ref`, to those that
+    /// match `filter`
+    pub fn try_new(
+        table_ref: impl Into<TableReference>,
+        inner: Arc<dyn TableProvider>,
+        filter: Expr,
+    ) -> Result<Self> {
+        let table_ref = table_ref.into();
+        let schema =
+            DFSchema::try_from_qualified_schema(table_ref.clone(), &inner.schema())?;
+        for column in filter.column_refs() {
+            schema.index_of_column(column)?;
+        }
+        Ok(Self {
+            table_ref,
+            inner,
+            filter,
+        })
+    }
+
+    /// Get the reference the filter is resolved against
+    pub fn table_ref(&self) -> &TableReference {
+        &self.table_ref
+    }
+
+    /// Get the filter the rows must match
+    pub fn filter(&self) -> &Expr {
+        &self.filter
+    }
+
+    /// Get the filter as it appears among the filters of the scans of the
+    /// table, which is the only filter the table handles
+    pub fn scan_filter(&self) -> Expr {
+        unnormalize_col(self.filter.clone())
+    }
+
+    /// Creates the physical filter over `schema`, a projection of the schema
+    /// of the inner table
+    fn create_predicate(
+        &self,
+        state: &dyn Session,
+        schema: &SchemaRef,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        let schema = DFSchema::try_from_qualified_schema(self.table_ref.clone(), schema)?;
+        state.create_physical_expr(self.filter.clone(), &schema)
+    }
+}
+
+#[async_trait]
+impl TableProvider for RowFilterTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+
+    fn constraints(&self) -> Option<&Constraints> {
+        self.inner.constraints()
+    }
+
+    fn table_type(&self) -> TableType {
+        self.inner.table_type()
+    }
+
+    fn get_column_default(&self, column: &str) -> Option<&Expr> {
+        self.inner.get_column_default(column)
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        // the filters of the query are evaluated above the RowFilterExec, as
+        // they could otherwise leak the rows not matching the filter, through
+        // errors for instance
+        let scan_filter = self.scan_filter();
+        Ok(filters
+            .iter()
+            .map(|filter| {
+                if **filter == scan_filter {
+                    TableProviderFilterPushDown::Exact
+                } else {
+                    TableProviderFilterPushDown::Unsupported
+                }
+            })
+            .collect())
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // the columns of the filter are scanned even if they are not projected
+        let schema = self.inner.schema();
+        let scan_projection = match projection {
+            Some(projection) => {
+                let mut scan_projection = projection.clone();
+                for column in self.filter.column_refs() {
+                    let index = schema.index_of(&column.name)?;
+                    if !scan_projection.contains(&index) {
+                        scan_projection.push(index);
+                    }
+                }
+                Some(scan_projection)
+            }
+            None => None,
+        };
+
+        // the only filter pushed down by the plan is the row filter itself
+        let mut scan_filters = vec![];
+        if self.inner.supports_filters_pushdown(&[&self.filter])?[0]
+            != TableProviderFilterPushDown::Unsupported
+        {
+            scan_filters.push(self.filter.clone());
+        }
+        // the limit only holds once the rows are filtered, and the plan keeps
+        // it above the scan
+        let input = self
+            .inner
+            .scan(state, scan_projection.as_ref(), &scan_filters, None)
+            .await?;
+        let predicate = self.create_predicate(state, &input.schema())?;
+        let plan: Arc<dyn ExecutionPlan> = Arc::new(RowFilterExec::new(
+            self.table_ref.clone(),
+            predicate,
+            RowFilterMode::Filter,
+            input,
+        ));
+
+        match projection {
+            Some(projection) if projection.len() < plan.schema().fields().len() => {
+                let schema = plan.schema();
+                let exprs = schema
+                    .fields()
+                    .iter()
+                    .take(projection.len())
+                    .enumerate()
+                    .map(|(index, field)| {
+                        (
+                            Arc::new(Column::new(field.name(), index)) as _,
+                            field.name().to_owned(),
+                        )
+                    })
+                    .collect();
+                Ok(Arc::new(ProjectionExec::try_new(exprs, plan)?))
+            }
+            _ => Ok(plan),
+        }
+    }
+
+    async fn insert_into(
+        &self,
+        state: &dyn Session,
+        input: Arc<dyn ExecutionPlan>,
+        overwrite: bool,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let predicate = self.create_predicate(state, &input.schema())?;
+        let input = Arc::new(RowFilterExec::new(
+            self.table_ref.clone(),
+            predicate,
+            RowFilterMode::Check,
+            input,
+        ));
+        self.inner.insert_into(state, input, overwrite).await
+    }
+}
+
+/// What a [`RowFilterExec`] does with the rows not matching the row filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowFilterMode {
+    /// Drop them, when scanning the table
+    Filter,
+    /// Fail, when inserting into the table
+    Check,
+}
+
+/// Execution plan enforcing the row filter of a [`RowFilterTable`] on its
+/// input, see [`RowFilterMode`]
+#[derive(Debug)]
+pub struct RowFilterExec {
+    /// The table whose row filter is enforced
+    table: TableReference,
+    /// The row filter, over the schema of `input`
+    predicate: Arc<dyn PhysicalExpr>,
+    /// What to do with the rows not matching the filter
+    mode: RowFilterMode,
+    /// The input plan
+    input: Arc<dyn ExecutionPlan>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Properties equivalence properties, partitioning, etc.
+    cache: PlanProperties,
+}
+
+impl RowFilterExec {
+    /// Create a new RowFilterExec enforcing the row filter `predicate` of
+    /// `table` on `input`
+    pub fn new(
+        table: TableReference,
+        predicate: Arc<dyn PhysicalExpr>,
+        mode: RowFilterMode,
+        input: Arc<dyn ExecutionPlan>,
+    ) -> Self {
+        let cache = input.properties().clone();
+        Self {
+            table,
+            predicate,
+            mode,
+            input,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        }
+    }
+
+    /// The table whose row filter is enforced
+    pub fn table(&self) -> &TableReference {
+        &self.table
+    }
+
+    /// The row filter
+    pub fn predicate(&self) -> &Arc<dyn PhysicalExpr> {
+        &self.predicate
+    }
+
+    /// What is done with the rows not matching the filter
+    pub fn mode(&self) -> RowFilterMode {
+        self.mode
+    }
+}
+
+impl DisplayAs for RowFilterExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let mode = match self.mode {
+                    RowFilterMode::Filter => "predicate",
+                    RowFilterMode::Check => "check",
+                };
+                write!(
+                    f,
+                    "RowFilterExec: table={}, {mode}={}",
+                    self.table, self.predicate
+                )
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for RowFilterExec {
+    fn name(&self) -> &'static str {
+        "RowFilterExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![&self.input]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![true]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(RowFilterExec::new(
+            self.table.clone(),
+            Arc::clone(&self.predicate),
+            self.mode,
+            Arc::clone(&children[0]),
+        )))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let input = self.input.execute(partition, context)?;
+        let schema = input.schema();
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let table = self.table.clone();
+        let predicate = Arc::clone(&self.predicate);
+        let mode = self.mode;
+        let stream = input.and_then(move |batch| {
+            let timer = baseline_metrics.elapsed_compute().timer();
+            let result = row_filter_batch(&table, &predicate, mode, &batch);
+            timer.done();
+            if let Ok(batch) = &result {
+                baseline_metrics.record_output(batch.num_rows());
+            }
+            future::ready(result)
+        });
+        Ok(Box::pin(RecordBatchStreamAdapter::new(schema, stream)))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+/// Enforces the row filter `predicate` of `table` on `batch`
+fn row_filter_batch(
+    table: &TableReference,
+    predicate: &Arc<dyn PhysicalExpr>,
+    mode: RowFilterMode,
+    batch: &RecordBatch,
+) -> Result<RecordBatch> {
+    let matches = predicate.evaluate(batch)?.into_array(batch.num_rows())?;
+    let matches = as_boolean_array(&matches)?;
+    match mode {
+        RowFilterMode::Filter => Ok(filter_record_batch(batch, matches)?),
+        RowFilterMode::Check if matches.true_count() == batch.num_rows() => {
+            Ok(batch.clone())
+        }
+        RowFilterMode::Check => {
+            exec_err!("Inserted rows do not match the row filter of table {table}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_expr::utils::conjunction;
+    use datafusion_expr::{col, lit};
+
+    use crate::assert_batches_eq;
+    use crate::datasource::MemTable;
+    use crate::logical_expr::UNNAMED_TABLE;
+    use crate::physical_plan::displayable;
+    use crate::physical_plan::filter::FilterExec;
+    use crate::prelude::SessionContext;
+
+    use super::*;
+
+    async fn create_tables(ctx: &SessionContext) -> Result<()> {
+        ctx.sql(
+            "CREATE TABLE t(id INT, tenant VARCHAR) AS VALUES \
+             (1, 'a'), (2, 'b'), (3, 'a')",
+        )
+        .await?;
+        ctx.sql(
+            "CREATE TABLE names(id INT, name VARCHAR) AS VALUES \
+             (1, 'one'), (2, 'two'), (3, 'three')",
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// A session whose policy only lets tenant "a" see the rows of `t`
+    async fn restricted_context() -> Result<SessionContext> {
+        let policy = |table: &TableReference| {
+            (table.table() == "t").then(|| col("tenant").eq(lit("a")))
+        };
+        let ctx = SessionContext::new().with_row_filter_policy(Arc::new(policy));
+        create_tables(&ctx).await?;
+        Ok(ctx)
+    }
+
+    /// A table evaluating the filters pushed down to it while scanning
+    struct FilteringTable(Arc<dyn TableProvider>);
+
+    #[async_trait]
+    impl TableProvider for FilteringTable {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn schema(&self) -> SchemaRef {
+            self.0.schema()
+        }
+
+        fn table_type(&self) -> TableType {
+            self.0.table_type()
+        }
+
+        fn supports_filters_pushdown(
+            &self,
+            filters: &[&Expr],
+        ) -> Result<Vec<TableProviderFilterPushDown>> {
+            Ok(vec![TableProviderFilterPushDown::Exact; filters.len()])
+        }
+
+        async fn scan(
+            &self,
+            state: &dyn Session,
+            projection: Option<&Vec<usize>>,
+            filters: &[Expr],
+            limit: Option<usize>,
+        ) -> Result<Arc<dyn ExecutionPlan>> {
+            let input = self.0.scan(state, None, &[], limit).await?;
+            let input = match conjunction(filters.to_vec()) {
+                Some(filter) => {
+                    let schema = DFSchema::try_from(self.schema().as_ref().clone())?;
+                    let predicate = state.create_physical_expr(filter, &schema)?;
+                    Arc::new(FilterExec::try_new(predicate, input)?)
+                }
+                None => input,
+            };
+            let Some(projection) = projection else {
+                return Ok(input);
+            };
+            let exprs = projection
+                .iter()
+                .map(|index| {
+                    let name = self.schema().field(*index).name().to_owned();
+                    (Arc::new(Column::new(&name, *index)) as _, name)
+                })
+                .collect();
+            Ok(Arc::new(ProjectionExec::try_new(exprs, input)?))
+        }
+    }
+
+    async fn count(ctx: &SessionContext, sql: &str) -> Result<usize> {
+        ctx.sql(sql).await?.count().await
+    }
+
+    #[tokio::test]
+    async fn register_table_with_row_filter() -> Result<()> {
+        let ctx = SessionContext::new();
+        create_tables(&ctx).await?;
+        let provider = ctx.deregister_table("t")?.unwrap();
+        ctx.register_table_with_row_filter("t", provider, col("tenant").eq(lit("a")))?;
+
+        let expected = [
+            "+----+--------+",
+            "| id | tenant |",
+            "+----+--------+",
+            "| 1  | a      |",
+            "| 3  | a      |",
+            "+----+--------+",
+        ];
+        let results = ctx
+            .sql("SELECT * FROM t ORDER BY id")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(expected, &results);
+        let results = ctx
+            .table("t")
+            .await?
+            .sort(vec![col("id").sort(true, false)])?;
+        assert_batches_eq!(expected, &results.collect().await?);
+
+        let expected = [
+            "+-------+",
+            "| name  |",
+            "+-------+",
+            "| one   |",
+            "| three |",
+            "+-------+",
+        ];
+        let results = ctx
+            .sql("SELECT name FROM names JOIN t ON names.id = t.id ORDER BY name")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(expected, &results);
+
+        // views and CTEs scan the filtered table too
+        ctx.sql("CREATE VIEW v AS SELECT id FROM t").await?;
+        assert_eq!(count(&ctx, "SELECT * FROM v").await?, 2);
+        assert_eq!(
+            count(&ctx, "WITH c AS (SELECT * FROM t) SELECT * FROM c").await?,
+            2
+        );
+        assert_eq!(count(&ctx, "SELECT id FROM t LIMIT 2").await?, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn explain_shows_row_filter() -> Result<()> {
+        let ctx = restricted_context().await?;
+        let df = ctx.sql("SELECT id FROM t WHERE id > 1").await?;
+        let plan = df
+            .clone()
+            .into_optimized_plan()?
+            .display_indent()
+            .to_string();
+        assert!(
+            plan.contains(
+                r#"TableScan: t projection=[id], full_filters=[tenant = Utf8("a")]"#
+            ),
+            "{plan}"
+        );
+        assert!(plan.contains("Filter: t.id > Int32(1)"), "{plan}");
+
+        // the filters of the query only see the rows matching the row filter
+        let plan = df.create_physical_plan().await?;
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        let filter = plan.find("FilterExec: id@0 > 1").unwrap();
+        let row_filter = plan
+            .find("RowFilterExec: table=datafusion.public.t, predicate=tenant@1 = a")
+            .unwrap();
+        assert!(filter < row_filter, "{plan}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn filters_do_not_leak_rows() -> Result<()> {
+        let ctx = restricted_context().await?;
+        let provider = ctx.deregister_table("t")?.unwrap();
+        ctx.register_table("t", Arc::new(FilteringTable(provider)))?;
+
+        // dividing by zero fails for the row of tenant "b" only, which must
+        // be dropped before the filters of the query see it
+        let sql = "SELECT id FROM t WHERE 10 / (id - 2) > -100 ORDER BY id";
+        let expected = ["+----+", "| id |", "+----+", "| 1  |", "| 3  |", "+----+"];
+        let results = ctx.sql(sql).await?.collect().await?;
+        assert_batches_eq!(expected, &results);
+
+        let results = ctx
+            .table("t")
+            .await?
+            .filter((lit(10) / (col("id") - lit(2))).gt(lit(-100)))?
+            .count()
+            .await?;
+        assert_eq!(results, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn row_filter_policy() -> Result<()> {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let policy = {
+            let seen = Arc::clone(&seen);
+            move |table: &TableReference| {
+                seen.lock().unwrap().push(table.to_string());
+                (table.table() == "t").then(|| col("tenant").eq(lit("b")))
+            }
+        };
+        let ctx = SessionContext::new().with_row_filter_policy(Arc::new(policy));
+        create_tables(&ctx).await?;
+        seen.lock().unwrap().clear();
+
+        let expected = ["+------+", "| name |", "+------+", "| two  |", "+------+"];
+        let results = ctx
+            .sql("SELECT name FROM names JOIN t ON names.id = t.id")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(expected, &results);
+
+        let mut seen_tables = seen.lock().unwrap().clone();
+        seen_tables.sort();
+        seen_tables.dedup();
+        assert_eq!(
+            seen_tables,
+            vec!["datafusion.public.names", "datafusion.public.t"]
+        );
+
+        let results = ctx.table("t").await?.count().await?;
+        assert_eq!(results, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn row_filter_policy_cannot_be_bypassed() -> Result<()> {
+        // the views created before the policy scan the restricted table too
+        let ctx = SessionContext::new();
+        create_tables(&ctx).await?;
+        ctx.sql("CREATE VIEW v AS SELECT id, tenant FROM t").await?;
+        let policy = |table: &TableReference| {
+            (table.table() == "t").then(|| col("tenant").eq(lit("a")))
+        };
+        let ctx = ctx.with_row_filter_policy(Arc::new(policy));
+        assert_eq!(count(&ctx, "SELECT * FROM v").await?, 2);
+        assert_eq!(ctx.table("v").await?.count().await?, 2);
+
+        // the catalog returns the tables as they are registered
+        let provider = ctx.table_provider("t").await?;
+        assert!(provider.as_any().is::<MemTable>());
+
+        // tables created from the table only get the rows matching the filter
+        ctx.sql("CREATE TABLE t2 AS SELECT * FROM t").await?;
+        assert_eq!(count(&ctx, "SELECT * FROM t2").await?, 2);
+        assert_eq!(
+            count(&ctx, "SELECT * FROM names WHERE id IN (SELECT id FROM t)").await?,
+            2
+        );
+
+        // a new policy replaces the previous one
+        let policy = |table: &TableReference| {
+            (table.table() == "t").then(|| col("tenant").eq(lit("b")))
+        };
+        let ctx = ctx.with_row_filter_policy(Arc::new(policy));
+        assert_eq!(count(&ctx, "SELECT * FROM t").await?, 1);
+        assert_eq!(count(&ctx, "SELECT * FROM v").await?, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn row_filter_policy_on_read_batch() -> Result<()> {
+        let policy = |table: &TableReference| {
+            (table.table() == UNNAMED_TABLE).then(|| col("tenant").eq(lit("a")))
+        };
+        let ctx = SessionContext::new().with_row_filter_policy(Arc::new(policy));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("tenant", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec!["a", "b", "a"])),
+            ],
+        )?;
+        assert_eq!(ctx.read_batch(batch.clone())?.count().await?, 2);
+        assert_eq!(ctx.read_batches(vec![batch.clone()])?.count().await?, 2);
+        let provider = MemTable::try_new(schema, vec![vec![batch]])?;
+        assert_eq!(ctx.read_table(Arc::new(provider))?.count().await?, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn insert_must_match_row_filter() -> Result<()> {
+        let ctx = restricted_context().await?;
+        ctx.sql("INSERT INTO t VALUES (4, 'a')")
+            .await?
+            .collect()
+            .await?;
+        assert_eq!(count(&ctx, "SELECT * FROM t").await?, 3);
+
+        let err = ctx
+            .sql("INSERT INTO t VALUES (5, 'a'), (6, 'b')")
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "Inserted rows do not match the row filter of table datafusion.public.t"
+            ),
+            "{err}"
+        );
+        assert_eq!(count(&ctx, "SELECT * FROM t").await?, 3);
+        Ok(())
+    }
+}
@@ -36,6 +36,10 @@ use super::listing::PartitionedFile;
 /// `limit` number of rows. `collect_stats` is passed down from the configuration parameter on
 /// `ListingTable`. If it is false we only construct bare statistics and skip a potentially expensive
 ///  call to `multiunzip` for constructing file level summary statistics.
+///
+/// When the limit leaves some files out, the returned statistics are marked
+/// [`Precision::Inexact`]: they only describe the files that were included,
+/// not the whole dataset.
 pub async fn get_statistics_with_limit(
     all_files: impl Stream<Item = Result<(PartitionedFile, Arc<Statistics>)>>,
     file_schema: SchemaRef,
@@ -274,3 +278,69 @@ fn set_min_if_lesser(
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_schema::Field;
+    use futures::stream;
+
+    fn file_with_rows(
+        name: &str,
+        num_rows: usize,
+    ) -> Result<(PartitionedFile, Arc<Statistics>)> {
+        let statistics = Statistics {
+            num_rows: Precision::Exact(num_rows),
+            total_byte_size: Precision::Exact(num_rows * 8),
+            column_statistics: vec![ColumnStatistics {
+                null_count: Precision::Exact(0),
+                ..ColumnStatistics::new_unknown()
+            }],
+        };
+        Ok((PartitionedFile::new(name, 1024), Arc::new(statistics)))
+    }
+
+    fn three_files() -> impl Stream<Item = Result<(PartitionedFile, Arc<Statistics>)>> {
+        stream::iter(vec![
+            file_with_rows("a.parquet", 10),
+            file_with_rows("b.parquet", 10),
+            file_with_rows("c.parquet", 10),
+        ])
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new("c", DataType::Int64, true)]))
+    }
+
+    #[tokio::test]
+    async fn statistics_without_limit_are_exact() -> Result<()> {
+        let (files, statistics) =
+            get_statistics_with_limit(three_files(), schema(), None, true).await?;
+        assert_eq!(files.len(), 3);
+        assert_eq!(statistics.num_rows, Precision::Exact(30));
+        assert_eq!(statistics.total_byte_size, Precision::Exact(240));
+        assert_eq!(
+            statistics.column_statistics[0].null_count,
+            Precision::Exact(0)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn statistics_with_limit_are_inexact() -> Result<()> {
+        let (files, statistics) =
+            get_statistics_with_limit(three_files(), schema(), Some(15), true).await?;
+        // the second file already covers the limit, the third is left out
+        assert_eq!(files.len(), 2);
+        assert_eq!(statistics.num_rows, Precision::Inexact(20));
+        assert_eq!(statistics.total_byte_size, Precision::Inexact(160));
+
+        // a limit covering all files keeps the statistics exact
+        let (files, statistics) =
+            get_statistics_with_limit(three_files(), schema(), Some(30), true).await?;
+        assert_eq!(files.len(), 3);
+        assert_eq!(statistics.num_rows, Precision::Exact(30));
+        Ok(())
+    }
+}
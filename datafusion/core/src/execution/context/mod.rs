@@ -24,7 +24,8 @@ use std::sync::{Arc, Weak};
 use super::options::ReadOptions;
 use crate::{
     catalog::{
-        CatalogProvider, CatalogProviderList, TableProvider, TableProviderFactory,
+        CatalogProvider, CatalogProviderList, SchemaProvider, TableProvider,
+        TableProviderFactory,
     },
     catalog_common::listing_schema::ListingSchemaProvider,
     catalog_common::memory::MemorySchemaProvider,
@@ -33,6 +34,7 @@ use crate::{
     datasource::{
         function::{TableFunction, TableFunctionImpl},
         listing::{ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl},
+        row_filter::{RowFilterPolicy, RowFilterTable},
     },
     datasource::{provider_as_source, MemTable, ViewTable},
     error::{DataFusionError, Result},
@@ -54,7 +56,7 @@ use arrow::record_batch::RecordBatch;
 use arrow_schema::Schema;
 use datafusion_common::{
     config::{ConfigExtension, TableOptions},
-    exec_err, not_impl_err, plan_datafusion_err, plan_err,
+    exec_err, internal_err, not_impl_err, plan_datafusion_err, plan_err,
     tree_node::{TreeNodeRecursion, TreeNodeVisitor},
     DFSchema, SchemaReference, TableReference,
 };
@@ -71,6 +73,7 @@ pub use crate::execution::session_state::SessionState;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use itertools::Itertools;
 use object_store::ObjectStore;
 use parking_lot::RwLock;
 use url::Url;
@@ -80,6 +83,9 @@ pub use datafusion_execution::config::SessionConfig;
 pub use datafusion_execution::TaskContext;
 pub use datafusion_expr::execution_props::ExecutionProps;
 use datafusion_optimizer::{AnalyzerRule, OptimizerRule};
+use datafusion_sql::parser::Statement as DFStatement;
+use datafusion_sql::planner::IdentNormalizer;
+use sqlparser::ast::{SchemaName, Statement as SQLStatement};
 
 mod avro;
 mod csv;
@@ -90,6 +96,9 @@ mod parquet;
 /// DataFilePaths adds a method to convert strings and vector of strings to vector of [`ListingTableUrl`] URLs.
 /// This allows methods such [`SessionContext::read_csv`] and [`SessionContext::read_avro`]
 /// to take either a single file or multiple files.
+///
+/// Braces in the paths are kept as is. Wrap a path in [`ExpandBraces`] to
+/// read every alternative of its `{a,b}` groups.
 pub trait DataFilePaths {
     /// Parse to a vector of [`ListingTableUrl`] URLs.
     fn to_urls(self) -> Result<Vec<ListingTableUrl>>;
@@ -124,6 +133,23 @@ where
     }
 }
 
+/// A path whose brace alternatives are expanded into one path each, see
+/// [`ListingTableUrl::parse_expanded`].
+///
+/// For example, `ExpandBraces("data/{2021,2022}/*.parquet")` reads the files
+/// matching `data/2021/*.parquet` and `data/2022/*.parquet`.
+#[derive(Debug, Clone)]
+pub struct ExpandBraces<P>(pub P);
+
+impl<P> DataFilePaths for ExpandBraces<P>
+where
+    P: AsRef<str>,
+{
+    fn to_urls(self) -> Result<Vec<ListingTableUrl>> {
+        ListingTableUrl::parse_expanded(self.0)
+    }
+}
+
 /// Main interface for executing queries with DataFusion. Maintains
 /// the state of the connection between a user and an instance of the
 /// DataFusion engine.
@@ -375,6 +401,13 @@ impl SessionContext {
         self
     }
 
+    /// Registers a [`RowFilterPolicy`] restricting the rows of the tables
+    /// queried by the session, see [`RowFilterPolicy`]
+    pub fn with_row_filter_policy(self, policy: Arc<dyn RowFilterPolicy>) -> Self {
+        self.state.write().set_row_filter_policy(policy);
+        self
+    }
+
     /// Adds an optimizer rule to the end of the existing rules.
     ///
     /// See [`SessionState`] for more control of when the rule is applied.
@@ -537,6 +570,232 @@ impl SessionContext {
         self.execute_logical_plan(plan).await
     }
 
+    /// Runs a SQL script made of several statements separated by
+    /// semicolons, in order, and returns the results of each statement.
+    ///
+    /// If a statement fails, the tables, views, schemas, catalogs and
+    /// functions created, replaced or dropped by the previous statements of
+    /// the script are restored, and the returned error names the failing
+    /// statement and its location in the script. Restoring is best effort:
+    /// every change is undone even if undoing another one fails, and these
+    /// failures are reported in the returned error. Other changes, such as
+    /// `SET` or `INSERT INTO`, are not rolled back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let results = ctx
+    ///     .sql_script(
+    ///         "CREATE TABLE t (x INT) AS VALUES (1), (2);
+    ///          SELECT sum(x) FROM t;",
+    ///     )
+    ///     .await?;
+    /// assert_eq!(results.len(), 2);
+    /// assert!(ctx.table_exist("t")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sql_script(&self, sql: &str) -> Result<Vec<Vec<RecordBatch>>> {
+        let (statements, spans) = {
+            let state = self.state.read();
+            let dialect = state.config().options().sql_parser.dialect.as_str();
+            (
+                state.sql_to_statements(sql, dialect)?,
+                state.sql_statement_spans(sql, dialect)?,
+            )
+        };
+        if statements.len() != spans.len() {
+            return internal_err!(
+                "Found {} spans for {} statements of SQL script",
+                spans.len(),
+                statements.len()
+            );
+        }
+
+        // catalog changes made by the script, to undo if a statement fails
+        let mut changes = vec![];
+        let mut results = Vec::with_capacity(statements.len());
+        for (idx, (statement, (start, end))) in
+            statements.into_iter().zip(spans).enumerate()
+        {
+            let result = async {
+                let created_schema = created_schema(&statement);
+                let plan = self.state().statement_to_plan(statement).await?;
+                if let Some(change) =
+                    self.script_catalog_change(&plan, created_schema).await
+                {
+                    changes.push(change);
+                }
+                self.execute_logical_plan(plan).await?.collect().await
+            }
+            .await;
+
+            match result {
+                Ok(batches) => results.push(batches),
+                Err(e) => {
+                    let rollback_errors = changes
+                        .into_iter()
+                        .rev()
+                        .filter_map(|change| {
+                            self.undo_script_catalog_change(change).err()
+                        })
+                        .collect::<Vec<_>>();
+                    let mut context = format!(
+                        "Failed to run statement {} of SQL script, from line {}, \
+                         column {} to line {}, column {}",
+                        idx + 1,
+                        start.line,
+                        start.column,
+                        end.line,
+                        end.column
+                    );
+                    if !rollback_errors.is_empty() {
+                        context.push_str(&format!(
+                            ". Rolling back the script failed: {}",
+                            rollback_errors.iter().join("; ")
+                        ));
+                    }
+                    return Err(e.context(context));
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    /// Return the catalog change that running `plan` makes in a SQL script,
+    /// with what is needed to undo it, see [`Self::sql_script`]
+    ///
+    /// `created_schema` is the schema that the statement of `plan` creates,
+    /// see [`created_schema`]
+    async fn script_catalog_change(
+        &self,
+        plan: &LogicalPlan,
+        created_schema: Option<SchemaReference>,
+    ) -> Option<ScriptCatalogChange> {
+        let LogicalPlan::Ddl(ddl) = plan else {
+            return None;
+        };
+        let table = match ddl {
+            DdlStatement::CreateExternalTable(cmd) => cmd.name.clone(),
+            DdlStatement::CreateMemoryTable(cmd) => cmd.name.clone(),
+            DdlStatement::CreateView(cmd) => cmd.name.clone(),
+            DdlStatement::DropTable(cmd) => cmd.name.clone(),
+            DdlStatement::DropView(cmd) => cmd.name.clone(),
+            DdlStatement::CreateCatalogSchema(_) => {
+                return created_schema.map(|schema| self.script_schema_change(&schema));
+            }
+            DdlStatement::DropCatalogSchema(cmd) => {
+                return Some(self.script_schema_change(&cmd.name));
+            }
+            DdlStatement::CreateCatalog(cmd) => {
+                // an existing catalog is left unchanged
+                return self
+                    .catalog(&cmd.catalog_name)
+                    .is_none()
+                    .then(|| ScriptCatalogChange::Catalog(cmd.catalog_name.clone()));
+            }
+            DdlStatement::CreateFunction(CreateFunction { name, .. })
+            | DdlStatement::DropFunction(DropFunction { name, .. }) => {
+                let state = self.state.read();
+                return Some(ScriptCatalogChange::Function {
+                    name: name.clone(),
+                    scalar: state.scalar_functions().get(name).cloned(),
+                    aggregate: state.aggregate_functions().get(name).cloned(),
+                    window: state.window_functions().get(name).cloned(),
+                    table: state.table_functions().get(name).cloned(),
+                });
+            }
+            _ => return None,
+        };
+        let previous = self.table_provider(table.clone()).await.ok();
+        Some(ScriptCatalogChange::Table(table, previous))
+    }
+
+    fn script_schema_change(&self, schema: &SchemaReference) -> ScriptCatalogChange {
+        let state = self.state.read();
+        let catalog = match schema {
+            SchemaReference::Full { catalog, .. } => catalog.to_string(),
+            SchemaReference::Bare { .. } => {
+                state.config_options().catalog.default_catalog.clone()
+            }
+        };
+        let previous = state
+            .catalog_list()
+            .catalog(&catalog)
+            .and_then(|c| c.schema(schema.schema_name()));
+        ScriptCatalogChange::Schema {
+            catalog,
+            schema: schema.schema_name().to_string(),
+            previous,
+        }
+    }
+
+    /// Undo a change made by a statement of a failed SQL script
+    fn undo_script_catalog_change(&self, change: ScriptCatalogChange) -> Result<()> {
+        match change {
+            ScriptCatalogChange::Table(table, previous) => {
+                self.deregister_table(table.clone())?;
+                if let Some(previous) = previous {
+                    self.register_table(table, previous)?;
+                }
+            }
+            ScriptCatalogChange::Schema {
+                catalog,
+                schema,
+                previous,
+            } => {
+                let Some(catalog) = self.catalog(&catalog) else {
+                    return exec_err!("Missing catalog '{catalog}'");
+                };
+                match previous {
+                    Some(previous) => {
+                        catalog.register_schema(&schema, previous)?;
+                    }
+                    None => {
+                        catalog.deregister_schema(&schema, true)?;
+                    }
+                }
+            }
+            ScriptCatalogChange::Catalog(catalog) => {
+                self.state
+                    .read()
+                    .catalog_list()
+                    .deregister_catalog(&catalog)?;
+            }
+            ScriptCatalogChange::Function {
+                name,
+                scalar,
+                aggregate,
+                window,
+                table,
+            } => {
+                let mut state = self.state.write();
+                state.deregister_udf(&name)?;
+                if let Some(f) = scalar {
+                    state.register_udf(f)?;
+                }
+                state.deregister_udaf(&name)?;
+                if let Some(f) = aggregate {
+                    state.register_udaf(f)?;
+                }
+                state.deregister_udwf(&name)?;
+                if let Some(f) = window {
+                    state.register_udwf(f)?;
+                }
+                state.deregister_udtf(&name)?;
+                if let Some(f) = table {
+                    state.register_udtf(&name, Arc::clone(f.function()));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Creates logical expressions from SQL query text.
     ///
     /// # Example: Parsing SQL queries
@@ -663,7 +922,7 @@ impl SessionContext {
         cmd: &CreateExternalTable,
     ) -> Result<DataFrame> {
         let exist = self.table_exist(cmd.name.clone())?;
-        if exist {
+        if exist && !cmd.or_replace {
             match cmd.if_not_exists {
                 true => return self.return_empty_dataframe(),
                 false => {
@@ -672,8 +931,13 @@ impl SessionContext {
             }
         }
 
+        // create the new table before dropping the one it replaces, so that
+        // the existing table is kept if the new one cannot be created
         let table_provider: Arc<dyn TableProvider> =
             self.create_custom_table(cmd).await?;
+        if exist {
+            self.deregister_table(cmd.name.clone())?;
+        }
         self.register_table(cmd.name.clone(), table_provider)?;
         self.return_empty_dataframe()
     }
@@ -1077,7 +1341,7 @@ impl SessionContext {
     async fn _read_type<'a, P: DataFilePaths>(
         &self,
         table_paths: P,
-        options: impl ReadOptions<'a>,
+        options: impl ReadOptions<'a> + Sync,
     ) -> Result<DataFrame> {
         let table_paths = table_paths.to_urls()?;
         let session_config = self.copied_config();
@@ -1103,7 +1367,7 @@ impl SessionContext {
         }
 
         let resolved_schema = options
-            .get_resolved_schema(&session_config, self.state(), table_paths[0].clone())
+            .get_resolved_schema_for_paths(&session_config, self.state(), &table_paths)
             .await?;
         let config = ListingTableConfig::new_with_multi_paths(table_paths)
             .with_listing_options(listing_options)
@@ -1136,6 +1400,9 @@ impl SessionContext {
 
     /// Creates a [`DataFrame`] for a [`TableProvider`] such as a
     /// [`ListingTable`] or a custom user defined provider.
+    ///
+    /// The rows of `provider` are restricted as those of [`UNNAMED_TABLE`] by
+    /// the [`RowFilterPolicy`] of the session, if any.
     pub fn read_table(&self, provider: Arc<dyn TableProvider>) -> Result<DataFrame> {
         Ok(DataFrame::new(
             self.state(),
@@ -1147,15 +1414,7 @@ impl SessionContext {
     /// Creates a [`DataFrame`] for reading a [`RecordBatch`]
     pub fn read_batch(&self, batch: RecordBatch) -> Result<DataFrame> {
         let provider = MemTable::try_new(batch.schema(), vec![vec![batch]])?;
-        Ok(DataFrame::new(
-            self.state(),
-            LogicalPlanBuilder::scan(
-                UNNAMED_TABLE,
-                provider_as_source(Arc::new(provider)),
-                None,
-            )?
-            .build()?,
-        ))
+        self.read_table(Arc::new(provider))
     }
     /// Create a [`DataFrame`] for reading a [`Vec[`RecordBatch`]`]
     pub fn read_batches(
@@ -1170,15 +1429,7 @@ impl SessionContext {
             Arc::new(Schema::empty())
         };
         let provider = MemTable::try_new(schema, vec![batches.collect()])?;
-        Ok(DataFrame::new(
-            self.state(),
-            LogicalPlanBuilder::scan(
-                UNNAMED_TABLE,
-                provider_as_source(Arc::new(provider)),
-                None,
-            )?
-            .build()?,
-        ))
+        self.read_table(Arc::new(provider))
     }
     /// Registers a [`ListingTable`] that can assemble multiple files
     /// from locations in an [`ObjectStore`] instance into a single
@@ -1279,6 +1530,22 @@ impl SessionContext {
             .register_table(table, provider)
     }
 
+    /// Registers a [`TableProvider`] as a table whose queries only see the
+    /// rows matching `filter`, see [`RowFilterTable`].
+    ///
+    /// Returns the [`TableProvider`] previously registered for this
+    /// reference, if any
+    pub fn register_table_with_row_filter(
+        &self,
+        table_ref: impl Into<TableReference>,
+        provider: Arc<dyn TableProvider>,
+        filter: Expr,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        let table_ref: TableReference = table_ref.into();
+        let provider = RowFilterTable::try_new(table_ref.clone(), provider, filter)?;
+        self.register_table(table_ref, Arc::new(provider))
+    }
+
     /// Deregisters the given table.
     ///
     /// Returns the registered provider, if any
@@ -1454,6 +1721,59 @@ impl FunctionRegistry for SessionContext {
     }
 }
 
+/// A catalog change made by a statement of a SQL script, with the previous
+/// state of what it changed, see [`SessionContext::sql_script`]
+enum ScriptCatalogChange {
+    /// A table or view was created, replaced or dropped
+    Table(TableReference, Option<Arc<dyn TableProvider>>),
+    /// A schema was created or dropped
+    Schema {
+        catalog: String,
+        schema: String,
+        previous: Option<Arc<dyn SchemaProvider>>,
+    },
+    /// A catalog was created
+    Catalog(String),
+    /// A function was created or dropped
+    Function {
+        name: String,
+        scalar: Option<Arc<ScalarUDF>>,
+        aggregate: Option<Arc<AggregateUDF>>,
+        window: Option<Arc<WindowUDF>>,
+        table: Option<Arc<TableFunction>>,
+    },
+}
+
+/// The schema that `statement` creates if it is a `CREATE SCHEMA`, resolved
+/// from its parsed name so that quoted identifiers containing dots are kept
+/// whole
+fn created_schema(statement: &DFStatement) -> Option<SchemaReference> {
+    let DFStatement::Statement(statement) = statement else {
+        return None;
+    };
+    let SQLStatement::CreateSchema { schema_name, .. } = statement.as_ref() else {
+        return None;
+    };
+    // like the SQL planner, which always normalizes schema names
+    let normalizer = IdentNormalizer::new(true);
+    match schema_name {
+        SchemaName::Simple(name) => match &name.0[..] {
+            [schema] => Some(SchemaReference::Bare {
+                schema: normalizer.normalize(schema.clone()).into(),
+            }),
+            [catalog, schema] => Some(SchemaReference::Full {
+                schema: normalizer.normalize(schema.clone()).into(),
+                catalog: normalizer.normalize(catalog.clone()).into(),
+            }),
+            _ => None,
+        },
+        SchemaName::UnnamedAuthorization(schema) => Some(SchemaReference::Bare {
+            schema: normalizer.normalize(schema.clone()).into(),
+        }),
+        SchemaName::NamedAuthorization(..) => None,
+    }
+}
+
 /// Create a new task context instance from SessionContext
 impl From<&SessionContext> for TaskContext {
     fn from(session: &SessionContext) -> Self {
@@ -1617,6 +1937,7 @@ impl<'n, 'a> TreeNodeVisitor<'n> for BadPlanVisitor<'a> {
 
 #[cfg(test)]
 mod tests {
+    use std::any::Any;
     use std::env;
     use std::path::PathBuf;
 
@@ -1628,6 +1949,7 @@ mod tests {
     use crate::test_util::{plan_and_collect, populate_csv_partitions};
 
     use datafusion_common_runtime::SpawnedTask;
+    use datafusion_sql::parser::DFParser;
 
     use crate::catalog::SchemaProvider;
     use crate::execution::session_state::SessionStateBuilder;
@@ -1670,6 +1992,155 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn sql_script() -> Result<()> {
+        let ctx = SessionContext::new();
+        let results = ctx
+            .sql_script(
+                "CREATE TABLE t (x INT, s VARCHAR) AS VALUES (1, 'a;b'), (2, 'c');
+                 CREATE VIEW v AS SELECT x FROM t WHERE s = 'a;b';
+                 SELECT x, s FROM t ORDER BY x;
+                 SELECT * FROM v",
+            )
+            .await?;
+        assert_eq!(results.len(), 4);
+
+        let expected = [
+            "+---+-----+",
+            "| x | s   |",
+            "+---+-----+",
+            "| 1 | a;b |",
+            "| 2 | c   |",
+            "+---+-----+",
+        ];
+        assert_batches_eq!(expected, &results[2]);
+        let expected = ["+---+", "| x |", "+---+", "| 1 |", "+---+"];
+        assert_batches_eq!(expected, &results[3]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sql_script_rollback() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql_script(
+            "CREATE TABLE existing (x INT) AS VALUES (1);
+             CREATE SCHEMA existing_schema;
+             CREATE TABLE existing_schema.t (x INT) AS VALUES (2)",
+        )
+        .await?;
+
+        let err = ctx
+            .sql_script(
+                "CREATE TABLE t1 (x INT);\n\
+                 CREATE SCHEMA s1;\n\
+                 DROP SCHEMA existing_schema CASCADE;\n\
+                 CREATE DATABASE db;\n\
+                 DROP FUNCTION abs;\n\
+                 DROP TABLE existing;\n\
+                 SELECT * FROM missing;\n\
+                 CREATE TABLE t2 (x INT)",
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().starts_with(
+                "Failed to run statement 7 of SQL script, \
+                 from line 7, column 1 to line 7, column 22\ncaused by"
+            ),
+            "{err}"
+        );
+
+        // changes made by the first six statements were rolled back
+        assert!(!ctx.table_exist("t1")?);
+        assert!(!ctx.table_exist("t2")?);
+        assert!(ctx.catalog("datafusion").unwrap().schema("s1").is_none());
+        assert!(ctx.catalog("db").is_none());
+        let results = ctx
+            .sql("SELECT abs(e.x - t.x) AS x FROM existing e, existing_schema.t t")
+            .await?
+            .collect()
+            .await?;
+        let expected = ["+---+", "| x |", "+---+", "| 1 |", "+---+"];
+        assert_batches_eq!(expected, &results);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sql_script_rollback_is_best_effort() -> Result<()> {
+        /// A catalog whose schemas can not be dropped
+        struct NoDropCatalog(MemoryCatalogProvider);
+
+        impl CatalogProvider for NoDropCatalog {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn schema_names(&self) -> Vec<String> {
+                self.0.schema_names()
+            }
+
+            fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
+                self.0.schema(name)
+            }
+
+            fn register_schema(
+                &self,
+                name: &str,
+                schema: Arc<dyn SchemaProvider>,
+            ) -> Result<Option<Arc<dyn SchemaProvider>>> {
+                self.0.register_schema(name, schema)
+            }
+        }
+
+        let ctx = SessionContext::new();
+        ctx.register_catalog(
+            "no_drop",
+            Arc::new(NoDropCatalog(MemoryCatalogProvider::new())),
+        );
+        let err = ctx
+            .sql_script(
+                "CREATE TABLE t1 (x INT);
+                 CREATE SCHEMA no_drop.s;
+                 SELECT * FROM missing",
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "Rolling back the script failed: This feature is not implemented: \
+                 Deregistering new schemas is not supported"
+            ),
+            "{err}"
+        );
+
+        // the other changes were rolled back
+        assert!(!ctx.table_exist("t1")?);
+        Ok(())
+    }
+
+    #[test]
+    fn created_schema_from_parsed_name() -> Result<()> {
+        let created = |sql: &str| -> Result<Option<SchemaReference>> {
+            let statement = DFParser::parse_sql(sql)?.pop_front().unwrap();
+            Ok(created_schema(&statement))
+        };
+        assert_eq!(
+            created(r#"CREATE SCHEMA "a.b""#)?,
+            Some(SchemaReference::Bare {
+                schema: "a.b".into()
+            })
+        );
+        assert_eq!(
+            created(r#"CREATE SCHEMA Cat."S""#)?,
+            Some(SchemaReference::Full {
+                schema: "S".into(),
+                catalog: "cat".into()
+            })
+        );
+        assert_eq!(created("CREATE TABLE t (x INT)")?, None);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn create_variable_expr() -> Result<()> {
         let tmp_dir = TempDir::new()?;
@@ -78,6 +78,7 @@ mod tests {
     use crate::arrow::datatypes::{DataType, Field, Schema};
     use crate::arrow::record_batch::RecordBatch;
     use crate::dataframe::DataFrameWriteOptions;
+    use crate::execution::context::ExpandBraces;
     use crate::parquet::basic::Compression;
     use crate::test_util::parquet_test_data;
 
@@ -330,4 +331,107 @@ mod tests {
         assert_eq!(total_rows, 5);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn read_from_multiple_directories() -> Result<()> {
+        // list the files of the subdirectories of the root
+        let config = SessionConfig::new().set_bool(
+            "datafusion.execution.listing_table_ignore_subdirectory",
+            false,
+        );
+        let ctx = SessionContext::new_with_config(config);
+        let temp_dir = tempdir()?;
+        let root = temp_dir.path().to_str().unwrap().to_string();
+
+        let write = |dir: &str, values: Vec<i32>| {
+            let batch = RecordBatch::try_new(
+                Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)])),
+                vec![Arc::new(Int32Array::from(values))],
+            );
+            let path = format!("{root}/{dir}/data.parquet");
+            let ctx = ctx.clone();
+            async move {
+                ctx.read_batch(batch?)?
+                    .write_parquet(
+                        &path,
+                        DataFrameWriteOptions::new().with_single_file_output(true),
+                        None,
+                    )
+                    .await
+            }
+        };
+        write("2021", vec![1, 2]).await?;
+        write("2022", vec![3]).await?;
+        write("2023", vec![4, 5, 6]).await?;
+
+        let count = |df: DataFrame| async move {
+            let batches = df.collect().await?;
+            Ok::<_, crate::error::DataFusionError>(
+                batches.iter().map(|b| b.num_rows()).sum::<usize>(),
+            )
+        };
+
+        // two directories scanned into one plan
+        let df = ctx
+            .read_parquet(
+                vec![format!("{root}/2021/"), format!("{root}/2022/")],
+                ParquetReadOptions::default(),
+            )
+            .await?;
+        assert_eq!(count(df).await?, 3);
+
+        // brace alternatives, if asked for
+        let df = ctx
+            .read_parquet(
+                ExpandBraces(format!("{root}/{{2021,2023}}/*.parquet")),
+                ParquetReadOptions::default(),
+            )
+            .await?;
+        assert_eq!(count(df).await?, 5);
+
+        // files matched by several paths are only read once
+        let df = ctx
+            .read_parquet(
+                vec![format!("{root}/"), format!("{root}/2022/")],
+                ParquetReadOptions::default(),
+            )
+            .await?;
+        assert_eq!(count(df).await?, 6);
+
+        // braces are literal unless expanded
+        write("{2021,2023}", vec![7]).await?;
+        let df = ctx
+            .read_parquet(
+                format!("{root}/{{2021,2023}}/*.parquet"),
+                ParquetReadOptions::default(),
+            )
+            .await?;
+        assert_eq!(count(df).await?, 1);
+
+        // the schemas of all the paths must be compatible
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Float32, false)])),
+            vec![Arc::new(Float32Array::from(vec![1.0]))],
+        )?;
+        ctx.read_batch(batch)?
+            .write_parquet(
+                &format!("{root}/other/data.parquet"),
+                DataFrameWriteOptions::new().with_single_file_output(true),
+                None,
+            )
+            .await?;
+        let err = ctx
+            .read_parquet(
+                vec![format!("{root}/2021/"), format!("{root}/other/")],
+                ParquetReadOptions::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            err.strip_backtrace()
+                .contains("Fail to merge schema field 'a' because the from data_type = Float32 does not equal Int32"),
+            "{err}"
+        );
+        Ok(())
+    }
 }
@@ -17,7 +17,9 @@
 
 //! [`SessionState`]: information required to run queries in a session
 
-use crate::catalog::{CatalogProviderList, SchemaProvider, TableProviderFactory};
+use crate::catalog::{
+    CatalogProviderList, SchemaProvider, TableProvider, TableProviderFactory,
+};
 use crate::catalog_common::information_schema::{
     InformationSchemaProvider, INFORMATION_SCHEMA,
 };
@@ -25,7 +27,8 @@ use crate::catalog_common::MemoryCatalogProviderList;
 use crate::datasource::cte_worktable::CteWorkTable;
 use crate::datasource::file_format::{format_as_file_type, FileFormatFactory};
 use crate::datasource::function::{TableFunction, TableFunctionImpl};
-use crate::datasource::provider_as_source;
+use crate::datasource::row_filter::{RowFilterPolicy, RowFilterTable};
+use crate::datasource::{provider_as_source, source_as_provider};
 use crate::execution::context::{EmptySerializerRegistry, FunctionFactory, QueryPlanner};
 use crate::execution::SessionStateDefaults;
 use crate::physical_optimizer::optimizer::PhysicalOptimizer;
@@ -38,7 +41,7 @@ use datafusion_common::alias::AliasGenerator;
 use datafusion_common::config::{ConfigExtension, ConfigOptions, TableOptions};
 use datafusion_common::display::{PlanType, StringifiedPlan, ToStringifiedPlan};
 use datafusion_common::file_options::file_type::FileType;
-use datafusion_common::tree_node::TreeNode;
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
 use datafusion_common::{
     config_err, not_impl_err, plan_datafusion_err, DFSchema, DataFusionError,
     ResolvedTableReference, TableReference,
@@ -54,7 +57,7 @@ use datafusion_expr::simplify::SimplifyInfo;
 use datafusion_expr::var_provider::{is_system_variables, VarType};
 use datafusion_expr::{
     AggregateUDF, Explain, Expr, ExprSchemable, LogicalPlan, ScalarUDF, TableSource,
-    WindowUDF,
+    WindowUDF, UNNAMED_TABLE,
 };
 use datafusion_optimizer::simplify_expressions::ExprSimplifier;
 use datafusion_optimizer::{
@@ -69,10 +72,12 @@ use datafusion_sql::planner::{ContextProvider, ParserOptions, PlannerContext, Sq
 use itertools::Itertools;
 use log::{debug, info};
 use sqlparser::ast::Expr as SQLExpr;
-use sqlparser::dialect::dialect_from_str;
+use sqlparser::dialect::{dialect_from_str, Dialect};
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::{Location, Token, Tokenizer};
 use std::any::Any;
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -169,6 +174,8 @@ pub struct SessionState {
     /// It will be invoked on `CREATE FUNCTION` statements.
     /// thus, changing dialect o PostgreSql is required
     function_factory: Option<Arc<dyn FunctionFactory>>,
+    /// [`RowFilterPolicy`] restricting the rows of the tables queries may see
+    row_filter_policy: Option<Arc<dyn RowFilterPolicy>>,
 }
 
 impl Debug for SessionState {
@@ -192,6 +199,7 @@ impl Debug for SessionState {
             .field("table_factories", &"...")
             .field("runtime_env", &self.runtime_env)
             .field("function_factory", &"...")
+            .field("row_filter_policy", &"...")
             .finish_non_exhaustive()
     }
 }
@@ -466,6 +474,87 @@ impl SessionState {
         self.function_factory.as_ref()
     }
 
+    /// Registers a [`RowFilterPolicy`] restricting the rows of the tables
+    /// that plans scan, see [`RowFilterPolicy`]
+    pub fn set_row_filter_policy(&mut self, row_filter_policy: Arc<dyn RowFilterPolicy>) {
+        self.row_filter_policy = Some(row_filter_policy);
+    }
+
+    /// Get the row filter policy
+    pub fn row_filter_policy(&self) -> Option<&Arc<dyn RowFilterPolicy>> {
+        self.row_filter_policy.as_ref()
+    }
+
+    /// Wraps `table`, scanned or written as `table_ref`, in a
+    /// [`RowFilterTable`] if the [`RowFilterPolicy`] restricts its rows and
+    /// it is not already restricted by the same filter
+    pub(crate) fn apply_row_filter_policy(
+        &self,
+        table_ref: &TableReference,
+        table: Arc<dyn TableProvider>,
+    ) -> datafusion_common::Result<Arc<dyn TableProvider>> {
+        // `UNNAMED_TABLE` is the name of the providers read directly
+        let table_ref = if *table_ref == TableReference::bare(UNNAMED_TABLE) {
+            table_ref.clone()
+        } else {
+            self.resolve_table_ref(table_ref.clone()).into()
+        };
+        let Some(filter) = self
+            .row_filter_policy
+            .as_ref()
+            .and_then(|policy| policy.row_filter(&table_ref))
+        else {
+            return Ok(table);
+        };
+        let restricted =
+            table
+                .as_any()
+                .downcast_ref::<RowFilterTable>()
+                .is_some_and(|table| {
+                    table.table_ref() == &table_ref && table.filter() == &filter
+                });
+        if restricted {
+            return Ok(table);
+        }
+        Ok(Arc::new(RowFilterTable::try_new(table_ref, table, filter)?))
+    }
+
+    /// Restricts the rows of every table scanned by `plan`, including the
+    /// tables of the views it inlines, according to the [`RowFilterPolicy`],
+    /// and adds the filter of each [`RowFilterTable`] to the filters of its
+    /// scans, so that it shows in the plan
+    fn apply_row_filter_policy_to_plan(
+        &self,
+        plan: LogicalPlan,
+    ) -> datafusion_common::Result<LogicalPlan> {
+        plan.transform_up_with_subqueries(|plan| {
+            let LogicalPlan::TableScan(mut scan) = plan else {
+                return Ok(Transformed::no(plan));
+            };
+            // only the scans of providers can be planned, see `DefaultTableSource`
+            let Ok(table) = source_as_provider(&scan.source) else {
+                return Ok(Transformed::no(LogicalPlan::TableScan(scan)));
+            };
+            let restricted =
+                self.apply_row_filter_policy(&scan.table_name, Arc::clone(&table))?;
+            let Some(row_filter_table) =
+                restricted.as_any().downcast_ref::<RowFilterTable>()
+            else {
+                return Ok(Transformed::no(LogicalPlan::TableScan(scan)));
+            };
+            let scan_filter = row_filter_table.scan_filter();
+            if Arc::ptr_eq(&restricted, &table) && scan.filters.contains(&scan_filter) {
+                return Ok(Transformed::no(LogicalPlan::TableScan(scan)));
+            }
+            if !scan.filters.contains(&scan_filter) {
+                scan.filters.push(scan_filter);
+            }
+            scan.source = provider_as_source(restricted);
+            Ok(Transformed::yes(LogicalPlan::TableScan(scan)))
+        })
+        .data()
+    }
+
     /// Get the table factories
     pub fn table_factories(&self) -> &HashMap<String, Arc<dyn TableProviderFactory>> {
         &self.table_factories
@@ -487,14 +576,7 @@ impl SessionState {
         sql: &str,
         dialect: &str,
     ) -> datafusion_common::Result<Statement> {
-        let dialect = dialect_from_str(dialect).ok_or_else(|| {
-            plan_datafusion_err!(
-                "Unsupported SQL dialect: {dialect}. Available dialects: \
-                     Generic, MySQL, PostgreSQL, Hive, SQLite, Snowflake, Redshift, \
-                     MsSQL, ClickHouse, BigQuery, Ansi."
-            )
-        })?;
-        let mut statements = DFParser::parse_sql_with_dialect(sql, dialect.as_ref())?;
+        let mut statements = self.sql_to_statements(sql, dialect)?;
         if statements.len() > 1 {
             return not_impl_err!(
                 "The context currently only supports a single SQL statement"
@@ -506,6 +588,68 @@ impl SessionState {
         Ok(statement)
     }
 
+    /// Convert a SQL string containing several statements separated by
+    /// semicolons into a list of datafusion [`Statement`]s
+    pub fn sql_to_statements(
+        &self,
+        sql: &str,
+        dialect: &str,
+    ) -> datafusion_common::Result<VecDeque<Statement>> {
+        let dialect = parse_dialect(dialect)?;
+        Ok(DFParser::parse_sql_with_dialect(sql, dialect.as_ref())?)
+    }
+
+    /// Returns the span of each statement of a SQL string containing several
+    /// statements separated by semicolons, in the order returned by
+    /// [`Self::sql_to_statements`].
+    ///
+    /// A span is the location of the first token of the statement and the
+    /// location right after its last token.
+    pub fn sql_statement_spans(
+        &self,
+        sql: &str,
+        dialect: &str,
+    ) -> datafusion_common::Result<Vec<(Location, Location)>> {
+        let dialect = parse_dialect(dialect)?;
+        let tokens = Tokenizer::new(dialect.as_ref(), sql)
+            .tokenize_with_location()
+            .map_err(ParserError::from)?;
+
+        let end_of_input = Location {
+            line: sql.matches('\n').count() as u64 + 1,
+            column: sql.rsplit('\n').next().unwrap_or_default().chars().count() as u64
+                + 1,
+        };
+        // a token ends where the next one starts
+        let end_of_token = |idx: usize| {
+            tokens
+                .get(idx + 1)
+                .map_or(end_of_input, |token| token.location)
+        };
+
+        // like the parser, skip empty statements
+        let mut spans = vec![];
+        let mut current: Option<(Location, usize)> = None;
+        for (idx, token) in tokens.iter().enumerate() {
+            match token.token {
+                Token::Whitespace(_) => {}
+                Token::SemiColon => {
+                    if let Some((start, last)) = current.take() {
+                        spans.push((start, end_of_token(last)));
+                    }
+                }
+                _ => {
+                    let start = current.map_or(token.location, |(start, _)| start);
+                    current = Some((start, idx));
+                }
+            }
+        }
+        if let Some((start, last)) = current {
+            spans.push((start, end_of_token(last)));
+        }
+        Ok(spans)
+    }
+
     /// parse a sql string into a sqlparser-rs AST [`SQLExpr`].
     ///
     /// See [`Self::create_logical_expr`] for parsing sql to [`Expr`].
@@ -514,13 +658,7 @@ impl SessionState {
         sql: &str,
         dialect: &str,
     ) -> datafusion_common::Result<SQLExpr> {
-        let dialect = dialect_from_str(dialect).ok_or_else(|| {
-            plan_datafusion_err!(
-                "Unsupported SQL dialect: {dialect}. Available dialects: \
-                     Generic, MySQL, PostgreSQL, Hive, SQLite, Snowflake, Redshift, \
-                     MsSQL, ClickHouse, BigQuery, Ansi."
-            )
-        })?;
+        let dialect = parse_dialect(dialect)?;
 
         let expr = DFParser::parse_sql_into_expr_with_dialect(sql, dialect.as_ref())?;
 
@@ -657,6 +795,9 @@ impl SessionState {
                     stringified_plans.push(analyzed_plan.to_stringified(plan_type));
                 },
             );
+            // once the views are inlined, the rows of the tables are restricted
+            let analyzer_result = analyzer_result
+                .and_then(|plan| self.apply_row_filter_policy_to_plan(plan));
             let analyzed_plan = match analyzer_result {
                 Ok(plan) => plan,
                 Err(DataFusionError::Context(analyzer_name, err)) => {
@@ -713,6 +854,8 @@ impl SessionState {
                 self.options(),
                 |_, _| {},
             )?;
+            // once the views are inlined, the rows of the tables are restricted
+            let analyzed_plan = self.apply_row_filter_policy_to_plan(analyzed_plan)?;
             self.optimizer.optimize(analyzed_plan, self, |_, _| {})
         }
     }
@@ -949,8 +1092,10 @@ pub struct SessionStateBuilder {
     table_factories: Option<HashMap<String, Arc<dyn TableProviderFactory>>>,
     runtime_env: Option<Arc<RuntimeEnv>>,
     function_factory: Option<Arc<dyn FunctionFactory>>,
+    row_filter_policy: Option<Arc<dyn RowFilterPolicy>>,
     // fields to support convenience functions
     analyzer_rules: Option<Vec<Arc<dyn AnalyzerRule + Send + Sync>>>,
+    function_rewrites: Option<Vec<Arc<dyn FunctionRewrite + Send + Sync>>>,
     optimizer_rules: Option<Vec<Arc<dyn OptimizerRule + Send + Sync>>>,
     physical_optimizer_rules: Option<Vec<Arc<dyn PhysicalOptimizerRule + Send + Sync>>>,
 }
@@ -978,8 +1123,10 @@ impl SessionStateBuilder {
             table_factories: None,
             runtime_env: None,
             function_factory: None,
+            row_filter_policy: None,
             // fields to support convenience functions
             analyzer_rules: None,
+            function_rewrites: None,
             optimizer_rules: None,
             physical_optimizer_rules: None,
         }
@@ -1027,9 +1174,11 @@ impl SessionStateBuilder {
             table_factories: Some(existing.table_factories),
             runtime_env: Some(existing.runtime_env),
             function_factory: existing.function_factory,
+            row_filter_policy: existing.row_filter_policy,
 
             // fields to support convenience functions
             analyzer_rules: None,
+            function_rewrites: None,
             optimizer_rules: None,
             physical_optimizer_rules: None,
         }
@@ -1045,6 +1194,7 @@ impl SessionStateBuilder {
         self.aggregate_functions =
             Some(SessionStateDefaults::default_aggregate_functions());
         self.window_functions = Some(SessionStateDefaults::default_window_functions());
+        self.function_rewrites = Some(SessionStateDefaults::default_function_rewrites());
         self
     }
 
@@ -1075,6 +1225,18 @@ impl SessionStateBuilder {
         self
     }
 
+    /// Add `function_rewrite` to the end of the list of [`FunctionRewrite`]s
+    /// applied by the analyzer.
+    pub fn with_function_rewrite(
+        mut self,
+        function_rewrite: Arc<dyn FunctionRewrite + Send + Sync>,
+    ) -> Self {
+        let mut rewrites = self.function_rewrites.unwrap_or_default();
+        rewrites.push(function_rewrite);
+        self.function_rewrites = Some(rewrites);
+        self
+    }
+
     /// Set the [`OptimizerRule`]s used to optimize plans.
     pub fn with_optimizer_rules(
         mut self,
@@ -1241,6 +1403,16 @@ impl SessionStateBuilder {
         self
     }
 
+    /// Set a [`RowFilterPolicy`] restricting the rows of the tables that
+    /// plans scan, see [`RowFilterPolicy`]
+    pub fn with_row_filter_policy(
+        mut self,
+        row_filter_policy: Option<Arc<dyn RowFilterPolicy>>,
+    ) -> Self {
+        self.row_filter_policy = row_filter_policy;
+        self
+    }
+
     /// Builds a [`SessionState`] with the current configuration.
     ///
     /// Note that there is an explicit option for enabling catalog and schema defaults
@@ -1267,7 +1439,9 @@ impl SessionStateBuilder {
             table_factories,
             runtime_env,
             function_factory,
+            row_filter_policy,
             analyzer_rules,
+            function_rewrites,
             optimizer_rules,
             physical_optimizer_rules,
         } = self;
@@ -1299,6 +1473,7 @@ impl SessionStateBuilder {
             table_factories: table_factories.unwrap_or_default(),
             runtime_env,
             function_factory,
+            row_filter_policy,
         };
 
         if let Some(file_formats) = file_formats {
@@ -1355,6 +1530,12 @@ impl SessionStateBuilder {
             }
         }
 
+        if let Some(function_rewrites) = function_rewrites {
+            for function_rewrite in function_rewrites {
+                state.analyzer.add_function_rewrite(function_rewrite);
+            }
+        }
+
         if let Some(optimizer_rules) = optimizer_rules {
             for optimizer_rule in optimizer_rules {
                 state.optimizer.rules.push(optimizer_rule);
@@ -1472,6 +1653,11 @@ impl SessionStateBuilder {
         &mut self.function_factory
     }
 
+    /// Returns the current row_filter_policy value
+    pub fn row_filter_policy(&mut self) -> &mut Option<Arc<dyn RowFilterPolicy>> {
+        &mut self.row_filter_policy
+    }
+
     /// Returns the current analyzer_rules value
     pub fn analyzer_rules(
         &mut self,
@@ -1479,6 +1665,13 @@ impl SessionStateBuilder {
         &mut self.analyzer_rules
     }
 
+    /// Returns the current function_rewrites value
+    pub fn function_rewrites(
+        &mut self,
+    ) -> &mut Option<Vec<Arc<dyn FunctionRewrite + Send + Sync>>> {
+        &mut self.function_rewrites
+    }
+
     /// Returns the current optimizer_rules value
     pub fn optimizer_rules(
         &mut self,
@@ -1769,6 +1962,17 @@ impl From<&SessionState> for TaskContext {
     }
 }
 
+/// Returns the SQL dialect named `dialect`
+fn parse_dialect(dialect: &str) -> datafusion_common::Result<Box<dyn Dialect>> {
+    dialect_from_str(dialect).ok_or_else(|| {
+        plan_datafusion_err!(
+            "Unsupported SQL dialect: {dialect}. Available dialects: \
+                 Generic, MySQL, PostgreSQL, Hive, SQLite, Snowflake, Redshift, \
+                 MsSQL, ClickHouse, BigQuery, Ansi."
+        )
+    })
+}
+
 /// The query planner used if no user defined planner is provided
 struct DefaultQueryPlanner {}
 
@@ -33,6 +33,7 @@ use crate::{functions, functions_aggregate, functions_window};
 use datafusion_execution::config::SessionConfig;
 use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_execution::runtime_env::RuntimeEnv;
+use datafusion_expr::expr_rewriter::FunctionRewrite;
 use datafusion_expr::planner::ExprPlanner;
 use datafusion_expr::{AggregateUDF, ScalarUDF, WindowUDF};
 use std::collections::HashMap;
@@ -107,6 +108,13 @@ impl SessionStateDefaults {
         functions
     }
 
+    /// returns the list of default [`FunctionRewrite`]s
+    pub fn default_function_rewrites() -> Vec<Arc<dyn FunctionRewrite + Send + Sync>> {
+        vec![Arc::new(
+            functions::math::decimal::DecimalFunctionRewriter::default(),
+        )]
+    }
+
     /// returns the list of default [`AggregateUDF']'s
     pub fn default_aggregate_functions() -> Vec<Arc<AggregateUDF>> {
         functions_aggregate::all_default_aggregate_functions()
@@ -1154,11 +1154,14 @@ fn ensure_distribution(
         .execution
         .use_row_number_estimates_to_optimize_partitioning;
     let is_unbounded = dist_context.plan.execution_mode().is_unbounded();
-    // Use order preserving variants either of the conditions true
+    // Use order preserving variants if any of the conditions is true
     // - it is desired according to config
     // - when plan is unbounded
-    let order_preserving_variants_desirable =
-        is_unbounded || config.optimizer.prefer_existing_sort;
+    // - the plan is a sort-merge join selected for its sorted inputs
+    let order_preserving_variants_desirable = is_unbounded
+        || config.optimizer.prefer_existing_sort
+        || (config.optimizer.prefer_sort_merge_join_for_sorted_inputs
+            && dist_context.plan.as_any().is::<SortMergeJoinExec>());
 
     // Remove unnecessary repartition from the physical plan if any
     let DistributionContext {
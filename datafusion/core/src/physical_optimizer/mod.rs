@@ -26,11 +26,14 @@ pub mod combine_partial_final_agg;
 pub mod enforce_distribution;
 pub mod enforce_sorting;
 pub mod join_selection;
+#[cfg(feature = "parquet")]
+pub mod nested_projection_pushdown;
 pub mod optimizer;
 pub mod projection_pushdown;
 pub mod pruning;
 pub mod replace_with_order_preserving_variants;
 pub mod sanity_checker;
+pub mod sort_merge_join_selection;
 #[cfg(test)]
 pub mod test_utils;
 pub mod topk_aggregation;
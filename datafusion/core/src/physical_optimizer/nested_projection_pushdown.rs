@@ -0,0 +1,195 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The [`NestedProjectionPushdown`] rule narrows the struct columns read by a
+//! Parquet scan to the nested fields that the plan above it accesses.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::ConfigOptions;
+use crate::datasource::physical_plan::ParquetExec;
+use crate::error::Result;
+use crate::physical_plan::coalesce_batches::CoalesceBatchesExec;
+use crate::physical_plan::filter::FilterExec;
+use crate::physical_plan::projection::ProjectionExec;
+use crate::physical_plan::repartition::RepartitionExec;
+use crate::physical_plan::{ExecutionPlan, Partitioning};
+
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_common::ScalarValue;
+use datafusion_physical_expr::expressions::{Column, Literal};
+use datafusion_physical_expr::{PhysicalExpr, ScalarFunctionExpr};
+use datafusion_physical_optimizer::PhysicalOptimizerRule;
+
+/// The [`NestedProjectionPushdown`] rule narrows the struct columns read by a
+/// [`ParquetExec`] to the nested fields that the plan above it accesses with
+/// `get_field`, such as `address['city']`, so that only the Parquet leaf
+/// columns of these fields are fetched and decoded.
+///
+/// The rule applies to a [`ProjectionExec`] over a [`ParquetExec`], possibly
+/// through [`FilterExec`], [`CoalesceBatchesExec`] and [`RepartitionExec`]
+/// operators, which pass the columns of the scan through unchanged. A struct
+/// column is narrowed to the fields that the expressions of these operators
+/// and the predicate of the scan access. A column that is used other than
+/// through `get_field` is read as a whole.
+///
+/// Narrowing a struct column changes its type in the output of the scan and
+/// of the operators above it up to the projection, whose output is unchanged.
+#[derive(Default)]
+pub struct NestedProjectionPushdown {}
+
+impl NestedProjectionPushdown {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PhysicalOptimizerRule for NestedProjectionPushdown {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        _config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        plan.transform_down(|plan| {
+            let Some(projection) = plan.as_any().downcast_ref::<ProjectionExec>() else {
+                return Ok(Transformed::no(plan));
+            };
+            match narrow_scan_below(projection)? {
+                Some(input) => Ok(Transformed::yes(plan.with_new_children(vec![input])?)),
+                None => Ok(Transformed::no(plan)),
+            }
+        })
+        .data()
+    }
+
+    fn name(&self) -> &str {
+        "NestedProjectionPushdown"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the input of `projection` with the struct columns of the Parquet
+/// scan below it narrowed, or `None` if there is no such scan or none of its
+/// columns can be narrowed
+fn narrow_scan_below(
+    projection: &ProjectionExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let mut exprs: Vec<Arc<dyn PhysicalExpr>> = projection
+        .expr()
+        .iter()
+        .map(|(expr, _)| Arc::clone(expr))
+        .collect();
+    // the operators between the projection and the scan, from the top
+    let mut operators = vec![];
+    let mut plan = projection.input();
+    let parquet = loop {
+        let any = plan.as_any();
+        if let Some(parquet) = any.downcast_ref::<ParquetExec>() {
+            break parquet;
+        }
+        if let Some(filter) = any.downcast_ref::<FilterExec>() {
+            exprs.push(Arc::clone(filter.predicate()));
+        } else if let Some(repartition) = any.downcast_ref::<RepartitionExec>() {
+            if let Partitioning::Hash(hash_exprs, _) = repartition.partitioning() {
+                exprs.extend(hash_exprs.iter().cloned());
+            }
+        } else if !any.is::<CoalesceBatchesExec>() {
+            return Ok(None);
+        }
+        operators.push(Arc::clone(plan));
+        plan = plan.children()[0];
+    };
+    if let Some(ordering) = parquet.properties().output_ordering() {
+        exprs.extend(ordering.iter().map(|sort| Arc::clone(&sort.expr)));
+    }
+
+    // the nested fields accessed in the columns of the scan, keyed by their
+    // index in the projection numbering of the scan
+    let mut accesses = HashMap::new();
+    for expr in &exprs {
+        collect_nested_accesses(expr, &mut accesses);
+    }
+    let mut paths: HashMap<usize, Vec<Vec<String>>> =
+        match &parquet.base_config().projection {
+            Some(scan_projection) => accesses
+                .into_iter()
+                .map(|(column, paths)| (scan_projection[column], paths))
+                .collect(),
+            None => accesses,
+        };
+    // the predicate of the scan refers to the file schema
+    if let Some(predicate) = parquet.predicate() {
+        collect_nested_accesses(predicate, &mut paths);
+    }
+
+    let narrowed = parquet.clone().with_nested_paths(&paths);
+    if narrowed.base_config().nested_projection()
+        == parquet.base_config().nested_projection()
+    {
+        return Ok(None);
+    }
+    let mut input: Arc<dyn ExecutionPlan> = Arc::new(narrowed);
+    for operator in operators.into_iter().rev() {
+        input = operator.with_new_children(vec![input])?;
+    }
+    Ok(Some(input))
+}
+
+/// Adds the paths of the nested fields that `expr` accesses to `accesses`,
+/// keyed by column index. A column that is used other than through
+/// `get_field` is accessed as a whole, which is recorded as an empty path.
+fn collect_nested_accesses(
+    expr: &Arc<dyn PhysicalExpr>,
+    accesses: &mut HashMap<usize, Vec<Vec<String>>>,
+) {
+    if let Some((column, path)) = nested_field_path(expr) {
+        accesses.entry(column).or_default().push(path);
+        return;
+    }
+    for child in expr.children() {
+        collect_nested_accesses(child, accesses);
+    }
+}
+
+/// Returns the index of the column and the path of the nested field if
+/// `expr` is a column, or `get_field` calls with literal names on a column
+fn nested_field_path(expr: &Arc<dyn PhysicalExpr>) -> Option<(usize, Vec<String>)> {
+    if let Some(column) = expr.as_any().downcast_ref::<Column>() {
+        return Some((column.index(), vec![]));
+    }
+    let function = expr.as_any().downcast_ref::<ScalarFunctionExpr>()?;
+    let [base, name] = function.args() else {
+        return None;
+    };
+    if function.fun().name() != "get_field" {
+        return None;
+    }
+    let name = match name.as_any().downcast_ref::<Literal>()?.value() {
+        ScalarValue::Utf8(Some(name))
+        | ScalarValue::LargeUtf8(Some(name))
+        | ScalarValue::Utf8View(Some(name)) => name.clone(),
+        _ => return None,
+    };
+    let (column, mut path) = nested_field_path(base)?;
+    path.push(name);
+    Some((column, path))
+}
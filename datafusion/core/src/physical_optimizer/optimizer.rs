@@ -30,8 +30,11 @@ use crate::physical_optimizer::enforce_sorting::EnforceSorting;
 use crate::physical_optimizer::join_selection::JoinSelection;
 use crate::physical_optimizer::limit_pushdown::LimitPushdown;
 use crate::physical_optimizer::limited_distinct_aggregation::LimitedDistinctAggregation;
+#[cfg(feature = "parquet")]
+use crate::physical_optimizer::nested_projection_pushdown::NestedProjectionPushdown;
 use crate::physical_optimizer::output_requirements::OutputRequirements;
 use crate::physical_optimizer::sanity_checker::SanityCheckPlan;
+use crate::physical_optimizer::sort_merge_join_selection::SortMergeJoinSelection;
 use crate::physical_optimizer::topk_aggregation::TopKAggregation;
 
 /// A rule-based physical optimizer.
@@ -61,6 +64,11 @@ impl PhysicalOptimizer {
             // repartitioning and local sorting steps to meet distribution and ordering requirements.
             // Therefore, it should run before EnforceDistribution and EnforceSorting.
             Arc::new(JoinSelection::new()),
+            // The SortMergeJoinSelection rule replaces hash joins on inputs sorted on the
+            // join keys with sort-merge joins. It should run after JoinSelection has picked
+            // the join sides and before EnforceDistribution, which keeps the ordering of
+            // the inputs when repartitioning them.
+            Arc::new(SortMergeJoinSelection::new()),
             // The LimitedDistinctAggregation rule should be applied before the EnforceDistribution rule,
             // as that rule may inject other operations in between the different AggregateExecs.
             // Applying the rule early means only directly-connected AggregateExecs must be examined.
@@ -99,6 +107,12 @@ impl PhysicalOptimizer {
             // are not present, the load of executors such as join or union will be
             // reduced by narrowing their input tables.
             Arc::new(ProjectionPushdown::new()),
+            // The NestedProjectionPushdown rule narrows the struct columns read
+            // by Parquet scans to the nested fields the projections above them
+            // access. It runs after ProjectionPushdown, which may merge these
+            // projections into others.
+            #[cfg(feature = "parquet")]
+            Arc::new(NestedProjectionPushdown::new()),
             // The LimitPushdown rule tries to push limits down as far as possible,
             // replacing operators with fetching variants, or adding limits
             // past operators that support limit pushdown.
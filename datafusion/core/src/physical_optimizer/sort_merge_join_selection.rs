@@ -0,0 +1,168 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The [`SortMergeJoinSelection`] rule replaces hash joins whose inputs are
+//! already sorted on the join keys with sort-merge joins, which merge the
+//! sorted inputs instead of building a hash table.
+
+use std::sync::Arc;
+
+use crate::config::ConfigOptions;
+use crate::error::Result;
+use crate::physical_plan::joins::utils::JoinOn;
+use crate::physical_plan::joins::{HashJoinExec, SortMergeJoinExec};
+use crate::physical_plan::{ExecutionPlan, ExecutionPlanProperties};
+
+use arrow_schema::{DataType, SortOptions};
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_common::JoinType;
+use datafusion_physical_optimizer::PhysicalOptimizerRule;
+
+/// The [`SortMergeJoinSelection`] rule replaces a [`HashJoinExec`] with a
+/// [`SortMergeJoinExec`] when both of its inputs are already sorted on the join
+/// keys, if enabled by `datafusion.optimizer.prefer_sort_merge_join_for_sorted_inputs`.
+///
+/// The rule must run before [`EnforceDistribution`], which then repartitions
+/// the inputs of the sort-merge join preserving their ordering, so that no
+/// sort is needed below the join.
+///
+/// [`EnforceDistribution`]: crate::physical_optimizer::enforce_distribution::EnforceDistribution
+#[derive(Default)]
+pub struct SortMergeJoinSelection {}
+
+impl SortMergeJoinSelection {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PhysicalOptimizerRule for SortMergeJoinSelection {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if !config.optimizer.prefer_sort_merge_join_for_sorted_inputs {
+            return Ok(plan);
+        }
+        plan.transform_up(|plan| {
+            let Some(hash_join) = plan.as_any().downcast_ref::<HashJoinExec>() else {
+                return Ok(Transformed::no(plan));
+            };
+            // the sort-merge join can neither embed a projection nor run a right semi join
+            if hash_join.contain_projection()
+                || *hash_join.join_type() == JoinType::RightSemi
+                || !join_key_types_supported(hash_join)?
+            {
+                return Ok(Transformed::no(plan));
+            }
+            let Some(sort_options) = sorted_join_keys_options(
+                hash_join.left(),
+                hash_join.right(),
+                &hash_join.on,
+            ) else {
+                return Ok(Transformed::no(plan));
+            };
+            let sort_merge_join = SortMergeJoinExec::try_new(
+                Arc::clone(hash_join.left()),
+                Arc::clone(hash_join.right()),
+                hash_join.on.clone(),
+                hash_join.filter().cloned(),
+                *hash_join.join_type(),
+                sort_options,
+                hash_join.null_equals_null(),
+            )?;
+            Ok(Transformed::yes(Arc::new(sort_merge_join)))
+        })
+        .data()
+    }
+
+    fn name(&self) -> &str {
+        "SortMergeJoinSelection"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Returns true if the comparator of [`SortMergeJoinExec`] supports the types
+/// of all the join keys of `hash_join`
+fn join_key_types_supported(hash_join: &HashJoinExec) -> Result<bool> {
+    let left_schema = hash_join.left().schema();
+    let right_schema = hash_join.right().schema();
+    for (left_key, right_key) in &hash_join.on {
+        if !is_supported_key_type(&left_key.data_type(&left_schema)?)
+            || !is_supported_key_type(&right_key.data_type(&right_schema)?)
+        {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// The types compared by the sort-merge join, which fails to run on any
+/// other key type, e.g. string views, dictionaries, binaries or timestamps
+/// with a time zone
+fn is_supported_key_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Null
+            | DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Decimal128(..)
+            | DataType::Timestamp(_, None)
+            | DataType::Date32
+            | DataType::Date64
+    )
+}
+
+/// Returns the sort options of the join keys if both inputs of an equijoin are
+/// already sorted on them, in the order of `join_on` and with the same options.
+fn sorted_join_keys_options(
+    left: &Arc<dyn ExecutionPlan>,
+    right: &Arc<dyn ExecutionPlan>,
+    join_on: &JoinOn,
+) -> Option<Vec<SortOptions>> {
+    let left_ordering = left.output_ordering()?;
+    let right_ordering = right.output_ordering()?;
+    if left_ordering.len() < join_on.len() || right_ordering.len() < join_on.len() {
+        return None;
+    }
+    join_on
+        .iter()
+        .zip(left_ordering.iter().zip(right_ordering))
+        .map(|((left_key, right_key), (left_sort, right_sort))| {
+            (left_sort.expr.eq(left_key)
+                && right_sort.expr.eq(right_key)
+                && left_sort.options == right_sort.options)
+                .then_some(left_sort.options)
+        })
+        .collect()
+}
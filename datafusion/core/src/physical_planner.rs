@@ -21,6 +21,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::dataframe::SampleNode;
 use crate::datasource::file_format::file_type_to_format;
 use crate::datasource::listing::ListingTableUrl;
 use crate::datasource::physical_plan::FileSinkConfig;
@@ -52,6 +53,7 @@ use crate::physical_plan::memory::MemoryExec;
 use crate::physical_plan::projection::ProjectionExec;
 use crate::physical_plan::recursive_query::RecursiveQueryExec;
 use crate::physical_plan::repartition::RepartitionExec;
+use crate::physical_plan::sample::SampleExec;
 use crate::physical_plan::sorts::sort::SortExec;
 use crate::physical_plan::union::UnionExec;
 use crate::physical_plan::unnest::UnnestExec;
@@ -547,6 +549,8 @@ impl DefaultPhysicalPlanner {
                 let name = table_name.table();
                 let schema = session_state.schema_for_ref(table_name.clone())?;
                 if let Some(provider) = schema.table(name).await? {
+                    let provider =
+                        session_state.apply_row_filter_policy(table_name, provider)?;
                     let input_exec = children.one()?;
                     provider
                         .insert_into(session_state, input_exec, false)
@@ -563,6 +567,8 @@ impl DefaultPhysicalPlanner {
                 let name = table_name.table();
                 let schema = session_state.schema_for_ref(table_name.clone())?;
                 if let Some(provider) = schema.table(name).await? {
+                    let provider =
+                        session_state.apply_row_filter_policy(table_name, provider)?;
                     let input_exec = children.one()?;
                     provider
                         .insert_into(session_state, input_exec, true)
@@ -1148,6 +1154,12 @@ impl DefaultPhysicalPlanner {
 
             // N Children
             LogicalPlan::Union(_) => Arc::new(UnionExec::new(children.vec())),
+            LogicalPlan::Extension(Extension { node })
+                if node.as_any().downcast_ref::<SampleNode>().is_some() =>
+            {
+                let sample = node.as_any().downcast_ref::<SampleNode>().unwrap();
+                plan_sample(sample, children.one()?)?
+            }
             LogicalPlan::Extension(Extension { node }) => {
                 let mut maybe_plan = None;
                 let children = children.vec();
@@ -1962,6 +1974,34 @@ impl DefaultPhysicalPlanner {
     }
 }
 
+/// Plans a [`SampleNode`] over the physical plan `input`
+fn plan_sample(
+    sample: &SampleNode,
+    input: Arc<dyn ExecutionPlan>,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    // a Parquet scan samples its row groups and skips the others
+    #[cfg(feature = "parquet")]
+    {
+        use crate::datasource::physical_plan::ParquetExec;
+        use crate::physical_plan::sample::SampleMethod;
+        if sample.method == SampleMethod::System {
+            if let Some(parquet) = input.as_any().downcast_ref::<ParquetExec>() {
+                let seed = sample.seed.unwrap_or_else(rand::random);
+                return Ok(Arc::new(
+                    parquet.clone().with_row_group_sample(sample.fraction, seed),
+                ));
+            }
+        }
+    }
+    Ok(Arc::new(SampleExec::try_new_with_method(
+        input,
+        sample.method,
+        sample.fraction,
+        sample.with_replacement,
+        sample.seed,
+    )?))
+}
+
 fn tuple_err<T, R>(value: (Result<T>, Result<R>)) -> Result<(T, R)> {
     match value {
         (Ok(e), Ok(e1)) => Ok((e, e1)),
@@ -18,8 +18,15 @@
 use crate::execution::context::SessionState;
 use crate::execution::session_state::SessionStateBuilder;
 use crate::prelude::SessionContext;
-use futures::FutureExt;
-use object_store::{memory::InMemory, path::Path, ObjectMeta, ObjectStore};
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use futures::{FutureExt, StreamExt};
+use object_store::{
+    memory::InMemory, path::Path, GetOptions, GetResult, ListResult, MultipartUpload,
+    ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions, PutPayload, PutResult,
+};
+use std::fmt;
+use std::io;
 use std::sync::Arc;
 use url::Url;
 
@@ -59,3 +66,116 @@ pub fn local_unpartitioned_file(path: impl AsRef<std::path::Path>) -> ObjectMeta
         version: None,
     }
 }
+
+/// An [`ObjectStore`] that fails to list the paths under a given prefix, as
+/// if they could not be read, by default because of missing permissions
+#[derive(Debug)]
+pub struct UnreadableObjectStore {
+    inner: Arc<InMemory>,
+    unreadable: Path,
+    error_kind: io::ErrorKind,
+}
+
+impl UnreadableObjectStore {
+    /// Wraps `inner`, failing the listing of the paths under `unreadable`
+    pub fn new(inner: Arc<InMemory>, unreadable: impl Into<Path>) -> Self {
+        Self {
+            inner,
+            unreadable: unreadable.into(),
+            error_kind: io::ErrorKind::PermissionDenied,
+        }
+    }
+
+    /// Fails with an I/O error of kind `error_kind` instead
+    pub fn with_error_kind(mut self, error_kind: io::ErrorKind) -> Self {
+        self.error_kind = error_kind;
+        self
+    }
+
+    fn check(&self, location: &Path) -> object_store::Result<()> {
+        if location.prefix_matches(&self.unreadable) {
+            return Err(object_store::Error::Generic {
+                store: "UnreadableObjectStore",
+                source: Box::new(io::Error::new(
+                    self.error_kind,
+                    format!("cannot read {location}"),
+                )),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for UnreadableObjectStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UnreadableObjectStore({})", self.unreadable)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for UnreadableObjectStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOpts,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        self.inner.put_multipart_opts(location, opts).await
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        self.inner.get_opts(location, options).await
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        self.inner.delete(location).await
+    }
+
+    fn list(
+        &self,
+        prefix: Option<&Path>,
+    ) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+        self.inner
+            .list(prefix)
+            .map(|result| {
+                let meta = result?;
+                self.check(&meta.location)?;
+                Ok(meta)
+            })
+            .boxed()
+    }
+
+    async fn list_with_delimiter(
+        &self,
+        prefix: Option<&Path>,
+    ) -> object_store::Result<ListResult> {
+        if let Some(prefix) = prefix {
+            self.check(prefix)?;
+        }
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(
+        &self,
+        from: &Path,
+        to: &Path,
+    ) -> object_store::Result<()> {
+        self.inner.copy_if_not_exists(from, to).await
+    }
+}
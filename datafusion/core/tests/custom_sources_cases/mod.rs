@@ -47,6 +47,7 @@ use datafusion_catalog::Session;
 use futures::stream::Stream;
 
 mod provider_filter_pushdown;
+mod scan_context;
 mod statistics;
 
 macro_rules! TEST_CUSTOM_SCHEMA_REF {
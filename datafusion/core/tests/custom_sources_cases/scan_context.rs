@@ -0,0 +1,289 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module contains tests of the session information available to a
+//! custom [`TableProvider`] while planning and executing a scan
+
+use std::{any::Any, sync::Arc};
+
+use arrow::array::{Int32Array, RecordBatch};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use datafusion::execution::context::TaskContext;
+use datafusion::execution::memory_pool::{
+    GreedyMemoryPool, MemoryConsumer, MemoryReservation,
+};
+use datafusion::execution::object_store::ObjectStoreUrl;
+use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+use datafusion::{
+    datasource::{TableProvider, TableType},
+    error::Result,
+    logical_expr::Expr,
+    physical_plan::{
+        collect, DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan,
+        Partitioning, PlanProperties, SendableRecordBatchStream,
+    },
+    prelude::{SessionConfig, SessionContext},
+};
+use datafusion_common::{internal_err, project_schema};
+use datafusion_physical_expr::EquivalenceProperties;
+use datafusion_physical_plan::stream::RecordBatchStreamAdapter;
+
+use async_trait::async_trait;
+use datafusion_catalog::Session;
+
+/// Number of rows of [`ContextTable`]
+const NUM_ROWS: i32 = 20;
+
+/// Bytes reserved by [`ContextExec`] for each row of a batch
+const BYTES_PER_ROW: usize = 8;
+
+/// A table of the integers `0..NUM_ROWS` that takes its batch size and
+/// memory pool from the session scanning it
+#[derive(Debug)]
+struct ContextTable {
+    schema: SchemaRef,
+}
+
+impl ContextTable {
+    fn new() -> Self {
+        let schema = Arc::new(Schema::new(vec![Field::new("i", DataType::Int32, false)]));
+        Self { schema }
+    }
+}
+
+#[async_trait]
+impl TableProvider for ContextTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // the object store registry of the session is available to resolve
+        // the locations of the table
+        state
+            .runtime_env()
+            .object_store(ObjectStoreUrl::local_filesystem())?;
+
+        // the buffer of a batch is accounted in the memory pool of the session
+        let batch_size = state.config().batch_size();
+        let mut reservation =
+            MemoryConsumer::new("ContextExec").register(&state.runtime_env().memory_pool);
+        reservation.try_grow(batch_size * BYTES_PER_ROW)?;
+
+        let schema = project_schema(&self.schema, projection)?;
+        Ok(Arc::new(ContextExec::new(
+            schema,
+            state.session_id().to_string(),
+            batch_size,
+            reservation,
+        )))
+    }
+}
+
+/// Scans [`ContextTable`] in batches of the batch size of the session that
+/// planned it
+#[derive(Debug)]
+struct ContextExec {
+    schema: SchemaRef,
+    session_id: String,
+    batch_size: usize,
+    /// Released when the plan is dropped
+    _reservation: MemoryReservation,
+    cache: PlanProperties,
+}
+
+impl ContextExec {
+    fn new(
+        schema: SchemaRef,
+        session_id: String,
+        batch_size: usize,
+        reservation: MemoryReservation,
+    ) -> Self {
+        let cache = PlanProperties::new(
+            EquivalenceProperties::new(Arc::clone(&schema)),
+            Partitioning::UnknownPartitioning(1),
+            ExecutionMode::Bounded,
+        );
+        Self {
+            schema,
+            session_id,
+            batch_size,
+            _reservation: reservation,
+            cache,
+        }
+    }
+}
+
+impl DisplayAs for ContextExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "ContextExec: batch_size={}", self.batch_size)
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for ContextExec {
+    fn name(&self) -> &'static str {
+        "ContextExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        _partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        // the plan is executed by the session that planned it
+        if context.session_id() != self.session_id {
+            return internal_err!(
+                "ContextExec planned by session {} executed by session {}",
+                self.session_id,
+                context.session_id()
+            );
+        }
+        let batch_size = context.session_config().batch_size();
+        if batch_size != self.batch_size {
+            return internal_err!(
+                "ContextExec planned with batch size {} executed with batch size {}",
+                self.batch_size,
+                batch_size
+            );
+        }
+
+        let schema = Arc::clone(&self.schema);
+        let batches = (0..NUM_ROWS)
+            .step_by(batch_size)
+            .map(|start| {
+                let end = (start + batch_size as i32).min(NUM_ROWS);
+                let columns = if schema.fields().is_empty() {
+                    vec![]
+                } else {
+                    vec![Arc::new(Int32Array::from_iter_values(start..end)) as _]
+                };
+                let options = arrow::array::RecordBatchOptions::new()
+                    .with_row_count(Some((end - start) as usize));
+                RecordBatch::try_new_with_options(Arc::clone(&schema), columns, &options)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::iter(batches.into_iter().map(Ok)),
+        )))
+    }
+}
+
+fn session_context(batch_size: usize, memory_limit: usize) -> Result<SessionContext> {
+    let config = SessionConfig::new().with_batch_size(batch_size);
+    let runtime = RuntimeEnv::new(
+        RuntimeConfig::new()
+            .with_memory_pool(Arc::new(GreedyMemoryPool::new(memory_limit))),
+    )?;
+    let ctx = SessionContext::new_with_config_rt(config, Arc::new(runtime));
+    ctx.register_table("t", Arc::new(ContextTable::new()))?;
+    Ok(ctx)
+}
+
+/// Plans and runs a scan of `t`, returning the number of rows of each batch
+/// and the memory reserved by the scan
+async fn scan_batch_sizes(ctx: &SessionContext) -> Result<(Vec<usize>, usize)> {
+    let plan = ctx
+        .sql("SELECT i FROM t")
+        .await?
+        .create_physical_plan()
+        .await?;
+    let reserved = ctx.runtime_env().memory_pool.reserved();
+    let batches = collect(plan, ctx.task_ctx()).await?;
+    Ok((batches.iter().map(|b| b.num_rows()).collect(), reserved))
+}
+
+#[tokio::test]
+async fn scan_uses_session_config_and_runtime() -> Result<()> {
+    let ctx1 = session_context(3, 1024)?;
+    let ctx2 = session_context(8, 1024)?;
+
+    let (res1, res2) = tokio::join!(scan_batch_sizes(&ctx1), scan_batch_sizes(&ctx2));
+    let (sizes1, reserved1) = res1?;
+    let (sizes2, reserved2) = res2?;
+
+    assert_eq!(sizes1, vec![3, 3, 3, 3, 3, 3, 2]);
+    assert_eq!(sizes2, vec![8, 8, 4]);
+
+    // each scan is accounted in the memory pool of its own session only
+    assert_eq!(reserved1, 3 * BYTES_PER_ROW);
+    assert_eq!(reserved2, 8 * BYTES_PER_ROW);
+
+    // and releases its reservation when the plan is dropped
+    assert_eq!(ctx1.runtime_env().memory_pool.reserved(), 0);
+    assert_eq!(ctx2.runtime_env().memory_pool.reserved(), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn scan_respects_session_memory_limit() -> Result<()> {
+    // the pool of the session is too small for a batch of the scan
+    let ctx = session_context(8, 8 * BYTES_PER_ROW - 1)?;
+    let err = scan_batch_sizes(&ctx).await.unwrap_err();
+    assert!(
+        err.to_string().contains("Resources exhausted"),
+        "unexpected error: {err}"
+    );
+
+    // while a session with the same pool but a smaller batch size can scan
+    let ctx = session_context(4, 8 * BYTES_PER_ROW - 1)?;
+    let (sizes, reserved) = scan_batch_sizes(&ctx).await?;
+    assert_eq!(sizes, vec![4; 5]);
+    assert_eq!(reserved, 4 * BYTES_PER_ROW);
+    Ok(())
+}
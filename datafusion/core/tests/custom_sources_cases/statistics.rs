@@ -299,3 +299,84 @@ async fn sql_window() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn sql_filter_range() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx = init_ctx(stats, schema)?;
+
+    let df = ctx.sql("SELECT * FROM stats_table WHERE c1 > 500").await?;
+    let physical_plan = df.create_physical_plan().await?;
+    let result = physical_plan.statistics()?;
+
+    // the selectivity is estimated from the range of `c1`, so the derived
+    // statistics are inexact
+    match result.num_rows {
+        Precision::Inexact(num_rows) => assert!(num_rows < 13, "{num_rows}"),
+        other => panic!("expected inexact num_rows, got {other:?}"),
+    }
+    let c1_stats = &result.column_statistics[0];
+    assert_eq!(
+        c1_stats.min_value,
+        Precision::Inexact(ScalarValue::Int32(Some(501)))
+    );
+    assert_eq!(
+        c1_stats.max_value,
+        Precision::Inexact(ScalarValue::Int32(Some(1023)))
+    );
+    assert_eq!(c1_stats.null_count, Precision::Inexact(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sql_projection() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx = init_ctx(stats.clone(), schema)?;
+
+    let df = ctx.sql("SELECT c2, c1 FROM stats_table").await?;
+    let physical_plan = df.create_physical_plan().await?;
+
+    // the number of rows is unchanged and the column statistics follow the columns
+    assert_eq!(
+        Statistics {
+            num_rows: Precision::Exact(13),
+            total_byte_size: Precision::Absent,
+            column_statistics: vec![
+                stats.column_statistics[1].clone(),
+                stats.column_statistics[0].clone(),
+            ],
+        },
+        physical_plan.statistics()?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sql_union_all() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx = init_ctx(stats.clone(), schema)?;
+
+    let df = ctx
+        .sql("SELECT * FROM stats_table UNION ALL SELECT * FROM stats_table")
+        .await?;
+    let physical_plan = df.create_physical_plan().await?;
+    let result = physical_plan.statistics()?;
+
+    // the number of rows and null counts of the inputs are summed, while the
+    // distinct counts of the inputs cannot be combined
+    assert_eq!(result.num_rows, Precision::Exact(26));
+    let expected_col_stats = stats
+        .column_statistics
+        .iter()
+        .map(|col_stats| ColumnStatistics {
+            distinct_count: Precision::Absent,
+            null_count: col_stats.null_count.add(&col_stats.null_count),
+            ..col_stats.clone()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(result.column_statistics, expected_col_stats);
+
+    Ok(())
+}
@@ -44,6 +44,7 @@ use datafusion::datasource::MemTable;
 use datafusion::error::Result;
 use datafusion::execution::context::SessionContext;
 use datafusion::execution::session_state::SessionStateBuilder;
+use datafusion::physical_plan::displayable;
 use datafusion::prelude::JoinType;
 use datafusion::prelude::{CsvReadOptions, ParquetReadOptions};
 use datafusion::test_util::{parquet_test_data, populate_csv_partitions};
@@ -327,6 +328,94 @@ async fn join() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn join_semi_anti_with_duplicate_and_null_keys() -> Result<()> {
+    let left = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("k", DataType::Int32, true),
+            Field::new("v", DataType::Utf8, false),
+        ])),
+        vec![
+            Arc::new(Int32Array::from(vec![
+                Some(1),
+                Some(2),
+                Some(2),
+                None,
+                Some(3),
+            ])),
+            Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e"])),
+        ],
+    )?;
+    // duplicate and null keys on the build side
+    let right = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("k2", DataType::Int32, true),
+            Field::new("w", DataType::Utf8, false),
+        ])),
+        vec![
+            Arc::new(Int32Array::from(vec![
+                Some(2),
+                Some(2),
+                Some(2),
+                None,
+                Some(4),
+            ])),
+            Arc::new(StringArray::from(vec!["x", "y", "z", "n", "q"])),
+        ],
+    )?;
+
+    let ctx = SessionContext::new();
+    ctx.register_batch("l", left)?;
+    ctx.register_batch("r", right)?;
+
+    let semi = ctx.table("l").await?.join(
+        ctx.table("r").await?,
+        JoinType::LeftSemi,
+        &["k"],
+        &["k2"],
+        None,
+    )?;
+    assert_eq!(semi.schema().fields().len(), 2);
+    let expected = [
+        "+---+---+",
+        "| k | v |",
+        "+---+---+",
+        "| 2 | b |",
+        "| 2 | c |",
+        "+---+---+",
+    ];
+    assert_batches_sorted_eq!(expected, &semi.collect().await?);
+
+    let anti = ctx.table("l").await?.join(
+        ctx.table("r").await?,
+        JoinType::LeftAnti,
+        &["k"],
+        &["k2"],
+        None,
+    )?;
+    assert_eq!(anti.schema().fields().len(), 2);
+    let expected = [
+        "+---+---+",
+        "| k | v |",
+        "+---+---+",
+        "|   | d |",
+        "| 1 | a |",
+        "| 3 | e |",
+        "+---+---+",
+    ];
+    assert_batches_sorted_eq!(expected, &anti.collect().await?);
+
+    // EXISTS and NOT EXISTS are decorrelated into the same joins
+    let results = ctx
+        .sql("SELECT * FROM l WHERE NOT EXISTS (SELECT 1 FROM r WHERE r.k2 = l.k)")
+        .await?
+        .collect()
+        .await?;
+    assert_batches_sorted_eq!(expected, &results);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn sort_on_unprojected_columns() -> Result<()> {
     let schema = Schema::new(vec![
@@ -2363,3 +2452,63 @@ async fn dense_union_is_null() {
     ];
     assert_batches_sorted_eq!(expected, &result_df.collect().await.unwrap());
 }
+
+#[tokio::test]
+async fn sample() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+    let batch = RecordBatch::try_new(
+        schema,
+        vec![Arc::new(Int32Array::from((0..10_000).collect::<Vec<_>>()))],
+    )?;
+    let ctx = SessionContext::new();
+    ctx.register_batch("t", batch)?;
+
+    // 10_000 input rows, expect ~1_000 with a standard deviation of ~30
+    let rows = ctx
+        .table("t")
+        .await?
+        .sample(0.1, false, Some(42))?
+        .count()
+        .await?;
+    assert!((850..=1_150).contains(&rows), "sampled {rows} rows");
+
+    // the same seed returns the same sample
+    let first = ctx.table("t").await?.sample(0.1, false, Some(42))?;
+    let second = ctx.table("t").await?.sample(0.1, false, Some(42))?;
+    assert_eq!(
+        pretty_format_batches(&first.collect().await?)?.to_string(),
+        pretty_format_batches(&second.collect().await?)?.to_string()
+    );
+
+    let physical_plan = ctx
+        .table("t")
+        .await?
+        .sample(2.0, true, Some(7))?
+        .create_physical_plan()
+        .await?;
+    let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+    assert_contains!(
+        formatted,
+        "SampleExec: method=bernoulli, fraction=2, with_replacement=true, seed=7"
+    );
+
+    // without row groups to skip, SYSTEM sampling keeps or drops whole batches
+    let physical_plan = ctx
+        .table("t")
+        .await?
+        .sample_system(0.5, Some(7))?
+        .create_physical_plan()
+        .await?;
+    let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+    assert_contains!(
+        formatted,
+        "SampleExec: method=system, fraction=0.5, with_replacement=false, seed=7"
+    );
+
+    let err = ctx.table("t").await?.sample(1.5, false, None).unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Sample fraction must be between 0 and 1 when sampling without replacement"
+    );
+    Ok(())
+}
@@ -19,7 +19,7 @@
 
 use arrow::datatypes::{Int32Type, SchemaRef};
 use arrow::record_batch::RecordBatch;
-use arrow_array::{ArrayRef, DictionaryArray};
+use arrow_array::{ArrayRef, DictionaryArray, Int64Array, StringArray};
 use arrow_schema::SortOptions;
 use async_trait::async_trait;
 use datafusion::assert_batches_eq;
@@ -27,13 +27,15 @@ use datafusion::physical_optimizer::PhysicalOptimizerRule;
 use datafusion::physical_plan::memory::MemoryExec;
 use datafusion::physical_plan::streaming::PartitionStream;
 use datafusion_execution::memory_pool::{
-    GreedyMemoryPool, MemoryPool, TrackConsumersPool,
+    GreedyMemoryPool, MemoryPool, MemoryReservation, TrackConsumersPool,
 };
 use datafusion_expr::{Expr, TableType};
 use datafusion_physical_expr::{LexOrdering, PhysicalSortExpr};
 use futures::StreamExt;
+use parquet::arrow::ArrowWriter;
 use std::any::Any;
 use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, OnceLock};
 use tokio::fs::File;
 
@@ -44,10 +46,10 @@ use datafusion::execution::runtime_env::RuntimeEnvBuilder;
 use datafusion::execution::session_state::SessionStateBuilder;
 use datafusion::physical_optimizer::join_selection::JoinSelection;
 use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
-use datafusion::physical_plan::{ExecutionPlan, SendableRecordBatchStream};
+use datafusion::physical_plan::{collect, ExecutionPlan, SendableRecordBatchStream};
 use datafusion_common::{assert_contains, Result};
 
-use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
 use datafusion_catalog::Session;
 use datafusion_execution::TaskContext;
 use test_utils::AccessLogGenerator;
@@ -253,6 +255,45 @@ async fn sort_preserving_merge() {
         .await
 }
 
+#[tokio::test]
+async fn window_on_sorted_input() {
+    let scenario = Scenario::new_dictionary_strings(1);
+    let partition_size = scenario.partition_size();
+    // use a single partition so no repartitioning is needed
+    let config = SessionConfig::new().with_target_partitions(1);
+
+    // The input is already sorted on the PARTITION BY and ORDER BY keys,
+    // so the window is evaluated one partition group at a time without
+    // sorting or buffering the input. A RANGE frame, the default, can not be
+    // ordered by the dictionary encoded strings, so use a ROWS frame
+    TestCase::new()
+        .with_query(
+            "select a, b, row_number() over (partition by a order by b \
+             rows between unbounded preceding and current row) from t",
+        )
+        .with_memory_limit(partition_size / 2)
+        .with_config(config.clone())
+        .with_scenario(scenario.clone())
+        .with_expected_success()
+        .run()
+        .await;
+
+    // A different ORDER BY requires sorting the input, which does not fit.
+    // Do not allow the sort to use the 'concat in place' path
+    let config = config.with_sort_in_place_threshold_bytes(10);
+    TestCase::new()
+        .with_query(
+            "select a, b, row_number() over (partition by a order by b desc \
+             rows between unbounded preceding and current row) from t",
+        )
+        .with_expected_errors(vec!["Resources exhausted"])
+        .with_memory_limit(partition_size / 2)
+        .with_config(config)
+        .with_scenario(scenario)
+        .run()
+        .await
+}
+
 #[tokio::test]
 async fn sort_spill_reservation() {
     let scenario = Scenario::new_dictionary_strings(1);
@@ -337,6 +378,107 @@ async fn oom_recursive_cte() {
         .await
 }
 
+#[tokio::test]
+async fn parquet_scan_reserves_decoded_batches() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("wide.parquet");
+
+    // 5000 rows of 20 string columns of 100 bytes, about 10MB when decoded
+    let columns = (0..20).map(|col| {
+        let values = (0..5000).map(|row| format!("{row:099}{col}"));
+        let array: ArrayRef = Arc::new(StringArray::from_iter_values(values));
+        (format!("c{col}"), array)
+    });
+    let batch = RecordBatch::try_from_iter(columns).unwrap();
+    let file = std::fs::File::create(&path).unwrap();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    /// Scans the file in batches of 100 rows, about 200KB each, with a
+    /// memory pool of `memory_limit` bytes, returning the number of rows read
+    /// and the most memory reserved at any time
+    async fn scan(path: &str, memory_limit: usize) -> (Result<usize>, usize) {
+        let pool = Arc::new(PeakMemoryPool::new(memory_limit));
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_pool(Arc::clone(&pool) as _)
+            .build()
+            .unwrap();
+        let config = SessionConfig::new()
+            .with_batch_size(100)
+            .with_target_partitions(1);
+        let ctx = SessionContext::new_with_config_rt(config, Arc::new(runtime));
+        ctx.register_parquet("t", path, ParquetReadOptions::default())
+            .await
+            .unwrap();
+        let rows = match ctx.sql("SELECT * FROM t").await.unwrap().collect().await {
+            Ok(batches) => Ok(batches.iter().map(|b| b.num_rows()).sum()),
+            Err(e) => Err(e),
+        };
+        assert_eq!(pool.reserved(), 0);
+        (rows, pool.peak())
+    }
+    let path = path.to_str().unwrap();
+
+    // the scan only reserves the batch it is decoding, so it can read a file
+    // larger than the pool
+    let (rows, peak) = scan(path, 4_000_000).await;
+    assert_eq!(rows.unwrap(), 5000);
+    assert!(peak > 0 && peak <= 4_000_000, "{peak}");
+
+    // a batch that does not fit in the pool fails the scan rather than
+    // reserving more memory than the pool holds
+    let (rows, peak) = scan(path, 10_000).await;
+    assert_contains!(rows.unwrap_err().to_string(), "Resources exhausted");
+    assert!(peak <= 10_000, "{peak}");
+}
+
+#[tokio::test]
+async fn parquet_scan_shares_pool_with_spilling_sort() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ints.parquet");
+
+    // 100000 rows of 2 integer columns, about 1.6MB when decoded
+    let a: ArrayRef = Arc::new(Int64Array::from_iter_values(0..100_000));
+    let b: ArrayRef = Arc::new(Int64Array::from_iter_values((0..100_000).rev()));
+    let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+    let file = std::fs::File::create(&path).unwrap();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let pool = Arc::new(PeakMemoryPool::new(1_200_000));
+    let runtime = RuntimeEnvBuilder::new()
+        .with_memory_pool(Arc::clone(&pool) as _)
+        .build()
+        .unwrap();
+    let config = SessionConfig::new()
+        .with_batch_size(1000)
+        .with_target_partitions(1)
+        .with_sort_spill_reservation_bytes(100_000);
+    let ctx = SessionContext::new_with_config_rt(config, Arc::new(runtime));
+    ctx.register_parquet("t", path.to_str().unwrap(), ParquetReadOptions::default())
+        .await
+        .unwrap();
+
+    // the sort spills once the pool is full, while the scan only reserves
+    // the batch it is decoding
+    let plan = ctx
+        .sql("SELECT * FROM t ORDER BY b")
+        .await
+        .unwrap()
+        .create_physical_plan()
+        .await
+        .unwrap();
+    let batches = collect(Arc::clone(&plan), ctx.task_ctx()).await.unwrap();
+    let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(rows, 100_000);
+    let spills = plan.metrics().and_then(|m| m.spill_count()).unwrap();
+    assert!(spills > 0);
+    assert_eq!(ctx.runtime_env().memory_pool.reserved(), 0);
+    assert!(pool.peak() <= 1_200_000, "{}", pool.peak());
+}
+
 #[tokio::test]
 async fn oom_parquet_sink() {
     let dir = tempfile::tempdir().unwrap();
@@ -394,6 +536,47 @@ async fn oom_with_tracked_consumer_pool() {
         .await
 }
 
+/// A [`GreedyMemoryPool`] that records the most memory reserved at any time
+#[derive(Debug)]
+struct PeakMemoryPool {
+    inner: GreedyMemoryPool,
+    peak: AtomicUsize,
+}
+
+impl PeakMemoryPool {
+    fn new(pool_size: usize) -> Self {
+        Self {
+            inner: GreedyMemoryPool::new(pool_size),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+impl MemoryPool for PeakMemoryPool {
+    fn grow(&self, reservation: &MemoryReservation, additional: usize) {
+        self.inner.grow(reservation, additional);
+        self.peak.fetch_max(self.inner.reserved(), Ordering::SeqCst);
+    }
+
+    fn shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        self.inner.shrink(reservation, shrink)
+    }
+
+    fn try_grow(&self, reservation: &MemoryReservation, additional: usize) -> Result<()> {
+        self.inner.try_grow(reservation, additional)?;
+        self.peak.fetch_max(self.inner.reserved(), Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn reserved(&self) -> usize {
+        self.inner.reserved()
+    }
+}
+
 /// Run the query with the specified memory limit,
 /// and verifies the expected errors are returned
 #[derive(Clone, Debug)]
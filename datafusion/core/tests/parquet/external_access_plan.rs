@@ -179,6 +179,38 @@ async fn plan_and_filter() {
     );
 }
 
+#[tokio::test]
+async fn row_group_mask() {
+    // a mask of the row groups to scan, e.g. computed by an external index
+    let parquet_metrics = Test {
+        access_plan: Some(ParquetAccessPlan::from(vec![false, true])),
+        expected_rows: 5,
+    }
+    .run_success()
+    .await;
+    let row_groups_pruned_statistics =
+        metric_value(&parquet_metrics, "row_groups_pruned_statistics").unwrap();
+    assert_eq!(row_groups_pruned_statistics, 0);
+
+    // with a predicate only the row groups selected by both the mask and the
+    // predicate are read: row group 1 is masked out and the values "a"-"d"
+    // of row group 0 do not match
+    let parquet_metrics = TestFull {
+        access_plan: Some(ParquetAccessPlan::from(vec![true, false])),
+        expected_rows: 0,
+        predicate: Some(col("utf8").eq(lit("z"))),
+    }
+    .run()
+    .await
+    .unwrap();
+    let row_groups_pruned_statistics =
+        metric_value(&parquet_metrics, "row_groups_pruned_statistics").unwrap();
+    assert_eq!(
+        row_groups_pruned_statistics, 1,
+        "metrics : {parquet_metrics:#?}",
+    );
+}
+
 #[tokio::test]
 async fn two_selections() {
     let plans = vec![
@@ -0,0 +1,118 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests that filters above a join are pushed into the Parquet scan of the
+//! joined table and prune its row groups
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array, StringArray};
+use arrow::record_batch::RecordBatch;
+use datafusion::physical_plan::{collect, displayable};
+use datafusion::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
+use datafusion_common::Result;
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use tempfile::TempDir;
+
+use crate::parquet::utils::MetricsFinder;
+
+/// Writes a `fact` table of ids `0..400` in row groups of 100 rows and
+/// registers it along with a `dim` table of every tenth id
+async fn setup(dir: &TempDir) -> Result<SessionContext> {
+    let ids: ArrayRef = Arc::new(Int64Array::from_iter_values(0..400));
+    let fact = RecordBatch::try_from_iter(vec![("id", ids)])?;
+    let path = dir.path().join("fact.parquet");
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(100)
+        .build();
+    let mut writer =
+        ArrowWriter::try_new(std::fs::File::create(&path)?, fact.schema(), Some(props))?;
+    writer.write(&fact)?;
+    writer.close()?;
+
+    let ids: ArrayRef = Arc::new(Int64Array::from_iter_values((0..400).step_by(10)));
+    let names: ArrayRef = Arc::new(StringArray::from_iter_values(
+        (0..400).step_by(10).map(|id| format!("name{id}")),
+    ));
+    let dim = RecordBatch::try_from_iter(vec![("id", ids), ("name", names)])?;
+
+    let ctx =
+        SessionContext::new_with_config(SessionConfig::new().with_target_partitions(1));
+    ctx.register_parquet(
+        "fact",
+        path.to_str().unwrap(),
+        ParquetReadOptions::default(),
+    )
+    .await?;
+    ctx.register_batch("dim", dim)?;
+    Ok(ctx)
+}
+
+/// Runs `sql`, returning the number of rows, the number of row groups of
+/// `fact` pruned by statistics and the physical plan
+async fn run(ctx: &SessionContext, sql: &str) -> Result<(usize, usize, String)> {
+    let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+    let batches = collect(Arc::clone(&plan), ctx.task_ctx()).await?;
+    let num_rows = batches.iter().map(|b| b.num_rows()).sum();
+
+    let metrics = MetricsFinder::find_metrics(plan.as_ref()).unwrap();
+    let pruned = metrics
+        .sum_by_name("row_groups_pruned_statistics")
+        .map(|v| v.as_usize())
+        .unwrap_or(0);
+    let plan = displayable(plan.as_ref()).indent(true).to_string();
+    Ok((num_rows, pruned, plan))
+}
+
+#[tokio::test]
+async fn filter_above_join_prunes_row_groups() -> Result<()> {
+    let dir = TempDir::new()?;
+    let ctx = setup(&dir).await?;
+
+    let (num_rows, pruned, _) = run(
+        &ctx,
+        "SELECT fact.id, name FROM fact JOIN dim ON fact.id = dim.id",
+    )
+    .await?;
+    assert_eq!(num_rows, 40);
+    assert_eq!(pruned, 0);
+
+    // the predicate only references the columns of `fact`, so it is pushed
+    // below the join into the scan, which skips three of the row groups
+    let (num_rows, pruned, plan) = run(
+        &ctx,
+        "SELECT fact.id, name FROM fact JOIN dim ON fact.id = dim.id WHERE fact.id < 100",
+    )
+    .await?;
+    assert_eq!(num_rows, 10);
+    assert_eq!(pruned, 3);
+    assert!(
+        plan.contains("predicate=id@0 < 100"),
+        "unexpected plan: {plan}"
+    );
+
+    // the predicate is also pushed to the preserved side of an outer join
+    let (num_rows, pruned, _) = run(
+        &ctx,
+        "SELECT fact.id, name FROM fact LEFT JOIN dim ON fact.id = dim.id WHERE fact.id >= 300",
+    )
+    .await?;
+    assert_eq!(num_rows, 100);
+    assert_eq!(pruned, 3);
+    Ok(())
+}
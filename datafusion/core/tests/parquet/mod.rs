@@ -33,6 +33,7 @@ use arrow::{
 use chrono::{Datelike, Duration, TimeDelta};
 use datafusion::{
     datasource::{provider_as_source, TableProvider},
+    execution::options::ReadOptions,
     physical_plan::metrics::MetricsSet,
     prelude::{ParquetReadOptions, SessionConfig, SessionContext},
 };
@@ -49,10 +50,14 @@ mod external_access_plan;
 mod file_statistics;
 #[cfg(not(target_family = "windows"))]
 mod filter_pushdown;
+mod join_filter_pushdown;
+mod nested_projection;
 mod page_pruning;
 mod row_group_pruning;
+mod sample;
 mod schema;
 mod schema_coercion;
+mod sorted_files;
 mod utils;
 
 #[cfg(test)]
@@ -174,6 +179,11 @@ impl TestOutput {
         self.metric_value("page_index_rows_filtered")
     }
 
+    /// The number of files pruned by their statistics before being opened
+    fn files_pruned(&self) -> Option<usize> {
+        self.metric_value("files_pruned")
+    }
+
     fn description(&self) -> String {
         format!(
             "Input:\n{}\nQuery:\n{}\nOutput:\n{}\nMetrics:\n{}",
@@ -190,10 +200,19 @@ impl ContextWithParquet {
         Self::with_config(scenario, unit, SessionConfig::new()).await
     }
 
-    async fn with_config(
+    async fn with_config(scenario: Scenario, unit: Unit, config: SessionConfig) -> Self {
+        Self::with_file_statistics(scenario, unit, config, true).await
+    }
+
+    /// Like [`Self::with_config`], with `collect_stat` setting whether the
+    /// statistics of the file are collected when it is registered. Without
+    /// them the file can not be pruned as a whole, so the pruning of its row
+    /// groups is always evaluated
+    async fn with_file_statistics(
         scenario: Scenario,
         unit: Unit,
         mut config: SessionConfig,
+        collect_stat: bool,
     ) -> Self {
         let file = match unit {
             Unit::RowGroup(row_per_group) => {
@@ -210,7 +229,10 @@ impl ContextWithParquet {
         // now, setup a the file as a data source and run a query against it
         let ctx = SessionContext::new_with_config(config);
 
-        ctx.register_parquet("t", &parquet_path, ParquetReadOptions::default())
+        let options = ParquetReadOptions::default()
+            .to_listing_options(&ctx.copied_config(), ctx.copied_table_options())
+            .with_collect_stat(collect_stat);
+        ctx.register_listing_table("t", &parquet_path, options, None, None)
             .await
             .unwrap();
         let provider = ctx.deregister_table("t").unwrap().unwrap();
@@ -0,0 +1,150 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests that Parquet scans only read the fields of struct columns that the
+//! query accesses
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array, StringArray, StructArray};
+use arrow::datatypes::{DataType, Field};
+use arrow::record_batch::RecordBatch;
+use arrow::util::pretty::pretty_format_batches;
+use datafusion::execution::session_state::SessionStateBuilder;
+use datafusion::physical_optimizer::optimizer::PhysicalOptimizer;
+use datafusion::physical_plan::collect;
+use datafusion::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
+use datafusion_common::Result;
+use parquet::arrow::ArrowWriter;
+use tempfile::TempDir;
+
+use crate::parquet::utils::MetricsFinder;
+
+/// Writes a table `t` of 1000 rows with an `id` column and a struct column
+/// `s` of 20 string fields `f0` to `f19`, where `s.f{i}` of row `id` is
+/// `"{i}-{id}"` padded to 100 characters
+fn write_wide_struct(dir: &TempDir) -> Result<String> {
+    let ids: ArrayRef = Arc::new(Int64Array::from_iter_values(0..1000));
+    let fields: Vec<(Arc<Field>, ArrayRef)> = (0..20)
+        .map(|i| {
+            let values: ArrayRef = Arc::new(StringArray::from_iter_values(
+                (0..1000).map(|id| format!("{i}-{id:0>97}")),
+            ));
+            (
+                Arc::new(Field::new(format!("f{i}"), DataType::Utf8, false)),
+                values,
+            )
+        })
+        .collect();
+    let s: ArrayRef = Arc::new(StructArray::from(fields));
+    let batch = RecordBatch::try_from_iter(vec![("id", ids), ("s", s)])?;
+
+    let path = dir.path().join("wide.parquet");
+    let mut writer =
+        ArrowWriter::try_new(std::fs::File::create(&path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(path.to_str().unwrap().to_string())
+}
+
+/// Registers the table written by [`write_wide_struct`] in a context with or
+/// without the `NestedProjectionPushdown` rule
+async fn context(path: &str, nested_projection_pushdown: bool) -> Result<SessionContext> {
+    let rules = PhysicalOptimizer::new()
+        .rules
+        .into_iter()
+        .filter(|rule| {
+            nested_projection_pushdown || rule.name() != "NestedProjectionPushdown"
+        })
+        .collect();
+    let state = SessionStateBuilder::new()
+        .with_config(SessionConfig::new().with_target_partitions(4))
+        .with_default_features()
+        .with_physical_optimizer_rules(rules)
+        .build();
+    let ctx = SessionContext::new_with_state(state);
+    ctx.register_parquet("t", path, ParquetReadOptions::default())
+        .await?;
+    Ok(ctx)
+}
+
+/// Runs `sql`, returning the formatted results and the number of bytes the
+/// Parquet scan read
+async fn run(ctx: &SessionContext, sql: &str) -> Result<(String, usize)> {
+    let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+    let batches = collect(Arc::clone(&plan), ctx.task_ctx()).await?;
+    let metrics = MetricsFinder::find_metrics(plan.as_ref()).unwrap();
+    let bytes_scanned = metrics
+        .sum_by_name("bytes_scanned")
+        .map(|v| v.as_usize())
+        .unwrap();
+    Ok((pretty_format_batches(&batches)?.to_string(), bytes_scanned))
+}
+
+#[tokio::test]
+async fn nested_projection_reads_only_accessed_fields() -> Result<()> {
+    let dir = TempDir::new()?;
+    let path = write_wide_struct(&dir)?;
+    let narrowed = context(&path, true).await?;
+    let full = context(&path, false).await?;
+
+    for sql in [
+        "SELECT s['f3'] FROM t ORDER BY s['f3'] LIMIT 3",
+        "SELECT id, s['f3'] FROM t WHERE s['f7'] LIKE '%5' ORDER BY id LIMIT 3",
+        "SELECT s['f3'], s['f11'] FROM t WHERE id = 42",
+    ] {
+        let (expected, full_bytes) = run(&full, sql).await?;
+        let (actual, narrowed_bytes) = run(&narrowed, sql).await?;
+        assert_eq!(actual, expected, "{sql}");
+        // at most 2 of the 20 string fields are read
+        assert!(
+            narrowed_bytes * 5 < full_bytes,
+            "{sql}: read {narrowed_bytes} bytes, {full_bytes} without narrowing"
+        );
+    }
+
+    // a struct that is used as a whole is read as a whole
+    let sql = "SELECT s['f3'], s FROM t WHERE id = 42";
+    let (expected, full_bytes) = run(&full, sql).await?;
+    let (actual, narrowed_bytes) = run(&narrowed, sql).await?;
+    assert_eq!(actual, expected);
+    assert_eq!(narrowed_bytes, full_bytes);
+    Ok(())
+}
+
+#[tokio::test]
+async fn nested_projection_narrows_the_scan_schema() -> Result<()> {
+    let dir = TempDir::new()?;
+    let path = write_wide_struct(&dir)?;
+    let ctx = context(&path, true).await?;
+
+    let sql = "SELECT s['f3'] AS f3, id FROM t WHERE s['f7'] LIKE '%5'";
+    let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+    assert_eq!(plan.schema().field(0).data_type(), &DataType::Utf8);
+
+    let mut scan = &plan;
+    while let Some(child) = scan.children().first() {
+        scan = child;
+    }
+    let scan_schema = scan.schema();
+    let DataType::Struct(fields) = scan_schema.field_with_name("s")?.data_type() else {
+        panic!("unexpected scan schema {scan_schema}");
+    };
+    let names: Vec<_> = fields.iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(names, ["f3", "f7"]);
+    Ok(())
+}
@@ -33,6 +33,7 @@ struct RowGroupPruningTest {
     expected_row_group_pruned_by_statistics: Option<usize>,
     expected_row_group_matched_by_bloom_filter: Option<usize>,
     expected_row_group_pruned_by_bloom_filter: Option<usize>,
+    expected_files_pruned: Option<usize>,
     expected_results: usize,
 }
 impl RowGroupPruningTest {
@@ -46,6 +47,7 @@ impl RowGroupPruningTest {
             expected_row_group_pruned_by_statistics: None,
             expected_row_group_matched_by_bloom_filter: None,
             expected_row_group_pruned_by_bloom_filter: None,
+            expected_files_pruned: None,
             expected_results: 0,
         }
     }
@@ -92,6 +94,14 @@ impl RowGroupPruningTest {
         self
     }
 
+    // Collect the statistics of the file, so that it can be pruned as a whole
+    // before being opened, and set the expected files pruned. The row group
+    // metrics of a pruned file are not reported, as its footer is never read
+    fn with_pruned_files(mut self, pruned_files: Option<usize>) -> Self {
+        self.expected_files_pruned = pruned_files;
+        self
+    }
+
     // Set the expected rows for the test
     fn with_expected_rows(mut self, rows: usize) -> Self {
         self.expected_results = rows;
@@ -100,12 +110,24 @@ impl RowGroupPruningTest {
 
     // Execute the test with the current configuration
     async fn test_row_group_prune(self) {
-        let output = ContextWithParquet::new(self.scenario, RowGroup(5))
-            .await
-            .query(&self.query)
-            .await;
+        let output = ContextWithParquet::with_file_statistics(
+            self.scenario,
+            RowGroup(5),
+            SessionConfig::new(),
+            self.expected_files_pruned.is_some(),
+        )
+        .await
+        .query(&self.query)
+        .await;
 
         println!("{}", output.description());
+        if let Some(files_pruned) = self.expected_files_pruned {
+            assert_eq!(
+                output.files_pruned(),
+                Some(files_pruned),
+                "mismatched files_pruned"
+            );
+        }
         assert_eq!(
             output.predicate_evaluation_errors(),
             self.expected_errors,
@@ -1323,3 +1345,39 @@ async fn test_row_group_with_null_values() {
         .test_row_group_prune()
         .await;
 }
+
+#[tokio::test]
+async fn prune_files_by_statistics() {
+    // The file statistics prove that no row matches, so the file is never
+    // opened
+    RowGroupPruningTest::new()
+        .with_scenario(Scenario::Int)
+        .with_query("SELECT * FROM t where i32 in (100)")
+        .with_pruned_files(Some(1))
+        .with_expected_rows(0)
+        .test_row_group_prune()
+        .await;
+
+    RowGroupPruningTest::new()
+        .with_scenario(Scenario::WithNullValues)
+        .with_query("SELECT * FROM t WHERE \"i32\" > 7")
+        .with_pruned_files(Some(1))
+        .with_expected_rows(0)
+        .test_row_group_prune()
+        .await;
+
+    // The file may contain matching rows, so its row groups are pruned as
+    // without file statistics
+    RowGroupPruningTest::new()
+        .with_scenario(Scenario::WithNullValues)
+        .with_query("SELECT * FROM t WHERE \"i8\" <= 5")
+        .with_pruned_files(Some(0))
+        .with_expected_errors(Some(0))
+        .with_matched_by_stats(Some(1))
+        .with_pruned_by_stats(Some(2))
+        .with_expected_rows(5)
+        .with_matched_by_bloom_filter(Some(0))
+        .with_pruned_by_bloom_filter(Some(0))
+        .test_row_group_prune()
+        .await;
+}
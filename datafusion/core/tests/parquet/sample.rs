@@ -0,0 +1,134 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests that `SYSTEM` sampling of a Parquet table skips whole row groups
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array};
+use arrow::record_batch::RecordBatch;
+use datafusion::physical_plan::{collect, displayable};
+use datafusion::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
+use datafusion_common::cast::as_int64_array;
+use datafusion_common::Result;
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use tempfile::TempDir;
+
+use crate::parquet::utils::MetricsFinder;
+
+/// Writes two files of 100 row groups of 100 rows each, with the ids
+/// `0..20_000`, and registers them as the table `t`
+async fn setup(dir: &TempDir, target_partitions: usize) -> Result<SessionContext> {
+    for file in 0..2 {
+        let ids: ArrayRef = Arc::new(Int64Array::from_iter_values(
+            file * 10_000..(file + 1) * 10_000,
+        ));
+        let batch = RecordBatch::try_from_iter(vec![("id", ids)])?;
+        let path = dir.path().join(format!("{file}.parquet"));
+        if path.exists() {
+            continue;
+        }
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .build();
+        let mut writer = ArrowWriter::try_new(
+            std::fs::File::create(&path)?,
+            batch.schema(),
+            Some(props),
+        )?;
+        writer.write(&batch)?;
+        writer.close()?;
+    }
+
+    let config = SessionConfig::new().with_target_partitions(target_partitions);
+    let ctx = SessionContext::new_with_config(config);
+    ctx.register_parquet(
+        "t",
+        dir.path().to_str().unwrap(),
+        ParquetReadOptions::default(),
+    )
+    .await?;
+    Ok(ctx)
+}
+
+/// Samples `t`, returning the sorted ids, the number of row groups skipped
+/// by the sample and the physical plan
+async fn sample(
+    ctx: &SessionContext,
+    fraction: f64,
+    seed: u64,
+) -> Result<(Vec<i64>, usize, String)> {
+    let plan = ctx
+        .table("t")
+        .await?
+        .sample_system(fraction, Some(seed))?
+        .create_physical_plan()
+        .await?;
+    let batches = collect(Arc::clone(&plan), ctx.task_ctx()).await?;
+    let mut ids = vec![];
+    for batch in &batches {
+        ids.extend(as_int64_array(batch.column(0))?.values().iter().copied());
+    }
+    ids.sort_unstable();
+
+    let metrics = MetricsFinder::find_metrics(plan.as_ref()).unwrap();
+    let pruned = metrics
+        .sum_by_name("row_groups_pruned_sample")
+        .map(|v| v.as_usize())
+        .unwrap_or(0);
+    let plan = displayable(plan.as_ref()).indent(true).to_string();
+    Ok((ids, pruned, plan))
+}
+
+#[tokio::test]
+async fn system_sample_skips_row_groups() -> Result<()> {
+    let dir = TempDir::new()?;
+    let ctx = setup(&dir, 1).await?;
+
+    let (ids, pruned, plan) = sample(&ctx, 0.25, 42).await?;
+    // the sample is planned into the scan
+    assert!(
+        plan.contains("sample_fraction=0.25, sample_seed=42"),
+        "unexpected plan: {plan}"
+    );
+    assert!(!plan.contains("SampleExec"), "unexpected plan: {plan}");
+
+    // whole row groups are read or skipped
+    assert_eq!(ids.len() % 100, 0);
+    let read = ids.len() / 100;
+    assert_eq!(read + pruned, 200);
+    assert!(ids.chunks(100).all(|group| group[0] % 100 == 0));
+    // expect ~50 row groups with a standard deviation of ~6
+    assert!((25..=75).contains(&read), "read {read} row groups");
+    Ok(())
+}
+
+#[tokio::test]
+async fn system_sample_is_deterministic_with_seed() -> Result<()> {
+    let dir = TempDir::new()?;
+    let (first, _, _) = sample(&setup(&dir, 1).await?, 0.5, 7).await?;
+
+    // the row groups only depend on the seed, not on how the files are split
+    // into partitions
+    let (second, _, plan) = sample(&setup(&dir, 4).await?, 0.5, 7).await?;
+    assert_eq!(first, second, "plan: {plan}");
+
+    let (other_seed, _, _) = sample(&setup(&dir, 1).await?, 0.5, 8).await?;
+    assert_ne!(first, other_seed);
+    Ok(())
+}
@@ -0,0 +1,141 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for merging Parquet files that are each sorted on the same key
+//! into a single sorted stream
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array, StringArray};
+use arrow::compute::concat_batches;
+use arrow::record_batch::RecordBatch;
+use datafusion::datasource::physical_plan::{FileScanConfig, ParquetExec};
+use datafusion::physical_expr::expressions::col;
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
+use datafusion::physical_plan::{collect, displayable, ExecutionPlan};
+use datafusion::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
+use datafusion_common::Result;
+use datafusion_execution::object_store::ObjectStoreUrl;
+use datafusion_expr::col as logical_col;
+use parquet::arrow::ArrowWriter;
+use tempfile::TempDir;
+
+use super::schema_coercion::local_unpartitioned_file;
+
+/// Writes three files whose keys interleave, so that concatenating the
+/// files is not sorted while each file is
+fn write_sorted_files(dir: &TempDir) -> Vec<RecordBatch> {
+    (0..3)
+        .map(|i| {
+            let keys: ArrayRef =
+                Arc::new(Int64Array::from_iter_values((0..4).map(|k| k * 3 + i)));
+            let values: ArrayRef = Arc::new(StringArray::from_iter_values(
+                (0..4).map(|k| format!("file{i}-{k}")),
+            ));
+            let batch =
+                RecordBatch::try_from_iter(vec![("key", keys), ("value", values)])
+                    .unwrap();
+
+            let file =
+                std::fs::File::create(dir.path().join(format!("{i}.parquet"))).unwrap();
+            let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            batch
+        })
+        .collect()
+}
+
+fn keys(batches: &[RecordBatch]) -> Vec<i64> {
+    let batch = concat_batches(&batches[0].schema(), batches).unwrap();
+    let keys = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    keys.values().to_vec()
+}
+
+#[tokio::test]
+async fn merge_sorted_file_groups() -> Result<()> {
+    let dir = TempDir::new()?;
+    let batches = write_sorted_files(&dir);
+    let schema = batches[0].schema();
+
+    // one file per partition, each declared sorted on `key`
+    let file_groups = (0..3)
+        .map(|i| {
+            vec![local_unpartitioned_file(dir.path().join(format!("{i}.parquet"))).into()]
+        })
+        .collect();
+    let sort_keys = vec![PhysicalSortExpr {
+        expr: col("key", &schema)?,
+        options: Default::default(),
+    }];
+    let parquet_exec = ParquetExec::builder(
+        FileScanConfig::new(ObjectStoreUrl::local_filesystem(), Arc::clone(&schema))
+            .with_file_groups(file_groups)
+            .with_output_ordering(vec![sort_keys.clone()]),
+    )
+    .build_arc();
+    assert_eq!(
+        parquet_exec.properties().output_ordering(),
+        Some(sort_keys.as_slice())
+    );
+
+    let merge = Arc::new(SortPreservingMergeExec::new(sort_keys, parquet_exec));
+    let ctx = SessionContext::new();
+    let output = collect(merge, ctx.task_ctx()).await?;
+
+    assert_eq!(keys(&output), (0..12).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[tokio::test]
+async fn sql_order_by_merges_sorted_files() -> Result<()> {
+    let dir = TempDir::new()?;
+    write_sorted_files(&dir);
+
+    let ctx =
+        SessionContext::new_with_config(SessionConfig::new().with_target_partitions(3));
+    let options = ParquetReadOptions::default()
+        .file_sort_order(vec![vec![logical_col("key").sort(true, false)]]);
+    ctx.register_parquet("t", dir.path().to_str().unwrap(), options)
+        .await?;
+
+    let plan = ctx
+        .sql("SELECT key, value FROM t ORDER BY key")
+        .await?
+        .create_physical_plan()
+        .await?;
+
+    // the declared order of the files is merged rather than re-sorted
+    let plan_string = displayable(plan.as_ref()).indent(true).to_string();
+    assert!(
+        plan_string.contains("SortPreservingMergeExec"),
+        "unexpected plan: {plan_string}"
+    );
+    assert!(
+        !plan_string.contains("SortExec"),
+        "unexpected plan: {plan_string}"
+    );
+
+    let output = collect(plan, ctx.task_ctx()).await?;
+    assert_eq!(keys(&output), (0..12).collect::<Vec<_>>());
+    Ok(())
+}
@@ -295,6 +295,66 @@ async fn count_distinct_integers_aggregated_multiple_partitions() -> Result<()>
     Ok(())
 }
 
+#[tokio::test]
+async fn approx_distinct_relative_error() -> Result<()> {
+    // 1M rows over 4 partitions, each of the 500K distinct values twice
+    let num_rows: usize = 1_000_000;
+    let num_distinct: usize = 500_000;
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("i", DataType::Int64, false),
+        Field::new("s", DataType::Utf8, false),
+        Field::new("b", DataType::Binary, false),
+    ]));
+    let partitions = (0..4)
+        .map(|p| {
+            (p * num_rows / 4..(p + 1) * num_rows / 4)
+                .step_by(8192)
+                .map(|start| {
+                    let end = (start + 8192).min((p + 1) * num_rows / 4);
+                    let values = (start..end).map(|i| (i % num_distinct) as i64);
+                    let strings: StringArray =
+                        values.clone().map(|v| Some(format!("value {v}"))).collect();
+                    let binaries: BinaryArray = values
+                        .clone()
+                        .map(|v| Some(v.to_le_bytes().to_vec()))
+                        .collect();
+                    RecordBatch::try_new(
+                        Arc::clone(&schema),
+                        vec![
+                            Arc::new(Int64Array::from_iter_values(values)),
+                            Arc::new(strings),
+                            Arc::new(binaries),
+                        ],
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let table = datafusion::datasource::MemTable::try_new(schema, partitions)?;
+
+    let ctx =
+        SessionContext::new_with_config(SessionConfig::new().with_target_partitions(4));
+    ctx.register_table("t", Arc::new(table))?;
+    let results = execute(
+        &ctx,
+        "SELECT approx_distinct(i), approx_distinct(s), approx_distinct(b) FROM t",
+    )
+    .await;
+
+    // the standard error of the estimate with 2^14 registers is 0.81%,
+    // allow for three standard errors
+    let margin = 3.0 * 1.04 / (16384_f64).sqrt();
+    for estimate in &results[0] {
+        let estimate: f64 = estimate.parse().unwrap();
+        let error = (estimate - num_distinct as f64).abs() / num_distinct as f64;
+        assert!(
+            error <= margin,
+            "estimate {estimate} of {num_distinct} is off by more than {margin}"
+        );
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_accumulator_row_accumulator() -> Result<()> {
     let config = SessionConfig::new();
@@ -19,8 +19,13 @@ use super::*;
 use rstest::rstest;
 
 use datafusion::config::ConfigOptions;
+use datafusion::execution::metrics_observer::{
+    MetricsObserver, MetricsRegistry, OperatorMetrics,
+};
+use datafusion::execution::runtime_env::RuntimeEnvBuilder;
 use datafusion::physical_plan::display::DisplayableExecutionPlan;
 use datafusion::physical_plan::metrics::Timestamp;
+use futures::TryStreamExt;
 
 #[tokio::test]
 async fn explain_analyze_baseline_metrics() {
@@ -833,3 +838,110 @@ async fn csv_explain_analyze_with_statistics() {
         ", statistics=[Rows=Absent, Bytes=Absent, [(Col[0]:)]]"
     );
 }
+
+/// Records every query reported to the metrics observer
+#[derive(Debug, Default)]
+struct CollectingObserver {
+    queries: parking_lot::Mutex<Vec<Vec<OperatorMetrics>>>,
+}
+
+impl MetricsObserver for CollectingObserver {
+    fn on_query_complete(&self, operators: &[OperatorMetrics]) {
+        self.queries.lock().push(operators.to_vec());
+    }
+}
+
+#[tokio::test]
+async fn metrics_observer_receives_operator_metrics() -> Result<()> {
+    let observer = Arc::new(CollectingObserver::default());
+    let registry = Arc::new(MetricsRegistry::new());
+    let runtime = RuntimeEnvBuilder::new()
+        .with_metrics_observer(Arc::clone(&observer) as _)
+        .build()?;
+    let ctx = SessionContext::new_with_config_rt(
+        SessionConfig::new().with_target_partitions(2),
+        Arc::new(runtime),
+    );
+    test_util::register_aggregate_csv(&ctx, "t1").await?;
+    test_util::register_aggregate_csv(&ctx, "t2").await?;
+
+    let sql = "SELECT t1.c1, t2.c2 FROM t1 JOIN t2 ON t1.c9 = t2.c9";
+    let results = ctx.sql(sql).await?.collect().await?;
+    let result_rows: usize = results.iter().map(|b| b.num_rows()).sum();
+
+    let queries = observer.queries.lock().clone();
+    assert_eq!(queries.len(), 1);
+    let operators = &queries[0];
+
+    // operators are identified by their position in the plan
+    let ids: Vec<_> = operators.iter().map(|op| op.id).collect();
+    assert_eq!(ids, (0..operators.len()).collect::<Vec<_>>());
+
+    let scans: Vec<_> = operators.iter().filter(|op| op.name == "CsvExec").collect();
+    assert_eq!(scans.len(), 2);
+    for scan in scans {
+        assert_eq!(scan.value("output_rows"), Some(100));
+    }
+
+    let joins: Vec<_> = operators
+        .iter()
+        .filter(|op| op.name == "HashJoinExec")
+        .collect();
+    assert_eq!(joins.len(), 1);
+    assert_eq!(joins[0].value("output_rows"), Some(result_rows));
+
+    // the ready made registry aggregates the same metrics by operator name
+    registry.on_query_complete(operators);
+    let join_rows = registry
+        .snapshot()
+        .into_iter()
+        .find(|(name, labels, _)| {
+            name == "output_rows"
+                && labels == &[("operator".to_string(), "HashJoinExec".to_string())]
+        })
+        .map(|(_, _, value)| value);
+    assert_eq!(join_rows, Some(result_rows));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn metrics_observer_receives_streamed_query_metrics() -> Result<()> {
+    let observer = Arc::new(CollectingObserver::default());
+    let runtime = RuntimeEnvBuilder::new()
+        .with_metrics_observer(Arc::clone(&observer) as _)
+        .build()?;
+    let ctx = SessionContext::new_with_config_rt(
+        SessionConfig::new().with_target_partitions(2),
+        Arc::new(runtime),
+    );
+    ctx.sql("CREATE TABLE t(a INT) AS VALUES (1), (2), (3)")
+        .await?
+        .collect()
+        .await?;
+    observer.queries.lock().clear();
+    let sql = "SELECT a FROM t UNION ALL SELECT a FROM t";
+
+    // the metrics are reported once the stream has completed
+    let stream = ctx.sql(sql).await?.execute_stream().await?;
+    assert!(observer.queries.lock().is_empty());
+    let batches: Vec<_> = stream.try_collect().await?;
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+    assert_eq!(observer.queries.lock().len(), 1);
+
+    // or once all the partitioned streams have
+    let mut streams = ctx.sql(sql).await?.execute_stream_partitioned().await?;
+    assert_eq!(streams.len(), 2);
+    let last = streams.pop().unwrap();
+    for stream in streams {
+        let _: Vec<_> = stream.try_collect().await?;
+    }
+    assert_eq!(observer.queries.lock().len(), 1);
+    let _: Vec<_> = last.try_collect().await?;
+
+    let queries = observer.queries.lock().clone();
+    assert_eq!(queries.len(), 2);
+    let union = queries[1].iter().find(|op| op.name == "UnionExec").unwrap();
+    assert_eq!(union.id, 0);
+    Ok(())
+}
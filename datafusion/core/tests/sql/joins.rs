@@ -15,7 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use arrow::util::pretty::pretty_format_batches;
 use datafusion::datasource::stream::{FileStreamProvider, StreamConfig, StreamTable};
+use datafusion::datasource::MemTable;
+use datafusion::physical_plan::joins::HashJoinExec;
 use datafusion::test_util::register_unbounded_file_with_ordering;
 
 use super::*;
@@ -235,3 +238,286 @@ async fn join_change_in_planner_without_sort_not_allowed() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test]
+async fn join_collects_small_side_by_statistics() -> Result<()> {
+    async fn plan_and_run(config: SessionConfig) -> Result<(String, Vec<RecordBatch>)> {
+        let ctx = SessionContext::new_with_config(config);
+        ctx.sql("CREATE TABLE dim(id INT, name VARCHAR) AS VALUES (1, 'a'), (2, 'b'), (3, 'c')")
+            .await?;
+        ctx.sql(
+            "CREATE TABLE fact(dim_id INT, v INT) AS VALUES \
+             (1, 1), (2, 2), (3, 3), (1, 4), (2, 5), (3, 6), \
+             (1, 7), (2, 8), (3, 9), (1, 10), (2, 11), (4, 12)",
+        )
+        .await?;
+        let dataframe = ctx
+            .sql(
+                "SELECT name, count(*) AS n, sum(v) AS total FROM fact \
+                 JOIN dim ON fact.dim_id = dim.id GROUP BY name ORDER BY name",
+            )
+            .await?;
+        let physical_plan = dataframe.create_physical_plan().await?;
+        let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+        let batches = collect(physical_plan, ctx.task_ctx()).await?;
+        Ok((formatted, batches))
+    }
+
+    let config = SessionConfig::new()
+        .with_target_partitions(4)
+        .with_collect_statistics(true);
+
+    // the small side is collected once and shared by every probe partition
+    let (collected_plan, collected) = plan_and_run(config.clone()).await?;
+    assert_contains!(&collected_plan, "HashJoinExec: mode=CollectLeft");
+
+    // a side exceeding the thresholds is repartitioned instead
+    let config = config
+        .set_usize(
+            "datafusion.optimizer.hash_join_single_partition_threshold",
+            0,
+        )
+        .set_usize(
+            "datafusion.optimizer.hash_join_single_partition_threshold_rows",
+            0,
+        );
+    let (partitioned_plan, partitioned) = plan_and_run(config).await?;
+    assert_contains!(&partitioned_plan, "HashJoinExec: mode=Partitioned");
+
+    let expected = [
+        "+------+---+-------+",
+        "| name | n | total |",
+        "+------+---+-------+",
+        "| a    | 4 | 22    |",
+        "| b    | 4 | 26    |",
+        "| c    | 3 | 18    |",
+        "+------+---+-------+",
+    ];
+    assert_batches_eq!(expected, &collected);
+    assert_batches_eq!(expected, &partitioned);
+    Ok(())
+}
+
+#[tokio::test]
+async fn join_builds_smaller_side_by_statistics() -> Result<()> {
+    /// Returns the names of the fields of the build side of the hash join in `plan`
+    fn build_side_fields(plan: &Arc<dyn ExecutionPlan>) -> Option<Vec<String>> {
+        match plan.as_any().downcast_ref::<HashJoinExec>() {
+            Some(hash_join) => Some(
+                hash_join
+                    .left()
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .collect(),
+            ),
+            None => plan.children().into_iter().find_map(build_side_fields),
+        }
+    }
+
+    async fn plan(config: SessionConfig) -> Result<(String, Vec<String>)> {
+        let ctx = SessionContext::new_with_config(config);
+        ctx.sql("CREATE TABLE dim(id INT, name VARCHAR) AS VALUES (1, 'a'), (2, 'b'), (3, 'c')")
+            .await?;
+        let values = (0..200)
+            .map(|v| format!("({}, {v})", v % 4))
+            .collect::<Vec<_>>()
+            .join(", ");
+        ctx.sql(&format!(
+            "CREATE TABLE fact(dim_id INT, v INT) AS VALUES {values}"
+        ))
+        .await?;
+        // the large table is on the left of the join as written
+        let physical_plan = ctx
+            .sql("SELECT name, v FROM fact JOIN dim ON fact.dim_id = dim.id")
+            .await?
+            .create_physical_plan()
+            .await?;
+        let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+        let build_side = build_side_fields(&physical_plan).expect("hash join");
+        Ok((formatted, build_side))
+    }
+
+    let config = SessionConfig::new().with_target_partitions(4);
+
+    // the inputs are swapped so that the hash table is built on the smaller side
+    let (formatted, build_side) = plan(config.clone()).await?;
+    assert_contains!(&formatted, "HashJoinExec: mode=CollectLeft");
+    assert_eq!(build_side, vec!["id", "name"]);
+
+    // also when both sides are repartitioned
+    let config = config
+        .set_usize(
+            "datafusion.optimizer.hash_join_single_partition_threshold",
+            0,
+        )
+        .set_usize(
+            "datafusion.optimizer.hash_join_single_partition_threshold_rows",
+            0,
+        );
+    let (formatted, build_side) = plan(config).await?;
+    assert_contains!(&formatted, "HashJoinExec: mode=Partitioned");
+    assert_eq!(build_side, vec!["id", "name"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn join_sorted_inputs_with_sort_merge_join() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let left_path = tmp_dir.path().join("left.csv");
+    File::create(&left_path)?.write_all(b"a,b\n1,x\n2,y\n3,z\n")?;
+    let right_path = tmp_dir.path().join("right.csv");
+    File::create(&right_path)?.write_all(b"a,c\n1,p\n3,q\n3,r\n4,s\n")?;
+
+    async fn plan_and_run(
+        config: SessionConfig,
+        left_path: &str,
+        right_path: &str,
+    ) -> Result<(String, Vec<RecordBatch>)> {
+        let ctx = SessionContext::new_with_config(config);
+        let sorted_on_a =
+            CsvReadOptions::new().file_sort_order(vec![vec![col("a").sort(true, false)]]);
+        ctx.register_csv("l", left_path, sorted_on_a.clone())
+            .await?;
+        ctx.register_csv("r", right_path, sorted_on_a).await?;
+        let dataframe = ctx
+            .sql("SELECT l.a, b, c FROM l JOIN r ON l.a = r.a ORDER BY l.a, c")
+            .await?;
+        let physical_plan = dataframe.create_physical_plan().await?;
+        let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+        let batches = collect(physical_plan, ctx.task_ctx()).await?;
+        Ok((formatted, batches))
+    }
+
+    let left_path = left_path.to_str().unwrap();
+    let right_path = right_path.to_str().unwrap();
+    let expected = [
+        "+---+---+---+",
+        "| a | b | c |",
+        "+---+---+---+",
+        "| 1 | x | p |",
+        "| 3 | z | q |",
+        "| 3 | z | r |",
+        "+---+---+---+",
+    ];
+
+    for target_partitions in [1, 2] {
+        let config = SessionConfig::new().with_target_partitions(target_partitions);
+        let (hash_plan, hash_results) =
+            plan_and_run(config.clone(), left_path, right_path).await?;
+        assert_contains!(&hash_plan, "HashJoinExec");
+
+        let config = config.set_bool(
+            "datafusion.optimizer.prefer_sort_merge_join_for_sorted_inputs",
+            true,
+        );
+        let (merge_plan, merge_results) =
+            plan_and_run(config, left_path, right_path).await?;
+        assert_contains!(&merge_plan, "SortMergeJoin: join_type=Inner");
+        assert_not_contains!(&merge_plan, "HashJoinExec");
+        // the sorted inputs are merged without sorting them again
+        let (_, below_join) = merge_plan.split_once("SortMergeJoin").unwrap();
+        assert_not_contains!(below_join, "SortExec");
+        if target_partitions > 1 {
+            assert_contains!(below_join, "preserve_order=true");
+        }
+
+        assert_batches_eq!(expected, &hash_results);
+        assert_batches_eq!(expected, &merge_results);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn sort_merge_join_with_null_keys() -> Result<()> {
+    // both files are sorted on `a`, with the nulls last
+    let tmp_dir = TempDir::new().unwrap();
+    let left_path = tmp_dir.path().join("left.csv");
+    File::create(&left_path)?.write_all(b"a,b\n1,x\n2,y\n3,z\n,n\n,m\n")?;
+    let right_path = tmp_dir.path().join("right.csv");
+    File::create(&right_path)?.write_all(b"a,c\n1,p\n3,q\n3,r\n4,s\n,o\n")?;
+    let left_path = left_path.to_str().unwrap();
+    let right_path = right_path.to_str().unwrap();
+
+    async fn plan_and_run(
+        prefer_sort_merge_join: bool,
+        join_type: &str,
+        left_path: &str,
+        right_path: &str,
+    ) -> Result<(String, String)> {
+        let config = SessionConfig::new().with_target_partitions(1).set_bool(
+            "datafusion.optimizer.prefer_sort_merge_join_for_sorted_inputs",
+            prefer_sort_merge_join,
+        );
+        let ctx = SessionContext::new_with_config(config);
+        let sorted_on_a =
+            CsvReadOptions::new().file_sort_order(vec![vec![col("a").sort(true, false)]]);
+        ctx.register_csv("l", left_path, sorted_on_a.clone())
+            .await?;
+        ctx.register_csv("r", right_path, sorted_on_a).await?;
+        let sql = format!(
+            "SELECT l.a, b, r.a, c FROM l {join_type} JOIN r ON l.a = r.a \
+             ORDER BY l.a, b, r.a, c"
+        );
+        let physical_plan = ctx.sql(&sql).await?.create_physical_plan().await?;
+        let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+        let batches = collect(physical_plan, ctx.task_ctx()).await?;
+        Ok((formatted, pretty_format_batches(&batches)?.to_string()))
+    }
+
+    // the null keys on the outer sides are kept
+    for (join_type, null_key_row) in [
+        ("LEFT", "|   | n |   |   |"),
+        ("RIGHT", "|   |   |   | o |"),
+        ("FULL", "|   | m |   |   |"),
+    ] {
+        let (hash_plan, hash_results) =
+            plan_and_run(false, join_type, left_path, right_path).await?;
+        assert_contains!(&hash_plan, "HashJoinExec");
+        let (merge_plan, merge_results) =
+            plan_and_run(true, join_type, left_path, right_path).await?;
+        assert_contains!(&merge_plan, "SortMergeJoin");
+        assert_not_contains!(&merge_plan, "HashJoinExec");
+        assert_contains!(&hash_results, null_key_row);
+        assert_eq!(hash_results, merge_results, "{join_type} JOIN");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn sort_merge_join_selection_skips_unsupported_key_types() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+        true,
+    )]));
+    let keys: DictionaryArray<Int32Type> = vec!["a", "b", "b", "c"].into_iter().collect();
+    let batch = RecordBatch::try_new(Arc::clone(&schema), vec![Arc::new(keys)])?;
+    let table = MemTable::try_new(schema, vec![vec![batch]])?
+        .with_sort_order(vec![vec![col("a").sort(true, false)]]);
+    let table = Arc::new(table);
+
+    let config = SessionConfig::new().with_target_partitions(1).set_bool(
+        "datafusion.optimizer.prefer_sort_merge_join_for_sorted_inputs",
+        true,
+    );
+    let ctx = SessionContext::new_with_config(config);
+    ctx.register_table("l", Arc::clone(&table) as _)?;
+    ctx.register_table("r", table)?;
+    let dataframe = ctx
+        .sql("SELECT l.a FROM l JOIN r ON l.a = r.a ORDER BY l.a")
+        .await?;
+    let physical_plan = dataframe.create_physical_plan().await?;
+    let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+    // the sort-merge join cannot compare dictionary keys
+    assert_contains!(&formatted, "HashJoinExec");
+    assert_not_contains!(&formatted, "SortMergeJoin");
+
+    let expected = [
+        "+---+", "| a |", "+---+", "| a |", "| b |", "| b |", "| b |", "| b |", "| c |",
+        "+---+",
+    ];
+    assert_batches_eq!(expected, &collect(physical_plan, ctx.task_ctx()).await?);
+    Ok(())
+}
@@ -396,6 +396,63 @@ async fn parquet_multiple_partitions() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn parquet_date_partition_values() -> Result<()> {
+    let ctx = SessionContext::new();
+
+    register_partitioned_alltypes_parquet(
+        &ctx,
+        &[
+            "dt=2021-01-01/region=eu/file.parquet",
+            "dt=2021-01-01/region=us/file.parquet",
+            "dt=2021-01-02/region=eu/file.parquet",
+        ],
+        &[("dt", DataType::Date32), ("region", DataType::Utf8)],
+        "mirror:///",
+        "alltypes_plain.parquet",
+    )
+    .await;
+
+    // every row of a file has the partition values of its path
+    let result = ctx
+        .sql(
+            "SELECT dt, region, count(*), count(DISTINCT id) FROM t \
+             GROUP BY dt, region",
+        )
+        .await?
+        .collect()
+        .await?;
+
+    let expected = [
+        "+------------+--------+----------+----------------------+",
+        "| dt         | region | count(*) | count(DISTINCT t.id) |",
+        "+------------+--------+----------+----------------------+",
+        "| 2021-01-01 | eu     | 8        | 8                    |",
+        "| 2021-01-01 | us     | 8        | 8                    |",
+        "| 2021-01-02 | eu     | 8        | 8                    |",
+        "+------------+--------+----------+----------------------+",
+    ];
+    assert_batches_sorted_eq!(expected, &result);
+
+    let result = ctx
+        .sql("SELECT id, region FROM t WHERE dt > DATE '2021-01-01' AND id < 2")
+        .await?
+        .collect()
+        .await?;
+
+    let expected = [
+        "+----+--------+",
+        "| id | region |",
+        "+----+--------+",
+        "| 0  | eu     |",
+        "| 1  | eu     |",
+        "+----+--------+",
+    ];
+    assert_batches_sorted_eq!(expected, &result);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn parquet_multiple_nonstring_partitions() -> Result<()> {
     let ctx = SessionContext::new();
@@ -19,13 +19,27 @@
 //! hashed among the directories listed in RuntimeConfig::local_dirs.
 
 use datafusion_common::{resources_datafusion_err, DataFusionError, Result};
-use log::debug;
+use log::{debug, warn};
 use parking_lot::Mutex;
 use rand::{thread_rng, Rng};
+use std::fs::Metadata;
+use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use tempfile::{Builder, NamedTempFile, TempDir};
 
+/// Prefix of the directories created by [`DiskManager`] to hold its files
+const LOCAL_DIR_PREFIX: &str = "datafusion-";
+
+/// Prefix of the temporary files created by [`DiskManager`]
+const TMP_FILE_PREFIX: &str = "datafusion-spill-";
+
+/// Name of the file recording the id of the process that created a
+/// directory of a [`DiskManager`], and the host and PID namespace of that
+/// process, see [`owner_marker`]
+const OWNER_FILE: &str = "datafusion.pid";
+
 /// Configuration for temporary disk access
 #[derive(Debug, Clone)]
 pub enum DiskManagerConfig {
@@ -76,6 +90,9 @@ pub struct DiskManager {
     /// If `Some(vec![])` a new OS specified temporary directory will be created
     /// If `None` an error will be returned (configured not to spill)
     local_dirs: Mutex<Option<Vec<Arc<TempDir>>>>,
+    /// The specified directories in which the `local_dirs` are created,
+    /// searched by [`Self::cleanup_stale`]
+    root_dirs: Vec<PathBuf>,
 }
 
 impl DiskManager {
@@ -85,19 +102,22 @@ impl DiskManager {
             DiskManagerConfig::Existing(manager) => Ok(manager),
             DiskManagerConfig::NewOs => Ok(Arc::new(Self {
                 local_dirs: Mutex::new(Some(vec![])),
+                root_dirs: vec![],
             })),
             DiskManagerConfig::NewSpecified(conf_dirs) => {
-                let local_dirs = create_local_dirs(conf_dirs)?;
+                let local_dirs = create_local_dirs(conf_dirs.clone())?;
                 debug!(
                     "Created local dirs {:?} as DataFusion working directory",
                     local_dirs
                 );
                 Ok(Arc::new(Self {
                     local_dirs: Mutex::new(Some(local_dirs)),
+                    root_dirs: conf_dirs,
                 }))
             }
             DiskManagerConfig::Disabled => Ok(Arc::new(Self {
                 local_dirs: Mutex::new(None),
+                root_dirs: vec![],
             })),
         }
     }
@@ -126,7 +146,7 @@ impl DiskManager {
 
         // Create a temporary directory if needed
         if local_dirs.is_empty() {
-            let tempdir = tempfile::tempdir().map_err(DataFusionError::IoError)?;
+            let tempdir = create_local_dir(None)?;
 
             debug!(
                 "Created directory '{:?}' as DataFusion tempfile directory for {}",
@@ -141,10 +161,174 @@ impl DiskManager {
         Ok(RefCountedTempFile {
             parent_temp_dir: Arc::clone(&local_dirs[dir_index]),
             tempfile: Builder::new()
+                .prefix(TMP_FILE_PREFIX)
                 .tempfile_in(local_dirs[dir_index].as_ref())
                 .map_err(DataFusionError::IoError)?,
         })
     }
+
+    /// Removes the temporary files last modified more than `older_than` ago
+    /// that other disk managers left in the configured directories, e.g.
+    /// because their process did not shut down cleanly, and returns the
+    /// number of files removed.
+    ///
+    /// Only the directories specified with [`DiskManagerConfig::NewSpecified`]
+    /// are searched, so a disk manager using the OS temporary directory does
+    /// not remove anything. Within them, only the directories created by a
+    /// [`DiskManager`] whose process is known to have exited are considered,
+    /// and only the files named like those created by a [`DiskManager`] are
+    /// removed. Directories left empty are removed as well. Directories that
+    /// can not be read are skipped.
+    ///
+    /// A process is only known to have exited if it ran on the same host and
+    /// in the same PID namespace as this process, so the directories shared
+    /// with other hosts or containers are left alone. This can only be
+    /// determined on Linux: on other platforms this does nothing.
+    pub fn cleanup_stale(&self, older_than: Duration) -> Result<usize> {
+        let mut removed = 0;
+        for root in &self.root_dirs {
+            let entries = match std::fs::read_dir(root) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => {
+                    warn!("Skipping cleanup of DataFusion directory {root:?}: {e}");
+                    continue;
+                }
+            };
+            for entry in entries.flatten() {
+                let dir = entry.path();
+                if !entry.file_type().is_ok_and(|t| t.is_dir())
+                    || !has_prefix(&dir, LOCAL_DIR_PREFIX)
+                {
+                    continue;
+                }
+                match cleanup_orphaned_dir(&dir, older_than) {
+                    Ok(removed_from_dir) => removed += removed_from_dir,
+                    Err(e) => {
+                        warn!("Skipping cleanup of DataFusion directory {dir:?}: {e}")
+                    }
+                }
+            }
+        }
+        Ok(removed)
+    }
+}
+
+/// Creates a directory for the files of a [`DiskManager`] in `root`, or in
+/// the OS temporary directory if `None`, recording the owning process
+fn create_local_dir(root: Option<&Path>) -> Result<TempDir> {
+    let mut builder = Builder::new();
+    builder.prefix(LOCAL_DIR_PREFIX);
+    let dir = match root {
+        Some(root) => builder.tempdir_in(root),
+        None => builder.tempdir(),
+    }
+    .map_err(DataFusionError::IoError)?;
+    std::fs::write(
+        dir.path().join(OWNER_FILE),
+        owner_marker(std::process::id()),
+    )?;
+    Ok(dir)
+}
+
+/// The content of the [`OWNER_FILE`] of a directory created by the process
+/// `pid` of this host and PID namespace: the process id, followed by the
+/// [`pid_namespace`] on a second line if it is known
+fn owner_marker(pid: u32) -> String {
+    match pid_namespace() {
+        Some(namespace) => format!("{pid}\n{namespace}"),
+        None => pid.to_string(),
+    }
+}
+
+/// Identifies the host and PID namespace of this process, within which the
+/// process ids recorded in the [`OWNER_FILE`]s are meaningful, or `None` if
+/// they can not be determined, i.e. on platforms other than Linux
+fn pid_namespace() -> Option<String> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let host = std::fs::read_to_string("/proc/sys/kernel/hostname").ok()?;
+    let namespace = std::fs::read_link("/proc/self/ns/pid").ok()?;
+    Some(format!("{}/{}", host.trim(), namespace.to_string_lossy()))
+}
+
+/// Removes the stale temporary files from `dir` if the process that created
+/// it is known to have exited, returning the number of files removed
+fn cleanup_orphaned_dir(dir: &Path, older_than: Duration) -> Result<usize> {
+    let owner = match std::fs::read_to_string(dir.join(OWNER_FILE)) {
+        Ok(owner) => owner,
+        // directories without an owner may be in use
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e.into()),
+    };
+    let mut owner = owner.lines();
+    let pid = owner.next().and_then(|pid| pid.trim().parse().ok());
+    // the processes of other hosts or namespaces can not be checked
+    let namespace = owner.next().map(str::trim);
+    if namespace.is_none() || namespace != pid_namespace().as_deref() {
+        return Ok(0);
+    }
+    if !pid.is_some_and(process_exited) {
+        return Ok(0);
+    }
+
+    let now = SystemTime::now();
+    let is_stale = |metadata: &Metadata| -> Result<bool> {
+        // files modified in the future are not stale
+        Ok(now
+            .duration_since(metadata.modified()?)
+            .is_ok_and(|age| age >= older_than))
+    };
+
+    let mut removed = 0;
+    for file in std::fs::read_dir(dir)? {
+        let file = file?;
+        let path = file.path();
+        let metadata = file.metadata()?;
+        if metadata.is_file()
+            && has_prefix(&path, TMP_FILE_PREFIX)
+            && is_stale(&metadata)?
+        {
+            remove_if_exists(std::fs::remove_file(&path))?;
+            debug!("Removed stale DataFusion temporary file {path:?}");
+            removed += 1;
+        }
+    }
+
+    // only the owner file is left
+    let mut remaining = std::fs::read_dir(dir)?;
+    if remaining.all(|entry| entry.is_ok_and(|entry| entry.file_name() == OWNER_FILE)) {
+        remove_if_exists(std::fs::remove_file(dir.join(OWNER_FILE)))?;
+        remove_if_exists(std::fs::remove_dir(dir))?;
+    }
+    Ok(removed)
+}
+
+/// Returns true if the process `pid` of this host and PID namespace is known
+/// to have exited
+///
+/// Whether a process is running can only be determined on Linux, elsewhere
+/// every process is assumed to be running.
+fn process_exited(pid: u32) -> bool {
+    cfg!(target_os = "linux")
+        && pid != std::process::id()
+        && !Path::new("/proc").join(pid.to_string()).exists()
+}
+
+/// Returns true if the file name of `path` starts with `prefix`
+fn has_prefix(path: &Path, prefix: &str) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with(prefix))
+}
+
+/// Ignores the error of removing a file that was concurrently removed
+fn remove_if_exists(result: std::io::Result<()>) -> Result<()> {
+    match result {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
 }
 
 /// A wrapper around a [`NamedTempFile`] that also contains
@@ -176,10 +360,7 @@ fn create_local_dirs(local_dirs: Vec<PathBuf>) -> Result<Vec<Arc<TempDir>>> {
             if !std::path::Path::new(root).exists() {
                 std::fs::create_dir(root)?;
             }
-            Builder::new()
-                .prefix("datafusion-")
-                .tempdir_in(root)
-                .map_err(DataFusionError::IoError)
+            create_local_dir(Some(root))
         })
         .map(|result| result.map(Arc::new))
         .collect()
@@ -260,6 +441,100 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn cleanup_stale_files() -> Result<()> {
+        let root = TempDir::new()?;
+        let config = DiskManagerConfig::new_specified(vec![root.path().into()]);
+        let dm = DiskManager::try_new(config.clone())?;
+        let active_file = dm.create_tmp_file("Testing")?;
+        // another disk manager of this process using the same directory
+        let other_dm = DiskManager::try_new(config)?;
+        let other_active_file = other_dm.create_tmp_file("Testing")?;
+
+        let hour_ago = SystemTime::now() - Duration::from_secs(3600);
+        let create_file = |path: PathBuf, modified: SystemTime| -> Result<PathBuf> {
+            std::fs::File::create(&path)?.set_modified(modified)?;
+            Ok(path)
+        };
+        let mut exited = std::process::Command::new("true").spawn()?;
+        exited.wait()?;
+        let create_dir = |name: &str, owner: Option<String>| -> Result<PathBuf> {
+            let dir = root.path().join(name);
+            std::fs::create_dir(&dir)?;
+            if let Some(owner) = owner {
+                std::fs::write(dir.join(OWNER_FILE), owner)?;
+            }
+            Ok(dir)
+        };
+        let exited_owner = || Some(owner_marker(exited.id()));
+
+        // the directory of a disk manager whose process exited
+        let orphaned = create_dir("datafusion-orphaned", exited_owner())?;
+        let stale = create_file(orphaned.join("datafusion-spill-1"), hour_ago)?;
+        let fresh = create_file(orphaned.join("datafusion-spill-2"), SystemTime::now())?;
+        let unknown = create_file(orphaned.join("other-file"), hour_ago)?;
+
+        // a directory only holding stale files is removed once empty
+        let emptied = create_dir("datafusion-emptied", exited_owner())?;
+        let emptied_file = create_file(emptied.join("datafusion-spill-3"), hour_ago)?;
+
+        // files in directories not created by a disk manager are kept
+        let other_dir = create_dir("other-dir", exited_owner())?;
+        let other = create_file(other_dir.join("datafusion-spill-4"), hour_ago)?;
+
+        // files in directories without a known owner are kept
+        let unowned = create_dir("datafusion-unowned", None)?;
+        let unowned_file = create_file(unowned.join("datafusion-spill-5"), hour_ago)?;
+
+        // and so are the files of processes of other hosts or namespaces, or
+        // of an unknown namespace, whose ids may be those of exited processes
+        // here
+        let mut foreign_files = vec![];
+        for (name, owner) in [
+            (
+                "datafusion-other-host",
+                format!("{}\nother-host/pid:[1]", exited.id()),
+            ),
+            ("datafusion-no-namespace", exited.id().to_string()),
+        ] {
+            let dir = create_dir(name, Some(owner))?;
+            foreign_files.push(create_file(dir.join("datafusion-spill-6"), hour_ago)?);
+        }
+
+        // files of running disk managers are kept however old they are
+        for file in [&active_file, &other_active_file] {
+            std::fs::File::options()
+                .write(true)
+                .open(file.path())?
+                .set_modified(hour_ago)?;
+        }
+
+        assert_eq!(dm.cleanup_stale(Duration::from_secs(60))?, 2);
+        assert!(!stale.exists());
+        assert!(!emptied_file.exists());
+        assert!(!emptied.exists());
+        assert!(fresh.exists());
+        assert!(unknown.exists());
+        assert!(other.exists());
+        assert!(unowned_file.exists());
+        assert!(foreign_files.iter().all(|file| file.exists()));
+        assert!(active_file.path().exists());
+        assert!(other_active_file.path().exists());
+
+        // nothing is left to remove
+        assert_eq!(dm.cleanup_stale(Duration::from_secs(60))?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn cleanup_stale_ignores_os_temp_dir() -> Result<()> {
+        let dm = DiskManager::try_new(DiskManagerConfig::NewOs)?;
+        assert!(dm.root_dirs.is_empty());
+        assert_eq!(dm.cleanup_stale(Duration::ZERO)?, 0);
+        Ok(())
+    }
+
     /// Asserts that `file_path` is found anywhere in any of `dir` directories
     fn assert_path_in_dirs<'a>(
         file_path: &'a Path,
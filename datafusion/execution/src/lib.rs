@@ -23,6 +23,7 @@ pub mod cache;
 pub mod config;
 pub mod disk_manager;
 pub mod memory_pool;
+pub mod metrics_observer;
 pub mod object_store;
 pub mod runtime_env;
 mod stream;
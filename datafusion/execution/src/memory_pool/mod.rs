@@ -75,10 +75,11 @@ pub use pool::*;
 /// However, mDataFusion comes with the following simple memory pool implementations that
 /// handle many common cases:
 ///
-/// * [`UnboundedMemoryPool`]: no memory limits (the default)
+/// * [`UnboundedMemoryPool`]: no memory limits
 ///
 /// * [`GreedyMemoryPool`]: Limits memory usage to a fixed size using a "first
-///   come first served" policy
+///   come first served" policy (the default, limited to a fraction of the
+///   memory of the system)
 ///
 /// * [`FairSpillPool`]: Limits memory usage to a fixed size, allocating memory
 ///   to all spilling operators fairly
@@ -108,6 +109,28 @@ pub trait MemoryPool: Send + Sync + std::fmt::Debug {
 
     /// Return the total amount of memory reserved
     fn reserved(&self) -> usize;
+
+    /// Return the maximum amount of memory that can be reserved, or `None`
+    /// if this pool does not limit memory use
+    fn pool_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Return the amount of memory that can still be reserved, or `None` if
+    /// this pool does not limit memory use
+    fn available(&self) -> Option<usize> {
+        self.pool_size()
+            .map(|pool_size| pool_size.saturating_sub(self.reserved()))
+    }
+
+    /// Return the registered [`MemoryConsumer`]s with the bytes they have
+    /// reserved across their reservations, from the largest to the smallest.
+    ///
+    /// Empty if this pool does not track its consumers, see
+    /// [`TrackConsumersPool`]
+    fn consumers(&self) -> Vec<(MemoryConsumer, usize)> {
+        vec![]
+    }
 }
 
 /// A memory consumer is a named allocation traced by a particular
@@ -21,6 +21,7 @@ use hashbrown::HashMap;
 use log::debug;
 use parking_lot::Mutex;
 use std::{
+    fmt::{self, Display},
     num::NonZeroUsize,
     sync::atomic::{AtomicU64, AtomicUsize, Ordering},
 };
@@ -100,6 +101,10 @@ impl MemoryPool for GreedyMemoryPool {
     fn reserved(&self) -> usize {
         self.used.load(Ordering::Relaxed)
     }
+
+    fn pool_size(&self) -> Option<usize> {
+        Some(self.pool_size)
+    }
 }
 
 /// A [`MemoryPool`] that prevents spillable reservations from using more than
@@ -233,6 +238,10 @@ impl MemoryPool for FairSpillPool {
         let state = self.state.lock();
         state.spillable + state.unspillable
     }
+
+    fn pool_size(&self) -> Option<usize> {
+        Some(self.pool_size)
+    }
 }
 
 /// Constructs a resources error based upon the individual [`MemoryReservation`].
@@ -281,7 +290,7 @@ impl<I: MemoryPool> TrackConsumersPool<I> {
     /// which have the same name.
     ///
     /// This is very tied to the implementation of the memory consumer.
-    fn has_multiple_consumers(&self, name: &String) -> bool {
+    fn has_multiple_consumers(&self, name: &str) -> bool {
         let consumer = MemoryConsumer::new(name);
         let consumer_with_spill = consumer.clone().with_can_spill(true);
         let guard = self.tracked_consumers.lock();
@@ -290,22 +299,13 @@ impl<I: MemoryPool> TrackConsumersPool<I> {
 
     /// The top consumers in a report string.
     pub fn report_top(&self, top: usize) -> String {
-        let mut consumers = self
-            .tracked_consumers
-            .lock()
-            .iter()
-            .map(|(consumer, reserved)| {
-                (
-                    (consumer.name().to_owned(), consumer.can_spill()),
-                    reserved.load(Ordering::Acquire),
-                )
-            })
-            .collect::<Vec<_>>();
-        consumers.sort_by(|a, b| b.1.cmp(&a.1)); // inverse ordering
+        let consumers = self.consumers();
 
         consumers[0..std::cmp::min(top, consumers.len())]
             .iter()
-            .map(|((name, can_spill), size)| {
+            .map(|(consumer, size)| {
+                let name = consumer.name();
+                let can_spill = consumer.can_spill();
                 if self.has_multiple_consumers(name) {
                     format!("{name}(can_spill={}) consumed {:?} bytes", can_spill, size)
                 } else {
@@ -386,6 +386,47 @@ impl<I: MemoryPool> MemoryPool for TrackConsumersPool<I> {
     fn reserved(&self) -> usize {
         self.inner.reserved()
     }
+
+    fn pool_size(&self) -> Option<usize> {
+        self.inner.pool_size()
+    }
+
+    fn consumers(&self) -> Vec<(MemoryConsumer, usize)> {
+        let mut consumers = self
+            .tracked_consumers
+            .lock()
+            .iter()
+            .map(|(consumer, reserved)| {
+                (consumer.clone(), reserved.load(Ordering::Acquire) as usize)
+            })
+            .collect::<Vec<_>>();
+        // inverse ordering, ties broken by name for a stable report
+        consumers.sort_by(|(a, a_size), (b, b_size)| {
+            b_size
+                .cmp(a_size)
+                .then_with(|| a.name().cmp(b.name()))
+                .then_with(|| a.can_spill().cmp(&b.can_spill()))
+        });
+        consumers
+    }
+}
+
+/// Summarizes the memory reserved in the pool and by each of its consumers,
+/// e.g. `50 of 100 bytes reserved: r1 consumed 30 bytes, r2 consumed 20 bytes`
+impl<I: MemoryPool> Display for TrackConsumersPool<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.pool_size() {
+            Some(pool_size) => {
+                write!(f, "{} of {pool_size} bytes reserved", self.reserved())?
+            }
+            None => write!(f, "{} bytes reserved", self.reserved())?,
+        }
+        let report = self.report_top(usize::MAX);
+        if !report.is_empty() {
+            write!(f, ": {report}")?;
+        }
+        Ok(())
+    }
 }
 
 fn provide_top_memory_consumers_to_error_msg(
@@ -513,6 +554,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tracked_consumers_pool_accounting() {
+        let pool = Arc::new(TrackConsumersPool::new(
+            GreedyMemoryPool::new(100),
+            NonZeroUsize::new(3).unwrap(),
+        ));
+        let dyn_pool: Arc<dyn MemoryPool> = Arc::clone(&pool) as _;
+        assert_eq!(pool.to_string(), "0 of 100 bytes reserved");
+
+        let mut r1 = MemoryConsumer::new("r1").register(&dyn_pool);
+        r1.grow(30);
+        let mut r2 = MemoryConsumer::new("r2")
+            .with_can_spill(true)
+            .register(&dyn_pool);
+        r2.try_grow(20).unwrap();
+
+        assert_eq!(pool.reserved(), 50);
+        assert_eq!(pool.pool_size(), Some(100));
+        assert_eq!(pool.available(), Some(50));
+        assert_eq!(
+            dyn_pool.consumers(),
+            vec![
+                (MemoryConsumer::new("r1"), 30),
+                (MemoryConsumer::new("r2").with_can_spill(true), 20)
+            ]
+        );
+        assert_eq!(
+            pool.to_string(),
+            "50 of 100 bytes reserved: r1 consumed 30 bytes, r2 consumed 20 bytes"
+        );
+
+        // dropping a reservation releases its memory and unregisters its consumer
+        drop(r1);
+        assert_eq!(pool.reserved(), 20);
+        assert_eq!(pool.available(), Some(80));
+        assert_eq!(
+            pool.consumers(),
+            vec![(MemoryConsumer::new("r2").with_can_spill(true), 20)]
+        );
+
+        // an unbounded pool has no limit
+        let pool = TrackConsumersPool::new(
+            UnboundedMemoryPool::default(),
+            NonZeroUsize::new(3).unwrap(),
+        );
+        assert_eq!(pool.pool_size(), None);
+        assert_eq!(pool.available(), None);
+        assert_eq!(pool.to_string(), "0 bytes reserved");
+    }
+
     #[test]
     fn test_tracked_consumers_pool_register() {
         let pool: Arc<dyn MemoryPool> = Arc::new(TrackConsumersPool::new(
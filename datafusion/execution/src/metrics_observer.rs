@@ -0,0 +1,307 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`MetricsObserver`] for exporting execution metrics to monitoring
+//! systems such as Prometheus or StatsD
+
+use crate::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
+use datafusion_common::Result;
+use hashbrown::HashMap;
+use parking_lot::Mutex;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Receives structured execution events, so that services embedding
+/// DataFusion can export them without parsing `EXPLAIN ANALYZE` output.
+///
+/// An observer is registered with
+/// [`RuntimeEnvBuilder::with_metrics_observer`](crate::runtime_env::RuntimeEnvBuilder::with_metrics_observer).
+/// When no observer is registered, none of these events are generated.
+///
+/// All methods have empty default implementations. They may be called
+/// concurrently from multiple threads and should return quickly.
+///
+/// See [`MetricsRegistry`] for an observer that aggregates the events.
+pub trait MetricsObserver: Debug + Send + Sync {
+    /// Called when a query has been run to completion with the metrics of
+    /// each operator of its plan
+    fn on_query_complete(&self, _operators: &[OperatorMetrics]) {}
+
+    /// Called each time the memory reserved from the memory pool reaches a
+    /// new maximum, with the consumer whose reservation grew
+    fn on_memory_high_water_mark(&self, _consumer: &str, _reserved: usize) {}
+
+    /// Called each time an operator spills data to disk
+    fn on_spill(&self, _event: &SpillEvent) {}
+}
+
+/// The metrics of a single operator of an execution plan
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperatorMetrics {
+    /// Identifies the operator within its plan: the position of the
+    /// operator in a pre-order traversal of the plan, starting at `0` for
+    /// the root
+    pub id: usize,
+    /// Name of the operator, such as `HashJoinExec`
+    pub name: String,
+    /// The metrics of the operator, summed across partitions
+    pub metrics: Vec<MetricSample>,
+}
+
+impl OperatorMetrics {
+    /// Return the value of the metric named `name`, if any
+    pub fn value(&self, name: &str) -> Option<usize> {
+        self.metrics
+            .iter()
+            .find(|sample| sample.name == name)
+            .map(|sample| sample.value)
+    }
+}
+
+/// `(name, value)` labels further describing a metric
+pub type MetricLabels = Vec<(String, String)>;
+
+/// A single named metric value with its labels
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetricSample {
+    /// Name of the metric, such as `output_rows`
+    pub name: String,
+    /// `(name, value)` labels further describing the metric
+    pub labels: MetricLabels,
+    /// Value of the metric. Times are reported in nanoseconds
+    pub value: usize,
+}
+
+/// Describes data spilled to disk by an operator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpillEvent {
+    /// Name of the memory consumer that spilled, such as `ExternalSorter[0]`
+    pub consumer: String,
+    /// Number of rows written to disk
+    pub rows: usize,
+    /// Size in bytes of the in-memory data that was spilled
+    pub bytes: usize,
+}
+
+/// A [`MetricsObserver`] that aggregates all events into a thread-safe
+/// registry that can be exported with [`MetricsRegistry::snapshot`].
+///
+/// * Operator metrics are summed across queries, labelled by `operator`
+/// * `memory_high_water_mark_bytes` records the largest memory reservation
+/// * `spill_count`, `spilled_rows` and `spilled_bytes` are summed,
+///   labelled by `consumer`
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    values: Mutex<HashMap<(String, MetricLabels), usize>>,
+}
+
+impl MetricsRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the current value of every metric as
+    /// `(name, labels, value)`, sorted by name and labels
+    pub fn snapshot(&self) -> Vec<(String, MetricLabels, usize)> {
+        let mut snapshot: Vec<_> = self
+            .values
+            .lock()
+            .iter()
+            .map(|((name, labels), value)| (name.clone(), labels.clone(), *value))
+            .collect();
+        snapshot.sort_unstable();
+        snapshot
+    }
+
+    fn add(&self, name: &str, labels: MetricLabels, value: usize) {
+        *self
+            .values
+            .lock()
+            .entry((name.to_string(), labels))
+            .or_default() += value;
+    }
+
+    fn set_max(&self, name: &str, labels: MetricLabels, value: usize) {
+        let mut values = self.values.lock();
+        let current = values.entry((name.to_string(), labels)).or_default();
+        *current = value.max(*current);
+    }
+}
+
+impl MetricsObserver for MetricsRegistry {
+    fn on_query_complete(&self, operators: &[OperatorMetrics]) {
+        for operator in operators {
+            for sample in &operator.metrics {
+                let mut labels = vec![("operator".to_string(), operator.name.clone())];
+                labels.extend(sample.labels.iter().cloned());
+                self.add(&sample.name, labels, sample.value);
+            }
+        }
+    }
+
+    fn on_memory_high_water_mark(&self, _consumer: &str, reserved: usize) {
+        self.set_max("memory_high_water_mark_bytes", vec![], reserved);
+    }
+
+    fn on_spill(&self, event: &SpillEvent) {
+        let labels = vec![("consumer".to_string(), event.consumer.clone())];
+        self.add("spill_count", labels.clone(), 1);
+        self.add("spilled_rows", labels.clone(), event.rows);
+        self.add("spilled_bytes", labels, event.bytes);
+    }
+}
+
+/// A [`MemoryPool`] that reports new high-water marks of its inner pool
+/// to a [`MetricsObserver`]
+#[derive(Debug)]
+pub(crate) struct ObservedMemoryPool {
+    inner: Arc<dyn MemoryPool>,
+    observer: Arc<dyn MetricsObserver>,
+    high_water_mark: AtomicUsize,
+}
+
+impl ObservedMemoryPool {
+    pub(crate) fn new(
+        inner: Arc<dyn MemoryPool>,
+        observer: Arc<dyn MetricsObserver>,
+    ) -> Self {
+        Self {
+            inner,
+            observer,
+            high_water_mark: AtomicUsize::new(0),
+        }
+    }
+
+    fn observe(&self, reservation: &MemoryReservation) {
+        let reserved = self.inner.reserved();
+        if reserved > self.high_water_mark.fetch_max(reserved, Ordering::Relaxed) {
+            self.observer
+                .on_memory_high_water_mark(reservation.consumer().name(), reserved);
+        }
+    }
+}
+
+impl MemoryPool for ObservedMemoryPool {
+    fn register(&self, consumer: &MemoryConsumer) {
+        self.inner.register(consumer)
+    }
+
+    fn unregister(&self, consumer: &MemoryConsumer) {
+        self.inner.unregister(consumer)
+    }
+
+    fn grow(&self, reservation: &MemoryReservation, additional: usize) {
+        self.inner.grow(reservation, additional);
+        self.observe(reservation);
+    }
+
+    fn shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        self.inner.shrink(reservation, shrink)
+    }
+
+    fn try_grow(&self, reservation: &MemoryReservation, additional: usize) -> Result<()> {
+        self.inner.try_grow(reservation, additional)?;
+        self.observe(reservation);
+        Ok(())
+    }
+
+    fn reserved(&self) -> usize {
+        self.inner.reserved()
+    }
+
+    fn pool_size(&self) -> Option<usize> {
+        self.inner.pool_size()
+    }
+
+    fn consumers(&self) -> Vec<(MemoryConsumer, usize)> {
+        self.inner.consumers()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::memory_pool::{GreedyMemoryPool, TrackConsumersPool};
+
+    #[test]
+    fn registry_aggregates_events() {
+        let registry = Arc::new(MetricsRegistry::new());
+        let pool: Arc<dyn MemoryPool> = Arc::new(ObservedMemoryPool::new(
+            Arc::new(GreedyMemoryPool::new(100)),
+            Arc::clone(&registry) as _,
+        ));
+
+        let mut r1 = MemoryConsumer::new("r1").register(&pool);
+        r1.grow(30);
+        r1.try_grow(20).unwrap();
+        r1.shrink(40);
+        r1.grow(10);
+        assert!(r1.try_grow(100).is_err());
+
+        let operator = OperatorMetrics {
+            id: 0,
+            name: "ProjectionExec".to_string(),
+            metrics: vec![MetricSample {
+                name: "output_rows".to_string(),
+                labels: vec![],
+                value: 7,
+            }],
+        };
+        registry.on_query_complete(&[operator.clone()]);
+        registry.on_query_complete(&[operator]);
+
+        let spill = SpillEvent {
+            consumer: "ExternalSorter[0]".to_string(),
+            rows: 3,
+            bytes: 24,
+        };
+        registry.on_spill(&spill);
+        registry.on_spill(&spill);
+
+        let operator_label = vec![("operator".to_string(), "ProjectionExec".to_string())];
+        let consumer_label =
+            vec![("consumer".to_string(), "ExternalSorter[0]".to_string())];
+        assert_eq!(
+            registry.snapshot(),
+            vec![
+                ("memory_high_water_mark_bytes".to_string(), vec![], 50),
+                ("output_rows".to_string(), operator_label, 14),
+                ("spill_count".to_string(), consumer_label.clone(), 2),
+                ("spilled_bytes".to_string(), consumer_label.clone(), 48),
+                ("spilled_rows".to_string(), consumer_label, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn observed_pool_forwards_consumers() {
+        let pool: Arc<dyn MemoryPool> = Arc::new(ObservedMemoryPool::new(
+            Arc::new(TrackConsumersPool::new(
+                GreedyMemoryPool::new(100),
+                NonZeroUsize::new(3).unwrap(),
+            )),
+            Arc::new(MetricsRegistry::new()),
+        ));
+        let mut r1 = MemoryConsumer::new("r1").register(&pool);
+        r1.grow(30);
+        assert_eq!(pool.consumers(), vec![(MemoryConsumer::new("r1"), 30)]);
+    }
+}
@@ -23,11 +23,12 @@ use crate::{
     memory_pool::{
         GreedyMemoryPool, MemoryPool, TrackConsumersPool, UnboundedMemoryPool,
     },
+    metrics_observer::{MetricsObserver, ObservedMemoryPool},
     object_store::{DefaultObjectStoreRegistry, ObjectStoreRegistry},
 };
 
 use crate::cache::cache_manager::{CacheManager, CacheManagerConfig};
-use datafusion_common::{DataFusionError, Result};
+use datafusion_common::{config_err, DataFusionError, Result};
 use object_store::ObjectStore;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -57,6 +58,8 @@ pub struct RuntimeEnv {
     pub cache_manager: Arc<CacheManager>,
     /// Object Store Registry
     pub object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    /// Receives execution metrics and events, if any
+    pub metrics_observer: Option<Arc<dyn MetricsObserver>>,
 }
 
 impl Debug for RuntimeEnv {
@@ -68,22 +71,7 @@ impl Debug for RuntimeEnv {
 impl RuntimeEnv {
     /// Create env based on configuration
     pub fn new(config: RuntimeConfig) -> Result<Self> {
-        let RuntimeConfig {
-            memory_pool,
-            disk_manager,
-            cache_manager,
-            object_store_registry,
-        } = config;
-
-        let memory_pool =
-            memory_pool.unwrap_or_else(|| Arc::new(UnboundedMemoryPool::default()));
-
-        Ok(Self {
-            memory_pool,
-            disk_manager: DiskManager::try_new(disk_manager)?,
-            cache_manager: CacheManager::try_new(&cache_manager)?,
-            object_store_registry,
-        })
+        config.build()
     }
 
     /// Registers a custom `ObjectStore` to be used with a specific url.
@@ -151,6 +139,10 @@ impl Default for RuntimeEnv {
     }
 }
 
+/// The default fraction of the memory of the system that the memory pool of
+/// a [`RuntimeEnv`] is limited to
+pub const DEFAULT_SYSTEM_MEMORY_FRACTION: f64 = 0.8;
+
 /// Please see: <https://github.com/apache/datafusion/issues/12156>
 /// This a type alias for backwards compatibility.
 pub type RuntimeConfig = RuntimeEnvBuilder;
@@ -162,12 +154,20 @@ pub struct RuntimeEnvBuilder {
     pub disk_manager: DiskManagerConfig,
     /// [`MemoryPool`] from which to allocate memory
     ///
-    /// Defaults to using an [`UnboundedMemoryPool`] if `None`
+    /// Defaults to a [`GreedyMemoryPool`] limited to `system_memory_fraction`
+    /// of the memory of the system if `None`, or to an
+    /// [`UnboundedMemoryPool`] if the memory of the system cannot be
+    /// determined
     pub memory_pool: Option<Arc<dyn MemoryPool>>,
+    /// Fraction of the memory of the system the default memory pool is
+    /// limited to, in `(0, 1]`
+    pub system_memory_fraction: f64,
     /// CacheManager to manage cache data
     pub cache_manager: CacheManagerConfig,
     /// ObjectStoreRegistry to get object store based on url
     pub object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    /// [`MetricsObserver`] to report execution metrics and events to
+    pub metrics_observer: Option<Arc<dyn MetricsObserver>>,
 }
 
 impl Default for RuntimeEnvBuilder {
@@ -182,8 +182,10 @@ impl RuntimeEnvBuilder {
         Self {
             disk_manager: Default::default(),
             memory_pool: Default::default(),
+            system_memory_fraction: DEFAULT_SYSTEM_MEMORY_FRACTION,
             cache_manager: Default::default(),
             object_store_registry: Arc::new(DefaultObjectStoreRegistry::default()),
+            metrics_observer: None,
         }
     }
 
@@ -199,6 +201,23 @@ impl RuntimeEnvBuilder {
         self
     }
 
+    /// Limit the default memory pool to `system_memory_fraction` of the
+    /// memory of the system, instead of [`DEFAULT_SYSTEM_MEMORY_FRACTION`].
+    /// [`Self::build`] fails if the fraction is not in `(0, 1]`.
+    ///
+    /// The default memory pool belongs to the built [`RuntimeEnv`]: every
+    /// runtime built without a memory pool has its own, so several of them in
+    /// one process may together use more than the fraction. To limit their
+    /// memory together, pass the same pool to all of them with
+    /// [`Self::with_memory_pool`].
+    ///
+    /// This has no effect if a memory pool is specified, e.g. with
+    /// [`Self::with_memory_pool`] or [`Self::with_memory_limit`]
+    pub fn with_system_memory_fraction(mut self, system_memory_fraction: f64) -> Self {
+        self.system_memory_fraction = system_memory_fraction;
+        self
+    }
+
     /// Customize cache policy
     pub fn with_cache_manager(mut self, cache_manager: CacheManagerConfig) -> Self {
         self.cache_manager = cache_manager;
@@ -214,6 +233,16 @@ impl RuntimeEnvBuilder {
         self
     }
 
+    /// Report query metrics, memory high-water marks and spills to
+    /// `metrics_observer`
+    pub fn with_metrics_observer(
+        mut self,
+        metrics_observer: Arc<dyn MetricsObserver>,
+    ) -> Self {
+        self.metrics_observer = Some(metrics_observer);
+        self
+    }
+
     /// Specify the total memory to use while running the DataFusion
     /// plan to `max_memory * memory_fraction` in bytes.
     ///
@@ -235,15 +264,155 @@ impl RuntimeEnvBuilder {
 
     /// Build a RuntimeEnv
     pub fn build(self) -> Result<RuntimeEnv> {
-        let memory_pool = self
-            .memory_pool
-            .unwrap_or_else(|| Arc::new(UnboundedMemoryPool::default()));
+        let system_memory_fraction = self.system_memory_fraction;
+        if !(system_memory_fraction > 0.0 && system_memory_fraction <= 1.0) {
+            return config_err!(
+                "system_memory_fraction must be in (0, 1], got {system_memory_fraction}"
+            );
+        }
+        // The default pool is a plain GreedyMemoryPool: a TrackConsumersPool
+        // would take a global lock on every reservation change
+        let mut memory_pool =
+            self.memory_pool
+                .unwrap_or_else(|| match total_system_memory() {
+                    Some(max_memory) => {
+                        let pool_size =
+                            (max_memory as f64 * system_memory_fraction) as usize;
+                        Arc::new(GreedyMemoryPool::new(pool_size))
+                    }
+                    None => Arc::new(UnboundedMemoryPool::default()),
+                });
+        if let Some(observer) = &self.metrics_observer {
+            memory_pool =
+                Arc::new(ObservedMemoryPool::new(memory_pool, Arc::clone(observer)));
+        }
 
         Ok(RuntimeEnv {
             memory_pool,
             disk_manager: DiskManager::try_new(self.disk_manager)?,
             cache_manager: CacheManager::try_new(&self.cache_manager)?,
             object_store_registry: self.object_store_registry,
+            metrics_observer: self.metrics_observer,
         })
     }
 }
+
+/// Returns the memory available to this process in bytes: the total memory
+/// of the system, or the memory limit of its cgroup if lower.
+///
+/// Returns `None` if the memory cannot be determined
+#[cfg(target_os = "linux")]
+fn total_system_memory() -> Option<usize> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let total_kb = meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemTotal:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+    let total = total_kb.checked_mul(1024)?;
+
+    // cgroup v2 and v1 limits, which are not numbers (v2) or very large (v1)
+    // when unlimited
+    let cgroup_limit = [
+        "/sys/fs/cgroup/memory.max",
+        "/sys/fs/cgroup/memory/memory.limit_in_bytes",
+    ]
+    .iter()
+    .find_map(|path| {
+        std::fs::read_to_string(path)
+            .ok()?
+            .trim()
+            .parse::<usize>()
+            .ok()
+    });
+
+    Some(cgroup_limit.map_or(total, |limit| limit.min(total)))
+}
+
+/// Returns the memory available to this process in bytes.
+///
+/// Returns `None` as the memory cannot be determined on this platform
+#[cfg(not(target_os = "linux"))]
+fn total_system_memory() -> Option<usize> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_pool::MemoryConsumer;
+
+    #[test]
+    fn default_memory_pool() {
+        let runtime = RuntimeEnvBuilder::new().build().unwrap();
+        let expected = total_system_memory().map(|max_memory| {
+            (max_memory as f64 * DEFAULT_SYSTEM_MEMORY_FRACTION) as usize
+        });
+        assert_eq!(runtime.memory_pool.pool_size(), expected);
+
+        let runtime = RuntimeEnvBuilder::new()
+            .with_system_memory_fraction(0.5)
+            .build()
+            .unwrap();
+        let expected =
+            total_system_memory().map(|max_memory| (max_memory as f64 * 0.5) as usize);
+        assert_eq!(runtime.memory_pool.pool_size(), expected);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn default_memory_pool_is_limited() {
+        let runtime = RuntimeEnv::default();
+        let pool_size = runtime.memory_pool.pool_size();
+        assert!(matches!(pool_size, Some(size) if size > 0 && size < usize::MAX));
+
+        // the pool refuses reservations beyond its size
+        let pool_size = pool_size.unwrap();
+        let mut reservation = MemoryConsumer::new("test").register(&runtime.memory_pool);
+        reservation.try_grow(pool_size).unwrap();
+        let err = reservation.try_grow(1).unwrap_err();
+        assert!(
+            matches!(err, DataFusionError::ResourcesExhausted(_)),
+            "unexpected error: {err}"
+        );
+        reservation.free();
+        reservation.try_grow(1).unwrap();
+    }
+
+    #[test]
+    fn invalid_system_memory_fraction() {
+        for fraction in [0.0, -0.5, 1.5, f64::NAN] {
+            let err = RuntimeEnvBuilder::new()
+                .with_system_memory_fraction(fraction)
+                .build()
+                .unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("system_memory_fraction must be in (0, 1]"),
+                "unexpected error: {err}"
+            );
+        }
+        RuntimeEnvBuilder::new()
+            .with_system_memory_fraction(1.0)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn explicit_memory_limit() {
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_limit(1000, 0.5)
+            .build()
+            .unwrap();
+        assert_eq!(runtime.memory_pool.pool_size(), Some(500));
+
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_pool(Arc::new(UnboundedMemoryPool::default()))
+            .build()
+            .unwrap();
+        assert_eq!(runtime.memory_pool.pool_size(), None);
+    }
+}
@@ -17,11 +17,12 @@
 
 //! [`ColumnarValue`] represents the result of evaluating an expression.
 
-use arrow::array::ArrayRef;
 use arrow::array::NullArray;
+use arrow::array::{ArrayRef, AsArray, TimestampNanosecondBuilder};
 use arrow::compute::{kernels, CastOptions};
 use arrow::datatypes::{DataType, TimeUnit};
 use datafusion_common::format::DEFAULT_CAST_OPTIONS;
+use datafusion_common::parsers::string_to_timestamp_nanos_with_fallback;
 use datafusion_common::{internal_err, Result, ScalarValue};
 use std::sync::Arc;
 
@@ -191,9 +192,11 @@ impl ColumnarValue {
     ) -> Result<ColumnarValue> {
         let cast_options = cast_options.cloned().unwrap_or(DEFAULT_CAST_OPTIONS);
         match self {
-            ColumnarValue::Array(array) => Ok(ColumnarValue::Array(
-                kernels::cast::cast_with_options(array, cast_type, &cast_options)?,
-            )),
+            ColumnarValue::Array(array) => Ok(ColumnarValue::Array(cast_array(
+                array,
+                cast_type,
+                &cast_options,
+            )?)),
             ColumnarValue::Scalar(scalar) => {
                 let scalar_array =
                     if cast_type == &DataType::Timestamp(TimeUnit::Nanosecond, None) {
@@ -208,11 +211,7 @@ impl ColumnarValue {
                     } else {
                         scalar.to_array()?
                     };
-                let cast_array = kernels::cast::cast_with_options(
-                    &scalar_array,
-                    cast_type,
-                    &cast_options,
-                )?;
+                let cast_array = cast_array(&scalar_array, cast_type, &cast_options)?;
                 let cast_scalar = ScalarValue::try_from_array(&cast_array, 0)?;
                 Ok(ColumnarValue::Scalar(cast_scalar))
             }
@@ -220,9 +219,114 @@ impl ColumnarValue {
     }
 }
 
+/// Casts `array` to `cast_type` with the arrow cast kernel.
+///
+/// Strings that the kernel cannot cast to a timestamp without time zone are
+/// parsed like `to_timestamp` does, trying the
+/// [`TIMESTAMP_FALLBACK_FORMATS`] after RFC3339. Strings that still cannot
+/// be parsed are an error, or null if `cast_options.safe` is set.
+///
+/// [`TIMESTAMP_FALLBACK_FORMATS`]: datafusion_common::parsers::TIMESTAMP_FALLBACK_FORMATS
+fn cast_array(
+    array: &ArrayRef,
+    cast_type: &DataType,
+    cast_options: &CastOptions<'static>,
+) -> Result<ArrayRef> {
+    let is_string = matches!(
+        array.data_type(),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    );
+    if !is_string || !matches!(cast_type, DataType::Timestamp(_, None)) {
+        return Ok(kernels::cast::cast_with_options(
+            array,
+            cast_type,
+            cast_options,
+        )?);
+    }
+
+    let strict = CastOptions {
+        safe: false,
+        ..cast_options.clone()
+    };
+    if let Ok(cast) = kernels::cast::cast_with_options(array, cast_type, &strict) {
+        return Ok(cast);
+    }
+
+    let strings = kernels::cast::cast(array, &DataType::Utf8)?;
+    let mut nanos = TimestampNanosecondBuilder::with_capacity(strings.len());
+    for s in strings.as_string::<i32>() {
+        match s.map(string_to_timestamp_nanos_with_fallback) {
+            Some(Ok(value)) => nanos.append_value(value),
+            Some(Err(e)) if !cast_options.safe => return Err(e.into()),
+            _ => nanos.append_null(),
+        }
+    }
+    Ok(kernels::cast::cast_with_options(
+        &nanos.finish(),
+        cast_type,
+        cast_options,
+    )?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow::array::{StringArray, TimestampSecondArray};
+
+    #[test]
+    fn cast_string_to_timestamp_with_fallback_formats() {
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("2021-09-07T16:00:00"),
+            Some("2021/09/07 16:00:00"),
+            Some("07-Sep-2021"),
+            None,
+        ]));
+        let cast_type = DataType::Timestamp(TimeUnit::Second, None);
+        let cast = ColumnarValue::Array(Arc::clone(&strings))
+            .cast_to(&cast_type, None)
+            .unwrap()
+            .into_array(4)
+            .unwrap();
+        let expected: ArrayRef = Arc::new(TimestampSecondArray::from(vec![
+            Some(1631030400),
+            Some(1631030400),
+            Some(1630972800),
+            None,
+        ]));
+        assert_eq!(&cast, &expected);
+
+        let scalar =
+            ColumnarValue::Scalar(ScalarValue::from("2021-09-07 16:00:00 +0200"))
+                .cast_to(&cast_type, None)
+                .unwrap();
+        let ColumnarValue::Scalar(scalar) = scalar else {
+            panic!("expected a scalar");
+        };
+        assert_eq!(scalar, ScalarValue::TimestampSecond(Some(1631023200), None));
+
+        // unparseable strings fail the cast unless it is safe
+        let strings: ArrayRef =
+            Arc::new(StringArray::from(vec!["2021/09/07", "not a timestamp"]));
+        let err = ColumnarValue::Array(Arc::clone(&strings))
+            .cast_to(&cast_type, None)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Error parsing timestamp from 'not a timestamp'"));
+
+        let safe = CastOptions {
+            safe: true,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let cast = ColumnarValue::Array(strings)
+            .cast_to(&cast_type, Some(&safe))
+            .unwrap()
+            .into_array(2)
+            .unwrap();
+        let expected: ArrayRef =
+            Arc::new(TimestampSecondArray::from(vec![Some(1630972800), None]));
+        assert_eq!(&cast, &expected);
+    }
 
     #[test]
     fn values_to_arrays() {
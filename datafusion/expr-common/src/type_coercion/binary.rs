@@ -77,7 +77,7 @@ fn signature(lhs: &DataType, op: &Operator, rhs: &DataType) -> Result<Signature>
         GtEq |
         IsDistinctFrom |
         IsNotDistinctFrom => {
-            comparison_coercion(lhs, rhs).map(Signature::comparison).ok_or_else(|| {
+            comparison_operator_coercion(lhs, rhs).map(Signature::comparison).ok_or_else(|| {
                 plan_datafusion_err!(
                     "Cannot infer common argument type for comparison operation {lhs} {op} {rhs}"
                 )
@@ -507,6 +507,26 @@ pub fn comparison_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<D
         .or_else(|| struct_coercion(lhs_type, rhs_type))
 }
 
+/// Coerce `lhs_type` and `rhs_type` to a common type for comparing them with
+/// a comparison operator, `IN` or `BETWEEN`, including join keys
+///
+/// This is [`comparison_coercion`], except that `UInt64` and a signed integer
+/// type are compared as `Decimal128(20, 0)`, which holds every value of both
+/// types exactly. [`comparison_coercion`] uses `Int64`, which wraps or fails
+/// on values above `i64::MAX`, but it also finds the common type of values
+/// that are combined rather than compared, such as the results of `CASE`.
+pub fn comparison_operator_coercion(
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Option<DataType> {
+    use arrow::datatypes::DataType::*;
+    match (lhs_type, rhs_type) {
+        (UInt64, Int8 | Int16 | Int32 | Int64)
+        | (Int8 | Int16 | Int32 | Int64, UInt64) => Some(Decimal128(20, 0)),
+        _ => comparison_coercion(lhs_type, rhs_type),
+    }
+}
+
 /// Coerce `lhs_type` and `rhs_type` to a common type for `VALUES` expression
 ///
 /// For example `VALUES (1, 2), (3.0, 4.0)` where the first row is `Int32` and
@@ -608,6 +628,7 @@ pub fn binary_numeric_coercion(
         // accommodates all values of both types. Note that some information
         // loss is inevitable when we have a signed type and a `UInt64`, in
         // which case we use `Int64`;i.e. the widest signed integral type.
+        // Comparisons avoid the loss, see `comparison_operator_coercion`.
 
         // TODO: For i64 and u64, we can use decimal or float64
         // Postgres has no unsigned type :(
@@ -756,7 +777,7 @@ pub fn get_wider_type(lhs: &DataType, rhs: &DataType) -> Result<DataType> {
 }
 
 /// Convert the numeric data type to the decimal data type.
-/// Now, we just support the signed integer type and floating-point type.
+/// Now, we just support the integer types and floating-point types.
 fn coerce_numeric_type_to_decimal(numeric_type: &DataType) -> Option<DataType> {
     use arrow::datatypes::DataType::*;
     // This conversion rule is from spark
@@ -766,6 +787,10 @@ fn coerce_numeric_type_to_decimal(numeric_type: &DataType) -> Option<DataType> {
         Int16 => Some(Decimal128(5, 0)),
         Int32 => Some(Decimal128(10, 0)),
         Int64 => Some(Decimal128(20, 0)),
+        UInt8 => Some(Decimal128(3, 0)),
+        UInt16 => Some(Decimal128(5, 0)),
+        UInt32 => Some(Decimal128(10, 0)),
+        UInt64 => Some(Decimal128(20, 0)),
         // TODO if we convert the floating-point data to the decimal type, it maybe overflow.
         Float32 => Some(Decimal128(14, 7)),
         Float64 => Some(Decimal128(30, 15)),
@@ -774,7 +799,7 @@ fn coerce_numeric_type_to_decimal(numeric_type: &DataType) -> Option<DataType> {
 }
 
 /// Convert the numeric data type to the decimal data type.
-/// Now, we just support the signed integer type and floating-point type.
+/// Now, we just support the integer types and floating-point types.
 fn coerce_numeric_type_to_decimal256(numeric_type: &DataType) -> Option<DataType> {
     use arrow::datatypes::DataType::*;
     // This conversion rule is from spark
@@ -784,6 +809,10 @@ fn coerce_numeric_type_to_decimal256(numeric_type: &DataType) -> Option<DataType
         Int16 => Some(Decimal256(5, 0)),
         Int32 => Some(Decimal256(10, 0)),
         Int64 => Some(Decimal256(20, 0)),
+        UInt8 => Some(Decimal256(3, 0)),
+        UInt16 => Some(Decimal256(5, 0)),
+        UInt32 => Some(Decimal256(10, 0)),
+        UInt64 => Some(Decimal256(20, 0)),
         // TODO if we convert the floating-point data to the decimal type, it maybe overflow.
         Float32 => Some(Decimal256(14, 7)),
         Float64 => Some(Decimal256(30, 15)),
@@ -1262,6 +1291,8 @@ mod tests {
             DataType::Int16,
             DataType::Int32,
             DataType::Int64,
+            DataType::UInt32,
+            DataType::UInt64,
             DataType::Float32,
             DataType::Float64,
             DataType::Decimal128(38, 10),
@@ -1273,6 +1304,8 @@ mod tests {
             DataType::Decimal128(20, 3),
             DataType::Decimal128(20, 3),
             DataType::Decimal128(23, 3),
+            DataType::Decimal128(20, 3),
+            DataType::Decimal128(23, 3),
             DataType::Decimal128(24, 7),
             DataType::Decimal128(32, 15),
             DataType::Decimal128(38, 10),
@@ -1763,6 +1796,25 @@ mod tests {
             Operator::Gt,
             DataType::UInt32
         );
+        // mixed signed and unsigned integer
+        test_coercion_binary_rule!(
+            DataType::UInt32,
+            DataType::Int64,
+            Operator::Eq,
+            DataType::Int64
+        );
+        test_coercion_binary_rule!(
+            DataType::UInt64,
+            DataType::Int64,
+            Operator::Eq,
+            DataType::Decimal128(20, 0)
+        );
+        test_coercion_binary_rule!(
+            DataType::Int8,
+            DataType::UInt64,
+            Operator::Lt,
+            DataType::Decimal128(20, 0)
+        );
         // numeric/decimal
         test_coercion_binary_rule!(
             DataType::Int64,
@@ -393,6 +393,11 @@ impl ExprSchemable for Expr {
         match self {
             Expr::Column(c) => Ok(schema.metadata(c)?.clone()),
             Expr::Alias(Alias { expr, .. }) => expr.metadata(schema),
+            Expr::AggregateFunction(AggregateFunction { func, args, .. })
+                if func.preserves_input_metadata() && !args.is_empty() =>
+            {
+                args[0].metadata(schema)
+            }
             _ => Ok(HashMap::new()),
         }
     }
@@ -202,6 +202,8 @@ pub struct CreateExternalTable {
     pub table_partition_cols: Vec<String>,
     /// Option to not error if table already exists
     pub if_not_exists: bool,
+    /// Option to replace the table if it already exists
+    pub or_replace: bool,
     /// SQL used to create the table, if available
     pub definition: Option<String>,
     /// Order expressions supplied by user
@@ -225,6 +227,7 @@ impl Hash for CreateExternalTable {
         self.file_type.hash(state);
         self.table_partition_cols.hash(state);
         self.if_not_exists.hash(state);
+        self.or_replace.hash(state);
         self.definition.hash(state);
         self.order_exprs.hash(state);
         self.unbounded.hash(state);
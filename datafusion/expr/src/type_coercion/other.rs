@@ -17,7 +17,7 @@
 
 use arrow::datatypes::DataType;
 
-use super::binary::comparison_coercion;
+use super::binary::{comparison_coercion, comparison_operator_coercion};
 
 /// Attempts to coerce the types of `list_types` to be comparable with the
 /// `expr_type`.
@@ -29,7 +29,7 @@ pub fn get_coerce_type_for_list(
     list_types
         .iter()
         .try_fold(expr_type.clone(), |left_type, right_type| {
-            comparison_coercion(&left_type, right_type)
+            comparison_operator_coercion(&left_type, right_type)
         })
 }
 
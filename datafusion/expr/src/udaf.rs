@@ -167,6 +167,11 @@ impl AggregateUDF {
         self.inner.is_nullable()
     }
 
+    /// See [`AggregateUDFImpl::preserves_input_metadata`] for more details.
+    pub fn preserves_input_metadata(&self) -> bool {
+        self.inner.preserves_input_metadata()
+    }
+
     /// Returns the aliases for this function.
     pub fn aliases(&self) -> &[String] {
         self.inner.aliases()
@@ -364,6 +369,16 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
         true
     }
 
+    /// Whether the output field of the aggregate function keeps the field
+    /// metadata of its first argument.
+    ///
+    /// Functions that return one of their input values unchanged can return
+    /// `true` so that the metadata is not lost. Defaults to `false`, meaning
+    /// the output field has no metadata.
+    fn preserves_input_metadata(&self) -> bool {
+        false
+    }
+
     /// Return a new [`Accumulator`] that aggregates values for a specific
     /// group during query execution.
     ///
@@ -710,6 +725,10 @@ impl AggregateUDFImpl for AliasedAggregateUDFImpl {
     fn is_descending(&self) -> Option<bool> {
         self.inner.is_descending()
     }
+
+    fn preserves_input_metadata(&self) -> bool {
+        self.inner.preserves_input_metadata()
+    }
 }
 
 /// Implementation of [`AggregateUDFImpl`] that wraps the function style pointers
@@ -0,0 +1,133 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`DictionaryCountAccumulator`] for dictionary encoded values
+
+use arrow::array::{Array, ArrayRef, AsArray, UInt32Array};
+use arrow::compute::take;
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr_common::accumulator::Accumulator;
+
+/// `COUNT DISTINCT` for [`DictionaryArray`]s.
+///
+/// Rather than hashing the value of every row, each batch passes the
+/// dictionary values referenced by at least one non-null key to an inner
+/// accumulator for the value type, so a value is hashed once per batch.
+/// The state of the accumulator is the state of the inner accumulator,
+/// i.e. a list of the unpacked distinct values.
+///
+/// [`DictionaryArray`]: arrow::array::DictionaryArray
+#[derive(Debug)]
+pub struct DictionaryCountAccumulator {
+    inner: Box<dyn Accumulator>,
+}
+
+impl DictionaryCountAccumulator {
+    /// Creates a new accumulator counting with `inner`, the distinct count
+    /// accumulator of the value type of the dictionary
+    pub fn new(inner: Box<dyn Accumulator>) -> Self {
+        Self { inner }
+    }
+}
+
+/// Returns the values of `dict` referenced by a non-null key
+fn referenced_values(dict: &ArrayRef) -> Result<ArrayRef> {
+    let dict = dict.as_any_dictionary();
+    let keys = dict.keys();
+    let mut referenced = vec![false; dict.values().len()];
+    for (i, key) in dict.normalized_keys().into_iter().enumerate() {
+        if keys.is_valid(i) {
+            referenced[key] = true;
+        }
+    }
+    let indices = UInt32Array::from_iter_values(
+        referenced
+            .iter()
+            .enumerate()
+            .filter(|(_, referenced)| **referenced)
+            .map(|(i, _)| i as u32),
+    );
+    Ok(take(dict.values(), &indices, None)?)
+}
+
+impl Accumulator for DictionaryCountAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let values = values
+            .iter()
+            .map(referenced_values)
+            .collect::<Result<Vec<_>>>()?;
+        self.inner.update_batch(&values)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.inner.merge_batch(states)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        self.inner.state()
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        self.inner.evaluate()
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self) + self.inner.size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use arrow::array::{DictionaryArray, StringArray};
+    use arrow::datatypes::Int32Type;
+    use datafusion_physical_expr_common::binary_map::OutputType;
+
+    use crate::aggregate::count_distinct::BytesDistinctCountAccumulator;
+
+    #[test]
+    fn count_referenced_non_null_values() -> Result<()> {
+        let mut acc = DictionaryCountAccumulator::new(Box::new(
+            BytesDistinctCountAccumulator::<i32>::new(OutputType::Utf8),
+        ));
+
+        // "c" is not referenced and the null value is not counted
+        let values = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("c"),
+            None,
+        ]));
+        let keys = vec![Some(0), None, Some(1), Some(0), Some(3)].into();
+        let dict: ArrayRef =
+            Arc::new(DictionaryArray::<Int32Type>::try_new(keys, values)?);
+        acc.update_batch(&[dict])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Int64(Some(2)));
+
+        // values seen in a previous batch are not counted again
+        let values = Arc::new(StringArray::from(vec!["b", "d"]));
+        let keys = vec![Some(1), Some(0)].into();
+        let dict: ArrayRef =
+            Arc::new(DictionaryArray::<Int32Type>::try_new(keys, values)?);
+        acc.update_batch(&[dict])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Int64(Some(3)));
+        Ok(())
+    }
+}
@@ -20,7 +20,7 @@ use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
 use arrow::array::{Array, RecordBatch};
-use arrow::compute::{filter, is_not_null};
+use arrow::compute::{cast, filter, is_not_null};
 use arrow::{
     array::{
         ArrayRef, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array,
@@ -35,7 +35,7 @@ use datafusion_common::{
     DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
-use datafusion_expr::type_coercion::aggregates::{INTEGERS, NUMERICS};
+use datafusion_expr::type_coercion::aggregates::{INTEGERS, NUMERICS, TIMESTAMPS};
 use datafusion_expr::utils::format_state_name;
 use datafusion_expr::{
     Accumulator, AggregateUDFImpl, ColumnarValue, Expr, Signature, TypeSignature,
@@ -84,9 +84,11 @@ impl Default for ApproxPercentileCont {
 impl ApproxPercentileCont {
     /// Create a new [`ApproxPercentileCont`] aggregate function.
     pub fn new() -> Self {
-        let mut variants = Vec::with_capacity(NUMERICS.len() * (INTEGERS.len() + 1));
-        // Accept any numeric value paired with a float64 percentile
-        for num in NUMERICS {
+        let mut variants = Vec::with_capacity(
+            (NUMERICS.len() + TIMESTAMPS.len()) * (INTEGERS.len() + 1),
+        );
+        // Accept any numeric or timestamp value paired with a float64 percentile
+        for num in NUMERICS.iter().chain(TIMESTAMPS) {
             variants.push(TypeSignature::Exact(vec![num.clone(), DataType::Float64]));
             // Additionally accept an integer number of centroids for T-Digest
             for int in INTEGERS {
@@ -124,7 +126,8 @@ impl ApproxPercentileCont {
             | DataType::Int32
             | DataType::Int64
             | DataType::Float32
-            | DataType::Float64) => {
+            | DataType::Float64
+            | DataType::Timestamp(_, _)) => {
                 if let Some(max_size) = tdigest_max_size {
                     ApproxPercentileAccumulator::new_with_max_size(percentile, t, max_size)
                 }else{
@@ -143,7 +146,7 @@ impl ApproxPercentileCont {
     }
 }
 
-fn get_scalar_value(expr: &Arc<dyn PhysicalExpr>) -> Result<ScalarValue> {
+pub(crate) fn get_scalar_value(expr: &Arc<dyn PhysicalExpr>) -> Result<ScalarValue> {
     let empty_schema = Arc::new(Schema::empty());
     let batch = RecordBatch::new_empty(Arc::clone(&empty_schema));
     if let ColumnarValue::Scalar(s) = expr.evaluate(&batch)? {
@@ -258,8 +261,12 @@ impl AggregateUDFImpl for ApproxPercentileCont {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        if !arg_types[0].is_numeric() {
-            return plan_err!("approx_percentile_cont requires numeric input types");
+        if !arg_types[0].is_numeric()
+            && !matches!(arg_types[0], DataType::Timestamp(_, _))
+        {
+            return plan_err!(
+                "approx_percentile_cont requires numeric or timestamp input types"
+            );
         }
         if arg_types.len() == 3 && !arg_types[2].is_integer() {
             return plan_err!(
@@ -387,6 +394,11 @@ impl ApproxPercentileAccumulator {
                     .filter_map(|v| v.try_as_f64().transpose())
                     .collect::<Result<Vec<_>>>()?)
             }
+            DataType::Timestamp(_, _) => {
+                // timestamps are approximated as their underlying i64 values
+                let values = cast(values, &DataType::Int64)?;
+                Self::convert_to_float(&values)
+            }
             e => internal_err!(
                 "APPROX_PERCENTILE_CONT is not expected to receive the type {e:?}"
             ),
@@ -430,6 +442,9 @@ impl Accumulator for ApproxPercentileAccumulator {
             DataType::UInt64 => ScalarValue::UInt64(Some(q as u64)),
             DataType::Float32 => ScalarValue::Float32(Some(q as f32)),
             DataType::Float64 => ScalarValue::Float64(Some(q)),
+            DataType::Timestamp(_, _) => {
+                ScalarValue::Int64(Some(q as i64)).cast_to(&self.return_type)?
+            }
             v => unreachable!("unexpected return type {:?}", v),
         })
     }
@@ -195,6 +195,42 @@ impl Accumulator for ArrayAggAccumulator {
         Ok(())
     }
 
+    fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        if values.len() != 1 {
+            return internal_err!("expects single batch");
+        }
+
+        // Values leave a sliding window in the order they entered it, so
+        // they are removed from the front
+        let mut to_retract = values[0].len();
+        let mut retracted_arrays = 0;
+        for arr in self.values.iter_mut() {
+            if to_retract == 0 {
+                break;
+            }
+            if arr.len() <= to_retract {
+                to_retract -= arr.len();
+                retracted_arrays += 1;
+            } else {
+                *arr = arr.slice(to_retract, arr.len() - to_retract);
+                to_retract = 0;
+            }
+        }
+        if to_retract > 0 {
+            return internal_err!("cannot retract more values than were accumulated");
+        }
+        self.values.drain(..retracted_arrays);
+        Ok(())
+    }
+
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+
     fn state(&mut self) -> Result<Vec<ScalarValue>> {
         Ok(vec![self.evaluate()?])
     }
@@ -654,4 +690,24 @@ mod tests {
         assert_eq!(&merged_ts, &expected_ts);
         Ok(())
     }
+
+    #[test]
+    fn test_retract_across_batches() -> Result<()> {
+        let mut acc = ArrayAggAccumulator::try_new(&DataType::Int64)?;
+        acc.update_batch(&[Arc::new(Int64Array::from(vec![1, 2]))])?;
+        acc.update_batch(&[Arc::new(Int64Array::from(vec![3, 4, 5]))])?;
+
+        acc.retract_batch(&[Arc::new(Int64Array::from(vec![1, 2, 3]))])?;
+        let ScalarValue::List(list) = acc.evaluate()? else {
+            panic!("expected a list");
+        };
+        let expected = Arc::new(Int64Array::from(vec![4, 5])) as ArrayRef;
+        assert_eq!(&list.value(0), &expected);
+
+        let err = acc
+            .retract_batch(&[Arc::new(Int64Array::from(vec![4, 5, 6]))])
+            .unwrap_err();
+        assert!(err.to_string().contains("cannot retract more values"));
+        Ok(())
+    }
 }
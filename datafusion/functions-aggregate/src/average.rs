@@ -41,9 +41,11 @@ use datafusion_functions_aggregate_common::aggregate::groups_accumulator::nulls:
     filtered_null_mask, set_nulls,
 };
 
-use datafusion_functions_aggregate_common::utils::DecimalAverager;
+use ahash::RandomState;
+use datafusion_functions_aggregate_common::utils::{DecimalAverager, Hashable};
 use log::debug;
 use std::any::Any;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -94,12 +96,12 @@ impl AggregateUDFImpl for Avg {
     }
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
-        if acc_args.is_distinct {
-            return exec_err!("avg(DISTINCT) aggregations are not available");
-        }
         use DataType::*;
 
         let data_type = acc_args.exprs[0].data_type(acc_args.schema)?;
+        if acc_args.is_distinct {
+            return distinct_avg_accumulator(&data_type, acc_args.return_type);
+        }
         // instantiate specialized accumulator based for the type
         match (&data_type, acc_args.return_type) {
             (Float64, Float64) => Ok(Box::<AvgAccumulator>::default()),
@@ -135,6 +137,14 @@ impl AggregateUDFImpl for Avg {
     }
 
     fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        if args.is_distinct {
+            return Ok(vec![Field::new_list(
+                format_state_name(args.name, "avg distinct"),
+                // See COMMENTS.md to understand why nullable is set to true
+                Field::new("item", args.input_types[0].clone(), true),
+                false,
+            )]);
+        }
         Ok(vec![
             Field::new(
                 format_state_name(args.name, "count"),
@@ -150,10 +160,11 @@ impl AggregateUDFImpl for Avg {
     }
 
     fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
-        matches!(
-            args.return_type,
-            DataType::Float64 | DataType::Decimal128(_, _)
-        )
+        !args.is_distinct
+            && matches!(
+                args.return_type,
+                DataType::Float64 | DataType::Decimal128(_, _)
+            )
     }
 
     fn create_groups_accumulator(
@@ -378,6 +389,168 @@ impl<T: DecimalType + ArrowNumericType + Debug> Accumulator for DecimalAvgAccumu
     }
 }
 
+/// Creates the accumulator of `avg(DISTINCT)` for `data_type`
+fn distinct_avg_accumulator(
+    data_type: &DataType,
+    return_type: &DataType,
+) -> Result<Box<dyn Accumulator>> {
+    use DataType::*;
+
+    match (data_type, return_type) {
+        (Float64, Float64) => {
+            Ok(Box::new(DistinctAvgAccumulator::<Float64Type, _>::new(
+                data_type,
+                return_type,
+                |sum: f64, count: u64| Ok(sum / count as f64),
+            )))
+        }
+        (Decimal128(_, sum_scale), Decimal128(target_precision, target_scale)) => {
+            let decimal_averager = DecimalAverager::<Decimal128Type>::try_new(
+                *sum_scale,
+                *target_precision,
+                *target_scale,
+            )?;
+            let avg_fn =
+                move |sum: i128, count: u64| decimal_averager.avg(sum, count as i128);
+            Ok(Box::new(DistinctAvgAccumulator::<Decimal128Type, _>::new(
+                data_type,
+                return_type,
+                avg_fn,
+            )))
+        }
+        (Decimal256(_, sum_scale), Decimal256(target_precision, target_scale)) => {
+            let decimal_averager = DecimalAverager::<Decimal256Type>::try_new(
+                *sum_scale,
+                *target_precision,
+                *target_scale,
+            )?;
+            let avg_fn = move |sum: i256, count: u64| {
+                decimal_averager.avg(sum, i256::from_usize(count as usize).unwrap())
+            };
+            Ok(Box::new(DistinctAvgAccumulator::<Decimal256Type, _>::new(
+                data_type,
+                return_type,
+                avg_fn,
+            )))
+        }
+        _ => not_impl_err!(
+            "DistinctAvgAccumulator for ({} --> {})",
+            data_type,
+            return_type
+        ),
+    }
+}
+
+/// An accumulator to compute the average of the distinct values of
+/// `[PrimitiveArray<T>]`.
+///
+/// The distinct values are kept in a hash set, which is also the state that
+/// is merged between partial and final aggregations.
+struct DistinctAvgAccumulator<T, F>
+where
+    T: ArrowNumericType + Send,
+    F: Fn(T::Native, u64) -> Result<T::Native> + Send + Sync,
+{
+    /// The distinct values seen so far
+    values: HashSet<Hashable<T::Native>, RandomState>,
+
+    /// The type of the input values
+    data_type: DataType,
+
+    /// The type of the returned average
+    return_data_type: DataType,
+
+    /// Function that computes the final average (value / count)
+    avg_fn: F,
+}
+
+impl<T, F> DistinctAvgAccumulator<T, F>
+where
+    T: ArrowNumericType + Send,
+    F: Fn(T::Native, u64) -> Result<T::Native> + Send + Sync,
+{
+    fn new(data_type: &DataType, return_data_type: &DataType, avg_fn: F) -> Self {
+        Self {
+            values: HashSet::default(),
+            data_type: data_type.clone(),
+            return_data_type: return_data_type.clone(),
+            avg_fn,
+        }
+    }
+}
+
+impl<T, F> Debug for DistinctAvgAccumulator<T, F>
+where
+    T: ArrowNumericType + Send,
+    F: Fn(T::Native, u64) -> Result<T::Native> + Send + Sync,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DistinctAvgAccumulator({} --> {})",
+            self.data_type, self.return_data_type
+        )
+    }
+}
+
+impl<T, F> Accumulator for DistinctAvgAccumulator<T, F>
+where
+    T: ArrowNumericType + Send,
+    F: Fn(T::Native, u64) -> Result<T::Native> + Send + Sync,
+{
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let distinct_values = self
+            .values
+            .iter()
+            .map(|value| ScalarValue::new_primitive::<T>(Some(value.0), &self.data_type))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(vec![ScalarValue::List(ScalarValue::new_list_nullable(
+            &distinct_values,
+            &self.data_type,
+        ))])
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let array = values[0].as_primitive::<T>();
+        match array.nulls().filter(|x| x.null_count() > 0) {
+            Some(n) => {
+                for idx in n.valid_indices() {
+                    self.values.insert(Hashable(array.value(idx)));
+                }
+            }
+            None => array.values().iter().for_each(|x| {
+                self.values.insert(Hashable(*x));
+            }),
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        for values in states[0].as_list::<i32>().iter().flatten() {
+            self.update_batch(&[values])?
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let avg = if self.values.is_empty() {
+            None
+        } else {
+            let sum = self
+                .values
+                .iter()
+                .fold(T::default_value(), |acc, value| acc.add_wrapping(value.0));
+            Some((self.avg_fn)(sum, self.values.len() as u64)?)
+        };
+        ScalarValue::new_primitive::<T>(avg, &self.return_data_type)
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self.values.capacity() * std::mem::size_of::<T::Native>()
+    }
+}
+
 /// An accumulator to compute the average of `[PrimitiveArray<T>]`.
 /// Stores values as native types, and does overflow checking
 ///
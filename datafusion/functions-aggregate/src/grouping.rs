@@ -32,7 +32,7 @@ make_udaf_expr_and_func!(
     Grouping,
     grouping,
     expression,
-    "Returns 1 if the data is aggregated across the specified column or 0 for not aggregated in the result set.",
+    "Returns 1 if the data is aggregated across the specified column or 0 for not aggregated in the result set. With several columns, returns a bit mask of those values.",
     grouping_udaf
 );
 
@@ -59,7 +59,7 @@ impl Grouping {
     /// Create a new GROUPING aggregate function.
     pub fn new() -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::variadic_any(Volatility::Immutable),
         }
     }
 }
@@ -91,7 +91,8 @@ impl AggregateUDFImpl for Grouping {
 
     fn accumulator(&self, _acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
         not_impl_err!(
-            "physical plan is not yet implemented for GROUPING aggregate function"
+            "physical plan is not yet implemented for GROUPING aggregate function, \
+             it must be resolved by the resolve_grouping_function analyzer rule"
         )
     }
 }
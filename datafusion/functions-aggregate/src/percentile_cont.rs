@@ -0,0 +1,278 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `PERCENTILE_CONT` aggregate implementation: [`PercentileCont`]
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, Float64Array};
+use arrow::datatypes::{DataType, Field, Float64Type};
+
+use datafusion_common::utils::array_into_list_array_nullable;
+use datafusion_common::{not_impl_datafusion_err, plan_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+
+use crate::approx_percentile_cont::get_scalar_value;
+
+make_udaf_expr_and_func!(
+    PercentileCont,
+    percentile_cont,
+    expression percentile,
+    "Computes the exact percentile of a set of numbers, interpolating between the closest values",
+    percentile_cont_udaf
+);
+
+/// PERCENTILE_CONT aggregate expression
+///
+/// `percentile_cont(x, p)` sorts the non-null values of `x` and linearly
+/// interpolates between the two values closest to the position
+/// `p * (count - 1)`, so `p = 0` returns the minimum, `p = 1` the maximum
+/// and `p = 0.5` the median. Like `MEDIAN`, all the values are kept in
+/// memory until the result is computed; `APPROX_PERCENTILE_CONT` is much
+/// cheaper if an approximation is sufficient.
+///
+/// The accumulator supports retraction, so it can be evaluated over
+/// sliding window frames.
+#[derive(Debug)]
+pub struct PercentileCont {
+    signature: Signature,
+}
+
+impl Default for PercentileCont {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PercentileCont {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(
+                vec![DataType::Float64, DataType::Float64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl AggregateUDFImpl for PercentileCont {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "percentile_cont"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        // Intermediate state is a list of the values collected so far
+        let field = Field::new("item", DataType::Float64, true);
+        Ok(vec![Field::new(
+            format_state_name(args.name, "percentile_cont"),
+            DataType::List(Arc::new(field)),
+            true,
+        )])
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let percentile = validate_percentile_expr(&acc_args.exprs[1])?;
+        Ok(Box::new(PercentileContAccumulator::new(percentile)))
+    }
+}
+
+/// Returns the value of the percentile argument, which must be a literal
+/// between 0 and 1
+fn validate_percentile_expr(expr: &Arc<dyn PhysicalExpr>) -> Result<f64> {
+    let percentile = match get_scalar_value(expr).map_err(|_| {
+        not_impl_datafusion_err!(
+            "Percentile value for 'PERCENTILE_CONT' must be a literal, got: {expr}"
+        )
+    })? {
+        ScalarValue::Float64(Some(value)) => value,
+        sv => {
+            return plan_err!(
+            "Percentile value for 'PERCENTILE_CONT' must be a non-null number, got: {sv}"
+        )
+        }
+    };
+
+    if !(0.0..=1.0).contains(&percentile) {
+        return plan_err!(
+            "Percentile value must be between 0.0 and 1.0 inclusive, {percentile} is invalid"
+        );
+    }
+    Ok(percentile)
+}
+
+/// Accumulates the non-null input values and interpolates the percentile
+/// from them when evaluated
+///
+/// The values are kept sorted up to `sorted_len`: new values are appended,
+/// and only sorted and merged into the sorted prefix when the accumulator is
+/// evaluated or values are retracted, which find them by binary search.
+#[derive(Debug)]
+pub struct PercentileContAccumulator {
+    percentile: f64,
+    values: Vec<f64>,
+    sorted_len: usize,
+}
+
+impl PercentileContAccumulator {
+    /// Creates an accumulator of the `percentile`th percentile, between 0
+    /// and 1
+    pub fn new(percentile: f64) -> Self {
+        Self {
+            percentile,
+            values: vec![],
+            sorted_len: 0,
+        }
+    }
+
+    /// Sorts the values appended since the last call
+    fn sort_values(&mut self) {
+        if self.sorted_len < self.values.len() {
+            self.values[self.sorted_len..].sort_unstable_by(f64::total_cmp);
+            // the stable sort merges the two sorted runs in linear time
+            self.values.sort_by(f64::total_cmp);
+            self.sorted_len = self.values.len();
+        }
+    }
+}
+
+impl Accumulator for PercentileContAccumulator {
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let values = Float64Array::from(self.values.clone());
+        let list = array_into_list_array_nullable(Arc::new(values));
+        Ok(vec![ScalarValue::List(Arc::new(list))])
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let values = values[0].as_primitive::<Float64Type>();
+        self.values.reserve(values.len() - values.null_count());
+        self.values.extend(values.iter().flatten());
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let array = states[0].as_list::<i32>();
+        for v in array.iter().flatten() {
+            self.update_batch(&[v])?
+        }
+        Ok(())
+    }
+
+    fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.sort_values();
+        let values = values[0].as_primitive::<Float64Type>();
+        for value in values.iter().flatten() {
+            if let Ok(index) = self.values.binary_search_by(|v| v.total_cmp(&value)) {
+                self.values.remove(index);
+            }
+        }
+        self.sorted_len = self.values.len();
+        Ok(())
+    }
+
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        self.sort_values();
+        Ok(ScalarValue::Float64(interpolate_percentile(
+            &self.values,
+            self.percentile,
+        )))
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self) + self.values.capacity() * std::mem::size_of::<f64>()
+    }
+}
+
+/// Linearly interpolates the `percentile`th percentile of the sorted
+/// `values`, or returns `None` if there are no values
+fn interpolate_percentile(values: &[f64], percentile: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let position = percentile * (values.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+    Some(values[lower] + (values[upper] - values[lower]) * fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate() {
+        assert_eq!(interpolate_percentile(&[], 0.5), None);
+        assert_eq!(interpolate_percentile(&[7.0], 0.3), Some(7.0));
+
+        // odd number of values: the median is the middle value
+        let odd = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(interpolate_percentile(&odd, 0.5), Some(3.0));
+        // even number of values: the median averages the middle values
+        let even = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(interpolate_percentile(&even, 0.5), Some(2.5));
+        assert_eq!(interpolate_percentile(&even, 0.25), Some(1.75));
+
+        assert_eq!(interpolate_percentile(&even, 0.0), Some(1.0));
+        assert_eq!(interpolate_percentile(&even, 1.0), Some(4.0));
+    }
+
+    #[test]
+    fn retract_values() -> Result<()> {
+        let mut acc = PercentileContAccumulator::new(0.5);
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(4.0),
+            None,
+            Some(1.0),
+            Some(2.0),
+        ]));
+        acc.update_batch(&[values])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Float64(Some(2.0)));
+
+        let retracted: ArrayRef = Arc::new(Float64Array::from(vec![Some(4.0), None]));
+        acc.retract_batch(&[retracted])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Float64(Some(1.5)));
+
+        // values added after the last evaluation are found when retracted
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![3.0, 0.0, 3.0]));
+        acc.update_batch(&[values])?;
+        let retracted: ArrayRef = Arc::new(Float64Array::from(vec![3.0, 1.0, 5.0]));
+        acc.retract_batch(&[retracted])?;
+        assert_eq!(acc.values, vec![0.0, 2.0, 3.0]);
+        assert_eq!(acc.evaluate()?, ScalarValue::Float64(Some(2.0)));
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the `scalar_subquery_value` aggregate, which guards uncorrelated
+//! scalar subqueries against returning more than one row
+
+use std::any::Any;
+use std::fmt::Debug;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::cast::as_uint64_array;
+use datafusion_common::{exec_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+
+make_udaf_expr_and_func!(
+    ScalarSubqueryValue,
+    scalar_subquery_value,
+    expression,
+    "Returns the value of the single input row, or NULL if there are no rows. Fails if there is more than one row.",
+    scalar_subquery_value_udaf
+);
+
+/// Returns the value of the single row of its input, or NULL if the input is
+/// empty, and fails if the input has more than one row.
+///
+/// The `ScalarSubqueryToJoin` optimizer rule aggregates uncorrelated scalar
+/// subqueries that are not known to return at most one row with this
+/// function. The output field keeps the metadata of the input field, so
+/// the guarded subquery has the same schema as the unguarded one.
+#[derive(Debug)]
+pub struct ScalarSubqueryValue {
+    signature: Signature,
+}
+
+impl Default for ScalarSubqueryValue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScalarSubqueryValue {
+    /// Create a new SCALAR_SUBQUERY_VALUE aggregate function.
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for ScalarSubqueryValue {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "scalar_subquery_value"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn preserves_input_metadata(&self) -> bool {
+        true
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(ScalarSubqueryValueAccumulator {
+            value: ScalarValue::try_from(acc_args.return_type)?,
+            rows: 0,
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        Ok(vec![
+            Field::new(
+                format_state_name(args.name, "value"),
+                args.return_type.clone(),
+                true,
+            ),
+            Field::new(format_state_name(args.name, "rows"), DataType::UInt64, true),
+        ])
+    }
+}
+
+#[derive(Debug)]
+struct ScalarSubqueryValueAccumulator {
+    value: ScalarValue,
+    rows: u64,
+}
+
+impl ScalarSubqueryValueAccumulator {
+    /// Adds `rows` rows, the first of which is at `index` of `values`
+    fn add(&mut self, values: &ArrayRef, index: usize, rows: u64) -> Result<()> {
+        self.rows += rows;
+        if self.rows > 1 {
+            return exec_err!("Scalar subquery returned more than one row");
+        }
+        self.value = ScalarValue::try_from_array(values, index)?;
+        Ok(())
+    }
+}
+
+impl Accumulator for ScalarSubqueryValueAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        if !values[0].is_empty() {
+            self.add(&values[0], 0, values[0].len() as u64)?;
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let rows = as_uint64_array(&states[1])?;
+        for (index, rows) in rows.iter().enumerate() {
+            if let Some(rows @ 1..) = rows {
+                self.add(&states[0], index, rows)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![
+            self.value.clone(),
+            ScalarValue::UInt64(Some(self.rows)),
+        ])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(self.value.clone())
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self) - std::mem::size_of_val(&self.value)
+            + self.value.size()
+    }
+}
@@ -22,9 +22,11 @@ use datafusion_expr::utils::AggregateOrderSensitivity;
 use std::any::Any;
 use std::collections::HashSet;
 
+use std::sync::Arc;
+
 use arrow::array::Array;
 use arrow::array::ArrowNativeTypeOp;
-use arrow::array::{ArrowNumericType, AsArray};
+use arrow::array::{ArrowNumericType, AsArray, BooleanArray, UInt64Array};
 use arrow::datatypes::ArrowNativeType;
 use arrow::datatypes::ArrowPrimitiveType;
 use arrow::datatypes::{
@@ -32,12 +34,19 @@ use arrow::datatypes::{
     DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
 };
 use arrow::{array::ArrayRef, datatypes::Field};
-use datafusion_common::{exec_err, not_impl_err, Result, ScalarValue};
+use datafusion_common::{
+    exec_datafusion_err, exec_err, not_impl_err, Result, ScalarValue,
+};
 use datafusion_expr::function::AccumulatorArgs;
 use datafusion_expr::function::StateFieldsArgs;
 use datafusion_expr::utils::format_state_name;
 use datafusion_expr::{
-    Accumulator, AggregateUDFImpl, GroupsAccumulator, ReversedUDAF, Signature, Volatility,
+    Accumulator, AggregateUDFImpl, EmitTo, GroupsAccumulator, ReversedUDAF, Signature,
+    Volatility,
+};
+use datafusion_functions_aggregate_common::aggregate::groups_accumulator::accumulate::NullState;
+use datafusion_functions_aggregate_common::aggregate::groups_accumulator::nulls::{
+    filtered_null_mask, set_nulls,
 };
 use datafusion_functions_aggregate_common::aggregate::groups_accumulator::prim_op::PrimitiveGroupsAccumulator;
 use datafusion_functions_aggregate_common::utils::Hashable;
@@ -164,6 +173,8 @@ impl AggregateUDFImpl for Sum {
                 };
             }
             downcast_sum!(args, helper)
+        } else if args.return_type == &DataType::UInt64 {
+            Ok(Box::<UInt64SumAccumulator>::default())
         } else {
             macro_rules! helper {
                 ($t:ty, $dt:expr) => {
@@ -203,6 +214,9 @@ impl AggregateUDFImpl for Sum {
         &self,
         args: AccumulatorArgs,
     ) -> Result<Box<dyn GroupsAccumulator>> {
+        if args.return_type == &DataType::UInt64 {
+            return Ok(Box::<UInt64SumGroupsAccumulator>::default());
+        }
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
                 Ok(Box::new(PrimitiveGroupsAccumulator::<$t, _>::new(
@@ -218,6 +232,9 @@ impl AggregateUDFImpl for Sum {
         &self,
         args: AccumulatorArgs,
     ) -> Result<Box<dyn Accumulator>> {
+        if args.return_type == &DataType::UInt64 {
+            return Ok(Box::<SlidingUInt64SumAccumulator>::default());
+        }
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
                 Ok(Box::new(SlidingSumAccumulator::<$t>::new($dt.clone())))
@@ -356,6 +373,186 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
     }
 }
 
+/// Converts a sum of `UInt64` values accumulated in a `u128` to the `UInt64`
+/// result, returning an error if it does not fit
+fn uint64_sum(sum: u128) -> Result<u64> {
+    u64::try_from(sum).map_err(|_| {
+        exec_datafusion_err!("UInt64 overflow in SUM: {sum} is out of range")
+    })
+}
+
+/// Returns the sum of the non null values of a `UInt64` array as a `u128`
+fn uint64_array_sum(values: &UInt64Array) -> u128 {
+    values.iter().flatten().map(u128::from).sum()
+}
+
+/// This accumulator computes SUM of `UInt64` values incrementally
+///
+/// Values are accumulated in a `u128`, so that a sum that does not fit in the
+/// `UInt64` result is reported as an error instead of wrapping around
+#[derive(Debug, Default)]
+struct UInt64SumAccumulator {
+    sum: Option<u128>,
+}
+
+impl Accumulator for UInt64SumAccumulator {
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![self.evaluate()?])
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let values = values[0].as_primitive::<UInt64Type>();
+        if values.null_count() < values.len() {
+            *self.sum.get_or_insert(0) += uint64_array_sum(values);
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.update_batch(states)
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(ScalarValue::UInt64(self.sum.map(uint64_sum).transpose()?))
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+}
+
+/// This accumulator incrementally computes sums of `UInt64` values over a
+/// sliding window, accumulated in a `u128` like [`UInt64SumAccumulator`]
+#[derive(Debug, Default)]
+struct SlidingUInt64SumAccumulator {
+    sum: u128,
+    count: u64,
+}
+
+impl Accumulator for SlidingUInt64SumAccumulator {
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![self.evaluate()?, self.count.into()])
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let values = values[0].as_primitive::<UInt64Type>();
+        self.count += (values.len() - values.null_count()) as u64;
+        self.sum += uint64_array_sum(values);
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.sum += uint64_array_sum(states[0].as_primitive::<UInt64Type>());
+        if let Some(x) = arrow::compute::sum(states[1].as_primitive::<UInt64Type>()) {
+            self.count += x;
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let v = (self.count != 0)
+            .then(|| uint64_sum(self.sum))
+            .transpose()?;
+        Ok(ScalarValue::UInt64(v))
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+
+    fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let values = values[0].as_primitive::<UInt64Type>();
+        self.sum -= uint64_array_sum(values);
+        self.count -= (values.len() - values.null_count()) as u64;
+        Ok(())
+    }
+
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+}
+
+/// Computes SUM of `UInt64` values per group, accumulated in a `u128` like
+/// [`UInt64SumAccumulator`]
+#[derive(Debug, Default)]
+struct UInt64SumGroupsAccumulator {
+    /// sums per group
+    sums: Vec<u128>,
+
+    /// Track nulls in the input / filters
+    null_state: NullState,
+}
+
+impl GroupsAccumulator for UInt64SumGroupsAccumulator {
+    fn update_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        assert_eq!(values.len(), 1, "single argument to update_batch");
+        let values = values[0].as_primitive::<UInt64Type>();
+
+        self.sums.resize(total_num_groups, 0);
+        self.null_state.accumulate(
+            group_indices,
+            values,
+            opt_filter,
+            total_num_groups,
+            |group_index, new_value| {
+                self.sums[group_index] += u128::from(new_value);
+            },
+        );
+
+        Ok(())
+    }
+
+    fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
+        let sums = emit_to.take_needed(&mut self.sums);
+        let nulls = self.null_state.build(emit_to);
+        let sums = sums
+            .into_iter()
+            .map(uint64_sum)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Arc::new(UInt64Array::new(sums.into(), Some(nulls))))
+    }
+
+    fn state(&mut self, emit_to: EmitTo) -> Result<Vec<ArrayRef>> {
+        self.evaluate(emit_to).map(|arr| vec![arr])
+    }
+
+    fn merge_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        // update / merge are the same
+        self.update_batch(values, group_indices, opt_filter, total_num_groups)
+    }
+
+    fn convert_to_state(
+        &self,
+        values: &[ArrayRef],
+        opt_filter: Option<&BooleanArray>,
+    ) -> Result<Vec<ArrayRef>> {
+        // the state of a single row is its value
+        let sums = values[0].as_primitive::<UInt64Type>().clone();
+        let nulls = filtered_null_mask(opt_filter, &sums);
+        Ok(vec![Arc::new(set_nulls(sums, nulls))])
+    }
+
+    fn supports_convert_to_state(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> usize {
+        self.sums.capacity() * std::mem::size_of::<u128>() + self.null_state.size()
+    }
+}
+
 struct DistinctSumAccumulator<T: ArrowPrimitiveType> {
     values: HashSet<Hashable<T::Native>, RandomState>,
     data_type: DataType,
@@ -424,9 +621,20 @@ impl<T: ArrowPrimitiveType> Accumulator for DistinctSumAccumulator<T> {
     }
 
     fn evaluate(&mut self) -> Result<ScalarValue> {
+        // sums of UInt64 values must not wrap around, see [`UInt64SumAccumulator`]
+        let checked = self.data_type == DataType::UInt64;
         let mut acc = T::Native::usize_as(0);
         for distinct_value in self.values.iter() {
-            acc = acc.add_wrapping(distinct_value.0)
+            acc = if checked {
+                acc.add_checked(distinct_value.0).map_err(|_| {
+                    exec_datafusion_err!(
+                        "UInt64 overflow in SUM: {acc:?} + {:?} is out of range",
+                        distinct_value.0
+                    )
+                })?
+            } else {
+                acc.add_wrapping(distinct_value.0)
+            }
         }
         let v = (!self.values.is_empty()).then_some(acc);
         ScalarValue::new_primitive::<T>(v, &self.data_type)
@@ -34,11 +34,15 @@ pub mod row_number;
 /// Fluent-style API for creating `Expr`s
 pub mod expr_fn {
     pub use super::row_number::row_number;
+    pub use super::row_number::row_number_reset;
 }
 
 /// Returns all default window functions
 pub fn all_default_window_functions() -> Vec<Arc<WindowUDF>> {
-    vec![row_number::row_number_udwf()]
+    vec![
+        row_number::row_number_udwf(),
+        row_number::row_number_reset_udwf(),
+    ]
 }
 /// Registers all enabled packages with a [`FunctionRegistry`]
 pub fn register_all(
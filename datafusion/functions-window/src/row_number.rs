@@ -23,8 +23,9 @@ use std::ops::Range;
 
 use datafusion_common::arrow::array::ArrayRef;
 use datafusion_common::arrow::array::UInt64Array;
-use datafusion_common::arrow::compute::SortOptions;
+use datafusion_common::arrow::compute::{SortColumn, SortOptions};
 use datafusion_common::arrow::datatypes::DataType;
+use datafusion_common::utils::evaluate_partition_ranges;
 use datafusion_common::{Result, ScalarValue};
 use datafusion_expr::expr::WindowFunction;
 use datafusion_expr::{Expr, PartitionEvaluator, Signature, Volatility, WindowUDFImpl};
@@ -50,6 +51,33 @@ pub fn row_number_udwf() -> std::sync::Arc<datafusion_expr::WindowUDF> {
         .clone()
 }
 
+/// Create a [`WindowFunction`](Expr::WindowFunction) expression for
+/// `row_number_reset` user-defined window function.
+pub fn row_number_reset(reset_key: Expr) -> Expr {
+    Expr::WindowFunction(WindowFunction::new(
+        row_number_reset_udwf(),
+        vec![reset_key],
+    ))
+}
+
+/// Singleton instance of `row_number_reset`, ensures the UDWF is only created once.
+#[allow(non_upper_case_globals)]
+static STATIC_RowNumberReset: std::sync::OnceLock<
+    std::sync::Arc<datafusion_expr::WindowUDF>,
+> = std::sync::OnceLock::new();
+
+/// Returns a [`WindowUDF`](datafusion_expr::WindowUDF) for `row_number_reset`
+/// user-defined window function.
+pub fn row_number_reset_udwf() -> std::sync::Arc<datafusion_expr::WindowUDF> {
+    STATIC_RowNumberReset
+        .get_or_init(|| {
+            std::sync::Arc::new(datafusion_expr::WindowUDF::from(
+                RowNumberReset::default(),
+            ))
+        })
+        .clone()
+}
+
 /// row_number expression
 #[derive(Debug)]
 pub struct RowNumber {
@@ -104,6 +132,83 @@ impl WindowUDFImpl for RowNumber {
     }
 }
 
+/// row_number_reset expression
+///
+/// `row_number_reset(key)` numbers the rows of a partition like
+/// `row_number`, but restarts from 1 whenever the value of `key` differs
+/// from its value in the previous row. The first row of each partition is
+/// numbered 1 and consecutive nulls belong to the same run, so the result
+/// is the position of each row within its run of equal keys, e.g. for
+/// gap-and-island queries.
+///
+/// Unlike `row_number`, the result is not increasing within a partition,
+/// so it does not report a sort order of its output.
+#[derive(Debug)]
+pub struct RowNumberReset {
+    signature: Signature,
+}
+
+impl RowNumberReset {
+    /// Create a new `row_number_reset` function
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for RowNumberReset {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowUDFImpl for RowNumberReset {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "row_number_reset"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn partition_evaluator(&self) -> Result<Box<dyn PartitionEvaluator>> {
+        Ok(Box::new(RunRowNumberEvaluator))
+    }
+
+    fn nullable(&self) -> bool {
+        false
+    }
+}
+
+/// Evaluator for the `row_number_reset` window function
+#[derive(Debug)]
+struct RunRowNumberEvaluator;
+
+impl PartitionEvaluator for RunRowNumberEvaluator {
+    fn evaluate_all(&mut self, values: &[ArrayRef], num_rows: usize) -> Result<ArrayRef> {
+        let reset_key = values
+            .iter()
+            .map(|values| SortColumn {
+                values: std::sync::Arc::clone(values),
+                options: None,
+            })
+            .collect::<Vec<_>>();
+        let runs = evaluate_partition_ranges(num_rows, &reset_key)?;
+        Ok(std::sync::Arc::new(UInt64Array::from_iter_values(
+            runs.into_iter().flat_map(|run| 1..(run.len() as u64) + 1),
+        )))
+    }
+}
+
 /// State for the `row_number` built-in window function.
 #[derive(Debug, Default)]
 struct NumRowsEvaluator {
@@ -144,7 +249,7 @@ impl PartitionEvaluator for NumRowsEvaluator {
 mod tests {
     use std::sync::Arc;
 
-    use datafusion_common::arrow::array::{Array, BooleanArray};
+    use datafusion_common::arrow::array::{Array, BooleanArray, Int32Array};
     use datafusion_common::cast::as_uint64_array;
 
     use super::*;
@@ -180,4 +285,28 @@ mod tests {
         assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8], *actual.values());
         Ok(())
     }
+
+    #[test]
+    fn row_number_reset_on_key_change() -> Result<()> {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(1),
+            Some(1),
+            Some(1),
+            None,
+            None,
+            Some(1),
+        ]));
+        let num_rows = values.len();
+
+        let actual = RowNumberReset::default()
+            .partition_evaluator()?
+            .evaluate_all(&[values], num_rows)?;
+        let actual = as_uint64_array(&actual)?;
+
+        assert_eq!(vec![1, 2, 1, 1, 2, 3, 1, 2, 1], *actual.values());
+        Ok(())
+    }
 }
@@ -18,6 +18,7 @@
 use arrow::array::{
     make_array, Array, Capacities, MutableArrayData, Scalar, StringArray,
 };
+use arrow::buffer::NullBuffer;
 use arrow::datatypes::DataType;
 use datafusion_common::cast::{as_map_array, as_struct_array};
 use datafusion_common::{
@@ -218,10 +219,19 @@ impl ScalarUDFImpl for GetFieldFunc {
             }
             (DataType::Struct(_), ScalarValue::Utf8(Some(k))) => {
                 let as_struct_array = as_struct_array(&array)?;
-                match as_struct_array.column_by_name(k) {
-                    None => exec_err!("get indexed field {k} not found in struct"),
-                    Some(col) => Ok(ColumnarValue::Array(Arc::clone(col))),
-                }
+                let Some(col) = as_struct_array.column_by_name(k) else {
+                    return exec_err!("get indexed field {k} not found in struct");
+                };
+                // a field of a null struct is null, even if the child array
+                // holds a value at that position
+                let field = match as_struct_array.nulls() {
+                    None => Arc::clone(col),
+                    Some(struct_nulls) => {
+                        let nulls = NullBuffer::union(Some(struct_nulls), col.nulls());
+                        make_array(col.to_data().into_builder().nulls(nulls).build()?)
+                    }
+                };
+                Ok(ColumnarValue::Array(field))
             }
             (DataType::Struct(_), name) => exec_err!(
                 "get indexed field is only possible on struct with utf8 indexes. \
@@ -235,3 +245,30 @@ impl ScalarUDFImpl for GetFieldFunc {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{ArrayRef, Int32Array, StructArray};
+    use arrow::datatypes::{Field, Fields};
+
+    #[test]
+    fn get_field_propagates_struct_nulls() -> Result<()> {
+        // the struct is null in the second row and the field in the third
+        let fields = Fields::from(vec![Field::new("a", DataType::Int32, true)]);
+        let child: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), Some(2), None]));
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let array = StructArray::new(fields, vec![child], Some(nulls));
+
+        let args = [
+            ColumnarValue::Array(Arc::new(array)),
+            ColumnarValue::Scalar(ScalarValue::from("a")),
+        ];
+        let ColumnarValue::Array(result) = GetFieldFunc::new().invoke(&args)? else {
+            panic!("Expected an array");
+        };
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, None]));
+        assert_eq!(&expected, &result);
+        Ok(())
+    }
+}
@@ -20,7 +20,6 @@ use std::sync::Arc;
 use arrow::array::{
     Array, ArrowPrimitiveType, GenericStringArray, OffsetSizeTrait, PrimitiveArray,
 };
-use arrow::compute::kernels::cast_utils::string_to_timestamp_nanos;
 use arrow::datatypes::DataType;
 use chrono::format::{parse, Parsed, StrftimeItems};
 use chrono::LocalResult::Single;
@@ -28,6 +27,7 @@ use chrono::{DateTime, TimeZone, Utc};
 use itertools::Either;
 
 use datafusion_common::cast::as_generic_string_array;
+use datafusion_common::parsers::string_to_timestamp_nanos_with_fallback;
 use datafusion_common::{
     exec_err, unwrap_or_internal_err, DataFusionError, Result, ScalarType, ScalarValue,
 };
@@ -37,8 +37,14 @@ use datafusion_expr::ColumnarValue;
 const ERR_NANOSECONDS_NOT_SUPPORTED: &str = "The dates that can be represented as nanoseconds have to be between 1677-09-21T00:12:44.0 and 2262-04-11T23:47:16.854775804";
 
 /// Calls string_to_timestamp_nanos and converts the error type
+///
+/// Strings that are not RFC3339 timestamps are parsed with the
+/// [`TIMESTAMP_FALLBACK_FORMATS`], and the RFC3339 error is returned if none
+/// matches.
+///
+/// [`TIMESTAMP_FALLBACK_FORMATS`]: datafusion_common::parsers::TIMESTAMP_FALLBACK_FORMATS
 pub(crate) fn string_to_timestamp_nanos_shim(s: &str) -> Result<i64> {
-    string_to_timestamp_nanos(s).map_err(|e| e.into())
+    string_to_timestamp_nanos_with_fallback(s).map_err(|e| e.into())
 }
 
 /// Checks that all the arguments from the second are of type [Utf8] or [LargeUtf8]
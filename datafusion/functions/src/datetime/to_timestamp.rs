@@ -489,6 +489,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn to_timestamp_fallback_formats() -> Result<()> {
+        let cases = [
+            ("2021/09/07", 1630972800000000000),
+            ("2021/09/07 16:00:00", 1631030400000000000),
+            ("2021/09/07 16:00:00.5", 1631030400500000000),
+            ("07-Sep-2021", 1630972800000000000),
+            ("07-Sep-2021 16:00:00", 1631030400000000000),
+            ("2021-09-07 16:00:00 +0200", 1631023200000000000),
+            ("2021-09-07 16:00:00.5 -01:00", 1631034000500000000),
+        ];
+        for (s, expected) in cases {
+            let arg = ColumnarValue::Scalar(ScalarValue::Utf8(Some(s.to_string())));
+            let ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(ts), None)) =
+                to_timestamp(&[arg])?
+            else {
+                panic!("Expected a timestamp scalar for {s}");
+            };
+            assert_eq!(ts, expected, "parsing {s}");
+        }
+
+        // the error of the RFC3339 parser is reported if no format matches
+        let arg = ColumnarValue::Scalar(ScalarValue::Utf8(Some("2021.09.07".into())));
+        let err = to_timestamp(&[arg]).unwrap_err();
+        assert_contains!(err.to_string(), "Error parsing timestamp from '2021.09.07'");
+        Ok(())
+    }
+
     #[test]
     fn to_timestamp_with_formats_arrays_and_nulls() -> Result<()> {
         // ensure that arrow array implementation is wired up and handles nulls correctly
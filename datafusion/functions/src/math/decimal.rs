@@ -0,0 +1,548 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decimal division and casts rounding with the configured
+//! `datafusion.execution.decimal_rounding_mode`
+
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use crate::utils::make_scalar_function;
+
+use arrow::array::{ArrayRef, AsArray, Decimal128Array};
+use arrow::compute::try_binary;
+use arrow::datatypes::DataType::{Decimal128, Int64, Utf8};
+use arrow::datatypes::{
+    i256, ArrowNativeTypeOp, DataType, Decimal128Type, DECIMAL128_MAX_PRECISION,
+    DECIMAL128_MAX_SCALE,
+};
+use arrow::error::ArrowError;
+use datafusion_common::config::{ConfigOptions, DecimalRoundingMode};
+use datafusion_common::tree_node::Transformed;
+use datafusion_common::{
+    exec_err, internal_err, plan_err, DFSchema, ExprSchema, Result, ScalarValue,
+};
+use datafusion_expr::expr::{Cast, ScalarFunction};
+use datafusion_expr::expr_rewriter::FunctionRewrite;
+use datafusion_expr::{
+    lit, BinaryExpr, ColumnarValue, Expr, ExprSchemable, Operator, ScalarUDF,
+    ScalarUDFImpl, Signature, Volatility,
+};
+
+/// Divides `dividend` by `divisor`, rounding the quotient with `mode`
+pub(crate) fn divide_rounded(
+    dividend: i256,
+    divisor: i256,
+    mode: DecimalRoundingMode,
+) -> Result<i256, ArrowError> {
+    let quotient = dividend.div_checked(divisor)?;
+    let remainder = dividend.mod_checked(divisor)?.wrapping_abs();
+    // the remainder is compared with the rest of the divisor to avoid overflows
+    let rest = divisor.wrapping_abs().sub_wrapping(remainder);
+    let away_from_zero = match remainder.cmp(&rest) {
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Equal => match mode {
+            DecimalRoundingMode::HalfUp => true,
+            DecimalRoundingMode::HalfEven => {
+                quotient.mod_wrapping(i256::from_i128(2)) != i256::ZERO
+            }
+        },
+    };
+    if !away_from_zero || remainder == i256::ZERO {
+        Ok(quotient)
+    } else if dividend.is_negative() != divisor.is_negative() {
+        Ok(quotient.sub_wrapping(i256::ONE))
+    } else {
+        Ok(quotient.add_wrapping(i256::ONE))
+    }
+}
+
+/// Divides the decimal `dividend` with `dividend_scale` by the decimal `divisor`
+/// with `divisor_scale`, returning the quotient with `scale` rounded with `mode`
+pub(crate) fn divide_decimal(
+    dividend: i128,
+    dividend_scale: i8,
+    divisor: i128,
+    divisor_scale: i8,
+    scale: i8,
+    mode: DecimalRoundingMode,
+) -> Result<i128, ArrowError> {
+    // dividend / 10^dividend_scale / (divisor / 10^divisor_scale) * 10^scale
+    let shift = scale as i32 - dividend_scale as i32 + divisor_scale as i32;
+    let factor = i256::from_i128(10).pow_checked(shift.unsigned_abs())?;
+    let (dividend, divisor) = if shift >= 0 {
+        (
+            i256::from_i128(dividend).mul_checked(factor)?,
+            i256::from_i128(divisor),
+        )
+    } else {
+        (
+            i256::from_i128(dividend),
+            i256::from_i128(divisor).mul_checked(factor)?,
+        )
+    };
+    divide_rounded(dividend, divisor, mode)?
+        .to_i128()
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Overflow dividing decimals to the scale {scale}"
+            ))
+        })
+}
+
+/// Returns the precision of the quotient with `scale` of decimals with the
+/// given precisions and scales, as computed by the arrow division kernel
+fn division_precision(dividend: &DataType, divisor: &DataType, scale: i8) -> Result<u8> {
+    match (dividend, divisor) {
+        (Decimal128(p1, s1), Decimal128(_, s2)) => {
+            let precision = *p1 as i32 - *s1 as i32 + *s2 as i32 + scale as i32;
+            Ok(precision.clamp(1, DECIMAL128_MAX_PRECISION as i32) as u8)
+        }
+        _ => plan_err!(
+            "decimal_div requires decimal arguments, got {dividend} and {divisor}"
+        ),
+    }
+}
+
+/// Returns the decimal type an operand of `decimal_div` is coerced to
+fn decimal_operand_type(operand: &DataType) -> Result<DataType> {
+    match operand {
+        Decimal128(_, _) => Ok(operand.clone()),
+        t if t.is_integer() || t.is_null() => Ok(Decimal128(20, 0)),
+        t => plan_err!("Unsupported data type {t} for function decimal_div"),
+    }
+}
+
+/// Returns the value of a constant `Int64` argument
+fn int64_arg(name: &str, arg: &ColumnarValue) -> Result<i64> {
+    match arg {
+        ColumnarValue::Scalar(ScalarValue::Int64(Some(value))) => Ok(*value),
+        _ => exec_err!("{name} requires a constant Int64 argument, got {arg:?}"),
+    }
+}
+
+/// Returns the value of a constant `Int64` argument expression
+fn int64_expr_arg(name: &str, arg: &Expr) -> Result<i64> {
+    match arg {
+        Expr::Literal(ScalarValue::Int64(Some(value))) => Ok(*value),
+        _ => plan_err!("{name} requires a constant Int64 argument, got {arg}"),
+    }
+}
+
+/// Returns the rounding mode of a constant `Utf8` argument
+pub(crate) fn rounding_mode_arg(
+    name: &str,
+    arg: &ColumnarValue,
+) -> Result<DecimalRoundingMode> {
+    match arg {
+        ColumnarValue::Scalar(ScalarValue::Utf8(Some(mode))) => mode.parse(),
+        _ => exec_err!("{name} requires a constant rounding mode, got {arg:?}"),
+    }
+}
+
+/// Returns the scale of a constant `Int64` argument expression
+fn scale_expr_arg(name: &str, arg: &Expr) -> Result<i8> {
+    let scale = int64_expr_arg(name, arg)?;
+    match i8::try_from(scale) {
+        Ok(scale) if scale <= DECIMAL128_MAX_SCALE => Ok(scale),
+        _ => plan_err!("Invalid scale {scale} for {name}"),
+    }
+}
+
+/// Divides decimals to a given scale, rounding the quotient with a given
+/// rounding mode: `decimal_div(dividend, divisor, scale, mode)`
+///
+/// Used in place of the `/` operator when `decimal_division_scale` is set.
+#[derive(Debug)]
+pub struct DecimalDivFunc {
+    signature: Signature,
+}
+
+impl Default for DecimalDivFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecimalDivFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for DecimalDivFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "decimal_div"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_type_from_exprs should be called instead")
+    }
+
+    fn return_type_from_exprs(
+        &self,
+        args: &[Expr],
+        _schema: &dyn ExprSchema,
+        arg_types: &[DataType],
+    ) -> Result<DataType> {
+        if args.len() != 4 {
+            return plan_err!("decimal_div requires 4 arguments, got {}", args.len());
+        }
+        let scale = scale_expr_arg(self.name(), &args[2])?;
+        // the return type is also computed before the operands are coerced
+        let precision = division_precision(
+            &decimal_operand_type(&arg_types[0])?,
+            &decimal_operand_type(&arg_types[1])?,
+            scale,
+        )?;
+        Ok(Decimal128(precision, scale))
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.len() != 4 {
+            return plan_err!(
+                "decimal_div requires 4 arguments, got {}",
+                arg_types.len()
+            );
+        }
+        Ok(vec![
+            decimal_operand_type(&arg_types[0])?,
+            decimal_operand_type(&arg_types[1])?,
+            Int64,
+            Utf8,
+        ])
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        if args.len() != 4 {
+            return exec_err!("decimal_div requires 4 arguments, got {}", args.len());
+        }
+        let scale = int64_arg(self.name(), &args[2])? as i8;
+        let mode = rounding_mode_arg(self.name(), &args[3])?;
+        make_scalar_function(
+            move |args: &[ArrayRef]| {
+                let (dividend_type, divisor_type) =
+                    (args[0].data_type(), args[1].data_type());
+                let precision = division_precision(dividend_type, divisor_type, scale)?;
+                let (Decimal128(_, dividend_scale), Decimal128(_, divisor_scale)) =
+                    (dividend_type, divisor_type)
+                else {
+                    return exec_err!("decimal_div requires decimal arguments");
+                };
+                let result: Decimal128Array = try_binary(
+                    args[0].as_primitive::<Decimal128Type>(),
+                    args[1].as_primitive::<Decimal128Type>(),
+                    |dividend, divisor| {
+                        divide_decimal(
+                            dividend,
+                            *dividend_scale,
+                            divisor,
+                            *divisor_scale,
+                            scale,
+                            mode,
+                        )
+                    },
+                )?;
+                let result = result.with_precision_and_scale(precision, scale)?;
+                result.validate_decimal_precision(precision)?;
+                Ok(Arc::new(result) as ArrayRef)
+            },
+            vec![],
+        )(&args[..2])
+    }
+}
+
+/// Casts decimals to a given precision and scale, rounding with a given
+/// rounding mode: `decimal_cast(value, precision, scale, mode)`
+///
+/// Used in place of casts of decimals to a smaller scale when the rounding
+/// mode is not the `half_up` rounding of the cast kernel.
+#[derive(Debug)]
+pub struct DecimalCastFunc {
+    signature: Signature,
+}
+
+impl Default for DecimalCastFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecimalCastFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for DecimalCastFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "decimal_cast"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_type_from_exprs should be called instead")
+    }
+
+    fn return_type_from_exprs(
+        &self,
+        args: &[Expr],
+        _schema: &dyn ExprSchema,
+        _arg_types: &[DataType],
+    ) -> Result<DataType> {
+        if args.len() != 4 {
+            return plan_err!("decimal_cast requires 4 arguments, got {}", args.len());
+        }
+        let precision = int64_expr_arg(self.name(), &args[1])?;
+        let scale = scale_expr_arg(self.name(), &args[2])?;
+        match u8::try_from(precision) {
+            Ok(precision) if (1..=DECIMAL128_MAX_PRECISION).contains(&precision) => {
+                Ok(Decimal128(precision, scale))
+            }
+            _ => plan_err!("Invalid precision {precision} for decimal_cast"),
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        match arg_types {
+            [value @ Decimal128(_, _), _, _, _] => {
+                Ok(vec![value.clone(), Int64, Int64, Utf8])
+            }
+            _ => plan_err!(
+                "decimal_cast requires a decimal and 3 constant arguments, got {arg_types:?}"
+            ),
+        }
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        if args.len() != 4 {
+            return exec_err!("decimal_cast requires 4 arguments, got {}", args.len());
+        }
+        let precision = int64_arg(self.name(), &args[1])? as u8;
+        let scale = int64_arg(self.name(), &args[2])? as i8;
+        let mode = rounding_mode_arg(self.name(), &args[3])?;
+        make_scalar_function(
+            move |args: &[ArrayRef]| {
+                let Decimal128(_, value_scale) = args[0].data_type() else {
+                    return exec_err!("decimal_cast requires a decimal argument");
+                };
+                let value_scale = *value_scale;
+                let result: Decimal128Array = args[0]
+                    .as_primitive::<Decimal128Type>()
+                    .try_unary(|value| {
+                        divide_decimal(value, value_scale, 1, 0, scale, mode)
+                    })?;
+                let result = result.with_precision_and_scale(precision, scale)?;
+                result.validate_decimal_precision(precision)?;
+                Ok(Arc::new(result) as ArrayRef)
+            },
+            vec![],
+        )(&args[..1])
+    }
+}
+
+/// Returns the [`ScalarUDF`] of [`DecimalDivFunc`]
+pub fn decimal_div_udf() -> Arc<ScalarUDF> {
+    static INSTANCE: OnceLock<Arc<ScalarUDF>> = OnceLock::new();
+    Arc::clone(
+        INSTANCE
+            .get_or_init(|| Arc::new(ScalarUDF::new_from_impl(DecimalDivFunc::new()))),
+    )
+}
+
+/// Returns the [`ScalarUDF`] of [`DecimalCastFunc`]
+pub fn decimal_cast_udf() -> Arc<ScalarUDF> {
+    static INSTANCE: OnceLock<Arc<ScalarUDF>> = OnceLock::new();
+    Arc::clone(
+        INSTANCE
+            .get_or_init(|| Arc::new(ScalarUDF::new_from_impl(DecimalCastFunc::new()))),
+    )
+}
+
+/// Rewrites decimal division, casts of decimals to a smaller scale and `round`
+/// of decimals to honor `datafusion.execution.decimal_division_scale` and
+/// `datafusion.execution.decimal_rounding_mode`
+#[derive(Debug, Default)]
+pub struct DecimalFunctionRewriter {}
+
+impl FunctionRewrite for DecimalFunctionRewriter {
+    fn name(&self) -> &str {
+        "DecimalFunctionRewriter"
+    }
+
+    fn rewrite(
+        &self,
+        expr: Expr,
+        schema: &DFSchema,
+        config: &ConfigOptions,
+    ) -> Result<Transformed<Expr>> {
+        let options = &config.execution;
+        let mode = options.decimal_rounding_mode;
+        let is_decimal =
+            |expr: &Expr| matches!(expr.get_type(schema), Ok(Decimal128(_, _)));
+        let transformed = match expr {
+            Expr::BinaryExpr(BinaryExpr {
+                left,
+                op: Operator::Divide,
+                right,
+            }) if options.decimal_division_scale.is_some()
+                && (is_decimal(&left) || is_decimal(&right))
+                && is_decimal_operand(&left, schema)
+                && is_decimal_operand(&right, schema) =>
+            {
+                let scale = options.decimal_division_scale.unwrap_or_default() as i64;
+                Expr::ScalarFunction(ScalarFunction::new_udf(
+                    decimal_div_udf(),
+                    vec![*left, *right, lit(scale), lit(mode.as_str())],
+                ))
+            }
+            // the cast kernel already rounds half up
+            Expr::Cast(Cast {
+                expr,
+                data_type: Decimal128(precision, scale),
+            }) if matches!(
+                expr.get_type(schema),
+                Ok(Decimal128(_, value_scale)) if value_scale > scale
+            ) && mode != DecimalRoundingMode::HalfUp =>
+            {
+                Expr::ScalarFunction(ScalarFunction::new_udf(
+                    decimal_cast_udf(),
+                    vec![
+                        *expr,
+                        lit(precision as i64),
+                        lit(scale as i64),
+                        lit(mode.as_str()),
+                    ],
+                ))
+            }
+            // round rounds decimals half up unless given a rounding mode
+            Expr::ScalarFunction(ScalarFunction { func, mut args })
+                if func.name() == "round"
+                    && (1..=2).contains(&args.len())
+                    && is_decimal(&args[0])
+                    && mode != DecimalRoundingMode::HalfUp =>
+            {
+                if args.len() == 1 {
+                    args.push(lit(0_i64));
+                }
+                args.push(lit(mode.as_str()));
+                Expr::ScalarFunction(ScalarFunction { func, args })
+            }
+            expr => return Ok(Transformed::no(expr)),
+        };
+        Ok(Transformed::yes(transformed))
+    }
+}
+
+/// Returns true if `expr` is a decimal or an integer, which decimal division
+/// converts to a decimal
+fn is_decimal_operand(expr: &Expr, schema: &DFSchema) -> bool {
+    matches!(
+        expr.get_type(schema),
+        Ok(t) if matches!(t, Decimal128(_, _)) || t.is_integer()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn divide(
+        dividend: i128,
+        divisor: i128,
+        scale: i8,
+        mode: DecimalRoundingMode,
+    ) -> i128 {
+        // both operands have the scale 2
+        divide_decimal(dividend, 2, divisor, 2, scale, mode).unwrap()
+    }
+
+    #[test]
+    fn test_divide_decimal() {
+        use DecimalRoundingMode::*;
+        // 1.00 / 3.00 = 0.333...
+        assert_eq!(divide(100, 300, 2, HalfUp), 33);
+        assert_eq!(divide(100, 300, 6, HalfEven), 333333);
+        // 2.00 / 3.00 = 0.666...
+        assert_eq!(divide(200, 300, 2, HalfUp), 67);
+        assert_eq!(divide(-200, 300, 2, HalfEven), -67);
+        // 1.00 / 8.00 = 0.125 is a tie at the scale 2
+        assert_eq!(divide(100, 800, 2, HalfUp), 13);
+        assert_eq!(divide(100, 800, 2, HalfEven), 12);
+        assert_eq!(divide(-100, 800, 2, HalfUp), -13);
+        assert_eq!(divide(-100, 800, 2, HalfEven), -12);
+        // 3.00 / 8.00 = 0.375 is a tie with an odd last digit
+        assert_eq!(divide(300, -800, 2, HalfUp), -38);
+        assert_eq!(divide(300, -800, 2, HalfEven), -38);
+        // 5.00 / 2.00 = 2.5 is a tie at the scale 0
+        assert_eq!(divide(500, 200, 0, HalfUp), 3);
+        assert_eq!(divide(500, 200, 0, HalfEven), 2);
+        // 7.00 / 2.00 = 3.5
+        assert_eq!(divide(700, 200, 0, HalfEven), 4);
+        // 0.01 / 1000.00 = 0.00001 is not a tie
+        assert_eq!(divide(1, 100000, 4, HalfUp), 0);
+        // 1.23 / 0.01 = 123, scaled down
+        assert_eq!(divide(123, 1, -1, HalfUp), 12);
+
+        assert!(divide_decimal(100, 2, 0, 2, 2, HalfUp).is_err());
+        assert!(divide_decimal(i128::MAX, 0, 1, 0, 2, HalfUp).is_err());
+    }
+
+    #[test]
+    fn test_cast_decimal() {
+        use DecimalRoundingMode::*;
+        // rescaling divides by 1
+        assert_eq!(divide_decimal(12345, 3, 1, 0, 2, HalfUp).unwrap(), 1235);
+        assert_eq!(divide_decimal(12345, 3, 1, 0, 2, HalfEven).unwrap(), 1234);
+        assert_eq!(divide_decimal(12355, 3, 1, 0, 2, HalfEven).unwrap(), 1236);
+        assert_eq!(divide_decimal(-12345, 3, 1, 0, 2, HalfEven).unwrap(), -1234);
+        assert_eq!(divide_decimal(123, 2, 1, 0, 4, HalfEven).unwrap(), 12300);
+    }
+
+    #[test]
+    fn test_rounding_mode() {
+        assert_eq!(
+            "half_up".parse::<DecimalRoundingMode>().unwrap(),
+            DecimalRoundingMode::HalfUp
+        );
+        assert_eq!(
+            "HALF_EVEN".parse::<DecimalRoundingMode>().unwrap(),
+            DecimalRoundingMode::HalfEven
+        );
+        assert!("half_down".parse::<DecimalRoundingMode>().is_err());
+    }
+}
@@ -24,6 +24,7 @@ use std::sync::Arc;
 pub mod abs;
 pub mod bounds;
 pub mod cot;
+pub mod decimal;
 pub mod factorial;
 pub mod gcd;
 pub mod iszero;
@@ -18,17 +18,24 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::math::decimal::{divide_rounded, rounding_mode_arg};
 use crate::utils::make_scalar_function;
+use datafusion_common::config::DecimalRoundingMode;
 
-use arrow::array::{ArrayRef, Float32Array, Float64Array, Int32Array};
-use arrow::compute::{cast_with_options, CastOptions};
-use arrow::datatypes::DataType;
-use arrow::datatypes::DataType::{Float32, Float64, Int32};
+use arrow::array::{
+    ArrayRef, AsArray, Decimal128Array, Float32Array, Float64Array, Int32Array,
+};
+use arrow::compute::{cast_with_options, try_binary, CastOptions};
+use arrow::datatypes::DataType::{Decimal128, Float32, Float64, Int32, Int64, Utf8};
+use arrow::datatypes::{
+    i256, ArrowNativeTypeOp, DataType, Decimal128Type, Int32Type,
+    DECIMAL128_MAX_PRECISION,
+};
+use arrow::error::ArrowError;
 use datafusion_common::{
-    exec_datafusion_err, exec_err, DataFusionError, Result, ScalarValue,
+    exec_datafusion_err, exec_err, plan_err, DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::sort_properties::{ExprProperties, SortProperties};
-use datafusion_expr::TypeSignature::Exact;
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 
 #[derive(Debug)]
@@ -44,17 +51,8 @@ impl Default for RoundFunc {
 
 impl RoundFunc {
     pub fn new() -> Self {
-        use DataType::*;
         Self {
-            signature: Signature::one_of(
-                vec![
-                    Exact(vec![Float64, Int64]),
-                    Exact(vec![Float32, Int64]),
-                    Exact(vec![Float64]),
-                    Exact(vec![Float32]),
-                ],
-                Volatility::Immutable,
-            ),
+            signature: Signature::user_defined(Volatility::Immutable),
         }
     }
 }
@@ -75,12 +73,39 @@ impl ScalarUDFImpl for RoundFunc {
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         match arg_types[0] {
             Float32 => Ok(Float32),
+            Decimal128(precision, scale) => Ok(Decimal128(precision, scale)),
             _ => Ok(Float64),
         }
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        // the rounding mode is only supported for decimals
+        match arg_types {
+            [value @ Decimal128(_, _), decimal_places, mode]
+                if mode.is_null() || matches!(mode, Utf8) =>
+            {
+                let mut arg_types =
+                    coerce_round_args(self.name(), &[value.clone(), decimal_places.clone()])?;
+                arg_types.push(Utf8);
+                Ok(arg_types)
+            }
+            [_, _, _] => plan_err!(
+                "round function with a rounding mode requires a decimal and a string, got {arg_types:?}"
+            ),
+            _ => coerce_round_args(self.name(), arg_types),
+        }
+    }
+
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        make_scalar_function(round, vec![])(args)
+        if args.len() == 3 {
+            let mode = rounding_mode_arg(self.name(), &args[2])?;
+            make_scalar_function(
+                move |args: &[ArrayRef]| round_with_mode(args, mode),
+                vec![],
+            )(&args[..2])
+        } else {
+            make_scalar_function(round, vec![])(args)
+        }
     }
 
     fn output_ordering(&self, input: &[ExprProperties]) -> Result<SortProperties> {
@@ -101,6 +126,11 @@ impl ScalarUDFImpl for RoundFunc {
 
 /// Round SQL function
 pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
+    round_with_mode(args, DecimalRoundingMode::HalfUp)
+}
+
+/// Round SQL function, rounding decimals with `mode`
+fn round_with_mode(args: &[ArrayRef], mode: DecimalRoundingMode) -> Result<ArrayRef> {
     if args.len() != 1 && args.len() != 2 {
         return exec_err!(
             "round function requires one or two arguments, got {}",
@@ -213,18 +243,109 @@ pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
             }
         },
 
+        // decimals are rounded exactly and keep their precision and scale
+        Decimal128(precision, scale) => {
+            let decimal_places = decimal_places.into_array(args[0].len())?;
+            let decimal_places =
+                cast_with_options(&decimal_places, &Int32, &CastOptions::default())?;
+            let scale = *scale;
+            let result: Decimal128Array = try_binary(
+                args[0].as_primitive::<Decimal128Type>(),
+                decimal_places.as_primitive::<Int32Type>(),
+                |value, decimal_places| round_decimal(value, scale, decimal_places, mode),
+            )?;
+            // rounding up may carry into a digit the precision has no room for
+            result.validate_decimal_precision(*precision)?;
+            Ok(Arc::new(
+                result.with_precision_and_scale(*precision, scale)?,
+            ))
+        }
+
         other => exec_err!("Unsupported data type {other:?} for function round"),
     }
 }
 
+/// Coerce the arguments of `round` and `trunc`: Float32 and Decimal128
+/// values keep their type, other numbers are converted to Float64, and the
+/// number of decimal places to an Int64. Decimals are rounded exactly.
+pub(crate) fn coerce_round_args(
+    name: &str,
+    arg_types: &[DataType],
+) -> Result<Vec<DataType>> {
+    if arg_types.is_empty() || arg_types.len() > 2 {
+        return plan_err!(
+            "{name} function requires one or two arguments, got {}",
+            arg_types.len()
+        );
+    }
+    let value = match &arg_types[0] {
+        Float32 | Decimal128(_, _) => arg_types[0].clone(),
+        t if t.is_numeric() || t.is_null() => Float64,
+        t => return plan_err!("Unsupported data type {t} for function {name}"),
+    };
+    match arg_types.get(1) {
+        None => Ok(vec![value]),
+        Some(t) if t.is_integer() || t.is_null() => Ok(vec![value, Int64]),
+        Some(t) => {
+            plan_err!("Unsupported data type {t} for the decimal places of {name}")
+        }
+    }
+}
+
+/// Round the decimal `value` with `scale` to `decimal_places` digits after the
+/// decimal point with `mode`. The scale is kept.
+pub(crate) fn round_decimal(
+    value: i128,
+    scale: i8,
+    decimal_places: i32,
+    mode: DecimalRoundingMode,
+) -> Result<i128, ArrowError> {
+    let dropped_digits = (scale as i32).saturating_sub(decimal_places);
+    if dropped_digits <= 0 {
+        return Ok(value);
+    }
+    // any Decimal128 value is smaller than half of 10^39
+    if dropped_digits > DECIMAL128_MAX_PRECISION as i32 {
+        return Ok(0);
+    }
+    let factor = i256::from_i128(10).pow_wrapping(dropped_digits as u32);
+    divide_rounded(i256::from_i128(value), factor, mode)?
+        .mul_wrapping(factor)
+        .to_i128()
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Overflow rounding {value} to {decimal_places} decimal places"
+            ))
+        })
+}
+
+/// Truncate the decimal `value` with `scale` to `decimal_places` digits after
+/// the decimal point. The scale is kept.
+pub(crate) fn truncate_decimal(value: i128, scale: i8, decimal_places: i32) -> i128 {
+    let dropped_digits = (scale as i32).saturating_sub(decimal_places);
+    if dropped_digits <= 0 {
+        value
+    } else if dropped_digits > DECIMAL128_MAX_PRECISION as i32 {
+        0
+    } else {
+        let factor = 10_i128.pow(dropped_digits as u32);
+        value / factor * factor
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
 
-    use crate::math::round::round;
+    use crate::math::round::{round, round_with_mode};
+    use datafusion_common::config::DecimalRoundingMode;
 
-    use arrow::array::{ArrayRef, Float32Array, Float64Array, Int64Array};
-    use datafusion_common::cast::{as_float32_array, as_float64_array};
+    use arrow::array::{
+        ArrayRef, Decimal128Array, Float32Array, Float64Array, Int64Array,
+    };
+    use datafusion_common::cast::{
+        as_decimal128_array, as_float32_array, as_float64_array,
+    };
     use datafusion_common::DataFusionError;
 
     #[test]
@@ -305,4 +426,53 @@ mod test {
         assert!(result.is_err());
         assert!(matches!(result, Err(DataFusionError::Execution { .. })));
     }
+
+    #[test]
+    fn test_round_decimal() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(
+                Decimal128Array::from(vec![12345, -12345, 12355, 99949, 12345, 12344])
+                    .with_precision_and_scale(5, 2)
+                    .unwrap(),
+            ), // input
+            Arc::new(Int64Array::from(vec![1, 1, 1, 0, -1, 40])), // decimal_places
+        ];
+
+        let result = round(&args).expect("failed to initialize function round");
+        let decimals =
+            as_decimal128_array(&result).expect("failed to initialize function round");
+
+        let expected =
+            Decimal128Array::from(vec![12350, -12350, 12360, 99900, 12000, 12344])
+                .with_precision_and_scale(5, 2)
+                .unwrap();
+
+        assert_eq!(decimals, &expected);
+
+        let result = round_with_mode(&args, DecimalRoundingMode::HalfEven)
+            .expect("failed to initialize function round");
+        let decimals =
+            as_decimal128_array(&result).expect("failed to initialize function round");
+
+        let expected =
+            Decimal128Array::from(vec![12340, -12340, 12360, 99900, 12000, 12344])
+                .with_precision_and_scale(5, 2)
+                .unwrap();
+
+        assert_eq!(decimals, &expected);
+    }
+
+    #[test]
+    fn test_round_decimal_overflow() {
+        // 999.99 rounds to 1000.00, which does not fit in a DECIMAL(5, 2)
+        let args: Vec<ArrayRef> = vec![Arc::new(
+            Decimal128Array::from(vec![99999])
+                .with_precision_and_scale(5, 2)
+                .unwrap(),
+        )];
+
+        let result = round(&args);
+
+        assert!(matches!(result, Err(DataFusionError::ArrowError(..))));
+    }
 }
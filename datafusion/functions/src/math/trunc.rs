@@ -18,15 +18,18 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::math::round::{coerce_round_args, truncate_decimal};
 use crate::utils::make_scalar_function;
 
-use arrow::array::{ArrayRef, Float32Array, Float64Array, Int64Array};
-use arrow::datatypes::DataType;
-use arrow::datatypes::DataType::{Float32, Float64};
+use arrow::array::{
+    ArrayRef, AsArray, Decimal128Array, Float32Array, Float64Array, Int64Array,
+};
+use arrow::compute::binary;
+use arrow::datatypes::DataType::{Decimal128, Float32, Float64};
+use arrow::datatypes::{DataType, Decimal128Type, Int64Type};
 use datafusion_common::ScalarValue::Int64;
 use datafusion_common::{exec_err, DataFusionError, Result};
 use datafusion_expr::sort_properties::{ExprProperties, SortProperties};
-use datafusion_expr::TypeSignature::Exact;
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 
 #[derive(Debug)]
@@ -42,22 +45,14 @@ impl Default for TruncFunc {
 
 impl TruncFunc {
     pub fn new() -> Self {
-        use DataType::*;
         Self {
             // math expressions expect 1 argument of type f64 or f32
             // priority is given to f64 because e.g. `sqrt(1i32)` is in IR (real numbers) and thus we
             // return the best approximation for it (in f64).
             // We accept f32 because in this case it is clear that the best approximation
-            // will be as good as the number of digits in the number
-            signature: Signature::one_of(
-                vec![
-                    Exact(vec![Float32, Int64]),
-                    Exact(vec![Float64, Int64]),
-                    Exact(vec![Float64]),
-                    Exact(vec![Float32]),
-                ],
-                Volatility::Immutable,
-            ),
+            // will be as good as the number of digits in the number, and decimals
+            // because they can be truncated exactly
+            signature: Signature::user_defined(Volatility::Immutable),
         }
     }
 }
@@ -78,10 +73,15 @@ impl ScalarUDFImpl for TruncFunc {
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         match arg_types[0] {
             Float32 => Ok(Float32),
+            Decimal128(precision, scale) => Ok(Decimal128(precision, scale)),
             _ => Ok(Float64),
         }
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_round_args(self.name(), arg_types)
+    }
+
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
         make_scalar_function(trunc, vec![])(args)
     }
@@ -151,6 +151,23 @@ fn trunc(args: &[ArrayRef]) -> Result<ArrayRef> {
             )) as ArrayRef),
             _ => exec_err!("trunc function requires a scalar or array for precision"),
         },
+        Decimal128(decimal_precision, scale) => {
+            let precision = precision.into_array(num.len())?;
+            let scale = *scale;
+            let result: Decimal128Array = binary(
+                num.as_primitive::<Decimal128Type>(),
+                precision.as_primitive::<Int64Type>(),
+                |value, decimal_places| {
+                    let decimal_places =
+                        decimal_places.clamp(i32::MIN as i64, i32::MAX as i64);
+                    truncate_decimal(value, scale, decimal_places as i32)
+                },
+            )?;
+            Ok(
+                Arc::new(result.with_precision_and_scale(*decimal_precision, scale)?)
+                    as ArrayRef,
+            )
+        }
         other => exec_err!("Unsupported data type {other:?} for function trunc"),
     }
 }
@@ -171,8 +188,12 @@ mod test {
 
     use crate::math::trunc::trunc;
 
-    use arrow::array::{ArrayRef, Float32Array, Float64Array, Int64Array};
-    use datafusion_common::cast::{as_float32_array, as_float64_array};
+    use arrow::array::{
+        ArrayRef, Decimal128Array, Float32Array, Float64Array, Int64Array,
+    };
+    use datafusion_common::cast::{
+        as_decimal128_array, as_float32_array, as_float64_array,
+    };
 
     #[test]
     fn test_truncate_32() {
@@ -245,4 +266,26 @@ mod test {
         assert_eq!(floats.value(3), 123.0);
         assert_eq!(floats.value(4), -321.0);
     }
+
+    #[test]
+    fn test_truncate_decimal() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(
+                Decimal128Array::from(vec![12345, -12345, 12399, 12345])
+                    .with_precision_and_scale(5, 2)
+                    .unwrap(),
+            ),
+            Arc::new(Int64Array::from(vec![1, 1, 0, -2])),
+        ];
+
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let decimals =
+            as_decimal128_array(&result).expect("failed to initialize function truncate");
+
+        let expected = Decimal128Array::from(vec![12340, -12340, 12300, 10000])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+
+        assert_eq!(decimals, &expected);
+    }
 }
@@ -17,6 +17,10 @@
 
 //! "regx" DataFusion functions
 
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::Expr;
+use regex::Regex;
+
 pub mod regexplike;
 pub mod regexpmatch;
 pub mod regexpreplace;
@@ -65,6 +69,54 @@ pub mod expr_fn {
     }
 }
 
+/// Checks that the pattern argument of the function `name` is a valid regular
+/// expression if it is a literal, so that an invalid pattern is reported when
+/// the query is planned rather than when the first batch is evaluated.
+///
+/// The flags argument is taken into account if it is a literal too; if it is
+/// not, the pattern can only be checked during execution.
+fn validate_literal_pattern(
+    name: &str,
+    args: &[Expr],
+    pattern_index: usize,
+    flags_index: usize,
+) -> Result<()> {
+    let Some(pattern) = args.get(pattern_index).and_then(literal_string) else {
+        return Ok(());
+    };
+    let flags = match args.get(flags_index) {
+        None => "",
+        Some(flags) => match literal_string(flags) {
+            Some(flags) => flags,
+            None => return Ok(()),
+        },
+    };
+
+    // the global flag is handled by the functions, not by the regex crate
+    let flags = flags.replace('g', "");
+    let pattern = if flags.is_empty() {
+        pattern.to_string()
+    } else {
+        format!("(?{flags}){pattern}")
+    };
+    match Regex::new(&pattern) {
+        Ok(_) => Ok(()),
+        Err(e) => plan_err!("{name}() got an invalid regular expression: {e}"),
+    }
+}
+
+/// Returns the value of a non null string literal
+fn literal_string(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Literal(
+            ScalarValue::Utf8(Some(s))
+            | ScalarValue::LargeUtf8(Some(s))
+            | ScalarValue::Utf8View(Some(s)),
+        ) => Some(s),
+        _ => None,
+    }
+}
+
 /// Returns all DataFusion functions defined in this package
 pub fn functions() -> Vec<std::sync::Arc<datafusion_expr::ScalarUDF>> {
     vec![regexp_match(), regexp_like(), regexp_replace()]
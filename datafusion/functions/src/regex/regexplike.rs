@@ -16,17 +16,19 @@
 // under the License.
 
 //! Regx expressions
+use crate::regex::validate_literal_pattern;
 use arrow::array::{Array, ArrayRef, OffsetSizeTrait};
 use arrow::compute::kernels::regexp;
 use arrow::datatypes::DataType;
 use datafusion_common::exec_err;
+use datafusion_common::ExprSchema;
 use datafusion_common::ScalarValue;
 use datafusion_common::{arrow_datafusion_err, plan_err};
 use datafusion_common::{
     cast::as_generic_string_array, internal_err, DataFusionError, Result,
 };
-use datafusion_expr::ColumnarValue;
 use datafusion_expr::TypeSignature::*;
+use datafusion_expr::{ColumnarValue, Expr};
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 use std::sync::Arc;
@@ -81,6 +83,17 @@ impl ScalarUDFImpl for RegexpLikeFunc {
             _ => Boolean,
         })
     }
+
+    fn return_type_from_exprs(
+        &self,
+        args: &[Expr],
+        _schema: &dyn ExprSchema,
+        arg_types: &[DataType],
+    ) -> Result<DataType> {
+        validate_literal_pattern(self.name(), args, 1, 2)?;
+        self.return_type(arg_types)
+    }
+
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
         let len = args
             .iter()
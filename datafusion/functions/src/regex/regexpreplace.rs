@@ -16,6 +16,7 @@
 // under the License.
 
 //! Regx expressions
+use crate::regex::validate_literal_pattern;
 use arrow::array::new_null_array;
 use arrow::array::ArrayAccessor;
 use arrow::array::ArrayDataBuilder;
@@ -27,13 +28,14 @@ use arrow::datatypes::DataType;
 use datafusion_common::cast::as_string_view_array;
 use datafusion_common::exec_err;
 use datafusion_common::plan_err;
+use datafusion_common::ExprSchema;
 use datafusion_common::ScalarValue;
 use datafusion_common::{
     cast::as_generic_string_array, internal_err, DataFusionError, Result,
 };
 use datafusion_expr::function::Hint;
-use datafusion_expr::ColumnarValue;
 use datafusion_expr::TypeSignature::*;
+use datafusion_expr::{ColumnarValue, Expr};
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use regex::Regex;
 use std::any::Any;
@@ -104,6 +106,17 @@ impl ScalarUDFImpl for RegexpReplaceFunc {
             }
         })
     }
+
+    fn return_type_from_exprs(
+        &self,
+        args: &[Expr],
+        _schema: &dyn ExprSchema,
+        arg_types: &[DataType],
+    ) -> Result<DataType> {
+        validate_literal_pattern(self.name(), args, 1, 3)?;
+        self.return_type(arg_types)
+    }
+
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
         let len = args
             .iter()
@@ -0,0 +1,300 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Analyzer rule to replace the `grouping` aggregate function with
+//! expressions over the output of the aggregation
+
+use std::sync::Arc;
+
+use crate::analyzer::AnalyzerRule;
+
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::tree_node::{Transformed, TransformedResult};
+use datafusion_common::{plan_err, Column, Result};
+use datafusion_expr::expr::{AggregateFunction, Alias, GroupingSet};
+use datafusion_expr::logical_plan::{Aggregate, Projection};
+use datafusion_expr::utils::enumerate_grouping_sets;
+use datafusion_expr::{lit, when, Expr, LogicalPlan};
+
+/// Maximum number of grouping sets a `CUBE` may expand to
+const MAX_GROUPING_SETS: usize = 4096;
+
+/// Replaces calls to the `grouping` aggregate function with expressions
+/// computed from the output of the aggregation.
+///
+/// `grouping(e1, .., en)` returns a bit mask whose bit `n - i` is set if
+/// `ei` is not part of the grouping set of the row, i.e. its value is the
+/// NULL of a super-aggregate row rather than a NULL of the input.
+///
+/// For every argument `ei` the rule adds a marker literal to each grouping
+/// set that contains `ei`. The marker is NULL exactly in the rows of the
+/// grouping sets that aggregate over `ei`, so that
+///
+/// ```text
+/// Aggregate: groupBy=[[ROLLUP (a, b)]], aggr=[[grouping(b)]]
+/// ```
+///
+/// is rewritten to
+///
+/// ```text
+/// Projection: a, b, CASE WHEN __grouping_0 IS NULL THEN 1 ELSE 0 END AS grouping(b)
+///   Aggregate: groupBy=[[GROUPING SETS ((), (a), (a, b, Int32(1) AS __grouping_0))]], aggr=[[]]
+/// ```
+///
+/// Without grouping sets, `grouping` is always 0.
+#[derive(Default)]
+pub struct ResolveGroupingFunction;
+
+impl ResolveGroupingFunction {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AnalyzerRule for ResolveGroupingFunction {
+    fn analyze(&self, plan: LogicalPlan, _: &ConfigOptions) -> Result<LogicalPlan> {
+        plan.transform_up_with_subqueries(analyze_internal).data()
+    }
+
+    fn name(&self) -> &str {
+        "resolve_grouping_function"
+    }
+}
+
+fn analyze_internal(plan: LogicalPlan) -> Result<Transformed<LogicalPlan>> {
+    match plan {
+        LogicalPlan::Aggregate(aggregate)
+            if aggregate.aggr_expr.iter().any(is_grouping_function) =>
+        {
+            resolve_grouping_function(aggregate).map(Transformed::yes)
+        }
+        _ => Ok(Transformed::no(plan)),
+    }
+}
+
+/// Returns the arguments of `expr` if it is a call to `grouping`
+fn grouping_function_args(expr: &Expr) -> Option<&[Expr]> {
+    match expr {
+        Expr::Alias(Alias { expr, .. }) => grouping_function_args(expr),
+        Expr::AggregateFunction(AggregateFunction { func, args, .. })
+            if func.name() == "grouping" =>
+        {
+            Some(args)
+        }
+        _ => None,
+    }
+}
+
+fn is_grouping_function(expr: &Expr) -> bool {
+    grouping_function_args(expr).is_some()
+}
+
+/// Name of the marker of the `index`th distinct argument of `grouping`
+fn grouping_marker_name(index: usize) -> String {
+    format!("__grouping_{index}")
+}
+
+fn resolve_grouping_function(aggregate: Aggregate) -> Result<LogicalPlan> {
+    let Aggregate {
+        input,
+        group_expr,
+        aggr_expr,
+        schema,
+        ..
+    } = aggregate;
+
+    let group_expr = enumerate_grouping_sets(group_expr)?;
+    let (grouping_sets, distinct_group_expr) = match group_expr.as_slice() {
+        [Expr::GroupingSet(grouping_set)] => (
+            Some(expand_grouping_set(grouping_set)?),
+            grouping_set.distinct_expr().into_iter().cloned().collect(),
+        ),
+        _ => (None, group_expr.clone()),
+    };
+
+    // the distinct arguments of all the calls to `grouping`
+    let mut grouping_args: Vec<Expr> = vec![];
+    for arg in aggr_expr
+        .iter()
+        .filter_map(grouping_function_args)
+        .flatten()
+    {
+        if !distinct_group_expr.contains(arg) {
+            return plan_err!(
+                "Argument {arg} of grouping function must be a grouping expression"
+            );
+        }
+        if !grouping_args.contains(arg) {
+            grouping_args.push(arg.clone());
+        }
+    }
+
+    // add the markers of the arguments to the grouping sets containing them
+    let group_expr = match &grouping_sets {
+        Some(grouping_sets) => {
+            let grouping_sets = grouping_sets
+                .iter()
+                .map(|grouping_set| {
+                    let markers = grouping_args
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, arg)| grouping_set.contains(*arg))
+                        .map(|(i, _)| lit(1).alias(grouping_marker_name(i)));
+                    grouping_set.iter().cloned().chain(markers).collect()
+                })
+                .collect();
+            vec![Expr::GroupingSet(GroupingSet::GroupingSets(grouping_sets))]
+        }
+        None => group_expr,
+    };
+    let new_aggr_expr = aggr_expr
+        .iter()
+        .filter(|expr| !is_grouping_function(expr))
+        .cloned()
+        .collect();
+    let aggregate = Aggregate::try_new(input, group_expr, new_aggr_expr)?;
+
+    // restore the output of the original aggregation
+    let num_group_columns = schema.fields().len() - aggr_expr.len();
+    let mut aggr_expr = aggr_expr.iter();
+    let exprs = schema
+        .iter()
+        .enumerate()
+        .map(|(i, (qualifier, field))| {
+            let column = Expr::Column(Column::new(qualifier.cloned(), field.name()));
+            if i < num_group_columns {
+                return Ok(column);
+            }
+            match aggr_expr.next().and_then(grouping_function_args) {
+                Some(args) => {
+                    let grouping = grouping_function_value(
+                        args,
+                        &grouping_args,
+                        grouping_sets.is_some(),
+                    )?;
+                    Ok(grouping.alias_qualified(qualifier.cloned(), field.name()))
+                }
+                None => Ok(column),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Projection::try_new(exprs, Arc::new(LogicalPlan::Aggregate(aggregate)))
+        .map(LogicalPlan::Projection)
+}
+
+/// Returns the value of `grouping(args)` computed from the grouping markers
+fn grouping_function_value(
+    args: &[Expr],
+    grouping_args: &[Expr],
+    has_grouping_sets: bool,
+) -> Result<Expr> {
+    if !has_grouping_sets {
+        return Ok(lit(0));
+    }
+    args.iter()
+        .rev()
+        .enumerate()
+        .map(|(bit, arg)| {
+            let index = grouping_args.iter().position(|e| e == arg).unwrap();
+            let marker = Expr::Column(Column::from_name(grouping_marker_name(index)));
+            when(marker.is_null(), lit(1 << bit)).otherwise(lit(0))
+        })
+        .reduce(|acc, bit| Ok(acc? + bit?))
+        .unwrap_or_else(|| plan_err!("grouping function requires at least one argument"))
+}
+
+/// Returns the expressions of each grouping set of `grouping_set`
+fn expand_grouping_set(grouping_set: &GroupingSet) -> Result<Vec<Vec<Expr>>> {
+    match grouping_set {
+        GroupingSet::GroupingSets(grouping_sets) => Ok(grouping_sets.clone()),
+        GroupingSet::Rollup(exprs) => {
+            Ok((0..=exprs.len()).map(|i| exprs[..i].to_vec()).collect())
+        }
+        GroupingSet::Cube(exprs) => {
+            if exprs.len() >= usize::BITS as usize || 1 << exprs.len() > MAX_GROUPING_SETS
+            {
+                return plan_err!(
+                    "The number of grouping_set in grouping_sets exceeds the maximum limit {MAX_GROUPING_SETS}, found 2^{}",
+                    exprs.len()
+                );
+            }
+            Ok((0..1_usize << exprs.len())
+                .map(|mask| {
+                    exprs
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| mask & (1 << i) != 0)
+                        .map(|(_, expr)| expr.clone())
+                        .collect()
+                })
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::*;
+    use datafusion_expr::{col, LogicalPlanBuilder};
+    use datafusion_functions_aggregate::expr_fn::{grouping, sum};
+
+    #[test]
+    fn grouping_in_rollup() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .aggregate(
+                vec![Expr::GroupingSet(GroupingSet::Rollup(vec![
+                    col("a"),
+                    col("b"),
+                ]))],
+                vec![grouping(col("b")), sum(col("c"))],
+            )?
+            .build()?;
+        let expected = "Projection: test.a, test.b, CASE WHEN __grouping_0 IS NULL THEN Int32(1) ELSE Int32(0) END AS grouping(test.b), sum(test.c)\
+        \n  Aggregate: groupBy=[[GROUPING SETS ((), (test.a), (test.a, test.b, Int32(1) AS __grouping_0))]], aggr=[[sum(test.c)]]\
+        \n    TableScan: test";
+        assert_analyzed_plan_eq(Arc::new(ResolveGroupingFunction::new()), plan, expected)
+    }
+
+    #[test]
+    fn grouping_without_grouping_sets() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .aggregate(vec![col("a")], vec![grouping(col("a"))])?
+            .build()?;
+        let expected = "Projection: test.a, Int32(0) AS grouping(test.a)\
+        \n  Aggregate: groupBy=[[test.a]], aggr=[[]]\
+        \n    TableScan: test";
+        assert_analyzed_plan_eq(Arc::new(ResolveGroupingFunction::new()), plan, expected)
+    }
+
+    #[test]
+    fn grouping_of_non_grouping_expression() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .aggregate(
+                vec![Expr::GroupingSet(GroupingSet::Rollup(vec![col("a")]))],
+                vec![grouping(col("b"))],
+            )?
+            .build()?;
+        assert_analyzer_check_err(
+            vec![Arc::new(ResolveGroupingFunction::new())],
+            plan,
+            "Argument test.b of grouping function must be a grouping expression",
+        );
+        Ok(())
+    }
+}
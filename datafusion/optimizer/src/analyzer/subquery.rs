@@ -22,7 +22,7 @@ use crate::analyzer::check_plan;
 use crate::utils::collect_subquery_cols;
 
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
-use datafusion_common::{plan_err, Result};
+use datafusion_common::{not_impl_err, plan_err, Result};
 use datafusion_expr::expr_rewriter::strip_outer_reference;
 use datafusion_expr::utils::split_conjunction;
 use datafusion_expr::{
@@ -75,7 +75,7 @@ pub fn check_subquery_expr(
                     {
                         Ok(())
                     } else {
-                        plan_err!(
+                        not_impl_err!(
                             "Correlated scalar subquery must be aggregated to return at most one row"
                         )
                     }
@@ -239,7 +239,7 @@ fn check_aggregation_in_scalar_subquery(
     agg: &Aggregate,
 ) -> Result<()> {
     if agg.aggr_expr.is_empty() {
-        return plan_err!(
+        return not_impl_err!(
             "Correlated scalar subquery must be aggregated to return at most one row"
         );
     }
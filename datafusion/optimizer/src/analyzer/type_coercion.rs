@@ -39,7 +39,7 @@ use datafusion_expr::expr_rewriter::coerce_plan_expr_for_schema;
 use datafusion_expr::expr_schema::cast_subquery;
 use datafusion_expr::logical_plan::Subquery;
 use datafusion_expr::type_coercion::binary::{
-    comparison_coercion, get_input_types, like_coercion,
+    comparison_coercion, comparison_operator_coercion, get_input_types, like_coercion,
 };
 use datafusion_expr::type_coercion::functions::{
     data_types_with_aggregate_udf, data_types_with_scalar_udf,
@@ -282,7 +282,7 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                 .data;
                 let expr_type = expr.get_type(self.schema)?;
                 let subquery_type = new_plan.schema().field(0).data_type();
-                let common_type = comparison_coercion(&expr_type, subquery_type).ok_or(plan_datafusion_err!(
+                let common_type = comparison_operator_coercion(&expr_type, subquery_type).ok_or(plan_datafusion_err!(
                         "expr type {expr_type:?} can't cast to {subquery_type:?} in InSubquery"
                     ),
                 )?;
@@ -366,21 +366,21 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
             }) => {
                 let expr_type = expr.get_type(self.schema)?;
                 let low_type = low.get_type(self.schema)?;
-                let low_coerced_type = comparison_coercion(&expr_type, &low_type)
+                let low_coerced_type = comparison_operator_coercion(&expr_type, &low_type)
                     .ok_or_else(|| {
                         DataFusionError::Internal(format!(
                             "Failed to coerce types {expr_type} and {low_type} in BETWEEN expression"
                         ))
                     })?;
                 let high_type = high.get_type(self.schema)?;
-                let high_coerced_type = comparison_coercion(&expr_type, &low_type)
+                let high_coerced_type = comparison_operator_coercion(&expr_type, &low_type)
                     .ok_or_else(|| {
                         DataFusionError::Internal(format!(
                             "Failed to coerce types {expr_type} and {high_type} in BETWEEN expression"
                         ))
                     })?;
                 let coercion_type =
-                    comparison_coercion(&low_coerced_type, &high_coerced_type)
+                    comparison_operator_coercion(&low_coerced_type, &high_coerced_type)
                         .ok_or_else(|| {
                             DataFusionError::Internal(format!(
                                 "Failed to coerce types {expr_type} and {high_type} in BETWEEN expression"
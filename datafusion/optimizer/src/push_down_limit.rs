@@ -262,7 +262,7 @@ mod test {
 
     use super::*;
     use crate::test::*;
-    use datafusion_expr::{col, exists, logical_plan::builder::LogicalPlanBuilder};
+    use datafusion_expr::{col, exists, lit, logical_plan::builder::LogicalPlanBuilder};
     use datafusion_functions_aggregate::expr_fn::max;
 
     fn assert_optimized_plan_equal(plan: LogicalPlan, expected: &str) -> Result<()> {
@@ -342,6 +342,69 @@ mod test {
         assert_optimized_plan_equal(plan, expected)
     }
 
+    #[test]
+    fn limit_doesnt_push_down_filter() -> Result<()> {
+        let table_scan = test_table_scan()?;
+
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .filter(col("a").eq(lit(1u32)))?
+            .limit(0, Some(1000))?
+            .build()?;
+
+        // Limit should *not* push down filter node
+        let expected = "Limit: skip=0, fetch=1000\
+        \n  Filter: test.a = UInt32(1)\
+        \n    TableScan: test";
+
+        assert_optimized_plan_equal(plan, expected)
+    }
+
+    #[test]
+    fn limit_should_push_down_union_of_projections() -> Result<()> {
+        let table_scan = test_table_scan()?;
+
+        let plan = LogicalPlanBuilder::from(table_scan.clone())
+            .project(vec![col("a")])?
+            .union(
+                LogicalPlanBuilder::from(table_scan)
+                    .project(vec![col("a")])?
+                    .build()?,
+            )?
+            .limit(0, Some(5))?
+            .build()?;
+
+        // Limit should push down through union and then projection
+        let expected = "Limit: skip=0, fetch=5\
+        \n  Union\
+        \n    Projection: test.a\
+        \n      Limit: skip=0, fetch=5\
+        \n        TableScan: test, fetch=5\
+        \n    Projection: test.a\
+        \n      Limit: skip=0, fetch=5\
+        \n        TableScan: test, fetch=5";
+
+        assert_optimized_plan_equal(plan, expected)
+    }
+
+    #[test]
+    fn limit_push_down_projection_sort() -> Result<()> {
+        let table_scan = test_table_scan()?;
+
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .sort_by(vec![col("a")])?
+            .project(vec![col("a")])?
+            .limit(0, Some(10))?
+            .build()?;
+
+        // Should push down limit through projection to sort
+        let expected = "Projection: test.a\
+        \n  Limit: skip=0, fetch=10\
+        \n    Sort: test.a ASC NULLS LAST, fetch=10\
+        \n      TableScan: test";
+
+        assert_optimized_plan_equal(plan, expected)
+    }
+
     #[test]
     fn limit_push_down_sort() -> Result<()> {
         let table_scan = test_table_scan()?;
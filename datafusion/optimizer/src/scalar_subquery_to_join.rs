@@ -66,6 +66,20 @@ impl ScalarSubqueryToJoin {
             .data()
             .map(|new_expr| (extract.sub_query_info, new_expr))
     }
+
+    /// Rewrites the scalar subqueries of `input` before the joins of its
+    /// parent are built on top of it: the joins capture the schema of
+    /// `input`, which rewriting a filter extends with the subquery columns
+    fn rewrite_input(
+        &self,
+        input: &LogicalPlan,
+        config: &dyn OptimizerConfig,
+    ) -> Result<LogicalPlan> {
+        input
+            .clone()
+            .transform_down(|plan| self.rewrite(plan, config))
+            .data()
+    }
 }
 
 impl OptimizerRule for ScalarSubqueryToJoin {
@@ -96,10 +110,10 @@ impl OptimizerRule for ScalarSubqueryToJoin {
                 }
 
                 // iterate through all subqueries in predicate, turning each into a left join
-                let mut cur_input = filter.input.as_ref().clone();
+                let mut cur_input = self.rewrite_input(&filter.input, config)?;
                 for (subquery, alias) in subqueries {
                     if let Some((optimized_subquery, expr_check_map)) =
-                        build_join(&subquery, &cur_input, &alias)?
+                        build_join(&subquery, &cur_input, &alias, config)?
                     {
                         if !expr_check_map.is_empty() {
                             rewrite_expr = rewrite_expr
@@ -150,10 +164,10 @@ impl OptimizerRule for ScalarSubqueryToJoin {
                     return internal_err!("Expected subqueries not found in projection");
                 }
                 // iterate through all subqueries in predicate, turning each into a left join
-                let mut cur_input = projection.input.as_ref().clone();
+                let mut cur_input = self.rewrite_input(&projection.input, config)?;
                 for (subquery, alias) in all_subqueryies {
                     if let Some((optimized_subquery, expr_check_map)) =
-                        build_join(&subquery, &cur_input, &alias)?
+                        build_join(&subquery, &cur_input, &alias, config)?
                     {
                         cur_input = optimized_subquery;
                         if !expr_check_map.is_empty() {
@@ -295,6 +309,7 @@ fn build_join(
     subquery: &Subquery,
     filter_input: &LogicalPlan,
     subquery_alias: &str,
+    config: &dyn OptimizerConfig,
 ) -> Result<Option<(LogicalPlan, HashMap<String, Expr>)>> {
     let subquery_plan = subquery.subquery.as_ref();
     let mut pull_up = PullUpCorrelatedExpr::new().with_need_handle_count_bug(true);
@@ -305,6 +320,15 @@ fn build_join(
 
     let collected_count_expr_map =
         pull_up.collected_count_expr_map.get(&new_plan).cloned();
+    // an uncorrelated subquery is joined to every outer row, so it has to
+    // fail at runtime if it returns more than one row
+    let new_plan = if pull_up.join_filters.is_empty()
+        && !new_plan.max_rows().is_some_and(|rows| rows <= 1)
+    {
+        single_row_guard(new_plan, config)?
+    } else {
+        new_plan
+    };
     let sub_query_alias = LogicalPlanBuilder::from(new_plan)
         .alias(subquery_alias.to_string())?
         .build()?;
@@ -385,6 +409,33 @@ fn build_join(
     Ok(Some((new_plan, computation_project_expr)))
 }
 
+/// Aggregates the single column of `plan` with the registered
+/// `scalar_subquery_value` function, which fails if `plan` returns more than
+/// one row. Returns a plan error if the function is not registered, as the
+/// subquery could then silently return several rows.
+fn single_row_guard(
+    plan: LogicalPlan,
+    config: &dyn OptimizerConfig,
+) -> Result<LogicalPlan> {
+    let Some(guard) = config
+        .function_registry()
+        .and_then(|registry| registry.udaf("scalar_subquery_value").ok())
+    else {
+        return plan_err!(
+            "Uncorrelated scalar subqueries that may return more than one row \
+            need the scalar_subquery_value aggregate function to be registered"
+        );
+    };
+    let (qualifier, field) = plan.schema().qualified_field(0);
+    let name = field.name().clone();
+    let value = guard
+        .call(vec![Expr::Column(Column::from((qualifier, field)))])
+        .alias(name);
+    LogicalPlanBuilder::from(plan)
+        .aggregate(Vec::<Expr>::new(), vec![value])?
+        .build()
+}
+
 #[cfg(test)]
 mod tests {
     use std::ops::Add;
@@ -1091,4 +1142,28 @@ mod tests {
         );
         Ok(())
     }
+
+    /// An uncorrelated subquery that may return several rows needs the
+    /// `scalar_subquery_value` function, which is not registered here
+    #[test]
+    fn uncorrelated_scalar_subquery_without_guard_function() -> Result<()> {
+        let sq = Arc::new(
+            LogicalPlanBuilder::from(scan_tpch_table("orders"))
+                .project(vec![col("orders.o_custkey")])?
+                .build()?,
+        );
+
+        let plan = LogicalPlanBuilder::from(scan_tpch_table("customer"))
+            .filter(col("customer.c_custkey").eq(scalar_subquery(sq)))?
+            .project(vec![col("customer.c_custkey")])?
+            .build()?;
+
+        assert_optimizer_err(
+            Arc::new(ScalarSubqueryToJoin::new()),
+            plan,
+            "Uncorrelated scalar subqueries that may return more than one row need \
+            the scalar_subquery_value aggregate function to be registered",
+        );
+        Ok(())
+    }
 }
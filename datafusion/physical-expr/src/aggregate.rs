@@ -48,7 +48,9 @@ use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
 use datafusion_physical_expr_common::sort_expr::{LexOrdering, PhysicalSortExpr};
 use datafusion_physical_expr_common::utils::reverse_order_bys;
 
+use crate::expressions::Column;
 use datafusion_expr_common::groups_accumulator::GroupsAccumulator;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -127,6 +129,17 @@ impl AggregateExprBuilder {
 
         let data_type = fun.return_type(&input_exprs_types)?;
         let is_nullable = fun.is_nullable();
+        // only a direct column reference has field metadata to keep
+        let metadata = if fun.preserves_input_metadata() {
+            args[0]
+                .as_any()
+                .downcast_ref::<Column>()
+                .and_then(|column| schema.fields().get(column.index()))
+                .map(|field| field.metadata().clone())
+                .unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
         let name = match alias {
             None => return internal_err!("alias should be provided"),
             Some(alias) => alias,
@@ -145,6 +158,7 @@ impl AggregateExprBuilder {
             input_types: input_exprs_types,
             is_reversed,
             is_nullable,
+            metadata,
         }))
     }
 
@@ -213,6 +227,8 @@ pub struct AggregateFunctionExpr {
     is_reversed: bool,
     input_types: Vec<DataType>,
     is_nullable: bool,
+    /// Metadata of the output field
+    metadata: HashMap<String, String>,
 }
 
 impl AggregateFunctionExpr {
@@ -255,6 +271,7 @@ impl AggregateFunctionExpr {
     /// the field of the final result of this aggregation.
     pub fn field(&self) -> Field {
         Field::new(&self.name, self.data_type.clone(), self.is_nullable)
+            .with_metadata(self.metadata.clone())
     }
 
     /// the accumulator used to accumulate values from the expressions.
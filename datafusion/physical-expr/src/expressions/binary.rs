@@ -262,6 +262,20 @@ impl PhysicalExpr for BinaryExpr {
             return apply_cmp_for_nested(self.op, &lhs, &rhs);
         }
 
+        // Decimal arithmetic is always checked: neither a wrapped value nor
+        // a value exceeding the precision of the result is a valid decimal
+        if is_decimal(&left_data_type) && self.op.is_numerical_operators() {
+            let result = match self.op {
+                Operator::Plus => apply(&lhs, &rhs, add)?,
+                Operator::Minus => apply(&lhs, &rhs, sub)?,
+                Operator::Multiply => apply(&lhs, &rhs, mul)?,
+                Operator::Divide => apply(&lhs, &rhs, div)?,
+                _ => apply(&lhs, &rhs, rem)?,
+            };
+            validate_decimal_precision(&result)?;
+            return Ok(result);
+        }
+
         match self.op {
             Operator::Plus if self.fail_on_overflow => return apply(&lhs, &rhs, add),
             Operator::Plus => return apply(&lhs, &rhs, add_wrapping),
@@ -502,6 +516,37 @@ impl PartialEq<dyn Any> for BinaryExpr {
     }
 }
 
+fn is_decimal(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
+    )
+}
+
+/// Returns an error if a value of the decimal result of an arithmetic
+/// operation does not fit in the precision of its type
+fn validate_decimal_precision(value: &ColumnarValue) -> Result<()> {
+    match value {
+        ColumnarValue::Array(array) => match array.data_type() {
+            DataType::Decimal128(precision, _) => array
+                .as_primitive::<Decimal128Type>()
+                .validate_decimal_precision(*precision)?,
+            DataType::Decimal256(precision, _) => array
+                .as_primitive::<Decimal256Type>()
+                .validate_decimal_precision(*precision)?,
+            _ => {}
+        },
+        ColumnarValue::Scalar(ScalarValue::Decimal128(Some(v), precision, _)) => {
+            Decimal128Type::validate_decimal_precision(*v, *precision)?
+        }
+        ColumnarValue::Scalar(ScalarValue::Decimal256(Some(v), precision, _)) => {
+            Decimal256Type::validate_decimal_precision(*v, *precision)?
+        }
+        ColumnarValue::Scalar(_) => {}
+    }
+    Ok(())
+}
+
 /// Casts dictionary array to result type for binary numerical operators. Such operators
 /// between array and scalar produce a dictionary array other than primitive array of the
 /// same operators between array and array. This leads to inconsistent result types causing
@@ -20,7 +20,11 @@
 use std::fmt;
 use std::sync::Arc;
 
-use crate::{physical_exprs_equal, EquivalenceProperties, PhysicalExpr};
+use crate::equivalence::ProjectionMapping;
+use crate::expressions::UnKnownColumn;
+use crate::{
+    physical_exprs_equal, EquivalenceProperties, PhysicalExpr, PhysicalSortExpr,
+};
 
 /// Output partitioning supported by [`ExecutionPlan`]s.
 ///
@@ -113,6 +117,15 @@ pub enum Partitioning {
     /// Allocate rows based on a hash of one of more expressions and the specified number of
     /// partitions
     Hash(Vec<Arc<dyn PhysicalExpr>>, usize),
+    /// Rows are distributed in non overlapping ranges of a sort key and the
+    /// specified number of partitions: every row of a partition sorts after
+    /// the rows of the partitions before it, according to the sort key.
+    ///
+    /// Rows with the same value of the sort key are thus in the same
+    /// partition. This does not satisfy [`Distribution::HashPartitioned`]
+    /// however, as operators such as partitioned joins also require their
+    /// inputs to be partitioned with the same hash function.
+    Range(Vec<PhysicalSortExpr>, usize),
     /// Unknown partitioning scheme with a known number of partitions
     UnknownPartitioning(usize),
 }
@@ -129,6 +142,14 @@ impl fmt::Display for Partitioning {
                     .join(", ");
                 write!(f, "Hash([{phy_exprs_str}], {size})")
             }
+            Partitioning::Range(sort_exprs, size) => {
+                let sort_exprs_str = sort_exprs
+                    .iter()
+                    .map(|e| format!("{e}"))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "Range([{sort_exprs_str}], {size})")
+            }
             Partitioning::UnknownPartitioning(size) => {
                 write!(f, "UnknownPartitioning({size})")
             }
@@ -140,7 +161,7 @@ impl Partitioning {
     pub fn partition_count(&self) -> usize {
         use Partitioning::*;
         match self {
-            RoundRobinBatch(n) | Hash(_, n) | UnknownPartitioning(n) => *n,
+            RoundRobinBatch(n) | Hash(_, n) | Range(_, n) | UnknownPartitioning(n) => *n,
         }
     }
 
@@ -191,6 +212,39 @@ impl Partitioning {
             _ => false,
         }
     }
+
+    /// Calculate the output partitioning after applying the given projection,
+    /// respecting the aliases of the expressions in `input_eq_properties`.
+    ///
+    /// Expressions that are not part of the projection are replaced with an
+    /// [`UnKnownColumn`].
+    pub fn project(
+        &self,
+        projection_mapping: &ProjectionMapping,
+        input_eq_properties: &EquivalenceProperties,
+    ) -> Self {
+        let project_expr = |expr: &Arc<dyn PhysicalExpr>| {
+            input_eq_properties
+                .project_expr(expr, projection_mapping)
+                .unwrap_or_else(|| Arc::new(UnKnownColumn::new(&expr.to_string())))
+        };
+        match self {
+            Partitioning::Hash(exprs, part) => {
+                Partitioning::Hash(exprs.iter().map(project_expr).collect(), *part)
+            }
+            Partitioning::Range(sort_exprs, part) => {
+                let sort_exprs = sort_exprs
+                    .iter()
+                    .map(|sort_expr| PhysicalSortExpr {
+                        expr: project_expr(&sort_expr.expr),
+                        options: sort_expr.options,
+                    })
+                    .collect();
+                Partitioning::Range(sort_exprs, *part)
+            }
+            _ => self.clone(),
+        }
+    }
 }
 
 impl PartialEq for Partitioning {
@@ -205,6 +259,10 @@ impl PartialEq for Partitioning {
             {
                 true
             }
+            (
+                Partitioning::Range(sort_exprs1, count1),
+                Partitioning::Range(sort_exprs2, count2),
+            ) if sort_exprs1 == sort_exprs2 && count1 == count2 => true,
             _ => false,
         }
     }
@@ -244,6 +302,7 @@ mod tests {
     use super::*;
     use crate::expressions::Column;
 
+    use arrow::compute::SortOptions;
     use arrow::datatypes::{DataType, Field, Schema};
     use datafusion_common::Result;
 
@@ -273,6 +332,16 @@ mod tests {
         let single_partition = Partitioning::UnknownPartitioning(1);
         let unspecified_partition = Partitioning::UnknownPartitioning(10);
         let round_robin_partition = Partitioning::RoundRobinBatch(10);
+        let range_partition = Partitioning::Range(
+            partition_exprs1
+                .iter()
+                .map(|expr| PhysicalSortExpr {
+                    expr: Arc::clone(expr),
+                    options: SortOptions::default(),
+                })
+                .collect(),
+            10,
+        );
         let hash_partition1 = Partitioning::Hash(partition_exprs1, 10);
         let hash_partition2 = Partitioning::Hash(partition_exprs2, 10);
         let eq_properties = EquivalenceProperties::new(schema);
@@ -284,17 +353,18 @@ mod tests {
                 round_robin_partition.satisfy(&distribution, &eq_properties),
                 hash_partition1.satisfy(&distribution, &eq_properties),
                 hash_partition2.satisfy(&distribution, &eq_properties),
+                range_partition.satisfy(&distribution, &eq_properties),
             );
 
             match distribution {
                 Distribution::UnspecifiedDistribution => {
-                    assert_eq!(result, (true, true, true, true, true))
+                    assert_eq!(result, (true, true, true, true, true, true))
                 }
                 Distribution::SinglePartition => {
-                    assert_eq!(result, (true, false, false, false, false))
+                    assert_eq!(result, (true, false, false, false, false, false))
                 }
                 Distribution::HashPartitioned(_) => {
-                    assert_eq!(result, (true, false, false, true, false))
+                    assert_eq!(result, (true, false, false, true, false, false))
                 }
             }
         }
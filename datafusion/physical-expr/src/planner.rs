@@ -342,6 +342,13 @@ pub fn create_physical_expr(
                 expressions::in_list(value_expr, list_exprs, negated, input_schema)
             }
         },
+        // subqueries are executed as joins, which the optimizer could not
+        // rewrite this one into
+        Expr::Exists(_) | Expr::InSubquery(_) | Expr::ScalarSubquery(_) => {
+            not_impl_err!(
+                "Physical plan does not support subquery expression {e}: it could not be rewritten into a join"
+            )
+        }
         other => {
             not_impl_err!("Physical plan does not support logical expression {other:?}")
         }
@@ -375,7 +382,7 @@ mod tests {
     use arrow_array::{ArrayRef, BooleanArray, RecordBatch, StringArray};
     use arrow_schema::{DataType, Field};
 
-    use datafusion_expr::{col, lit};
+    use datafusion_expr::{col, exists, lit, LogicalPlanBuilder};
 
     use super::*;
 
@@ -403,4 +410,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_create_physical_expr_subquery() -> Result<()> {
+        let subquery = Arc::new(LogicalPlanBuilder::empty(false).build()?);
+        let expr = exists(subquery);
+
+        let schema = Schema::new(vec![Field::new("letter", DataType::Utf8, false)]);
+        let df_schema = DFSchema::try_from_qualified_schema("data", &schema)?;
+        let err =
+            create_physical_expr(&expr, &df_schema, &ExecutionProps::new()).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "This feature is not implemented: Physical plan does not support subquery expression EXISTS (<subquery>): it could not be rewritten into a join"
+        );
+        Ok(())
+    }
 }
@@ -39,6 +39,10 @@ pub struct WindowShift {
     shift_offset: i64,
     expr: Arc<dyn PhysicalExpr>,
     default_value: ScalarValue,
+    /// Whether the offset counts only the non-null values of `expr`. The
+    /// offset never crosses a partition boundary: if the partition has
+    /// fewer non-null values in the shift direction, `default_value` is
+    /// returned
     ignore_nulls: bool,
 }
 
@@ -42,6 +42,8 @@ pub struct NthValue {
     /// Output data type
     data_type: DataType,
     kind: NthValueKind,
+    /// Whether `n` indexes the non-null values of `expr` within the window
+    /// frame. If the frame has fewer non-null values the result is null
     ignore_nulls: bool,
 }
 
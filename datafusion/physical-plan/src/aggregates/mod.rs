@@ -28,7 +28,7 @@ use crate::aggregates::{
 use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use crate::windows::get_ordered_partition_by_indices;
 use crate::{
-    DisplayFormatType, Distribution, ExecutionPlan, InputOrderMode, Partitioning,
+    DisplayFormatType, Distribution, ExecutionPlan, InputOrderMode,
     SendableRecordBatchStream, Statistics,
 };
 
@@ -41,7 +41,7 @@ use datafusion_execution::TaskContext;
 use datafusion_expr::Accumulator;
 use datafusion_physical_expr::{
     equivalence::{collapse_lex_req, ProjectionMapping},
-    expressions::{Column, UnKnownColumn},
+    expressions::Column,
     physical_exprs_contains, EquivalenceProperties, LexOrdering, LexRequirement,
     PhysicalExpr, PhysicalSortRequirement,
 };
@@ -565,19 +565,8 @@ impl AggregateExec {
             // but needs to respect aliases (e.g. mapping in the GROUP BY
             // expression).
             let input_eq_properties = input.equivalence_properties();
-            if let Partitioning::Hash(exprs, part) = output_partitioning {
-                let normalized_exprs = exprs
-                    .iter()
-                    .map(|expr| {
-                        input_eq_properties
-                            .project_expr(expr, projection_mapping)
-                            .unwrap_or_else(|| {
-                                Arc::new(UnKnownColumn::new(&expr.to_string()))
-                            })
-                    })
-                    .collect();
-                output_partitioning = Partitioning::Hash(normalized_exprs, part);
-            }
+            output_partitioning =
+                output_partitioning.project(projection_mapping, input_eq_properties);
         }
 
         // Determine execution mode:
@@ -826,7 +815,10 @@ fn create_schema(
         }
     }
 
-    Ok(Schema::new(fields))
+    Ok(Schema::new_with_metadata(
+        fields,
+        input_schema.metadata().clone(),
+    ))
 }
 
 fn group_schema(schema: &Schema, group_count: usize) -> SchemaRef {
@@ -1200,12 +1192,13 @@ mod tests {
     use crate::memory::MemoryExec;
     use crate::test::assert_is_pending;
     use crate::test::exec::{assert_strong_count_converges_to_zero, BlockingExec};
-    use crate::RecordBatchStream;
+    use crate::{Partitioning, RecordBatchStream};
 
     use arrow::array::{Float64Array, UInt32Array};
     use arrow::compute::{concat_batches, SortOptions};
     use arrow::datatypes::DataType;
     use arrow_array::{Float32Array, Int32Array};
+    use datafusion_common::cast::as_int32_array;
     use datafusion_common::{
         assert_batches_eq, assert_batches_sorted_eq, internal_err, DataFusionError,
         ScalarValue,
@@ -1605,6 +1598,45 @@ mod tests {
         Ok(())
     }
 
+    /// DISTINCT over more groups than fit in memory spills sorted groups to
+    /// disk and merges them back
+    #[tokio::test]
+    async fn distinct_with_spill() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        // 4 overlapping batches with 250 distinct values in total
+        let batches = (0..4)
+            .map(|i| {
+                let a = Int32Array::from_iter_values(i * 50..i * 50 + 100);
+                RecordBatch::try_new(Arc::clone(&schema), vec![Arc::new(a)])
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let input = Arc::new(MemoryExec::try_new(&[batches], Arc::clone(&schema), None)?);
+
+        let group_by =
+            PhysicalGroupBy::new_single(vec![(col("a", &schema)?, "a".to_string())]);
+        let distinct = Arc::new(AggregateExec::try_new(
+            AggregateMode::Single,
+            group_by,
+            vec![],
+            vec![],
+            input,
+            Arc::clone(&schema),
+        )?);
+
+        let task_ctx = new_spill_ctx(2, 3000);
+        let result = collect(distinct.execute(0, task_ctx)?).await?;
+        let batch = concat_batches(&distinct.schema(), &result)?;
+        let mut values = as_int32_array(batch.column(0))?.values().to_vec();
+        values.sort_unstable();
+        assert_eq!(values, (0..250).collect::<Vec<_>>());
+
+        let metrics = distinct.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+        assert!(metrics.spilled_rows().unwrap() > 0);
+        assert!(metrics.spilled_bytes().unwrap() > 0);
+        Ok(())
+    }
+
     /// Define a test source that can yield back to runtime before returning its first item ///
 
     #[derive(Debug)]
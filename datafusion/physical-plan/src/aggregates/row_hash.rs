@@ -31,7 +31,7 @@ use crate::common::IPCWriter;
 use crate::metrics::{BaselineMetrics, MetricBuilder, RecordOutput};
 use crate::sorts::sort::sort_batch;
 use crate::sorts::streaming_merge;
-use crate::spill::read_spill_as_stream;
+use crate::spill::{notify_spill, read_spill_as_stream};
 use crate::stream::RecordBatchStreamAdapter;
 use crate::{aggregates, metrics, ExecutionPlan, PhysicalExpr};
 use crate::{RecordBatchStream, SendableRecordBatchStream};
@@ -103,6 +103,18 @@ struct SpillState {
 
     /// true when streaming merge is in progress
     is_stream_merging: bool,
+
+    // ========================================================================
+    // METRICS:
+    // ========================================================================
+    /// Number of spills
+    spill_count: metrics::Count,
+
+    /// Total size in bytes of the in-memory groups that were spilled
+    spilled_bytes: metrics::Count,
+
+    /// Total number of spilled groups
+    spilled_rows: metrics::Count,
 }
 
 /// Tracks if the aggregate should skip partial aggregations
@@ -518,6 +530,9 @@ impl GroupedHashAggregateStream {
             is_stream_merging: false,
             merging_aggregate_arguments,
             merging_group_by: PhysicalGroupBy::new_single(agg_group_by.expr.clone()),
+            spill_count: MetricBuilder::new(&agg.metrics).spill_count(partition),
+            spilled_bytes: MetricBuilder::new(&agg.metrics).spilled_bytes(partition),
+            spilled_rows: MetricBuilder::new(&agg.metrics).spilled_rows(partition),
         };
 
         // Skip aggregation is supported if:
@@ -918,6 +933,15 @@ impl GroupedHashAggregateStream {
         }
 
         writer.finish()?;
+        self.spill_state.spill_count.add(1);
+        self.spill_state.spilled_bytes.add(self.reservation.size());
+        self.spill_state.spilled_rows.add(total_rows);
+        notify_spill(
+            &self.runtime,
+            &self.reservation,
+            total_rows,
+            self.reservation.size(),
+        );
         self.spill_state.spills.push(spillfile);
         Ok(())
     }
@@ -17,18 +17,22 @@
 
 use std::any::Any;
 use std::fmt::Debug;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
-use futures::stream::{StreamExt, TryStreamExt};
+use futures::stream::{Stream, StreamExt, TryStreamExt};
 use tokio::task::JoinSet;
 
 use datafusion_common::config::ConfigOptions;
 pub use datafusion_common::hash_utils;
 pub use datafusion_common::utils::project_schema;
-use datafusion_common::{exec_err, Result};
+use datafusion_common::{exec_err, DataFusionError, ErrorOrigin, Result};
 pub use datafusion_common::{internal_err, ColumnStatistics, Statistics};
+use datafusion_execution::metrics_observer::{MetricSample, OperatorMetrics};
 use datafusion_execution::TaskContext;
 pub use datafusion_execution::{RecordBatchStream, SendableRecordBatchStream};
 pub use datafusion_expr::{Accumulator, ColumnarValue};
@@ -707,6 +711,11 @@ pub async fn collect(
 ///
 /// See [collect] to buffer the `RecordBatch`es in memory.
 ///
+/// The metrics of `plan` are reported to the [`MetricsObserver`] of the
+/// runtime, if any, once the stream has completed.
+///
+/// [`MetricsObserver`]: datafusion_execution::metrics_observer::MetricsObserver
+///
 /// # Aborting Execution
 ///
 /// Dropping the stream will abort the execution of the query, and free up
@@ -715,17 +724,22 @@ pub fn execute_stream(
     plan: Arc<dyn ExecutionPlan>,
     context: Arc<TaskContext>,
 ) -> Result<SendableRecordBatchStream> {
-    match plan.output_partitioning().partition_count() {
-        0 => Ok(Box::pin(EmptyRecordBatchStream::new(plan.schema()))),
-        1 => plan.execute(0, context),
+    let stream = match plan.output_partitioning().partition_count() {
+        0 => Box::pin(EmptyRecordBatchStream::new(plan.schema())),
+        1 => plan
+            .execute(0, Arc::clone(&context))
+            .map_err(raised_during_execution)?,
         _ => {
             // merge into a single partition
             let plan = CoalescePartitionsExec::new(Arc::clone(&plan));
             // CoalescePartitionsExec must produce a single partition
             assert_eq!(1, plan.properties().output_partitioning().partition_count());
-            plan.execute(0, context)
+            plan.execute(0, Arc::clone(&context))
+                .map_err(raised_during_execution)?
         }
-    }
+    };
+    let stream = with_execution_errors(stream);
+    Ok(observe_streams(plan, context, vec![stream]).remove(0))
 }
 
 /// Execute the [ExecutionPlan] and collect the results in memory
@@ -768,9 +782,126 @@ pub async fn collect_partitioned(
     Ok(batches)
 }
 
+/// Return the metrics of every operator in `plan`, summed across partitions.
+///
+/// Operators are identified by their position in a pre-order traversal of
+/// the plan, so the root has id `0`.
+pub fn operator_metrics(plan: &dyn ExecutionPlan) -> Vec<OperatorMetrics> {
+    fn visit(plan: &dyn ExecutionPlan, operators: &mut Vec<OperatorMetrics>) {
+        let metrics = plan
+            .metrics()
+            .map(|metrics| {
+                metrics
+                    .aggregate_by_name()
+                    .timestamps_removed()
+                    .sorted_for_display()
+                    .iter()
+                    .map(|metric| MetricSample {
+                        name: metric.value().name().to_string(),
+                        labels: metric
+                            .labels()
+                            .iter()
+                            .map(|l| (l.name().to_string(), l.value().to_string()))
+                            .collect(),
+                        value: metric.value().as_usize(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        operators.push(OperatorMetrics {
+            id: operators.len(),
+            name: plan.name().to_string(),
+            metrics,
+        });
+        for child in plan.children() {
+            visit(child.as_ref(), operators);
+        }
+    }
+
+    let mut operators = vec![];
+    visit(plan, &mut operators);
+    operators
+}
+
+/// Wraps the `streams` executing `plan` so that its metrics are reported to
+/// the [`MetricsObserver`] of the runtime once all of them have completed,
+/// if an observer is registered. Nothing is reported if one of the streams
+/// fails or is dropped before completing.
+///
+/// [`MetricsObserver`]: datafusion_execution::metrics_observer::MetricsObserver
+fn observe_streams(
+    plan: Arc<dyn ExecutionPlan>,
+    context: Arc<TaskContext>,
+    streams: Vec<SendableRecordBatchStream>,
+) -> Vec<SendableRecordBatchStream> {
+    if context.runtime_env().metrics_observer.is_none() {
+        return streams;
+    }
+    let remaining = Arc::new(AtomicUsize::new(streams.len()));
+    streams
+        .into_iter()
+        .map(|input| {
+            Box::pin(ObservedStream {
+                input,
+                plan: Some(Arc::clone(&plan)),
+                context: Arc::clone(&context),
+                remaining: Arc::clone(&remaining),
+            }) as SendableRecordBatchStream
+        })
+        .collect()
+}
+
+/// One of the streams executing `plan`, see [`observe_streams`]
+struct ObservedStream {
+    input: SendableRecordBatchStream,
+    /// The plan to report, until the stream completes or fails
+    plan: Option<Arc<dyn ExecutionPlan>>,
+    context: Arc<TaskContext>,
+    /// The number of streams executing `plan` that have not completed yet
+    remaining: Arc<AtomicUsize>,
+}
+
+impl Stream for ObservedStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.input.poll_next_unpin(cx);
+        match &poll {
+            Poll::Ready(None) => {
+                if let Some(plan) = self.plan.take() {
+                    if self.remaining.fetch_sub(1, Ordering::AcqRel) == 1 {
+                        if let Some(observer) =
+                            &self.context.runtime_env().metrics_observer
+                        {
+                            observer.on_query_complete(&operator_metrics(plan.as_ref()));
+                        }
+                    }
+                }
+            }
+            Poll::Ready(Some(Err(_))) => self.plan = None,
+            _ => {}
+        }
+        poll
+    }
+}
+
+impl RecordBatchStream for ObservedStream {
+    fn schema(&self) -> SchemaRef {
+        self.input.schema()
+    }
+}
+
 /// Execute the [ExecutionPlan] and return a vec with one stream per output
 /// partition
 ///
+/// The metrics of `plan` are reported to the [`MetricsObserver`] of the
+/// runtime, if any, once all the streams have completed.
+///
+/// [`MetricsObserver`]: datafusion_execution::metrics_observer::MetricsObserver
+///
 /// # Aborting Execution
 ///
 /// Dropping the stream will abort the execution of the query, and free up
@@ -782,9 +913,32 @@ pub fn execute_stream_partitioned(
     let num_partitions = plan.output_partitioning().partition_count();
     let mut streams = Vec::with_capacity(num_partitions);
     for i in 0..num_partitions {
-        streams.push(plan.execute(i, Arc::clone(&context))?);
+        let stream = plan
+            .execute(i, Arc::clone(&context))
+            .map_err(raised_during_execution)?;
+        streams.push(with_execution_errors(stream));
+    }
+    Ok(observe_streams(plan, context, streams))
+}
+
+/// Records that `error` was raised while executing a plan, so that errors
+/// such as [`DataFusionError::Plan`] are not classified as
+/// [`ErrorOrigin::Planning`], see [`DataFusionError::origin`]
+fn raised_during_execution(error: DataFusionError) -> DataFusionError {
+    match error.origin() {
+        ErrorOrigin::Planning => error.with_origin(ErrorOrigin::Execution),
+        _ => error,
     }
-    Ok(streams)
+}
+
+/// Records that the errors of `stream` were raised while executing a plan,
+/// see [`raised_during_execution`]
+fn with_execution_errors(stream: SendableRecordBatchStream) -> SendableRecordBatchStream {
+    let schema = stream.schema();
+    Box::pin(RecordBatchStreamAdapter::new(
+        schema,
+        stream.map_err(raised_during_execution),
+    ))
 }
 
 /// Executes an input stream and ensures that the resulting stream adheres to
@@ -893,9 +1047,11 @@ mod tests {
 
     use arrow_schema::{Schema, SchemaRef};
 
-    use datafusion_common::{Result, Statistics};
+    use datafusion_common::{plan_err, Result, Statistics};
     use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 
+    use crate::test::exec::MockExec;
+
     use crate::{DisplayAs, DisplayFormatType, ExecutionPlan};
 
     #[derive(Debug)]
@@ -1036,6 +1192,29 @@ mod tests {
     fn use_execution_plan_as_trait_object(plan: &dyn ExecutionPlan) {
         let _ = plan.name();
     }
+
+    #[tokio::test]
+    async fn execution_error_origin() -> Result<()> {
+        let schema = Arc::new(Schema::empty());
+        let task_ctx = Arc::new(TaskContext::default());
+
+        // a plan error raised while executing the plan
+        let exec = MockExec::new(vec![plan_err!("bad value")], Arc::clone(&schema));
+        let err = collect(Arc::new(exec), Arc::clone(&task_ctx))
+            .await
+            .unwrap_err();
+        assert_eq!(err.origin(), ErrorOrigin::Execution);
+        assert_eq!(err.strip_backtrace(), "Error during planning: bad value");
+
+        // errors of other systems are still external
+        let io_error = DataFusionError::IoError(std::io::Error::other("reset"));
+        let exec = MockExec::new(vec![Err(io_error)], schema);
+        let err = collect_partitioned(Arc::new(exec), task_ctx)
+            .await
+            .unwrap_err();
+        assert_eq!(err.origin(), ErrorOrigin::External);
+        Ok(())
+    }
 }
 
 // pub mod test;
@@ -28,10 +28,12 @@ use super::{
     utils::{OnceAsync, OnceFut},
     PartitionMode,
 };
+use crate::spill::{notify_spill, read_spill_as_stream};
+use crate::stream::{EmptyRecordBatchStream, RecordBatchStreamAdapter};
 use crate::ExecutionPlanProperties;
 use crate::{
     coalesce_partitions::CoalescePartitionsExec,
-    common::can_project,
+    common::{can_project, IPCWriter},
     execution_mode_from_children, handle_state,
     hash_utils::create_hashes,
     joins::utils::{
@@ -42,7 +44,7 @@ use crate::{
         BuildProbeJoinMetrics, ColumnIndex, JoinFilter, JoinHashMap, JoinHashMapOffset,
         JoinHashMapType, JoinOn, JoinOnRef, StatefulStreamResult,
     },
-    metrics::{ExecutionPlanMetricsSet, MetricsSet},
+    metrics::{Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet},
     DisplayAs, DisplayFormatType, Distribution, ExecutionMode, ExecutionPlan,
     Partitioning, PlanProperties, RecordBatchStream, SendableRecordBatchStream,
     Statistics,
@@ -52,7 +54,7 @@ use arrow::array::{
     Array, ArrayRef, BooleanArray, BooleanBufferBuilder, UInt32Array, UInt64Array,
 };
 use arrow::compute::kernels::cmp::{eq, not_distinct};
-use arrow::compute::{and, concat_batches, take, FilterBuilder};
+use arrow::compute::{and, concat_batches, take, take_record_batch, FilterBuilder};
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use arrow::util::bit_util;
@@ -63,18 +65,18 @@ use datafusion_common::{
     internal_datafusion_err, internal_err, plan_err, project_schema, DataFusionError,
     JoinSide, JoinType, Result,
 };
+use datafusion_execution::disk_manager::RefCountedTempFile;
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::equivalence::{
     join_equivalence_properties, ProjectionMapping,
 };
-use datafusion_physical_expr::expressions::UnKnownColumn;
 use datafusion_physical_expr::{PhysicalExpr, PhysicalExprRef};
 
 use ahash::RandomState;
 use datafusion_expr::Operator;
 use datafusion_physical_expr_common::datum::compare_op_for_nested;
-use futures::{ready, Stream, StreamExt, TryStreamExt};
+use futures::{ready, stream, Stream, StreamExt, TryStreamExt};
 use parking_lot::Mutex;
 
 type SharedBitmapBuilder = Mutex<BooleanBufferBuilder>;
@@ -136,6 +138,134 @@ impl JoinLeftData {
     }
 }
 
+/// Number of partitions both inputs of a hash join are split into when its
+/// build side does not fit in memory
+const SPILL_PARTITIONS: usize = 16;
+
+/// Maximum number of times the build side of a hash join is split into
+/// partitions, when the partitions it was split into still do not fit in memory
+const MAX_SPILL_DEPTH: usize = 3;
+
+/// The left (build) side of a join, as collected by [`collect_left_input`]
+enum JoinLeftInput {
+    /// The build side fits in memory
+    InMemory(Arc<JoinLeftData>),
+    /// The build side does not fit in memory, and was spilled to disk
+    Spilled(Arc<SpilledJoinLeftData>),
+}
+
+/// The left (build) side of a join that did not fit in memory, hash
+/// partitioned on the join keys into spill files.
+///
+/// Each probe thread partitions its right (probe) input the same way, and joins
+/// the pairs of partitions one at a time. The hash table of each left partition
+/// is built once and shared by all the probe threads, like the hash table of
+/// the whole left side in [`PartitionMode::CollectLeft`]. A left partition that
+/// still does not fit in memory is itself partitioned, up to [`MAX_SPILL_DEPTH`]
+/// times.
+struct SpilledJoinLeftData {
+    /// The partitions of the left side
+    partitions: Vec<Mutex<SpilledJoinLeftPartition>>,
+    /// Schema of the left side
+    schema: SchemaRef,
+    /// Random state used to partition both sides. It is different from the
+    /// one of the hash tables, and at every depth.
+    random_state: RandomState,
+    /// Number of times the left side was partitioned before this
+    depth: usize,
+    /// How to collect the left partitions
+    options: LeftInputOptions,
+}
+
+/// A partition of [`SpilledJoinLeftData`]
+struct SpilledJoinLeftPartition {
+    /// The rows of the partition, until its hash table is built
+    file: Option<RefCountedTempFile>,
+    /// Future building the hash table of the partition
+    left_fut: Option<OnceFut<JoinLeftInput>>,
+    /// Number of probe threads that did not take `left_fut` yet
+    remaining_probe_threads: usize,
+}
+
+impl SpilledJoinLeftData {
+    /// Returns the future building the hash table of partition `i`.
+    ///
+    /// Each probe thread must call this once for each partition. The future
+    /// is forgotten once all of them did, so that the hash table is dropped as
+    /// soon as the last probe thread is done with it.
+    fn left_fut(&self, i: usize) -> OnceFut<JoinLeftInput> {
+        let mut guard = self.partitions[i].lock();
+        let state = &mut *guard;
+        if state.left_fut.is_none() {
+            state.left_fut = state.file.take().map(|file| {
+                OnceFut::new(load_spilled_left_partition(
+                    file,
+                    Arc::clone(&self.schema),
+                    self.options.clone(),
+                    self.depth + 1,
+                ))
+            });
+        }
+        let left_fut = match &state.left_fut {
+            Some(left_fut) => left_fut.clone(),
+            None => OnceFut::new(async move {
+                internal_err!("Spilled hash join partition {i} was already joined")
+            }),
+        };
+        state.remaining_probe_threads = state.remaining_probe_threads.saturating_sub(1);
+        if state.remaining_probe_threads == 0 {
+            state.left_fut = None;
+        }
+        left_fut
+    }
+}
+
+/// How to collect the left (build) side of a hash join
+#[derive(Clone)]
+struct LeftInputOptions {
+    /// Random state of the hash table
+    random_state: RandomState,
+    /// Join keys of the left side
+    on_left: Vec<PhysicalExprRef>,
+    metrics: BuildProbeJoinMetrics,
+    with_visited_indices_bitmap: bool,
+    probe_threads_count: usize,
+    /// How to spill the left side if it does not fit in memory. `None` if it
+    /// must not be spilled.
+    spill: Option<JoinSpill>,
+}
+
+/// Resources and metrics to spill the inputs of a hash join with
+#[derive(Clone)]
+struct JoinSpill {
+    context: Arc<TaskContext>,
+    /// Name of the memory consumers of the spilled partitions of the left side
+    consumer_name: String,
+    /// Number of spill files written
+    spill_count: Count,
+    /// Total bytes spilled
+    spilled_bytes: Count,
+    /// Total rows spilled
+    spilled_rows: Count,
+}
+
+impl JoinSpill {
+    fn new(
+        partition: usize,
+        context: Arc<TaskContext>,
+        consumer_name: String,
+        metrics: &ExecutionPlanMetricsSet,
+    ) -> Self {
+        Self {
+            context,
+            consumer_name,
+            spill_count: MetricBuilder::new(metrics).spill_count(partition),
+            spilled_bytes: MetricBuilder::new(metrics).spilled_bytes(partition),
+            spilled_rows: MetricBuilder::new(metrics).spilled_rows(partition),
+        }
+    }
+}
+
 /// Join execution plan: Evaluates eqijoin predicates in parallel on multiple
 /// partitions using a hash table and an optional filter list to apply post
 /// join.
@@ -256,6 +386,14 @@ impl JoinLeftData {
 ///
 /// ```
 ///
+/// # Spilling
+///
+/// If the build side does not fit in memory and
+/// `datafusion.execution.enable_hash_join_spill` is set, both inputs are
+/// hash partitioned on the join keys into spill files, and each pair of
+/// partitions is then joined like above. Build side partitions that still do
+/// not fit in memory are partitioned again, a few times at most.
+///
 /// # Example "Optimal" Plans
 ///
 /// The differences in the inputs means that for classic "Star Schema Query",
@@ -309,7 +447,7 @@ pub struct HashJoinExec {
     /// if there is a projection, the schema isn't the same as the output schema.
     join_schema: SchemaRef,
     /// Future that consumes left input and builds the hash table
-    left_fut: OnceAsync<JoinLeftData>,
+    left_fut: OnceAsync<JoinLeftInput>,
     /// Shared the `RandomState` for the hashing algorithm
     random_state: RandomState,
     /// Partitioning mode to use
@@ -533,19 +671,8 @@ impl HashJoinExec {
             let projection_mapping =
                 ProjectionMapping::try_new(&projection_exprs, &schema)?;
             let out_schema = project_schema(&schema, Some(projection))?;
-            if let Partitioning::Hash(exprs, part) = output_partitioning {
-                let normalized_exprs = exprs
-                    .iter()
-                    .map(|expr| {
-                        eq_properties
-                            .project_expr(expr, &projection_mapping)
-                            .unwrap_or_else(|| {
-                                Arc::new(UnKnownColumn::new(&expr.to_string()))
-                            })
-                    })
-                    .collect();
-                output_partitioning = Partitioning::Hash(normalized_exprs, part);
-            }
+            output_partitioning =
+                output_partitioning.project(&projection_mapping, &eq_properties);
             eq_properties = eq_properties.project(&projection_mapping, out_schema);
         }
         Ok(PlanProperties::new(
@@ -721,37 +848,77 @@ impl ExecutionPlan for HashJoinExec {
         }
 
         let join_metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
+        // Spilling does not preserve the order of the probe side
+        let spill_enabled = context
+            .session_config()
+            .options()
+            .execution
+            .enable_hash_join_spill
+            && context.runtime_env().disk_manager.tmp_files_enabled()
+            && self.right.output_ordering().is_none();
         let left_fut = match self.mode {
             PartitionMode::CollectLeft => self.left_fut.once(|| {
-                let reservation =
-                    MemoryConsumer::new("HashJoinInput").register(context.memory_pool());
+                let consumer_name = "HashJoinInput".to_string();
+                let reservation = MemoryConsumer::new(&consumer_name)
+                    .with_can_spill(spill_enabled)
+                    .register(context.memory_pool());
+                let options = LeftInputOptions {
+                    random_state: self.random_state.clone(),
+                    on_left: on_left.clone(),
+                    metrics: join_metrics.clone(),
+                    with_visited_indices_bitmap: need_produce_result_in_final(
+                        self.join_type,
+                    ),
+                    probe_threads_count: self
+                        .right()
+                        .output_partitioning()
+                        .partition_count(),
+                    spill: spill_enabled.then(|| {
+                        JoinSpill::new(
+                            partition,
+                            Arc::clone(&context),
+                            consumer_name,
+                            &self.metrics,
+                        )
+                    }),
+                };
                 collect_left_input(
                     None,
-                    self.random_state.clone(),
                     Arc::clone(&self.left),
-                    on_left.clone(),
                     Arc::clone(&context),
-                    join_metrics.clone(),
                     reservation,
-                    need_produce_result_in_final(self.join_type),
-                    self.right().output_partitioning().partition_count(),
+                    options,
                 )
             }),
             PartitionMode::Partitioned => {
-                let reservation =
-                    MemoryConsumer::new(format!("HashJoinInput[{partition}]"))
-                        .register(context.memory_pool());
+                let consumer_name = format!("HashJoinInput[{partition}]");
+                let reservation = MemoryConsumer::new(&consumer_name)
+                    .with_can_spill(spill_enabled)
+                    .register(context.memory_pool());
+                let options = LeftInputOptions {
+                    random_state: self.random_state.clone(),
+                    on_left: on_left.clone(),
+                    metrics: join_metrics.clone(),
+                    with_visited_indices_bitmap: need_produce_result_in_final(
+                        self.join_type,
+                    ),
+                    probe_threads_count: 1,
+                    spill: spill_enabled.then(|| {
+                        JoinSpill::new(
+                            partition,
+                            Arc::clone(&context),
+                            consumer_name,
+                            &self.metrics,
+                        )
+                    }),
+                };
 
                 OnceFut::new(collect_left_input(
                     Some(partition),
-                    self.random_state.clone(),
                     Arc::clone(&self.left),
-                    on_left.clone(),
                     Arc::clone(&context),
-                    join_metrics.clone(),
                     reservation,
-                    need_produce_result_in_final(self.join_type),
-                    1,
+                    options,
                 ))
             }
             PartitionMode::Auto => {
@@ -827,20 +994,16 @@ impl ExecutionPlan for HashJoinExec {
 
 /// Reads the left (build) side of the input, buffering it in memory, to build a
 /// hash table (`LeftJoinData`)
-#[allow(clippy::too_many_arguments)]
+///
+/// If the left side does not fit in memory and `options.spill` is set, it is
+/// spilled to disk instead, see [`SpilledJoinLeftData`].
 async fn collect_left_input(
     partition: Option<usize>,
-    random_state: RandomState,
     left: Arc<dyn ExecutionPlan>,
-    on_left: Vec<PhysicalExprRef>,
     context: Arc<TaskContext>,
-    metrics: BuildProbeJoinMetrics,
     reservation: MemoryReservation,
-    with_visited_indices_bitmap: bool,
-    probe_threads_count: usize,
-) -> Result<JoinLeftData> {
-    let schema = left.schema();
-
+    options: LeftInputOptions,
+) -> Result<JoinLeftInput> {
     let (left_input, left_input_partition) = if let Some(partition) = partition {
         (left, partition)
     } else if left.output_partitioning().partition_count() != 1 {
@@ -852,35 +1015,90 @@ async fn collect_left_input(
     // Depending on partition argument load single partition or whole left side in memory
     let stream = left_input.execute(left_input_partition, Arc::clone(&context))?;
 
+    load_left_input(stream, reservation, options, 0).await
+}
+
+/// Reads a partition of the left (build) side of a join spilled to disk by
+/// [`spill_left_input`] to build its hash table
+async fn load_spilled_left_partition(
+    file: RefCountedTempFile,
+    schema: SchemaRef,
+    options: LeftInputOptions,
+    depth: usize,
+) -> Result<JoinLeftInput> {
+    let Some(spill) = &options.spill else {
+        return internal_err!("Hash join spilled without a spill configuration");
+    };
+    let reservation = MemoryConsumer::new(&spill.consumer_name)
+        .with_can_spill(true)
+        .register(spill.context.memory_pool());
+    let stream = read_spill_as_stream(file, schema, 2)?;
+    load_left_input(stream, reservation, options, depth).await
+}
+
+/// Buffers `stream` in memory to build its hash table, or spills it to disk if
+/// it does not fit. `depth` is the number of times the left side of the join
+/// was partitioned to get `stream`.
+async fn load_left_input(
+    mut stream: SendableRecordBatchStream,
+    mut reservation: MemoryReservation,
+    options: LeftInputOptions,
+    depth: usize,
+) -> Result<JoinLeftInput> {
+    let schema = stream.schema();
+    let metrics = &options.metrics;
+
     // This operation performs 2 steps at once:
     // 1. creates a [JoinHashMap] of all batches from the stream
     // 2. stores the batches in a vector.
-    let initial = (Vec::new(), 0, metrics, reservation);
-    let (batches, num_rows, metrics, mut reservation) = stream
-        .try_fold(initial, |mut acc, batch| async {
-            let batch_size = batch.get_array_memory_size();
-            // Reserve memory for incoming batch
-            acc.3.try_grow(batch_size)?;
-            // Update metrics
-            acc.2.build_mem_used.add(batch_size);
-            acc.2.build_input_batches.add(1);
-            acc.2.build_input_rows.add(batch.num_rows());
-            // Update rowcount
-            acc.1 += batch.num_rows();
-            // Push batch to output
-            acc.0.push(batch);
-            Ok(acc)
-        })
-        .await?;
+    let mut batches = vec![];
+    let mut num_rows = 0;
+    while let Some(batch) = stream.next().await.transpose()? {
+        // Rows read back from spill files were counted already
+        if depth == 0 {
+            metrics.build_input_batches.add(1);
+            metrics.build_input_rows.add(batch.num_rows());
+        }
+        let batch_size = batch.get_array_memory_size();
+        // Reserve memory for incoming batch
+        if let Err(e) = reservation.try_grow(batch_size) {
+            batches.push(batch);
+            return spill_left_input(
+                e,
+                schema,
+                batches,
+                Some(stream),
+                reservation,
+                options,
+                depth,
+            )
+            .await;
+        }
+        metrics.build_mem_used.add(batch_size);
+        // Update rowcount
+        num_rows += batch.num_rows();
+        // Push batch to output
+        batches.push(batch);
+    }
 
     // Estimation of memory size, required for hashtable, prior to allocation.
     // Final result can be verified using `RawTable.allocation_info()`
     let fixed_size = std::mem::size_of::<JoinHashMap>();
     let estimated_hashtable_size =
         estimate_memory_size::<(u64, u64)>(num_rows, fixed_size)?;
-
-    reservation.try_grow(estimated_hashtable_size)?;
-    metrics.build_mem_used.add(estimated_hashtable_size);
+    // Reserve additional memory for visited indices bitmap
+    let bitmap_size = if options.with_visited_indices_bitmap {
+        bit_util::ceil(num_rows, 8)
+    } else {
+        0
+    };
+    if let Err(e) = reservation.try_grow(estimated_hashtable_size + bitmap_size) {
+        return spill_left_input(e, schema, batches, None, reservation, options, depth)
+            .await;
+    }
+    metrics
+        .build_mem_used
+        .add(estimated_hashtable_size + bitmap_size);
 
     let mut hashmap = JoinHashMap::with_capacity(num_rows);
     let mut hashes_buffer = Vec::new();
@@ -892,11 +1110,11 @@ async fn collect_left_input(
         hashes_buffer.clear();
         hashes_buffer.resize(batch.num_rows(), 0);
         update_hash(
-            &on_left,
+            &options.on_left,
             batch,
             &mut hashmap,
             offset,
-            &random_state,
+            &options.random_state,
             &mut hashes_buffer,
             0,
             true,
@@ -906,12 +1124,8 @@ async fn collect_left_input(
     // Merge all batches into a single batch, so we can directly index into the arrays
     let single_batch = concat_batches(&schema, batches_iter)?;
 
-    // Reserve additional memory for visited indices bitmap and create shared builder
-    let visited_indices_bitmap = if with_visited_indices_bitmap {
-        let bitmap_size = bit_util::ceil(single_batch.num_rows(), 8);
-        reservation.try_grow(bitmap_size)?;
-        metrics.build_mem_used.add(bitmap_size);
-
+    // Create shared builder for visited indices bitmap
+    let visited_indices_bitmap = if options.with_visited_indices_bitmap {
         let mut bitmap_buffer = BooleanBufferBuilder::new(single_batch.num_rows());
         bitmap_buffer.append_n(num_rows, false);
         bitmap_buffer
@@ -923,11 +1137,158 @@ async fn collect_left_input(
         hashmap,
         single_batch,
         Mutex::new(visited_indices_bitmap),
-        AtomicUsize::new(probe_threads_count),
+        AtomicUsize::new(options.probe_threads_count),
         reservation,
     );
 
-    Ok(data)
+    Ok(JoinLeftInput::InMemory(Arc::new(data)))
+}
+
+/// Spills the left (build) side of a join that does not fit in memory, as
+/// reported by `error`, to disk: the `batches` buffered so far and the rest of
+/// `stream`.
+///
+/// Returns `error` if the left side can not be spilled, or was partitioned
+/// [`MAX_SPILL_DEPTH`] times already.
+#[allow(clippy::too_many_arguments)]
+async fn spill_left_input(
+    error: DataFusionError,
+    schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+    stream: Option<SendableRecordBatchStream>,
+    mut reservation: MemoryReservation,
+    options: LeftInputOptions,
+    depth: usize,
+) -> Result<JoinLeftInput> {
+    let spill = match &options.spill {
+        Some(spill)
+            if depth < MAX_SPILL_DEPTH
+                && matches!(error, DataFusionError::ResourcesExhausted(_)) =>
+        {
+            spill.clone()
+        }
+        _ => return Err(error),
+    };
+
+    let random_state = RandomState::with_seeds(depth as u64 + 1, 0, 0, 0);
+    let mut writer = SpillPartitionWriter::try_new(
+        &spill,
+        &schema,
+        options.on_left.clone(),
+        random_state.clone(),
+    )?;
+    for batch in batches {
+        writer.write(&batch)?;
+    }
+    let spilled_bytes = reservation.size();
+    reservation.free();
+    if let Some(mut stream) = stream {
+        while let Some(batch) = stream.next().await.transpose()? {
+            if depth == 0 {
+                options.metrics.build_input_batches.add(1);
+                options.metrics.build_input_rows.add(batch.num_rows());
+            }
+            writer.write(&batch)?;
+        }
+    }
+    let files = writer.finish(&spill)?;
+    let spilled_rows = files.iter().map(|(_, num_rows)| num_rows).sum();
+    notify_spill(
+        spill.context.runtime_env().as_ref(),
+        &reservation,
+        spilled_rows,
+        spilled_bytes,
+    );
+
+    let partitions = files
+        .into_iter()
+        .map(|(file, _)| {
+            Mutex::new(SpilledJoinLeftPartition {
+                file: Some(file),
+                left_fut: None,
+                remaining_probe_threads: options.probe_threads_count,
+            })
+        })
+        .collect();
+    Ok(JoinLeftInput::Spilled(Arc::new(SpilledJoinLeftData {
+        partitions,
+        schema,
+        random_state,
+        depth,
+        options,
+    })))
+}
+
+/// Writes record batches into [`SPILL_PARTITIONS`] spill files, hash
+/// partitioned on the values of the join keys `on`
+struct SpillPartitionWriter {
+    on: Vec<PhysicalExprRef>,
+    random_state: RandomState,
+    files: Vec<(RefCountedTempFile, IPCWriter)>,
+    hashes_buffer: Vec<u64>,
+}
+
+impl SpillPartitionWriter {
+    fn try_new(
+        spill: &JoinSpill,
+        schema: &Schema,
+        on: Vec<PhysicalExprRef>,
+        random_state: RandomState,
+    ) -> Result<Self> {
+        let files = (0..SPILL_PARTITIONS)
+            .map(|_| {
+                let file = spill
+                    .context
+                    .runtime_env()
+                    .disk_manager
+                    .create_tmp_file("HashJoin spill")?;
+                let writer = IPCWriter::new(file.path(), schema)?;
+                Ok((file, writer))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            on,
+            random_state,
+            files,
+            hashes_buffer: vec![],
+        })
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let keys_values = self
+            .on
+            .iter()
+            .map(|c| c.evaluate(batch)?.into_array(batch.num_rows()))
+            .collect::<Result<Vec<_>>>()?;
+        self.hashes_buffer.clear();
+        self.hashes_buffer.resize(batch.num_rows(), 0);
+        create_hashes(&keys_values, &self.random_state, &mut self.hashes_buffer)?;
+
+        let mut indices = vec![vec![]; self.files.len()];
+        for (row, hash) in self.hashes_buffer.iter().enumerate() {
+            indices[(*hash % self.files.len() as u64) as usize].push(row as u32);
+        }
+        for ((_, writer), indices) in self.files.iter_mut().zip(indices) {
+            if !indices.is_empty() {
+                writer.write(&take_record_batch(batch, &UInt32Array::from(indices))?)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finishes the spill files, returning them with their number of rows
+    fn finish(self, spill: &JoinSpill) -> Result<Vec<(RefCountedTempFile, usize)>> {
+        self.files
+            .into_iter()
+            .map(|(file, mut writer)| {
+                writer.finish()?;
+                spill.spill_count.add(1);
+                spill.spilled_bytes.add(writer.num_bytes);
+                spill.spilled_rows.add(writer.num_rows);
+                Ok((file, writer.num_rows))
+            })
+            .collect()
+    }
 }
 
 /// Updates `hash_map` with new entries from `batch` evaluated against the expressions `on`
@@ -988,7 +1349,7 @@ enum BuildSide {
 /// Container for BuildSide::Initial related data
 struct BuildSideInitialState {
     /// Future for building hash table from build-side input
-    left_fut: OnceFut<JoinLeftData>,
+    left_fut: OnceFut<JoinLeftInput>,
 }
 
 /// Container for BuildSide::Ready related data
@@ -1032,9 +1393,9 @@ impl BuildSide {
 ///
 /// ```text
 ///
-///       WaitBuildSide
-///             │
-///             ▼
+///       WaitBuildSide ───► JoinSpilledPartitions ──────────┐
+///             │                                             │
+///             ▼                                             ▼
 ///  ┌─► FetchProbeBatch ───► ExhaustedProbeSide ───► Completed
 ///  │          │
 ///  │          ▼
@@ -1044,6 +1405,9 @@ impl BuildSide {
 enum HashJoinStreamState {
     /// Initial state for HashJoinStream indicating that build-side data not collected yet
     WaitBuildSide,
+    /// Indicates that build-side has been spilled to disk, and the stream
+    /// joins its partitions with the ones of the probe-side
+    JoinSpilledPartitions(SendableRecordBatchStream),
     /// Indicates that build-side has been collected, and stream is ready for fetching probe-side
     FetchProbeBatch,
     /// Indicates that non-empty batch has been fetched from probe-side, and is ready to be processed
@@ -1305,6 +1669,15 @@ impl HashJoinStream {
                 HashJoinStreamState::WaitBuildSide => {
                     handle_state!(ready!(self.collect_build_side(cx)))
                 }
+                HashJoinStreamState::JoinSpilledPartitions(ref mut stream) => {
+                    match ready!(stream.poll_next_unpin(cx)) {
+                        Some(result) => Poll::Ready(Some(result)),
+                        None => {
+                            self.state = HashJoinStreamState::Completed;
+                            continue;
+                        }
+                    }
+                }
                 HashJoinStreamState::FetchProbeBatch => {
                     handle_state!(ready!(self.fetch_probe_batch(cx)))
                 }
@@ -1335,12 +1708,104 @@ impl HashJoinStream {
             .get_shared(cx))?;
         build_timer.done();
 
-        self.state = HashJoinStreamState::FetchProbeBatch;
-        self.build_side = BuildSide::Ready(BuildSideReadyState { left_data });
+        match left_data.as_ref() {
+            JoinLeftInput::InMemory(left_data) => {
+                self.state = HashJoinStreamState::FetchProbeBatch;
+                self.build_side = BuildSide::Ready(BuildSideReadyState {
+                    left_data: Arc::clone(left_data),
+                });
+            }
+            JoinLeftInput::Spilled(left_data) => {
+                let stream = self.join_spilled_partitions(Arc::clone(left_data));
+                self.state = HashJoinStreamState::JoinSpilledPartitions(stream);
+            }
+        }
 
         Poll::Ready(Ok(StatefulStreamResult::Continue))
     }
 
+    /// Joins the partitions of the spilled build-side with the probe-side,
+    /// partitioned the same way
+    fn join_spilled_partitions(
+        &mut self,
+        left_data: Arc<SpilledJoinLeftData>,
+    ) -> SendableRecordBatchStream {
+        let right_schema = self.right.schema();
+        let right = std::mem::replace(
+            &mut self.right,
+            Box::pin(EmptyRecordBatchStream::new(Arc::clone(&right_schema))),
+        );
+
+        // Streams joining each pair of partitions, with the same settings as this one
+        let schema = Arc::clone(&self.schema);
+        let on_left = self.on_left.clone();
+        let on_right = self.on_right.clone();
+        let filter = self.filter.clone();
+        let join_type = self.join_type;
+        let column_indices = self.column_indices.clone();
+        let random_state = self.random_state.clone();
+        let join_metrics = self.join_metrics.clone();
+        let null_equals_null = self.null_equals_null;
+        let batch_size = self.batch_size;
+        let partition_stream = move |right, left_fut| HashJoinStream {
+            schema: Arc::clone(&schema),
+            on_left: on_left.clone(),
+            on_right: on_right.clone(),
+            filter: filter.clone(),
+            join_type,
+            right,
+            column_indices: column_indices.clone(),
+            random_state: random_state.clone(),
+            join_metrics: join_metrics.clone(),
+            null_equals_null,
+            state: HashJoinStreamState::WaitBuildSide,
+            build_side: BuildSide::Initial(BuildSideInitialState { left_fut }),
+            batch_size,
+            hashes_buffer: vec![],
+            right_side_ordered: false,
+        };
+
+        let on_right = self.on_right.clone();
+        let output_schema = Arc::clone(&self.schema);
+        let join = async move {
+            let Some(spill) = left_data.options.spill.clone() else {
+                return internal_err!("Hash join spilled without a spill configuration");
+            };
+            let mut writer = SpillPartitionWriter::try_new(
+                &spill,
+                &right_schema,
+                on_right,
+                left_data.random_state.clone(),
+            )?;
+            let mut right = right;
+            while let Some(batch) = right.next().await.transpose()? {
+                writer.write(&batch)?;
+            }
+            let right_files = writer.finish(&spill)?;
+
+            let partitions = right_files.into_iter().enumerate().map(
+                move |(i, (file, num_rows))| -> Result<SendableRecordBatchStream> {
+                    let left_fut = left_data.left_fut(i);
+                    // Nothing to join with an empty probe partition, unless
+                    // unmatched build rows are part of the output
+                    if num_rows == 0 && !need_produce_result_in_final(join_type) {
+                        return Ok(Box::pin(EmptyRecordBatchStream::new(Arc::clone(
+                            &output_schema,
+                        ))));
+                    }
+                    let right = read_spill_as_stream(file, Arc::clone(&right_schema), 2)?;
+                    Ok(Box::pin(partition_stream(right, left_fut)))
+                },
+            );
+            Ok::<_, DataFusionError>(stream::iter(partitions).try_flatten())
+        };
+
+        Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            stream::once(join).try_flatten(),
+        ))
+    }
+
     /// Fetches next batch from probe-side
     ///
     /// If non-empty batch has been fetched, updates state to `ProcessProbeBatchState`,
@@ -3820,7 +4285,7 @@ mod tests {
             // Asserting that operator-level reservation attempting to overallocate
             assert_contains!(
                 err.to_string(),
-                "External error: Resources exhausted: Additional allocation failed with top memory consumers (across reservations) as: HashJoinInput"
+                "Resources exhausted: Additional allocation failed with top memory consumers (across reservations) as: HashJoinInput"
             );
         }
 
@@ -3902,14 +4367,175 @@ mod tests {
             // Asserting that stream-level reservation attempting to overallocate
             assert_contains!(
                 err.to_string(),
-                "External error: Resources exhausted: Additional allocation failed with top memory consumers (across reservations) as: HashJoinInput[1]"
+                "Resources exhausted: Additional allocation failed with top memory consumers (across reservations) as: HashJoinInput[1]"
+
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Builds a table of `num_rows` rows where column `a` is `i % modulo + offset`
+    /// and columns `b` and `c` are `i`, in `num_partitions` partitions of batches
+    /// of at most 1000 rows, hash partitioned on `a`
+    fn build_partitioned_table(
+        names: (&str, &str, &str),
+        num_rows: i32,
+        modulo: i32,
+        offset: i32,
+        num_partitions: i32,
+    ) -> Arc<dyn ExecutionPlan> {
+        let mut partitions = vec![vec![]; num_partitions as usize];
+        for start in (0..num_rows).step_by(1000) {
+            for (p, batches) in partitions.iter_mut().enumerate() {
+                let values: Vec<i32> = (start..num_rows.min(start + 1000))
+                    .filter(|i| (i % modulo + offset) % num_partitions == p as i32)
+                    .collect();
+                let keys = values.iter().map(|i| i % modulo + offset).collect();
+                batches.push(build_table_i32(
+                    (names.0, &keys),
+                    (names.1, &values),
+                    (names.2, &values),
+                ));
+            }
+        }
+        let schema = partitions[0][0].schema();
+        Arc::new(MemoryExec::try_new(&partitions, schema, None).unwrap())
+    }
 
+    /// Executes all the partitions of `join` concurrently, returning the
+    /// formatted rows of its output in sorted order
+    async fn collect_sorted_rows(
+        join: &HashJoinExec,
+        context: Arc<TaskContext>,
+    ) -> Result<Vec<String>> {
+        let partitions = (0..join.properties().output_partitioning().partition_count())
+            .map(|i| join.execute(i, Arc::clone(&context)).map(common::collect))
+            .collect::<Result<Vec<_>>>()?;
+        let batches: Vec<RecordBatch> = futures::future::try_join_all(partitions)
+            .await?
+            .into_iter()
+            .flatten()
+            .filter(|batch| batch.num_rows() > 0)
+            .collect();
+        let formatted = arrow::util::pretty::pretty_format_batches(&batches)?;
+        let mut rows: Vec<String> =
+            formatted.to_string().lines().map(String::from).collect();
+        rows.sort();
+        Ok(rows)
+    }
+
+    fn spilling_task_ctx(memory_limit: usize) -> Result<Arc<TaskContext>> {
+        let runtime = Arc::new(
+            RuntimeEnvBuilder::new()
+                .with_memory_limit(memory_limit, 1.0)
+                .build()?,
+        );
+        let session_config = SessionConfig::default()
+            .set_bool("datafusion.execution.enable_hash_join_spill", true);
+        Ok(Arc::new(
+            TaskContext::default()
+                .with_session_config(session_config)
+                .with_runtime(runtime),
+        ))
+    }
+
+    #[tokio::test]
+    async fn join_spill_to_disk() -> Result<()> {
+        let join_types = vec![
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftSemi,
+            JoinType::LeftAnti,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
+        ];
+
+        for (partition_mode, left_partitions) in [
+            (PartitionMode::CollectLeft, 1),
+            (PartitionMode::Partitioned, 2),
+        ] {
+            // keys 0..4000 on the left, with duplicates, and 500..5500 on the right
+            let left = build_partitioned_table(
+                ("a1", "b1", "c1"),
+                10_000,
+                4_000,
+                0,
+                left_partitions,
             );
+            let right = build_partitioned_table(("a2", "b2", "c2"), 6_000, 5_000, 500, 2);
+            let on = vec![(
+                Arc::new(Column::new_with_schema("a1", &left.schema())?) as _,
+                Arc::new(Column::new_with_schema("a2", &right.schema())?) as _,
+            )];
+
+            for join_type in &join_types {
+                let new_join = || {
+                    HashJoinExec::try_new(
+                        Arc::clone(&left),
+                        Arc::clone(&right),
+                        on.clone(),
+                        None,
+                        join_type,
+                        None,
+                        partition_mode,
+                        false,
+                    )
+                };
+
+                let expected =
+                    collect_sorted_rows(&new_join()?, Arc::new(TaskContext::default()))
+                        .await?;
+
+                // the build side does not fit in memory, but each of its
+                // partitions does
+                let join = new_join()?;
+                let actual =
+                    collect_sorted_rows(&join, spilling_task_ctx(160_000)?).await?;
+                assert_eq!(
+                    expected, actual,
+                    "{join_type:?} join in {partition_mode:?} mode"
+                );
+                let metrics = join.metrics().unwrap();
+                assert!(metrics.spill_count().unwrap() > 0);
+                assert!(metrics.spilled_rows().unwrap() > 0);
+            }
         }
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn join_spill_to_disk_overallocation() -> Result<()> {
+        let left = build_partitioned_table(("a1", "b1", "c1"), 10_000, 4_000, 0, 2);
+        let right = build_partitioned_table(("a2", "b2", "c2"), 6_000, 5_000, 500, 2);
+        let on = vec![(
+            Arc::new(Column::new_with_schema("a1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("a2", &right.schema())?) as _,
+        )];
+        let join = HashJoinExec::try_new(
+            left,
+            right,
+            on,
+            None,
+            &JoinType::Inner,
+            None,
+            PartitionMode::Partitioned,
+            false,
+        )?;
+
+        // even the partitions of the partitions of the build side do not fit
+        let err = collect_sorted_rows(&join, spilling_task_ctx(1_000)?)
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "Resources exhausted");
+        assert!(join.metrics().unwrap().spill_count().unwrap() > 0);
+
+        Ok(())
+    }
+
     fn build_table_struct(
         struct_name: &str,
         field_name_and_values: (&str, &Vec<Option<i32>>),
@@ -59,7 +59,7 @@ use crate::joins::utils::{
     symmetric_join_output_partitioning, JoinFilter, JoinOn, JoinOnRef,
 };
 use crate::metrics::{Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
-use crate::spill::spill_record_batches;
+use crate::spill::{notify_spill, spill_record_batches};
 use crate::{
     execution_mode_from_children, metrics, DisplayAs, DisplayFormatType, Distribution,
     ExecutionPlan, ExecutionPlanProperties, PhysicalExpr, PlanProperties,
@@ -936,6 +936,12 @@ impl SMJStream {
                         .spilled_bytes
                         .add(buffered_batch.size_estimation);
                     self.join_metrics.spilled_rows.add(buffered_batch.num_rows);
+                    notify_spill(
+                        &self.runtime_env,
+                        &self.reservation,
+                        buffered_batch.num_rows,
+                        buffered_batch.size_estimation,
+                    );
                     Ok(())
                 } else {
                     internal_err!("Buffered batch has empty body")
@@ -440,6 +440,19 @@ pub fn adjust_right_output_partitioning(
                 .collect();
             Partitioning::Hash(new_exprs, *size)
         }
+        Partitioning::Range(sort_exprs, size) => {
+            let new_sort_exprs = sort_exprs
+                .iter()
+                .map(|sort_expr| PhysicalSortExpr {
+                    expr: add_offset_to_expr(
+                        Arc::clone(&sort_expr.expr),
+                        left_columns_len,
+                    ),
+                    options: sort_expr.options,
+                })
+                .collect();
+            Partitioning::Range(new_sort_exprs, *size)
+        }
         result => result.clone(),
     }
 }
@@ -1116,7 +1129,7 @@ impl<T: 'static> OnceFut<T> {
             OnceFutState::Ready(r) => Poll::Ready(
                 r.as_ref()
                     .map(|r| r.as_ref())
-                    .map_err(|e| DataFusionError::External(Box::new(Arc::clone(e)))),
+                    .map_err(|e| DataFusionError::Shared(Arc::clone(e))),
             ),
         }
     }
@@ -1130,10 +1143,9 @@ impl<T: 'static> OnceFut<T> {
 
         match &self.state {
             OnceFutState::Pending(_) => unreachable!(),
-            OnceFutState::Ready(r) => Poll::Ready(
-                r.clone()
-                    .map_err(|e| DataFusionError::External(Box::new(e))),
-            ),
+            OnceFutState::Ready(r) => {
+                Poll::Ready(r.clone().map_err(DataFusionError::Shared))
+            }
         }
     }
 }
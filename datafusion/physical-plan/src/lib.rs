@@ -38,8 +38,9 @@ pub use crate::display::{DefaultDisplay, DisplayAs, DisplayFormatType, VerboseDi
 pub(crate) use crate::execution_plan::execution_mode_from_children;
 pub use crate::execution_plan::{
     collect, collect_partitioned, displayable, execute_input_stream, execute_stream,
-    execute_stream_partitioned, get_plan_string, with_new_children_if_necessary,
-    ExecutionMode, ExecutionPlan, ExecutionPlanProperties, PlanProperties,
+    execute_stream_partitioned, get_plan_string, operator_metrics,
+    with_new_children_if_necessary, ExecutionMode, ExecutionPlan,
+    ExecutionPlanProperties, PlanProperties,
 };
 pub use crate::metrics::Metric;
 pub use crate::ordering::InputOrderMode;
@@ -70,6 +71,7 @@ pub mod placeholder_row;
 pub mod projection;
 pub mod recursive_query;
 pub mod repartition;
+pub mod sample;
 pub mod sorts;
 pub mod spill;
 pub mod stream;
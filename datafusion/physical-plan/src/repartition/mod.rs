@@ -651,6 +651,7 @@ impl ExecutionPlan for RepartitionExec {
                     input: rx.swap_remove(0),
                     drop_helper: abort_helper,
                     reservation,
+                    baseline_metrics: BaselineMetrics::new(&metrics, partition),
                 }) as SendableRecordBatchStream)
             }
         })
@@ -872,7 +873,7 @@ impl RepartitionExec {
 
                 for (_, tx) in txs {
                     // wrap it because need to send error to all output partitions
-                    let err = Err(DataFusionError::External(Box::new(Arc::clone(&e))));
+                    let err = Err(DataFusionError::Shared(Arc::clone(&e)));
                     tx.send(Some(err)).await.ok();
                 }
             }
@@ -906,15 +907,16 @@ struct RepartitionStream {
 
     /// Memory reservation.
     reservation: SharedMemoryReservation,
-}
 
-impl Stream for RepartitionStream {
-    type Item = Result<RecordBatch>;
+    /// Output rows of this output partition
+    baseline_metrics: BaselineMetrics,
+}
 
-    fn poll_next(
-        mut self: Pin<&mut Self>,
+impl RepartitionStream {
+    fn poll_next_inner(
+        &mut self,
         cx: &mut Context<'_>,
-    ) -> Poll<Option<Self::Item>> {
+    ) -> Poll<Option<Result<RecordBatch>>> {
         loop {
             match self.input.recv().poll_unpin(cx) {
                 Poll::Ready(Some(Some(v))) => {
@@ -948,6 +950,18 @@ impl Stream for RepartitionStream {
     }
 }
 
+impl Stream for RepartitionStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.poll_next_inner(cx);
+        self.baseline_metrics.record_poll(poll)
+    }
+}
+
 impl RecordBatchStream for RepartitionStream {
     /// Get the schema
     fn schema(&self) -> SchemaRef {
@@ -1112,6 +1126,33 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn hash_partition_output_rows_metrics() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let schema = test_schema();
+        let partition = create_vec_batches(50);
+        let partitions = vec![partition.clone(), partition.clone(), partition];
+
+        let exec = MemoryExec::try_new(&partitions, Arc::clone(&schema), None)?;
+        let exec = RepartitionExec::try_new(
+            Arc::new(exec),
+            Partitioning::Hash(vec![col("c0", &schema)?], 8),
+        )?;
+
+        let mut total_rows = 0;
+        for i in 0..8 {
+            let stream = exec.execute(i, Arc::clone(&task_ctx))?;
+            let batches = crate::common::collect(stream).await?;
+            total_rows += batches.iter().map(|b| b.num_rows()).sum::<usize>();
+        }
+
+        let metrics = exec.metrics().unwrap();
+        assert_eq!(total_rows, 8 * 50 * 3);
+        assert_eq!(metrics.output_rows(), Some(total_rows));
+
+        Ok(())
+    }
+
     fn test_schema() -> Arc<Schema> {
         Arc::new(Schema::new(vec![Field::new("c0", DataType::UInt32, false)]))
     }
@@ -0,0 +1,678 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the SAMPLE plan, which returns a random subset of its input rows
+
+use std::any::Any;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+
+use super::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use super::{
+    DisplayAs, ExecutionPlanProperties, PlanProperties, RecordBatchStream,
+    SendableRecordBatchStream, Statistics,
+};
+use crate::{DisplayFormatType, ExecutionPlan};
+
+use arrow::array::{BooleanArray, UInt32Array};
+use arrow::compute::{filter_record_batch, take_record_batch};
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{internal_err, plan_err, Result};
+use datafusion_execution::TaskContext;
+
+use futures::stream::{Stream, StreamExt};
+use log::trace;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// How [`SampleExec`] selects the rows it returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleMethod {
+    /// Every row is selected independently of the others
+    Bernoulli,
+    /// Whole blocks of rows are selected or skipped together. This is less
+    /// random than [`SampleMethod::Bernoulli`], but lets scans skip the
+    /// blocks that are not selected without reading them.
+    System,
+}
+
+impl std::fmt::Display for SampleMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SampleMethod::Bernoulli => write!(f, "bernoulli"),
+            SampleMethod::System => write!(f, "system"),
+        }
+    }
+}
+
+/// SampleExec returns a random subset of the rows of its input.
+///
+/// With [`SampleMethod::Bernoulli`] and without replacement, every input row
+/// is kept independently with probability `fraction`. With replacement,
+/// every input row is emitted `k` times where `k` is drawn from a Poisson
+/// distribution with mean `fraction`, so `fraction` may exceed `1.0`.
+///
+/// With [`SampleMethod::System`], every input batch is kept or dropped as a
+/// whole with probability `fraction`. Scans that can skip blocks of rows by
+/// themselves, such as the row groups of a Parquet file, use
+/// [`sample_block`] instead of being wrapped in a SampleExec.
+///
+/// When a `seed` is supplied, each partition derives its random number
+/// generator from the seed and its partition index, so repeated executions
+/// over the same input produce identical output.
+#[derive(Debug)]
+pub struct SampleExec {
+    /// The input plan
+    input: Arc<dyn ExecutionPlan>,
+    /// How rows are selected
+    method: SampleMethod,
+    /// Expected fraction of the input rows to return
+    fraction: f64,
+    /// Whether a row may be returned more than once
+    with_replacement: bool,
+    /// Seed for the random number generator, `None` means a random seed
+    seed: Option<u64>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    cache: PlanProperties,
+}
+
+impl SampleExec {
+    /// Create a new SampleExec using [`SampleMethod::Bernoulli`]
+    pub fn try_new(
+        input: Arc<dyn ExecutionPlan>,
+        fraction: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        Self::try_new_with_method(
+            input,
+            SampleMethod::Bernoulli,
+            fraction,
+            with_replacement,
+            seed,
+        )
+    }
+
+    /// Create a new SampleExec using `method`
+    pub fn try_new_with_method(
+        input: Arc<dyn ExecutionPlan>,
+        method: SampleMethod,
+        fraction: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        if method == SampleMethod::System && with_replacement {
+            return plan_err!(
+                "SYSTEM sampling does not support sampling with replacement"
+            );
+        }
+        if !fraction.is_finite() || fraction < 0.0 {
+            return plan_err!(
+                "Sample fraction must be a non-negative number, got {fraction}"
+            );
+        }
+        if !with_replacement && fraction > 1.0 {
+            return plan_err!(
+                "Sample fraction must be between 0 and 1 when sampling without replacement, got {fraction}"
+            );
+        }
+        let cache = Self::compute_properties(&input);
+        Ok(Self {
+            input,
+            method,
+            fraction,
+            with_replacement,
+            seed,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// The input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// How rows are selected
+    pub fn method(&self) -> SampleMethod {
+        self.method
+    }
+
+    /// Expected fraction of the input rows to return
+    pub fn fraction(&self) -> f64 {
+        self.fraction
+    }
+
+    /// Whether a row may be returned more than once
+    pub fn with_replacement(&self) -> bool {
+        self.with_replacement
+    }
+
+    /// Seed for the random number generator
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(input: &Arc<dyn ExecutionPlan>) -> PlanProperties {
+        // Sampling only removes (or repeats in place) rows, so the ordering
+        // and partitioning of the input are preserved.
+        PlanProperties::new(
+            input.equivalence_properties().clone(), // Equivalence Properties
+            input.output_partitioning().clone(),    // Output Partitioning
+            input.execution_mode(),                 // Execution Mode
+        )
+    }
+}
+
+impl DisplayAs for SampleExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(
+                    f,
+                    "SampleExec: method={}, fraction={}, with_replacement={}, seed={}",
+                    self.method,
+                    self.fraction,
+                    self.with_replacement,
+                    self.seed.map_or("None".to_string(), |x| x.to_string())
+                )
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for SampleExec {
+    fn name(&self) -> &'static str {
+        "SampleExec"
+    }
+
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![&self.input]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![true]
+    }
+
+    fn benefits_from_input_partitioning(&self) -> Vec<bool> {
+        // Repartitioning the input would make the rows seen by each seeded
+        // partition depend on scheduling, breaking reproducibility.
+        vec![false]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        match children.len() {
+            1 => Ok(Arc::new(SampleExec::try_new_with_method(
+                Arc::clone(&children[0]),
+                self.method,
+                self.fraction,
+                self.with_replacement,
+                self.seed,
+            )?)),
+            _ => internal_err!("SampleExec wrong number of children"),
+        }
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        trace!("Start SampleExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(mix_seed(seed, partition as u64)),
+            None => StdRng::from_entropy(),
+        };
+        Ok(Box::pin(SampleStream {
+            schema: self.input.schema(),
+            input: self.input.execute(partition, context)?,
+            method: self.method,
+            fraction: self.fraction,
+            with_replacement: self.with_replacement,
+            rng,
+            baseline_metrics,
+        }))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    /// The expected output size is the input size scaled by `fraction`, but
+    /// the actual size is random, so the statistics are always inexact.
+    fn statistics(&self) -> Result<Statistics> {
+        let mut stats = self.input.statistics()?.to_inexact();
+        stats.num_rows = stats.num_rows.with_estimated_selectivity(self.fraction);
+        stats.total_byte_size = stats
+            .total_byte_size
+            .with_estimated_selectivity(self.fraction);
+        stats.column_statistics = stats
+            .column_statistics
+            .into_iter()
+            .map(|mut s| {
+                s.null_count = s.null_count.with_estimated_selectivity(self.fraction);
+                s
+            })
+            .collect();
+        Ok(stats)
+    }
+}
+
+/// The SampleExec stream wraps the input stream and randomly drops or
+/// repeats rows of each batch
+struct SampleStream {
+    /// Output schema, which is the same as the input schema for this operator
+    schema: SchemaRef,
+    /// The input partition to sample
+    input: SendableRecordBatchStream,
+    /// How rows are selected
+    method: SampleMethod,
+    /// Expected fraction of the input rows to return
+    fraction: f64,
+    /// Whether a row may be returned more than once
+    with_replacement: bool,
+    /// Random number generator for this partition
+    rng: StdRng,
+    /// runtime metrics recording
+    baseline_metrics: BaselineMetrics,
+}
+
+impl SampleStream {
+    fn sample_batch(&mut self, batch: &RecordBatch) -> Result<RecordBatch> {
+        if self.method == SampleMethod::System {
+            if self.rng.gen::<f64>() < self.fraction {
+                Ok(batch.clone())
+            } else {
+                Ok(batch.slice(0, 0))
+            }
+        } else if self.with_replacement {
+            let mut indices = vec![];
+            for row in 0..batch.num_rows() {
+                let count = poisson(&mut self.rng, self.fraction);
+                indices.extend(std::iter::repeat(row as u32).take(count));
+            }
+            Ok(take_record_batch(batch, &UInt32Array::from(indices))?)
+        } else {
+            let fraction = self.fraction;
+            let mask = (0..batch.num_rows())
+                .map(|_| Some(self.rng.gen::<f64>() < fraction))
+                .collect::<BooleanArray>();
+            Ok(filter_record_batch(batch, &mask)?)
+        }
+    }
+}
+
+/// Derives a seed from `seed` and `value`, such as a partition index.
+///
+/// The seeds derived for consecutive values are unrelated, unlike e.g.
+/// `seed + value`, which would seed the partitions with overlapping random
+/// number streams for consecutive seeds. This is the SplitMix64 generator
+/// evaluated at position `value` of the stream starting at `seed`.
+pub fn mix_seed(seed: u64, value: u64) -> u64 {
+    let mut z =
+        seed.wrapping_add(value.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns whether the block `index` of the source `key`, e.g. a row group of
+/// a file, is part of a [`SampleMethod::System`] sample of `fraction` of the
+/// blocks.
+///
+/// The result only depends on the arguments, so a seeded sample is the same
+/// whichever partition reads the block.
+pub fn sample_block(key: &str, index: usize, fraction: f64, seed: u64) -> bool {
+    // FNV-1a, which unlike the std hashers is stable across releases
+    let key_hash = key.bytes().fold(0xCBF2_9CE4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    });
+    let hash = mix_seed(mix_seed(seed, key_hash), index as u64);
+    // the 53 high bits as a uniformly distributed number in [0, 1)
+    let unit = (hash >> 11) as f64 / (1_u64 << 53) as f64;
+    unit < fraction
+}
+
+/// Draws a value from a Poisson distribution with mean `lambda`.
+///
+/// Knuth's multiplication method needs O(`lambda`) random numbers, so it is
+/// only used for the small means typical of sampling fractions. Larger means
+/// use the transformed rejection method with squeeze (PTRS) of Hörmann,
+/// "The transformed rejection method for generating Poisson random
+/// variables" (1993), which needs a constant number of them on average.
+fn poisson(rng: &mut StdRng, lambda: f64) -> usize {
+    if lambda >= 10.0 {
+        return poisson_ptrs(rng, lambda);
+    }
+    let limit = (-lambda).exp();
+    let mut count = 0;
+    let mut product = rng.gen::<f64>();
+    while product > limit {
+        count += 1;
+        product *= rng.gen::<f64>();
+    }
+    count
+}
+
+/// The PTRS Poisson sampler, see [`poisson`]. Only valid for `lambda >= 10`.
+fn poisson_ptrs(rng: &mut StdRng, lambda: f64) -> usize {
+    let sqrt_lambda = lambda.sqrt();
+    let log_lambda = lambda.ln();
+    let b = 0.931 + 2.53 * sqrt_lambda;
+    let a = -0.059 + 0.02483 * b;
+    let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+    loop {
+        let u = rng.gen::<f64>() - 0.5;
+        let v = rng.gen::<f64>();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+        if us >= 0.07 && v <= v_r {
+            return k as usize;
+        }
+        if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+        if (v * inv_alpha / (a / (us * us) + b)).ln()
+            <= -lambda + k * log_lambda - ln_factorial(k)
+        {
+            return k as usize;
+        }
+    }
+}
+
+/// The natural logarithm of `k!` for a non-negative integer `k`, using
+/// Stirling's series, which is accurate to double precision for `k >= 10`
+fn ln_factorial(k: f64) -> f64 {
+    if k < 10.0 {
+        return (2..=k as usize).map(|i| (i as f64).ln()).sum();
+    }
+    let x = k + 1.0;
+    let x2 = x * x;
+    (x - 0.5) * x.ln() - x
+        + 0.5 * (2.0 * std::f64::consts::PI).ln()
+        + (1.0 / 12.0 - (1.0 / 360.0 - 1.0 / (1260.0 * x2)) / x2) / x
+}
+
+impl Stream for SampleStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll;
+        loop {
+            match ready!(self.input.poll_next_unpin(cx)) {
+                Some(Ok(batch)) => {
+                    let elapsed_compute = self.baseline_metrics.elapsed_compute().clone();
+                    let timer = elapsed_compute.timer();
+                    let sampled_batch = self.sample_batch(&batch);
+                    timer.done();
+                    // skip batches where no rows were sampled
+                    if matches!(&sampled_batch, Ok(batch) if batch.num_rows() == 0) {
+                        continue;
+                    }
+                    poll = Poll::Ready(Some(sampled_batch));
+                    break;
+                }
+                value => {
+                    poll = Poll::Ready(value);
+                    break;
+                }
+            }
+        }
+        self.baseline_metrics.record_poll(poll)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // at most the same number of record batches
+        self.input.size_hint()
+    }
+}
+
+impl RecordBatchStream for SampleStream {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coalesce_partitions::CoalescePartitionsExec;
+    use crate::common::collect;
+    use crate::memory::MemoryExec;
+    use crate::test;
+
+    use arrow::array::Int32Array;
+    use datafusion_common::cast::as_int32_array;
+    use datafusion_common::stats::Precision;
+
+    /// 4 partitions of 10 batches of 1000 rows each
+    fn large_input() -> Arc<dyn ExecutionPlan> {
+        let partitions: Vec<Vec<RecordBatch>> = (0..4)
+            .map(|_| (0..10).map(|_| test::make_partition(1000)).collect())
+            .collect();
+        let schema = partitions[0][0].schema();
+        Arc::new(MemoryExec::try_new(&partitions, schema, None).unwrap())
+    }
+
+    async fn sample_values(
+        fraction: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<Vec<i32>> {
+        let sample =
+            SampleExec::try_new(large_input(), fraction, with_replacement, seed)?;
+        let plan = Arc::new(CoalescePartitionsExec::new(Arc::new(sample)));
+        let batches = collect(plan.execute(0, Arc::new(TaskContext::default()))?).await?;
+        let mut values = vec![];
+        for batch in batches {
+            values.extend(as_int32_array(batch.column(0))?.values().iter().copied());
+        }
+        Ok(values)
+    }
+
+    #[tokio::test]
+    async fn sample_fraction_within_tolerance() -> Result<()> {
+        // 40_000 input rows, expect ~4_000 with a standard deviation of ~60
+        let rows = sample_values(0.1, false, Some(42)).await?.len();
+        assert!((3_600..=4_400).contains(&rows), "sampled {rows} rows");
+
+        let rows = sample_values(0.1, true, Some(42)).await?.len();
+        assert!((3_600..=4_400).contains(&rows), "sampled {rows} rows");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sample_with_replacement_above_one() -> Result<()> {
+        // 40_000 input rows, expect ~80_000
+        let rows = sample_values(2.0, true, Some(7)).await?.len();
+        assert!((78_000..=82_000).contains(&rows), "sampled {rows} rows");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sample_with_replacement_large_fraction() -> Result<()> {
+        // 40_000 input rows, expect ~800_000 with a standard deviation of ~900
+        let rows = sample_values(20.0, true, Some(11)).await?.len();
+        assert!((795_000..=805_000).contains(&rows), "sampled {rows} rows");
+        Ok(())
+    }
+
+    #[test]
+    fn poisson_mean_and_variance() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for lambda in [0.5, 9.5, 10.0, 40.0, 1000.0] {
+            let draws: Vec<f64> = (0..20_000)
+                .map(|_| poisson(&mut rng, lambda) as f64)
+                .collect();
+            let mean = draws.iter().sum::<f64>() / draws.len() as f64;
+            let variance = draws.iter().map(|d| (d - mean).powi(2)).sum::<f64>()
+                / draws.len() as f64;
+            // the standard deviation of the mean is sqrt(lambda / 20_000)
+            let tolerance = 5.0 * (lambda / 20_000.0).sqrt();
+            assert!((mean - lambda).abs() < tolerance, "{lambda}: mean {mean}");
+            assert!(
+                (variance / lambda - 1.0).abs() < 0.05,
+                "{lambda}: variance {variance}"
+            );
+        }
+    }
+
+    #[test]
+    fn partition_seeds_are_unrelated() {
+        // the first draws of consecutive partitions of consecutive seeds
+        // must not coincide, as they would with `seed + partition`
+        let first_draws: Vec<u64> = (0..4)
+            .flat_map(|seed| {
+                (0..4).map(move |partition| {
+                    StdRng::seed_from_u64(mix_seed(seed, partition)).gen::<u64>()
+                })
+            })
+            .collect();
+        let mut unique = first_draws.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), first_draws.len());
+    }
+
+    #[test]
+    fn sample_block_fraction() {
+        let selected = (0..10_000)
+            .filter(|i| sample_block("file.parquet", *i, 0.2, 42))
+            .count();
+        // expect ~2_000 with a standard deviation of 40
+        assert!((1_800..=2_200).contains(&selected), "selected {selected}");
+
+        // the selection only depends on the key, index and seed
+        let selection = |key: &str, seed| {
+            (0..100)
+                .map(|i| sample_block(key, i, 0.5, seed))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(selection("a", 1), selection("a", 1));
+        assert_ne!(selection("a", 1), selection("b", 1));
+        assert_ne!(selection("a", 1), selection("a", 2));
+    }
+
+    #[tokio::test]
+    async fn sample_system_keeps_whole_batches() -> Result<()> {
+        let sample = SampleExec::try_new_with_method(
+            large_input(),
+            SampleMethod::System,
+            0.5,
+            false,
+            Some(9),
+        )?;
+        let plan = Arc::new(CoalescePartitionsExec::new(Arc::new(sample)));
+        let batches = collect(plan.execute(0, Arc::new(TaskContext::default()))?).await?;
+        // 40 input batches, expect ~20 with a standard deviation of ~3
+        assert!(
+            (8..=32).contains(&batches.len()),
+            "{} batches",
+            batches.len()
+        );
+        assert!(batches.iter().all(|b| b.num_rows() == 1000));
+
+        assert!(SampleExec::try_new_with_method(
+            large_input(),
+            SampleMethod::System,
+            0.5,
+            true,
+            None
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sample_is_deterministic_with_seed() -> Result<()> {
+        let mut first = sample_values(0.3, false, Some(1234)).await?;
+        let mut second = sample_values(0.3, false, Some(1234)).await?;
+        // partitions are merged in a nondeterministic order
+        first.sort_unstable();
+        second.sort_unstable();
+        assert_eq!(first, second);
+
+        let mut other_seed = sample_values(0.3, false, Some(4321)).await?;
+        other_seed.sort_unstable();
+        assert_ne!(first, other_seed);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sample_preserves_order() -> Result<()> {
+        let input = Arc::new(MemoryExec::try_new(
+            &[vec![test::make_partition(1000)]],
+            test::make_partition(0).schema(),
+            None,
+        )?);
+        let sample = SampleExec::try_new(input, 0.5, true, Some(3))?;
+        let batches =
+            collect(sample.execute(0, Arc::new(TaskContext::default()))?).await?;
+        let values: Vec<i32> = batches
+            .iter()
+            .flat_map(|b| {
+                let array: &Int32Array = as_int32_array(b.column(0)).unwrap();
+                array.values().to_vec()
+            })
+            .collect();
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+        Ok(())
+    }
+
+    #[test]
+    fn sample_statistics() -> Result<()> {
+        let sample = SampleExec::try_new(test::scan_partitioned(4), 0.25, false, None)?;
+        assert_eq!(sample.statistics()?.num_rows, Precision::Inexact(100));
+        Ok(())
+    }
+
+    #[test]
+    fn sample_invalid_fraction() {
+        assert!(
+            SampleExec::try_new(test::scan_partitioned(1), -0.5, false, None).is_err()
+        );
+        assert!(
+            SampleExec::try_new(test::scan_partitioned(1), 1.5, false, None).is_err()
+        );
+        assert!(SampleExec::try_new(test::scan_partitioned(1), 1.5, true, None).is_ok());
+    }
+}
@@ -31,7 +31,7 @@ use crate::metrics::{
     BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet,
 };
 use crate::sorts::streaming_merge::streaming_merge;
-use crate::spill::{read_spill_as_stream, spill_record_batches};
+use crate::spill::{notify_spill, read_spill_as_stream, spill_record_batches};
 use crate::stream::RecordBatchStreamAdapter;
 use crate::topk::TopK;
 use crate::{
@@ -404,6 +404,7 @@ impl ExternalSorter {
         self.metrics.spill_count.add(1);
         self.metrics.spilled_bytes.add(used);
         self.metrics.spilled_rows.add(spilled_rows);
+        notify_spill(&self.runtime, &self.reservation, spilled_rows, used);
         self.spills.push(spill_file);
         Ok(used)
     }
@@ -1203,6 +1204,85 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_sort_spill_random_input() -> Result<()> {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let batch_size = 64;
+        let session_config = SessionConfig::new().with_batch_size(batch_size);
+        let sort_spill_reservation_bytes = session_config
+            .options()
+            .execution
+            .sort_spill_reservation_bytes;
+        let runtime = Arc::new(
+            RuntimeEnvBuilder::new()
+                .with_memory_limit(sort_spill_reservation_bytes + 12288, 1.0)
+                .build()?,
+        );
+        let task_ctx = Arc::new(
+            TaskContext::default()
+                .with_session_config(session_config)
+                .with_runtime(runtime),
+        );
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let schema = Arc::new(Schema::new(vec![Field::new("i", DataType::Int32, true)]));
+        let partitions = (0..8)
+            .map(|_| {
+                (0..10)
+                    .map(|_| {
+                        let values: Int32Array =
+                            (0..100).map(|_| Some(rng.gen_range(0..1000))).collect();
+                        RecordBatch::try_new(Arc::clone(&schema), vec![Arc::new(values)])
+                    })
+                    .collect::<std::result::Result<Vec<_>, _>>()
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let mut expected: Vec<i32> = partitions
+            .iter()
+            .flatten()
+            .flat_map(|batch| as_primitive_array::<Int32Type>(batch.column(0)).unwrap())
+            .flatten()
+            .collect();
+        expected.sort_unstable();
+
+        let input =
+            Arc::new(MemoryExec::try_new(&partitions, Arc::clone(&schema), None)?);
+        let sort_exec = Arc::new(SortExec::new(
+            vec![PhysicalSortExpr {
+                expr: col("i", &schema)?,
+                options: SortOptions::default(),
+            }],
+            Arc::new(CoalescePartitionsExec::new(input)),
+        ));
+
+        let result = collect(
+            Arc::clone(&sort_exec) as Arc<dyn ExecutionPlan>,
+            Arc::clone(&task_ctx),
+        )
+        .await?;
+
+        let metrics = sort_exec.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+
+        // the merge of the spilled runs produces batches of the session batch size
+        assert!(result.iter().all(|batch| batch.num_rows() <= batch_size));
+        let actual: Vec<i32> = result
+            .iter()
+            .flat_map(|batch| as_primitive_array::<Int32Type>(batch.column(0)).unwrap())
+            .flatten()
+            .collect();
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            task_ctx.runtime_env().memory_pool.reserved(),
+            0,
+            "The sort should have returned all memory used back to the memory manager"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_sort_fetch_memory_calculation() -> Result<()> {
         // This test mirrors down the size from the example above.
@@ -1268,6 +1348,77 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_sort_fetch_ties_and_metrics() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("k", DataType::Int32, false),
+            Field::new("v", DataType::Int32, false),
+        ]));
+        let batch = |k: Vec<i32>, v: Vec<i32>| {
+            RecordBatch::try_new(
+                Arc::clone(&schema),
+                vec![Arc::new(Int32Array::from(k)), Arc::new(Int32Array::from(v))],
+            )
+        };
+        let batches = vec![
+            batch(vec![1, 2, 2, 3], vec![0, 1, 2, 3])?,
+            batch(vec![2, 0, 2], vec![4, 5, 6])?,
+        ];
+        let input = Arc::new(MemoryExec::try_new(&[batches], Arc::clone(&schema), None)?);
+
+        let sort_exec = Arc::new(
+            SortExec::new(
+                vec![PhysicalSortExpr {
+                    expr: col("k", &schema)?,
+                    options: SortOptions::default(),
+                }],
+                input,
+            )
+            .with_fetch(Some(3)),
+        );
+
+        let result = collect(
+            Arc::clone(&sort_exec) as Arc<dyn ExecutionPlan>,
+            Arc::clone(&task_ctx),
+        )
+        .await?;
+
+        // rows with equal keys are kept and emitted in input order
+        let expected = [
+            "+---+---+",
+            "| k | v |",
+            "+---+---+",
+            "| 0 | 5 |",
+            "| 1 | 0 |",
+            "| 2 | 1 |",
+            "+---+---+",
+        ];
+        assert_batches_eq!(expected, &result);
+
+        let metrics = sort_exec.metrics().unwrap();
+        assert_eq!(
+            metrics.sum_by_name("input_rows").map(|v| v.as_usize()),
+            Some(7)
+        );
+        assert_eq!(metrics.output_rows(), Some(3));
+        // the first three rows, and then `0`
+        assert_eq!(
+            metrics
+                .sum_by_name("row_replacements")
+                .map(|v| v.as_usize()),
+            Some(4)
+        );
+
+        assert_eq!(
+            task_ctx.runtime_env().memory_pool.reserved(),
+            0,
+            "The sort should have returned all memory used back to the memory manager"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_sort_metadata() -> Result<()> {
         let task_ctx = Arc::new(TaskContext::default());
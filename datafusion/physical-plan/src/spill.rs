@@ -29,7 +29,9 @@ use tokio::sync::mpsc::Sender;
 
 use datafusion_common::{exec_datafusion_err, Result};
 use datafusion_execution::disk_manager::RefCountedTempFile;
-use datafusion_execution::memory_pool::human_readable_size;
+use datafusion_execution::memory_pool::{human_readable_size, MemoryReservation};
+use datafusion_execution::metrics_observer::SpillEvent;
+use datafusion_execution::runtime_env::RuntimeEnv;
 use datafusion_execution::SendableRecordBatchStream;
 
 use crate::common::IPCWriter;
@@ -75,6 +77,23 @@ pub(crate) fn spill_record_batches(
     Ok(writer.num_rows)
 }
 
+/// Report a spill of `rows` rows, that used `bytes` bytes of `reservation`,
+/// to the metrics observer of `runtime`, if any
+pub(crate) fn notify_spill(
+    runtime: &RuntimeEnv,
+    reservation: &MemoryReservation,
+    rows: usize,
+    bytes: usize,
+) {
+    if let Some(observer) = &runtime.metrics_observer {
+        observer.on_spill(&SpillEvent {
+            consumer: reservation.consumer().name().to_string(),
+            rows,
+            bytes,
+        });
+    }
+}
+
 fn read_spill(sender: Sender<Result<RecordBatch>>, path: &Path) -> Result<()> {
     let file = BufReader::new(File::open(path)?);
     let reader = FileReader::try_new(file, None)?;
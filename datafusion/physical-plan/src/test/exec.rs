@@ -271,6 +271,8 @@ fn clone_error(e: &DataFusionError) -> DataFusionError {
     use DataFusionError::*;
     match e {
         Execution(msg) => Execution(msg.to_string()),
+        Plan(msg) => Plan(msg.to_string()),
+        IoError(e) => IoError(std::io::Error::new(e.kind(), e.to_string())),
         _ => unimplemented!(),
     }
 }
@@ -147,6 +147,7 @@ impl TopK {
     pub fn insert_batch(&mut self, batch: RecordBatch) -> Result<()> {
         // Updates on drop
         let _timer = self.metrics.baseline.elapsed_compute().timer();
+        self.metrics.input_rows.add(batch.num_rows());
 
         let sort_keys: Vec<ArrayRef> = self
             .expr
@@ -168,8 +169,9 @@ impl TopK {
         let mut batch_entry = self.heap.register_batch(batch);
         for (index, row) in rows.iter().enumerate() {
             match self.heap.max() {
-                // heap has k items, and the new row is greater than the
-                // current max in the heap ==> it is not a new topk
+                // heap has k items, and the new row is greater than or
+                // equal to the current max in the heap ==> it is not a
+                // new topk (ties keep the row inserted first)
                 Some(max_row) if row.as_ref() >= max_row.row() => {}
                 // don't yet have k items or new item is lower than the currently k low values
                 None | Some(_) => {
@@ -236,6 +238,9 @@ struct TopKMetrics {
     /// metrics
     pub baseline: BaselineMetrics,
 
+    /// count of how many input rows were evaluated against the heap
+    pub input_rows: Count,
+
     /// count of how many rows were replaced in the heap
     pub row_replacements: Count,
 }
@@ -244,6 +249,7 @@ impl TopKMetrics {
     fn new(metrics: &ExecutionPlanMetricsSet, partition: usize) -> Self {
         Self {
             baseline: BaselineMetrics::new(metrics, partition),
+            input_rows: MetricBuilder::new(metrics).counter("input_rows", partition),
             row_replacements: MetricBuilder::new(metrics)
                 .counter("row_replacements", partition),
         }
@@ -455,6 +461,13 @@ impl TopKHeap {
 /// according to memcmp of row (e.g. the arrow Row format, but could
 /// also be primitive values)
 ///
+/// Rows with equal sort keys are ordered by the batch and index they
+/// came from, i.e. in the order they were inserted, so that ties are
+/// broken deterministically: the first rows seen are kept and emitted
+/// first. Compaction preserves this order, as it rewrites the retained
+/// rows into a new batch, in order, with a larger id than any previous
+/// batch.
+///
 /// Reuses allocations to minimize runtime overhead of creating new Vecs
 #[derive(Debug, PartialEq)]
 struct TopKRow {
@@ -522,7 +535,10 @@ impl PartialOrd for TopKRow {
 
 impl Ord for TopKRow {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.row.cmp(&other.row)
+        self.row
+            .cmp(&other.row)
+            .then_with(|| self.batch_id.cmp(&other.batch_id))
+            .then_with(|| self.index.cmp(&other.index))
     }
 }
 
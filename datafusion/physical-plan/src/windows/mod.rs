@@ -22,21 +22,26 @@ use std::sync::Arc;
 
 use crate::{
     expressions::{
-        cume_dist, dense_rank, lag, lead, percent_rank, rank, Literal, NthValue, Ntile,
-        PhysicalSortExpr,
+        cast, cume_dist, dense_rank, lag, lead, percent_rank, rank, Literal, NthValue,
+        Ntile, PhysicalSortExpr,
     },
     ExecutionPlan, ExecutionPlanProperties, InputOrderMode, PhysicalExpr,
 };
 
-use arrow::datatypes::Schema;
+use arrow::datatypes::{Schema, SchemaBuilder};
 use arrow_schema::{DataType, Field, SchemaRef};
 use datafusion_common::{
-    exec_datafusion_err, exec_err, DataFusionError, Result, ScalarValue,
+    exec_datafusion_err, exec_err, plan_datafusion_err, DataFusionError, Result,
+    ScalarValue,
 };
+use datafusion_expr::expr::find_df_window_func;
+use datafusion_expr::type_coercion::functions::data_types_with_aggregate_udf;
 use datafusion_expr::{
     BuiltInWindowFunction, PartitionEvaluator, WindowFrame, WindowFunctionDefinition,
     WindowUDF,
 };
+use datafusion_functions_aggregate::all_default_aggregate_functions;
+use datafusion_functions_window::all_default_window_functions;
 use datafusion_physical_expr::aggregate::{AggregateExprBuilder, AggregateFunctionExpr};
 use datafusion_physical_expr::equivalence::collapse_lex_req;
 use datafusion_physical_expr::{
@@ -136,6 +141,89 @@ pub fn create_window_expr(
     })
 }
 
+/// Create a physical expression for the window function called `name`
+///
+/// `name` is resolved, ignoring case, against the built-in window functions,
+/// the default window UDFs such as `row_number` and the default aggregate
+/// functions, including their aliases, which are evaluated over the
+/// `window_frame`. This allows building window expressions without knowing
+/// the type implementing the function. The arguments of aggregate functions
+/// are coerced to their signature. An error is returned if there is no
+/// function called `name`.
+pub fn window_expr_from_name(
+    name: &str,
+    args: &[Arc<dyn PhysicalExpr>],
+    partition_by: &[Arc<dyn PhysicalExpr>],
+    order_by: &[PhysicalSortExpr],
+    window_frame: Arc<WindowFrame>,
+    input_schema: &Schema,
+) -> Result<Arc<dyn WindowExpr>> {
+    let fun = window_function_from_name(name)
+        .ok_or_else(|| plan_datafusion_err!("Unknown window function: {name}"))?;
+    let arg_types = args
+        .iter()
+        .map(|arg| arg.data_type(input_schema))
+        .collect::<Result<Vec<_>>>()?;
+    // coerce the arguments of aggregate functions to their signature, as the
+    // planner does
+    let args = match &fun {
+        WindowFunctionDefinition::AggregateUDF(udaf) if !args.is_empty() => {
+            let coerced_types = data_types_with_aggregate_udf(&arg_types, udaf)?;
+            args.iter()
+                .zip(coerced_types)
+                .map(|(arg, data_type)| cast(Arc::clone(arg), input_schema, data_type))
+                .collect::<Result<Vec<_>>>()?
+        }
+        _ => args.to_vec(),
+    };
+    // built-in window functions take their output type from the field called
+    // `name`, which the planner passes with the output of the window
+    let mut schema = input_schema.clone();
+    if let WindowFunctionDefinition::BuiltInWindowFunction(_) = &fun {
+        if schema.field_with_name(name).is_err() {
+            let arg_nullable = args
+                .iter()
+                .map(|arg| arg.nullable(input_schema))
+                .collect::<Result<Vec<_>>>()?;
+            let data_type = fun.return_type(&arg_types, &arg_nullable)?;
+            let mut builder = SchemaBuilder::from(schema.fields());
+            builder.push(Field::new(name, data_type, true));
+            schema = builder.finish();
+        }
+    }
+    create_window_expr(
+        &fun,
+        name.to_string(),
+        &args,
+        partition_by,
+        order_by,
+        window_frame,
+        &schema,
+        false,
+    )
+}
+
+/// Returns the built-in window function, default window UDF or default
+/// aggregate function called `name`, in that order of precedence
+fn window_function_from_name(name: &str) -> Option<WindowFunctionDefinition> {
+    let name = name.to_lowercase();
+    let matches =
+        |fn_name: &str, aliases: &[String]| fn_name == name || aliases.contains(&name);
+    find_df_window_func(&name)
+        .or_else(|| {
+            all_default_window_functions()
+                .into_iter()
+                .find(|udwf| matches(udwf.name(), udwf.aliases()))
+                .map(WindowFunctionDefinition::WindowUDF)
+        })
+        .or_else(|| {
+            all_default_aggregate_functions()
+                .into_iter()
+                .find(|udaf| matches(udaf.name(), udaf.aliases()))
+                .map(WindowFunctionDefinition::AggregateUDF)
+        })
+}
+
 /// Creates an appropriate [`WindowExpr`] based on the window frame and
 fn window_expr_from_aggregate_expr(
     partition_by: &[Arc<dyn PhysicalExpr>],
@@ -780,6 +868,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_window_expr_from_name() -> Result<()> {
+        let schema = create_test_schema()?;
+        let a = col("nullable_col", &schema)?;
+        let frame = Arc::new(WindowFrame::new(None));
+        let create = |name: &str, args: &[Arc<dyn PhysicalExpr>]| {
+            window_expr_from_name(name, args, &[], &[], Arc::clone(&frame), &schema)
+        };
+
+        // built-in window functions
+        let expr = create("rank", &[])?;
+        assert!(expr.as_any().is::<BuiltInWindowExpr>());
+        assert_eq!(expr.field()?.data_type(), &DataType::UInt64);
+        let expr = create("LAG", &[Arc::clone(&a)])?;
+        assert_eq!(expr.name(), "LAG");
+        assert_eq!(expr.field()?.data_type(), &DataType::Int32);
+
+        // window UDFs
+        let expr = create("row_number", &[])?;
+        assert!(expr.as_any().is::<BuiltInWindowExpr>());
+        assert_eq!(expr.field()?.data_type(), &DataType::UInt64);
+
+        // aggregate functions, by name and by alias
+        let expr = create("Sum", &[Arc::clone(&a)])?;
+        assert!(expr.as_any().is::<PlainAggregateWindowExpr>());
+        assert_eq!(expr.field()?.data_type(), &DataType::Int64);
+        let expr = create("stddev_samp", &[Arc::clone(&a)])?;
+        assert_eq!(expr.field()?.data_type(), &DataType::Float64);
+
+        let err = create("no_such_function", &[a]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Unknown window function: no_such_function"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_satisfy_nullable() -> Result<()> {
         let schema = create_test_schema()?;
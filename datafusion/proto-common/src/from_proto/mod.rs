@@ -957,6 +957,7 @@ impl TryFrom<&protobuf::ParquetOptions> for ParquetOptions {
             maximum_parallel_row_group_writers: value.maximum_parallel_row_group_writers as usize,
             maximum_buffered_record_batches_per_stream: value.maximum_buffered_record_batches_per_stream as usize,
             schema_force_string_view: value.schema_force_string_view,
+            skip_corrupt_row_groups: value.skip_corrupt_row_groups,
         })
     }
 }
@@ -4874,6 +4874,9 @@ impl serde::Serialize for ParquetOptions {
         if self.schema_force_string_view {
             len += 1;
         }
+        if self.skip_corrupt_row_groups {
+            len += 1;
+        }
         if self.dictionary_page_size_limit != 0 {
             len += 1;
         }
@@ -4960,6 +4963,9 @@ impl serde::Serialize for ParquetOptions {
         if self.schema_force_string_view {
             struct_ser.serialize_field("schemaForceStringView", &self.schema_force_string_view)?;
         }
+        if self.skip_corrupt_row_groups {
+            struct_ser.serialize_field("skipCorruptRowGroups", &self.skip_corrupt_row_groups)?;
+        }
         if self.dictionary_page_size_limit != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("dictionaryPageSizeLimit", ToString::to_string(&self.dictionary_page_size_limit).as_str())?;
@@ -5079,6 +5085,8 @@ impl<'de> serde::Deserialize<'de> for ParquetOptions {
             "bloomFilterOnWrite",
             "schema_force_string_view",
             "schemaForceStringView",
+            "skip_corrupt_row_groups",
+            "skipCorruptRowGroups",
             "dictionary_page_size_limit",
             "dictionaryPageSizeLimit",
             "data_page_row_count_limit",
@@ -5121,6 +5129,7 @@ impl<'de> serde::Deserialize<'de> for ParquetOptions {
             BloomFilterOnRead,
             BloomFilterOnWrite,
             SchemaForceStringView,
+            SkipCorruptRowGroups,
             DictionaryPageSizeLimit,
             DataPageRowCountLimit,
             MaxRowGroupSize,
@@ -5169,6 +5178,7 @@ impl<'de> serde::Deserialize<'de> for ParquetOptions {
                             "bloomFilterOnRead" | "bloom_filter_on_read" => Ok(GeneratedField::BloomFilterOnRead),
                             "bloomFilterOnWrite" | "bloom_filter_on_write" => Ok(GeneratedField::BloomFilterOnWrite),
                             "schemaForceStringView" | "schema_force_string_view" => Ok(GeneratedField::SchemaForceStringView),
+                            "skipCorruptRowGroups" | "skip_corrupt_row_groups" => Ok(GeneratedField::SkipCorruptRowGroups),
                             "dictionaryPageSizeLimit" | "dictionary_page_size_limit" => Ok(GeneratedField::DictionaryPageSizeLimit),
                             "dataPageRowCountLimit" | "data_page_row_count_limit" => Ok(GeneratedField::DataPageRowCountLimit),
                             "maxRowGroupSize" | "max_row_group_size" => Ok(GeneratedField::MaxRowGroupSize),
@@ -5215,6 +5225,7 @@ impl<'de> serde::Deserialize<'de> for ParquetOptions {
                 let mut bloom_filter_on_read__ = None;
                 let mut bloom_filter_on_write__ = None;
                 let mut schema_force_string_view__ = None;
+                let mut skip_corrupt_row_groups__ = None;
                 let mut dictionary_page_size_limit__ = None;
                 let mut data_page_row_count_limit__ = None;
                 let mut max_row_group_size__ = None;
@@ -5322,6 +5333,12 @@ impl<'de> serde::Deserialize<'de> for ParquetOptions {
                             }
                             schema_force_string_view__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::SkipCorruptRowGroups => {
+                            if skip_corrupt_row_groups__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("skipCorruptRowGroups"));
+                            }
+                            skip_corrupt_row_groups__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::DictionaryPageSizeLimit => {
                             if dictionary_page_size_limit__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("dictionaryPageSizeLimit"));
@@ -5423,6 +5440,7 @@ impl<'de> serde::Deserialize<'de> for ParquetOptions {
                     bloom_filter_on_read: bloom_filter_on_read__.unwrap_or_default(),
                     bloom_filter_on_write: bloom_filter_on_write__.unwrap_or_default(),
                     schema_force_string_view: schema_force_string_view__.unwrap_or_default(),
+                    skip_corrupt_row_groups: skip_corrupt_row_groups__.unwrap_or_default(),
                     dictionary_page_size_limit: dictionary_page_size_limit__.unwrap_or_default(),
                     data_page_row_count_limit: data_page_row_count_limit__.unwrap_or_default(),
                     max_row_group_size: max_row_group_size__.unwrap_or_default(),
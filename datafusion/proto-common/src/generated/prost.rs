@@ -817,6 +817,9 @@ pub struct ParquetOptions {
     /// default = false
     #[prost(bool, tag = "28")]
     pub schema_force_string_view: bool,
+    /// default = false
+    #[prost(bool, tag = "29")]
+    pub skip_corrupt_row_groups: bool,
     #[prost(uint64, tag = "12")]
     pub dictionary_page_size_limit: u64,
     #[prost(uint64, tag = "18")]
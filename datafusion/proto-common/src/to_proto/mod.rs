@@ -829,6 +829,7 @@ impl TryFrom<&ParquetOptions> for protobuf::ParquetOptions {
             maximum_parallel_row_group_writers: value.maximum_parallel_row_group_writers as u64,
             maximum_buffered_record_batches_per_stream: value.maximum_buffered_record_batches_per_stream as u64,
             schema_force_string_view: value.schema_force_string_view,
+            skip_corrupt_row_groups: value.skip_corrupt_row_groups,
         })
     }
 }
@@ -401,6 +401,7 @@ impl TableParquetOptionsProto {
                 maximum_parallel_row_group_writers: global_options.global.maximum_parallel_row_group_writers as u64,
                 maximum_buffered_record_batches_per_stream: global_options.global.maximum_buffered_record_batches_per_stream as u64,
                 schema_force_string_view: global_options.global.schema_force_string_view,
+                skip_corrupt_row_groups: global_options.global.skip_corrupt_row_groups,
             }),
             column_specific_options: column_specific_options.into_iter().map(|(column_name, options)| {
                 ParquetColumnSpecificOptions {
@@ -491,6 +492,7 @@ impl From<&ParquetOptionsProto> for ParquetOptions {
             maximum_parallel_row_group_writers: proto.maximum_parallel_row_group_writers as usize,
             maximum_buffered_record_batches_per_stream: proto.maximum_buffered_record_batches_per_stream as usize,
             schema_force_string_view: proto.schema_force_string_view,
+            skip_corrupt_row_groups: proto.skip_corrupt_row_groups,
         }
     }
 }
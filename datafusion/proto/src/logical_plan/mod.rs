@@ -566,6 +566,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                             .clone(),
                         order_exprs,
                         if_not_exists: create_extern_table.if_not_exists,
+                        or_replace: create_extern_table.or_replace,
                         definition,
                         unbounded: create_extern_table.unbounded,
                         options: create_extern_table.options.clone(),
@@ -1328,6 +1329,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     schema: df_schema,
                     table_partition_cols,
                     if_not_exists,
+                    or_replace,
                     definition,
                     order_exprs,
                     unbounded,
@@ -1366,6 +1368,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                             options: options.clone(),
                             constraints: Some(constraints.clone().into()),
                             column_defaults: converted_column_defaults,
+                            or_replace: *or_replace,
                         },
                     )),
                 })
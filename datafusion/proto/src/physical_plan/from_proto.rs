@@ -17,6 +17,7 @@
 
 //! Serde code to convert from protocol buffers to Rust data structures.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow::compute::SortOptions;
@@ -24,7 +25,7 @@ use chrono::{TimeZone, Utc};
 use object_store::path::Path;
 use object_store::ObjectMeta;
 
-use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::datatypes::{Field, Schema};
 use datafusion::datasource::file_format::csv::CsvSink;
 use datafusion::datasource::file_format::json::JsonSink;
 #[cfg(feature = "parquet")]
@@ -526,16 +527,23 @@ pub fn parse_protobuf_file_scan_config(
         output_ordering.push(sort_expr);
     }
 
-    Ok(FileScanConfig {
-        object_store_url,
-        file_schema,
-        file_groups,
-        statistics,
-        projection,
-        limit: proto.limit.as_ref().map(|sl| sl.limit as usize),
-        table_partition_cols,
-        output_ordering,
-    })
+    let nested_projection = proto
+        .nested_projection
+        .iter()
+        .map(|column| {
+            let field: Field = convert_required!(column.field)?;
+            Ok((column.column as usize, Arc::new(field)))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    Ok(FileScanConfig::new(object_store_url, file_schema)
+        .with_file_groups(file_groups)
+        .with_statistics(statistics)
+        .with_projection(projection)
+        .with_limit(proto.limit.as_ref().map(|sl| sl.limit as usize))
+        .with_table_partition_cols(table_partition_cols)
+        .with_output_ordering(output_ordering)
+        .with_nested_projection(nested_projection))
 }
 
 impl TryFrom<&protobuf::PartitionedFile> for PartitionedFile {
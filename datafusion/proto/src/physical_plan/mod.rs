@@ -24,6 +24,8 @@ use prost::Message;
 
 use datafusion::arrow::compute::SortOptions;
 use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::ipc::reader::StreamReader;
+use datafusion::arrow::ipc::writer::StreamWriter;
 use datafusion::datasource::file_format::csv::CsvSink;
 use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
 use datafusion::datasource::file_format::json::JsonSink;
@@ -58,6 +60,7 @@ use datafusion::physical_plan::repartition::RepartitionExec;
 use datafusion::physical_plan::sorts::sort::SortExec;
 use datafusion::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
 use datafusion::physical_plan::union::{InterleaveExec, UnionExec};
+use datafusion::physical_plan::values::ValuesExec;
 use datafusion::physical_plan::windows::{BoundedWindowAggExec, WindowAggExec};
 use datafusion::physical_plan::{
     ExecutionPlan, InputOrderMode, PhysicalExpr, WindowExpr,
@@ -818,6 +821,14 @@ impl AsExecutionPlan for protobuf::PhysicalPlanNode {
                 let schema = Arc::new(convert_required!(placeholder.schema)?);
                 Ok(Arc::new(PlaceholderRowExec::new(schema)))
             }
+            PhysicalPlanType::Values(values) => {
+                let schema: SchemaRef = Arc::new(convert_required!(values.schema)?);
+                let reader = StreamReader::try_new(values.data.as_slice(), None)?;
+                let batches = reader
+                    .map(|batch| Ok(batch?.with_schema(Arc::clone(&schema))?))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Arc::new(ValuesExec::try_new_from_batches(schema, batches)?))
+            }
             PhysicalPlanType::Sort(sort) => {
                 let input: Arc<dyn ExecutionPlan> =
                     into_physical_plan(&sort.input, registry, runtime, extension_codec)?;
@@ -1523,6 +1534,22 @@ impl AsExecutionPlan for protobuf::PhysicalPlanNode {
             });
         }
 
+        if let Some(values) = plan.downcast_ref::<ValuesExec>() {
+            let schema = values.schema();
+            let mut writer = StreamWriter::try_new(vec![], &schema)?;
+            for batch in values.data() {
+                writer.write(&batch)?;
+            }
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Values(
+                    protobuf::ValuesExecNode {
+                        schema: Some(schema.as_ref().try_into()?),
+                        data: writer.into_inner()?,
+                    },
+                )),
+            });
+        }
+
         if let Some(coalesce_batches) = plan.downcast_ref::<CoalesceBatchesExec>() {
             let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
                 coalesce_batches.input().to_owned(),
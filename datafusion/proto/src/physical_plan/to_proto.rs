@@ -448,6 +448,9 @@ pub fn serialize_partitioning(
                 *partition_count as u64,
             )),
         },
+        Partitioning::Range(..) => {
+            return not_impl_err!("Serializing range partitioning is not supported");
+        }
     };
     Ok(serialized_partitioning)
 }
@@ -539,6 +542,18 @@ pub fn serialize_file_scan_config(
     fields.extend(conf.table_partition_cols.iter().cloned().map(Arc::new));
     let schema = Arc::new(arrow::datatypes::Schema::new(fields.clone()));
 
+    let mut nested_projection = conf
+        .nested_projection()
+        .iter()
+        .map(|(column, field)| {
+            Ok(protobuf::NestedProjectionColumn {
+                column: *column as u32,
+                field: Some(field.as_ref().try_into()?),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    nested_projection.sort_by_key(|column| column.column);
+
     Ok(protobuf::FileScanExecConf {
         file_groups,
         statistics: Some((&conf.statistics).into()),
@@ -563,6 +578,7 @@ pub fn serialize_file_scan_config(
                 physical_sort_expr_nodes: e,
             })
             .collect::<Vec<_>>(),
+        nested_projection,
     })
 }
 
@@ -43,7 +43,7 @@ use datafusion::functions_aggregate::count::count_udaf;
 use datafusion::functions_aggregate::expr_fn::{
     approx_median, approx_percentile_cont, approx_percentile_cont_with_weight, count,
     count_distinct, covar_pop, covar_samp, first_value, grouping, max, median, min,
-    stddev, stddev_pop, sum, var_pop, var_sample,
+    percentile_cont, stddev, stddev_pop, sum, var_pop, var_sample,
 };
 use datafusion::functions_aggregate::min_max::max_udaf;
 use datafusion::functions_nested::map::map;
@@ -63,10 +63,10 @@ use datafusion_expr::expr::{
 };
 use datafusion_expr::logical_plan::{Extension, UserDefinedLogicalNodeCore};
 use datafusion_expr::{
-    Accumulator, AggregateUDF, ColumnarValue, ExprFunctionExt, ExprSchemable, Literal,
-    LogicalPlan, Operator, PartitionEvaluator, ScalarUDF, Signature, TryCast, Volatility,
-    WindowFrame, WindowFrameBound, WindowFrameUnits, WindowFunctionDefinition, WindowUDF,
-    WindowUDFImpl,
+    Accumulator, AggregateUDF, ColumnarValue, DdlStatement, ExprFunctionExt,
+    ExprSchemable, Literal, LogicalPlan, Operator, PartitionEvaluator, ScalarUDF,
+    Signature, TryCast, Volatility, WindowFrame, WindowFrameBound, WindowFrameUnits,
+    WindowFunctionDefinition, WindowUDF, WindowUDFImpl,
 };
 use datafusion_functions_aggregate::average::avg_udaf;
 use datafusion_functions_aggregate::expr_fn::{
@@ -279,6 +279,28 @@ async fn roundtrip_custom_listing_tables() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn roundtrip_create_or_replace_external_table() -> Result<()> {
+    let ctx = SessionContext::new();
+
+    let query = "CREATE OR REPLACE EXTERNAL TABLE t
+            STORED AS CSV
+            LOCATION '../core/tests/data/example.csv'
+            OPTIONS ('format.has_header' 'true')";
+
+    let plan = ctx.state().create_logical_plan(query).await?;
+    let LogicalPlan::Ddl(DdlStatement::CreateExternalTable(cmd)) = &plan else {
+        panic!("expected a CreateExternalTable plan, got {plan:?}");
+    };
+    assert!(cmd.or_replace);
+
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+    assert_eq!(plan, logical_round_trip);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_logical_plan_aggregation_with_pk() -> Result<()> {
     let ctx = SessionContext::new();
@@ -889,6 +911,7 @@ async fn roundtrip_expr_api() -> Result<()> {
         approx_percentile_cont(lit(2), lit(0.5), None),
         approx_percentile_cont(lit(2), lit(0.5), Some(lit(50))),
         approx_percentile_cont_with_weight(lit(2), lit(1), lit(0.5)),
+        percentile_cont(lit(2.0), lit(0.5)),
         grouping(lit(1)),
         bit_and(lit(2)),
         bit_or(lit(2)),
@@ -940,6 +963,26 @@ async fn roundtrip_logical_plan_with_view_scan() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn roundtrip_uncorrelated_scalar_subquery() -> Result<()> {
+    let ctx = SessionContext::new();
+    ctx.register_csv("t1", "tests/testdata/test.csv", CsvReadOptions::default())
+        .await?;
+
+    // the subquery is guarded by the `scalar_subquery_value` aggregate
+    let plan = ctx
+        .sql("SELECT a, (SELECT b FROM t1) FROM t1")
+        .await?
+        .into_optimized_plan()?;
+    assert!(format!("{plan}").contains("scalar_subquery_value"));
+
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+    assert_eq!(format!("{plan}"), format!("{logical_round_trip}"));
+
+    Ok(())
+}
+
 pub mod proto {
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TopKPlanProto {
@@ -22,7 +22,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 use std::vec;
 
-use arrow::array::RecordBatch;
+use arrow::array::{RecordBatch, RecordBatchOptions};
 use arrow::csv::WriterBuilder;
 use datafusion::physical_expr::aggregate::AggregateExprBuilder;
 use datafusion::physical_plan::coalesce_batches::CoalesceBatchesExec;
@@ -32,9 +32,9 @@ use datafusion_functions_aggregate::min_max::max_udaf;
 use prost::Message;
 
 use crate::cases::{MyAggregateUDF, MyAggregateUdfNode, MyRegexUdf, MyRegexUdfNode};
-use datafusion::arrow::array::ArrayRef;
+use datafusion::arrow::array::{ArrayRef, Int64Array, StringArray};
 use datafusion::arrow::compute::kernels::sort::SortOptions;
-use datafusion::arrow::datatypes::{DataType, Field, IntervalUnit, Schema};
+use datafusion::arrow::datatypes::{DataType, Field, Fields, IntervalUnit, Schema};
 use datafusion::datasource::file_format::csv::CsvSink;
 use datafusion::datasource::file_format::json::JsonSink;
 use datafusion::datasource::file_format::parquet::ParquetSink;
@@ -71,6 +71,7 @@ use datafusion::physical_plan::repartition::RepartitionExec;
 use datafusion::physical_plan::sorts::sort::SortExec;
 use datafusion::physical_plan::udaf::AggregateFunctionExpr;
 use datafusion::physical_plan::union::{InterleaveExec, UnionExec};
+use datafusion::physical_plan::values::ValuesExec;
 use datafusion::physical_plan::windows::{
     BuiltInWindowExpr, PlainAggregateWindowExpr, WindowAggExec,
 };
@@ -147,6 +148,50 @@ fn roundtrip_empty() -> Result<()> {
     roundtrip_test(Arc::new(EmptyExec::new(Arc::new(Schema::empty()))))
 }
 
+#[test]
+fn roundtrip_values() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        Arc::clone(&schema),
+        vec![
+            Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(StringArray::from(vec!["x", "y", "z"])),
+        ],
+    )?;
+    roundtrip_test(Arc::new(ValuesExec::try_new_from_batches(
+        schema,
+        vec![batch],
+    )?))
+}
+
+#[test]
+fn roundtrip_empty_values() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::new_empty(Arc::clone(&schema));
+    roundtrip_test(Arc::new(ValuesExec::try_new_from_batches(
+        Arc::clone(&schema),
+        vec![batch],
+    )?))?;
+
+    // values without columns still have rows
+    let schema = Arc::new(Schema::empty());
+    let batch = RecordBatch::try_new_with_options(
+        Arc::clone(&schema),
+        vec![],
+        &RecordBatchOptions::new().with_row_count(Some(2)),
+    )?;
+    roundtrip_test(Arc::new(ValuesExec::try_new_from_batches(
+        schema,
+        vec![batch],
+    )?))
+}
+
 #[test]
 fn roundtrip_date_time_interval() -> Result<()> {
     let schema = Schema::new(vec![
@@ -647,29 +692,19 @@ fn roundtrip_coalesce_with_fetch() -> Result<()> {
 
 #[test]
 fn roundtrip_parquet_exec_with_pruning_predicate() -> Result<()> {
-    let scan_config = FileScanConfig {
-        object_store_url: ObjectStoreUrl::local_filesystem(),
-        file_schema: Arc::new(Schema::new(vec![Field::new(
-            "col",
-            DataType::Utf8,
-            false,
-        )])),
-        file_groups: vec![vec![PartitionedFile::new(
-            "/path/to/file.parquet".to_string(),
-            1024,
-        )]],
-        statistics: Statistics {
-            num_rows: Precision::Inexact(100),
-            total_byte_size: Precision::Inexact(1024),
-            column_statistics: Statistics::unknown_column(&Arc::new(Schema::new(vec![
-                Field::new("col", DataType::Utf8, false),
-            ]))),
-        },
-        projection: None,
-        limit: None,
-        table_partition_cols: vec![],
-        output_ordering: vec![],
-    };
+    let file_schema =
+        Arc::new(Schema::new(vec![Field::new("col", DataType::Utf8, false)]));
+    let scan_config =
+        FileScanConfig::new(ObjectStoreUrl::local_filesystem(), file_schema.clone())
+            .with_file(PartitionedFile::new(
+                "/path/to/file.parquet".to_string(),
+                1024,
+            ))
+            .with_statistics(Statistics {
+                num_rows: Precision::Inexact(100),
+                total_byte_size: Precision::Inexact(1024),
+                column_statistics: Statistics::unknown_column(&file_schema),
+            });
 
     let predicate = Arc::new(BinaryExpr::new(
         Arc::new(Column::new("col", 1)),
@@ -691,49 +726,67 @@ async fn roundtrip_parquet_exec_with_table_partition_cols() -> Result<()> {
         vec![wrap_partition_value_in_dict(ScalarValue::Int64(Some(0)))];
     let schema = Arc::new(Schema::new(vec![Field::new("col", DataType::Utf8, false)]));
 
-    let scan_config = FileScanConfig {
-        object_store_url: ObjectStoreUrl::local_filesystem(),
-        file_groups: vec![vec![file_group]],
-        statistics: Statistics::new_unknown(&schema),
-        file_schema: schema,
-        projection: Some(vec![0, 1]),
-        limit: None,
-        table_partition_cols: vec![Field::new(
+    let scan_config = FileScanConfig::new(ObjectStoreUrl::local_filesystem(), schema)
+        .with_file(file_group)
+        .with_projection(Some(vec![0, 1]))
+        .with_table_partition_cols(vec![Field::new(
             "part".to_string(),
             wrap_partition_type_in_dict(DataType::Int16),
             false,
-        )],
-        output_ordering: vec![],
-    };
+        )]);
 
     roundtrip_test(ParquetExec::builder(scan_config).build_arc())
 }
 
 #[test]
-fn roundtrip_parquet_exec_with_custom_predicate_expr() -> Result<()> {
-    let scan_config = FileScanConfig {
-        object_store_url: ObjectStoreUrl::local_filesystem(),
-        file_schema: Arc::new(Schema::new(vec![Field::new(
-            "col",
-            DataType::Utf8,
-            false,
-        )])),
-        file_groups: vec![vec![PartitionedFile::new(
+fn roundtrip_parquet_exec_with_nested_projection() -> Result<()> {
+    let address = DataType::Struct(Fields::from(vec![
+        Field::new("street", DataType::Utf8, true),
+        Field::new("zip", DataType::Utf8, true),
+    ]));
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        Field::new("address", address, true),
+    ]));
+    let scan_config = FileScanConfig::new(ObjectStoreUrl::local_filesystem(), schema)
+        .with_file(PartitionedFile::new(
             "/path/to/file.parquet".to_string(),
             1024,
-        )]],
-        statistics: Statistics {
-            num_rows: Precision::Inexact(100),
-            total_byte_size: Precision::Inexact(1024),
-            column_statistics: Statistics::unknown_column(&Arc::new(Schema::new(vec![
-                Field::new("col", DataType::Utf8, false),
-            ]))),
-        },
-        projection: None,
-        limit: None,
-        table_partition_cols: vec![],
-        output_ordering: vec![],
-    };
+        ))
+        .with_projection_by_names(&["address.zip", "id"])?;
+    assert!(!scan_config.nested_projection().is_empty());
+    let exec_plan = ParquetExec::builder(scan_config).build_arc();
+
+    let ctx = SessionContext::new();
+    let codec = DefaultPhysicalExtensionCodec {};
+    let result = roundtrip_test_and_return(exec_plan.clone(), &ctx, &codec)?;
+    let result = result.as_any().downcast_ref::<ParquetExec>().unwrap();
+    assert_eq!(
+        result.base_config().nested_projection(),
+        exec_plan.base_config().nested_projection()
+    );
+    assert_eq!(
+        result.base_config().file_schema,
+        exec_plan.base_config().file_schema
+    );
+    Ok(())
+}
+
+#[test]
+fn roundtrip_parquet_exec_with_custom_predicate_expr() -> Result<()> {
+    let file_schema =
+        Arc::new(Schema::new(vec![Field::new("col", DataType::Utf8, false)]));
+    let scan_config =
+        FileScanConfig::new(ObjectStoreUrl::local_filesystem(), file_schema.clone())
+            .with_file(PartitionedFile::new(
+                "/path/to/file.parquet".to_string(),
+                1024,
+            ))
+            .with_statistics(Statistics {
+                num_rows: Precision::Inexact(100),
+                total_byte_size: Precision::Inexact(1024),
+                column_statistics: Statistics::unknown_column(&file_schema),
+            });
 
     #[derive(Debug, Hash, Clone)]
     struct CustomPredicateExpr {
@@ -22,7 +22,7 @@ use datafusion_expr::planner::{
 };
 use sqlparser::ast::{
     BinaryOperator, CastKind, DictionaryField, Expr as SQLExpr, MapEntry, StructField,
-    Subscript, TrimWhereField, Value,
+    Subscript, TrimWhereField, UnaryOperator, Value,
 };
 
 use datafusion_common::{
@@ -37,6 +37,7 @@ use datafusion_expr::{
 };
 
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
+use value::infer_decimal_literal;
 
 mod binary_op;
 mod function;
@@ -48,6 +49,22 @@ mod substring;
 mod unary_op;
 mod value;
 
+/// Returns the unsigned text and sign of `sql` if it is a number literal,
+/// such as `1.05` or `-1.05`
+fn sql_number(sql: &SQLExpr) -> Option<(String, bool)> {
+    match sql {
+        SQLExpr::Value(Value::Number(n, _)) => Some((n.clone(), false)),
+        SQLExpr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr,
+        } => match expr.as_ref() {
+            SQLExpr::Value(Value::Number(n, _)) => Some((n.clone(), true)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 impl<'a, S: ContextProvider> SqlToRel<'a, S> {
     pub(crate) fn sql_expr_to_logical_expr(
         &self,
@@ -79,12 +96,13 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                             stack.push(StackEntry::SQLExpr(left));
                         }
                         _ => {
+                            let number = sql_number(&sql_expr);
                             let expr = self.sql_expr_to_logical_expr_internal(
                                 *sql_expr,
                                 schema,
                                 planner_context,
                             )?;
-                            eval_stack.push(expr);
+                            eval_stack.push((expr, number));
                         }
                     }
                 }
@@ -92,21 +110,21 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                     let right = eval_stack.pop().unwrap();
                     let left = eval_stack.pop().unwrap();
                     let expr = self.build_logical_expr(op, left, right, schema)?;
-                    eval_stack.push(expr);
+                    eval_stack.push((expr, None));
                 }
             }
         }
 
         assert_eq!(1, eval_stack.len());
-        let expr = eval_stack.pop().unwrap();
+        let (expr, _) = eval_stack.pop().unwrap();
         Ok(expr)
     }
 
     fn build_logical_expr(
         &self,
         op: BinaryOperator,
-        left: Expr,
-        right: Expr,
+        (left, left_number): (Expr, Option<(String, bool)>),
+        (right, right_number): (Expr, Option<(String, bool)>),
         schema: &DFSchema,
     ) -> Result<Expr> {
         // try extension planers
@@ -123,9 +141,18 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }
 
         let RawBinaryExpr { op, left, right } = binary_expr;
+        let op = self.parse_sql_binary_op(op)?;
+        let (left, right) = if op.is_numerical_operators() || op.is_comparison_operator()
+        {
+            let left = infer_decimal_literal(left, left_number, &right, schema);
+            let right = infer_decimal_literal(right, right_number, &left, schema);
+            (left, right)
+        } else {
+            (left, right)
+        };
         Ok(Expr::BinaryExpr(BinaryExpr::new(
             Box::new(left),
-            self.parse_sql_binary_op(op)?,
+            op,
             Box::new(right),
         )))
     }
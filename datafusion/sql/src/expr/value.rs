@@ -17,14 +17,14 @@
 
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 use arrow::compute::kernels::cast_utils::parse_interval_month_day_nano;
-use arrow::datatypes::DECIMAL128_MAX_PRECISION;
+use arrow::datatypes::{i256, DECIMAL128_MAX_PRECISION};
 use arrow_schema::DataType;
 use datafusion_common::{
     internal_err, not_impl_err, plan_err, DFSchema, DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::expr::{BinaryExpr, Placeholder};
 use datafusion_expr::planner::PlannerResult;
-use datafusion_expr::{lit, Expr, Operator};
+use datafusion_expr::{lit, Expr, ExprSchemable, Operator};
 use log::debug;
 use sqlparser::ast::{BinaryOperator, Expr as SQLExpr, Interval, Value};
 use sqlparser::parser::ParserError::ParserError;
@@ -407,6 +407,44 @@ const fn try_decode_hex_char(c: u8) -> Option<u8> {
     }
 }
 
+/// Returns the float literal `expr` as a decimal if `other` is a decimal, so
+/// that comparing or combining them does not convert the decimal to a float.
+///
+/// `number` is the unsigned text and sign of the number as written in the
+/// SQL, which is parsed instead of the float so that no digits are lost.
+/// Other expressions and numbers that do not fit a decimal are kept.
+pub(super) fn infer_decimal_literal(
+    expr: Expr,
+    number: Option<(String, bool)>,
+    other: &Expr,
+    schema: &DFSchema,
+) -> Expr {
+    let (Expr::Literal(ScalarValue::Float64(Some(_))), Some((number, negative))) =
+        (&expr, number)
+    else {
+        return expr;
+    };
+    let Ok(other_type @ (DataType::Decimal128(_, _) | DataType::Decimal256(_, _))) =
+        other.get_type(schema)
+    else {
+        return expr;
+    };
+    let Ok(decimal) = parse_decimal_128(&number, negative) else {
+        return expr;
+    };
+    match (decimal, other_type) {
+        (
+            Expr::Literal(ScalarValue::Decimal128(value, precision, scale)),
+            DataType::Decimal256(_, _),
+        ) => Expr::Literal(ScalarValue::Decimal256(
+            value.map(i256::from_i128),
+            precision,
+            scale,
+        )),
+        (decimal, _) => decimal,
+    }
+}
+
 /// Parse Decimal128 from a string
 ///
 /// TODO: support parsing from scientific notation
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::collections::HashSet;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
 use crate::planner::{
@@ -41,8 +42,8 @@ use datafusion_expr::{
     GroupingSet, LogicalPlan, LogicalPlanBuilder, Partitioning,
 };
 use sqlparser::ast::{
-    Distinct, Expr as SQLExpr, GroupByExpr, NamedWindowExpr, OrderByExpr,
-    WildcardAdditionalOptions, WindowType,
+    Distinct, Expr as SQLExpr, GroupByExpr, Ident, NamedWindowExpr, OrderByExpr, Query,
+    VisitMut, VisitorMut, WildcardAdditionalOptions, WindowSpec, WindowType,
 };
 use sqlparser::ast::{NamedWindowDefinition, Select, SelectItem, TableWithJoins};
 
@@ -805,35 +806,138 @@ fn check_conflicting_windows(window_defs: &[NamedWindowDefinition]) -> Result<()
 // If the projection is done over a named window, that window
 // name must be defined. Otherwise, it gives an error.
 fn match_window_definitions(
-    projection: &mut [SelectItem],
+    projection: &mut Vec<SelectItem>,
     named_windows: &[NamedWindowDefinition],
 ) -> Result<()> {
-    for proj in projection.iter_mut() {
-        if let SelectItem::ExprWithAlias {
-            expr: SQLExpr::Function(f),
-            alias: _,
+    let mut matcher = WindowDefinitionMatcher {
+        named_windows: resolve_named_windows(named_windows)?,
+        query_depth: 0,
+    };
+    match projection.visit(&mut matcher) {
+        ControlFlow::Break(e) => Err(e),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+/// Resolve the `WINDOW` clause into the window specifications it names.
+///
+/// A definition can be based on a window defined before it, either by
+/// copying it (`w2 AS w1`) or by extending it (`w2 AS (w1 ORDER BY c)`).
+/// Following the SQL standard, an extension inherits the `PARTITION BY` of
+/// the base window, may only add an `ORDER BY` if the base window has none,
+/// and cannot be based on a window with a frame clause.
+fn resolve_named_windows(
+    named_windows: &[NamedWindowDefinition],
+) -> Result<Vec<(Ident, WindowSpec)>> {
+    let mut resolved: Vec<(Ident, WindowSpec)> = vec![];
+    for NamedWindowDefinition(window_ident, window_expr) in named_windows {
+        let lookup = |ident: &Ident| -> Result<WindowSpec> {
+            if let Some((_, spec)) = resolved.iter().find(|(name, _)| name == ident) {
+                Ok(spec.clone())
+            } else if named_windows.iter().any(|def| &def.0 == ident) {
+                plan_err!(
+                    "The window {ident} must be defined before the window {window_ident}"
+                )
+            } else {
+                plan_err!("The window {ident} is not defined!")
+            }
+        };
+        let spec = match window_expr {
+            NamedWindowExpr::NamedWindow(ident) => lookup(ident)?,
+            NamedWindowExpr::WindowSpec(spec) => match &spec.window_name {
+                Some(ident) => extend_window_spec(ident, lookup(ident)?, spec.clone())?,
+                None => spec.clone(),
+            },
+        };
+        resolved.push((window_ident.clone(), spec));
+    }
+    Ok(resolved)
+}
+
+/// Merge `spec`, which refers to the window `base_ident`, with the
+/// definition `base` of that window
+fn extend_window_spec(
+    base_ident: &Ident,
+    base: WindowSpec,
+    spec: WindowSpec,
+) -> Result<WindowSpec> {
+    if !spec.partition_by.is_empty() {
+        return plan_err!("Cannot override PARTITION BY clause of window {base_ident}");
+    }
+    if !spec.order_by.is_empty() && !base.order_by.is_empty() {
+        return plan_err!("Cannot override ORDER BY clause of window {base_ident}");
+    }
+    if base.window_frame.is_some() {
+        return plan_err!(
+            "Cannot copy window {base_ident} because it has a frame clause"
+        );
+    }
+    let order_by = if spec.order_by.is_empty() {
+        base.order_by
+    } else {
+        spec.order_by
+    };
+    Ok(WindowSpec {
+        window_name: None,
+        partition_by: base.partition_by,
+        order_by,
+        window_frame: spec.window_frame,
+    })
+}
+
+/// Replaces the references to named windows in the `OVER` clauses of an
+/// expression with the window specifications they name. The expressions of
+/// subqueries are left untouched, they are resolved against the `WINDOW`
+/// clause of their own query.
+struct WindowDefinitionMatcher {
+    named_windows: Vec<(Ident, WindowSpec)>,
+    query_depth: usize,
+}
+
+impl WindowDefinitionMatcher {
+    fn lookup(&self, ident: &Ident) -> Result<WindowSpec> {
+        match self.named_windows.iter().find(|(name, _)| name == ident) {
+            Some((_, spec)) => Ok(spec.clone()),
+            None => plan_err!("The window {ident} is not defined!"),
         }
-        | SelectItem::UnnamedExpr(SQLExpr::Function(f)) = proj
-        {
-            for NamedWindowDefinition(window_ident, window_expr) in named_windows.iter() {
-                if let Some(WindowType::NamedWindow(ident)) = &f.over {
-                    if ident.eq(window_ident) {
-                        f.over = Some(match window_expr {
-                            NamedWindowExpr::NamedWindow(ident) => {
-                                WindowType::NamedWindow(ident.clone())
-                            }
-                            NamedWindowExpr::WindowSpec(spec) => {
-                                WindowType::WindowSpec(spec.clone())
-                            }
-                        })
+    }
+
+    fn resolve(&self, over: WindowType) -> Result<WindowType> {
+        let spec = match over {
+            WindowType::NamedWindow(ident) => self.lookup(&ident)?,
+            WindowType::WindowSpec(spec) => match spec.window_name.clone() {
+                Some(ident) => extend_window_spec(&ident, self.lookup(&ident)?, spec)?,
+                None => spec,
+            },
+        };
+        Ok(WindowType::WindowSpec(spec))
+    }
+}
+
+impl VisitorMut for WindowDefinitionMatcher {
+    type Break = DataFusionError;
+
+    fn pre_visit_query(&mut self, _query: &mut Query) -> ControlFlow<Self::Break> {
+        self.query_depth += 1;
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_query(&mut self, _query: &mut Query) -> ControlFlow<Self::Break> {
+        self.query_depth -= 1;
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_expr(&mut self, expr: &mut SQLExpr) -> ControlFlow<Self::Break> {
+        if self.query_depth == 0 {
+            if let SQLExpr::Function(f) = expr {
+                if let Some(over) = f.over.take() {
+                    match self.resolve(over) {
+                        Ok(over) => f.over = Some(over),
+                        Err(e) => return ControlFlow::Break(e),
                     }
                 }
             }
-            // All named windows must be defined with a WindowSpec.
-            if let Some(WindowType::NamedWindow(ident)) = &f.over {
-                return plan_err!("The window {ident} is not defined!");
-            }
         }
+        ControlFlow::Continue(())
     }
-    Ok(())
 }
@@ -994,6 +994,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             location,
             table_partition_cols,
             if_not_exists,
+            or_replace,
             order_exprs,
             unbounded,
             options,
@@ -1035,8 +1036,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         let ordered_exprs =
             self.build_order_by(order_exprs, &df_schema, &mut planner_context)?;
 
-        // External tables do not support schemas at the moment, so the name is just a table name
-        let name = TableReference::bare(name);
+        let name = self.object_name_to_table_reference(name)?;
         let constraints =
             Constraints::new_from_table_constraints(&all_constraints, &df_schema)?;
         Ok(LogicalPlan::Ddl(DdlStatement::CreateExternalTable(
@@ -1047,6 +1047,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 file_type,
                 table_partition_cols,
                 if_not_exists,
+                or_replace,
                 definition,
                 order_exprs: ordered_exprs,
                 unbounded,
@@ -1475,7 +1476,20 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             PlannerContext::new().with_prepare_param_data_types(prepare_param_data_types);
         let source = self.query_to_plan(*source, &mut planner_context)?;
         if fields.len() != source.schema().fields().len() {
-            plan_err!("Column count doesn't match insert query!")?;
+            let names = |fields: &Fields| {
+                fields
+                    .iter()
+                    .map(|f| f.name().as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            plan_err!(
+                "Column count doesn't match insert query! Expected {} columns ({}) but the query has {} columns ({})",
+                fields.len(),
+                names(&fields),
+                source.schema().fields().len(),
+                names(source.schema().fields())
+            )?;
         }
 
         let exprs = value_indices
@@ -559,11 +559,11 @@ Dml: op=[Insert Into] table=[test_decimal]
 )]
 #[case::target_column_count_mismatch(
     "INSERT INTO person (id, first_name, last_name) VALUES ($1, $2)",
-    "Error during planning: Column count doesn't match insert query!"
+    "Error during planning: Column count doesn't match insert query! Expected 3 columns (id, first_name, last_name) but the query has 2 columns (column1, column2)"
 )]
 #[case::source_column_count_mismatch(
     "INSERT INTO person VALUES ($1, $2)",
-    "Error during planning: Column count doesn't match insert query!"
+    "Error during planning: Column count doesn't match insert query! Expected 8 columns (id, first_name, last_name, age, state, salary, birth_date, 😀) but the query has 2 columns (column1, column2)"
 )]
 #[case::extra_placeholder(
     "INSERT INTO person (id, first_name, last_name) VALUES ($1, $2, $3, $4)",